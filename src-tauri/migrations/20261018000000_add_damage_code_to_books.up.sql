@@ -0,0 +1,24 @@
+-- Mark books that could only be opened partially (e.g. a truncated ZIP salvaged from
+-- its local file headers). The column holds the error code explaining the damage and
+-- is NULL for books that opened cleanly.
+ALTER TABLE books ADD COLUMN damage_code INTEGER;
+
+-- Recreate the view so `damage_code` is surfaced alongside the other book columns.
+DROP VIEW IF EXISTS book_with_state_view;
+CREATE VIEW book_with_state_view AS
+SELECT
+    b.id,
+    b.file_path,
+    b.item_type,
+    b.display_name,
+    b.total_pages,
+    b.series_id,
+    b.series_order,
+    b.thumbnail_path,
+    b.created_at,
+    b.damage_code,
+    r.last_read_page_index,
+    r.last_opened_at,
+    CAST((SELECT GROUP_CONCAT(tag_id) FROM book_tags WHERE book_id = b.id) AS TEXT) AS tag_ids_str
+FROM books b
+LEFT JOIN reading_state r ON b.id = r.book_id;
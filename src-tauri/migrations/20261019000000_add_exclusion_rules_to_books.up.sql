@@ -0,0 +1,4 @@
+-- Per-book entry exclusion rules, applied on top of the global ones from the settings.
+-- The column holds a JSON array of `{ "pattern": ..., "syntax": "glob" | "regex" }`
+-- objects and is NULL for books without rules of their own.
+ALTER TABLE books ADD COLUMN exclusion_rules TEXT;
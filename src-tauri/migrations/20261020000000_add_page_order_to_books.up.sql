@@ -0,0 +1,3 @@
+-- A manual page order overriding the sort mode for one book. The column holds a JSON
+-- array of entry names and is NULL for books without one.
+ALTER TABLE books ADD COLUMN page_order TEXT;
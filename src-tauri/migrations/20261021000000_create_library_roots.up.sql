@@ -0,0 +1,36 @@
+-- Folders watched for books. Supported files found below a root are registered as
+-- books by the library scanner.
+CREATE TABLE library_roots (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    path TEXT UNIQUE NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    last_scanned_at DATETIME
+);
+
+-- The modification time (seconds since the Unix epoch) of the book file when it was
+-- last indexed, so a rescan only re-reads files that changed. NULL for books that were
+-- never indexed by the scanner.
+ALTER TABLE books ADD COLUMN file_modified_at INTEGER;
+-- When the scanner found the book file gone. NULL while the file exists.
+ALTER TABLE books ADD COLUMN missing_since DATETIME;
+
+-- Recreate the view so `missing_since` is surfaced alongside the other book columns.
+DROP VIEW IF EXISTS book_with_state_view;
+CREATE VIEW book_with_state_view AS
+SELECT
+    b.id,
+    b.file_path,
+    b.item_type,
+    b.display_name,
+    b.total_pages,
+    b.series_id,
+    b.series_order,
+    b.thumbnail_path,
+    b.created_at,
+    b.damage_code,
+    b.missing_since,
+    r.last_read_page_index,
+    r.last_opened_at,
+    CAST((SELECT GROUP_CONCAT(tag_id) FROM book_tags WHERE book_id = b.id) AS TEXT) AS tag_ids_str
+FROM books b
+LEFT JOIN reading_state r ON b.id = r.book_id;
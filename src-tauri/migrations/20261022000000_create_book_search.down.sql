@@ -0,0 +1,13 @@
+DROP TRIGGER IF EXISTS book_search_metadata_delete;
+DROP TRIGGER IF EXISTS book_search_metadata_update;
+DROP TRIGGER IF EXISTS book_search_metadata_insert;
+DROP TRIGGER IF EXISTS book_search_series_update;
+DROP TRIGGER IF EXISTS book_search_tags_update;
+DROP TRIGGER IF EXISTS book_search_book_tags_delete;
+DROP TRIGGER IF EXISTS book_search_book_tags_insert;
+DROP TRIGGER IF EXISTS book_search_books_delete;
+DROP TRIGGER IF EXISTS book_search_books_update;
+DROP TRIGGER IF EXISTS book_search_books_insert;
+DROP TABLE IF EXISTS book_search;
+DROP VIEW IF EXISTS book_search_source;
+DROP TABLE IF EXISTS book_metadata;
@@ -0,0 +1,157 @@
+-- Metadata read from the book file itself (EPUB package metadata or ComicInfo.xml).
+CREATE TABLE book_metadata (
+    book_id INTEGER PRIMARY KEY NOT NULL,
+    -- The authors, joined with ", ".
+    authors TEXT,
+    publisher TEXT,
+    description TEXT,
+    FOREIGN KEY (book_id) REFERENCES books(id) ON DELETE CASCADE
+);
+
+-- The text indexed for each book. The triggers below re-read a book's row from this
+-- view whenever anything it is built from changes.
+CREATE VIEW book_search_source AS
+SELECT
+    b.id AS book_id,
+    b.display_name,
+    b.file_path,
+    s.name AS series_name,
+    (
+        SELECT GROUP_CONCAT(t.name, ' ')
+        FROM book_tags bt
+        JOIN tags t ON t.id = bt.tag_id
+        WHERE bt.book_id = b.id
+    ) AS tag_names,
+    m.authors,
+    m.publisher,
+    m.description
+FROM books b
+LEFT JOIN series s ON s.id = b.series_id
+LEFT JOIN book_metadata m ON m.book_id = b.id;
+
+-- Full-text index keyed by book ID (rowid). The trigram tokenizer matches substrings,
+-- which also works for Japanese titles that have no spaces between words.
+CREATE VIRTUAL TABLE book_search USING fts5(
+    display_name,
+    file_path,
+    series_name,
+    tag_names,
+    authors,
+    publisher,
+    description,
+    tokenize = 'trigram'
+);
+
+INSERT INTO book_search (
+    rowid, display_name, file_path, series_name, tag_names, authors, publisher, description
+)
+SELECT
+    book_id, display_name, file_path, series_name, tag_names, authors, publisher, description
+FROM book_search_source;
+
+CREATE TRIGGER book_search_books_insert AFTER INSERT ON books
+BEGIN
+    INSERT INTO book_search (
+        rowid, display_name, file_path, series_name, tag_names, authors, publisher, description
+    )
+    SELECT
+        book_id, display_name, file_path, series_name, tag_names, authors, publisher, description
+    FROM book_search_source WHERE book_id = NEW.id;
+END;
+
+CREATE TRIGGER book_search_books_update AFTER UPDATE OF display_name, file_path, series_id ON books
+BEGIN
+    DELETE FROM book_search WHERE rowid = NEW.id;
+    INSERT INTO book_search (
+        rowid, display_name, file_path, series_name, tag_names, authors, publisher, description
+    )
+    SELECT
+        book_id, display_name, file_path, series_name, tag_names, authors, publisher, description
+    FROM book_search_source WHERE book_id = NEW.id;
+END;
+
+CREATE TRIGGER book_search_books_delete AFTER DELETE ON books
+BEGIN
+    DELETE FROM book_search WHERE rowid = OLD.id;
+END;
+
+CREATE TRIGGER book_search_book_tags_insert AFTER INSERT ON book_tags
+BEGIN
+    DELETE FROM book_search WHERE rowid = NEW.book_id;
+    INSERT INTO book_search (
+        rowid, display_name, file_path, series_name, tag_names, authors, publisher, description
+    )
+    SELECT
+        book_id, display_name, file_path, series_name, tag_names, authors, publisher, description
+    FROM book_search_source WHERE book_id = NEW.book_id;
+END;
+
+CREATE TRIGGER book_search_book_tags_delete AFTER DELETE ON book_tags
+BEGIN
+    DELETE FROM book_search WHERE rowid = OLD.book_id;
+    INSERT INTO book_search (
+        rowid, display_name, file_path, series_name, tag_names, authors, publisher, description
+    )
+    SELECT
+        book_id, display_name, file_path, series_name, tag_names, authors, publisher, description
+    FROM book_search_source WHERE book_id = OLD.book_id;
+END;
+
+CREATE TRIGGER book_search_tags_update AFTER UPDATE OF name ON tags
+BEGIN
+    DELETE FROM book_search
+    WHERE rowid IN (SELECT book_id FROM book_tags WHERE tag_id = NEW.id);
+    INSERT INTO book_search (
+        rowid, display_name, file_path, series_name, tag_names, authors, publisher, description
+    )
+    SELECT
+        book_id, display_name, file_path, series_name, tag_names, authors, publisher, description
+    FROM book_search_source
+    WHERE book_id IN (SELECT book_id FROM book_tags WHERE tag_id = NEW.id);
+END;
+
+CREATE TRIGGER book_search_series_update AFTER UPDATE OF name ON series
+BEGIN
+    DELETE FROM book_search
+    WHERE rowid IN (SELECT id FROM books WHERE series_id = NEW.id);
+    INSERT INTO book_search (
+        rowid, display_name, file_path, series_name, tag_names, authors, publisher, description
+    )
+    SELECT
+        book_id, display_name, file_path, series_name, tag_names, authors, publisher, description
+    FROM book_search_source
+    WHERE book_id IN (SELECT id FROM books WHERE series_id = NEW.id);
+END;
+
+CREATE TRIGGER book_search_metadata_insert AFTER INSERT ON book_metadata
+BEGIN
+    DELETE FROM book_search WHERE rowid = NEW.book_id;
+    INSERT INTO book_search (
+        rowid, display_name, file_path, series_name, tag_names, authors, publisher, description
+    )
+    SELECT
+        book_id, display_name, file_path, series_name, tag_names, authors, publisher, description
+    FROM book_search_source WHERE book_id = NEW.book_id;
+END;
+
+CREATE TRIGGER book_search_metadata_update AFTER UPDATE ON book_metadata
+BEGIN
+    DELETE FROM book_search WHERE rowid = NEW.book_id;
+    INSERT INTO book_search (
+        rowid, display_name, file_path, series_name, tag_names, authors, publisher, description
+    )
+    SELECT
+        book_id, display_name, file_path, series_name, tag_names, authors, publisher, description
+    FROM book_search_source WHERE book_id = NEW.book_id;
+END;
+
+CREATE TRIGGER book_search_metadata_delete AFTER DELETE ON book_metadata
+BEGIN
+    DELETE FROM book_search WHERE rowid = OLD.book_id;
+    INSERT INTO book_search (
+        rowid, display_name, file_path, series_name, tag_names, authors, publisher, description
+    )
+    SELECT
+        book_id, display_name, file_path, series_name, tag_names, authors, publisher, description
+    FROM book_search_source WHERE book_id = OLD.book_id;
+END;
@@ -0,0 +1,128 @@
+-- Restore the triggers that write the search index directly, and rebuild it from the
+-- unnormalized text.
+DROP TRIGGER IF EXISTS book_search_books_insert;
+DROP TRIGGER IF EXISTS book_search_books_update;
+DROP TRIGGER IF EXISTS book_search_books_delete;
+DROP TRIGGER IF EXISTS book_search_book_tags_insert;
+DROP TRIGGER IF EXISTS book_search_book_tags_delete;
+DROP TRIGGER IF EXISTS book_search_tags_update;
+DROP TRIGGER IF EXISTS book_search_series_update;
+DROP TRIGGER IF EXISTS book_search_metadata_insert;
+DROP TRIGGER IF EXISTS book_search_metadata_update;
+DROP TRIGGER IF EXISTS book_search_metadata_delete;
+DROP TABLE IF EXISTS book_search_dirty;
+
+DELETE FROM book_search;
+INSERT INTO book_search (
+    rowid, display_name, file_path, series_name, tag_names, authors, publisher, description
+)
+SELECT
+    book_id, display_name, file_path, series_name, tag_names, authors, publisher, description
+FROM book_search_source;
+
+CREATE TRIGGER book_search_books_insert AFTER INSERT ON books
+BEGIN
+    INSERT INTO book_search (
+        rowid, display_name, file_path, series_name, tag_names, authors, publisher, description
+    )
+    SELECT
+        book_id, display_name, file_path, series_name, tag_names, authors, publisher, description
+    FROM book_search_source WHERE book_id = NEW.id;
+END;
+
+CREATE TRIGGER book_search_books_update AFTER UPDATE OF display_name, file_path, series_id ON books
+BEGIN
+    DELETE FROM book_search WHERE rowid = NEW.id;
+    INSERT INTO book_search (
+        rowid, display_name, file_path, series_name, tag_names, authors, publisher, description
+    )
+    SELECT
+        book_id, display_name, file_path, series_name, tag_names, authors, publisher, description
+    FROM book_search_source WHERE book_id = NEW.id;
+END;
+
+CREATE TRIGGER book_search_books_delete AFTER DELETE ON books
+BEGIN
+    DELETE FROM book_search WHERE rowid = OLD.id;
+END;
+
+CREATE TRIGGER book_search_book_tags_insert AFTER INSERT ON book_tags
+BEGIN
+    DELETE FROM book_search WHERE rowid = NEW.book_id;
+    INSERT INTO book_search (
+        rowid, display_name, file_path, series_name, tag_names, authors, publisher, description
+    )
+    SELECT
+        book_id, display_name, file_path, series_name, tag_names, authors, publisher, description
+    FROM book_search_source WHERE book_id = NEW.book_id;
+END;
+
+CREATE TRIGGER book_search_book_tags_delete AFTER DELETE ON book_tags
+BEGIN
+    DELETE FROM book_search WHERE rowid = OLD.book_id;
+    INSERT INTO book_search (
+        rowid, display_name, file_path, series_name, tag_names, authors, publisher, description
+    )
+    SELECT
+        book_id, display_name, file_path, series_name, tag_names, authors, publisher, description
+    FROM book_search_source WHERE book_id = OLD.book_id;
+END;
+
+CREATE TRIGGER book_search_tags_update AFTER UPDATE OF name ON tags
+BEGIN
+    DELETE FROM book_search
+    WHERE rowid IN (SELECT book_id FROM book_tags WHERE tag_id = NEW.id);
+    INSERT INTO book_search (
+        rowid, display_name, file_path, series_name, tag_names, authors, publisher, description
+    )
+    SELECT
+        book_id, display_name, file_path, series_name, tag_names, authors, publisher, description
+    FROM book_search_source
+    WHERE book_id IN (SELECT book_id FROM book_tags WHERE tag_id = NEW.id);
+END;
+
+CREATE TRIGGER book_search_series_update AFTER UPDATE OF name ON series
+BEGIN
+    DELETE FROM book_search
+    WHERE rowid IN (SELECT id FROM books WHERE series_id = NEW.id);
+    INSERT INTO book_search (
+        rowid, display_name, file_path, series_name, tag_names, authors, publisher, description
+    )
+    SELECT
+        book_id, display_name, file_path, series_name, tag_names, authors, publisher, description
+    FROM book_search_source
+    WHERE book_id IN (SELECT id FROM books WHERE series_id = NEW.id);
+END;
+
+CREATE TRIGGER book_search_metadata_insert AFTER INSERT ON book_metadata
+BEGIN
+    DELETE FROM book_search WHERE rowid = NEW.book_id;
+    INSERT INTO book_search (
+        rowid, display_name, file_path, series_name, tag_names, authors, publisher, description
+    )
+    SELECT
+        book_id, display_name, file_path, series_name, tag_names, authors, publisher, description
+    FROM book_search_source WHERE book_id = NEW.book_id;
+END;
+
+CREATE TRIGGER book_search_metadata_update AFTER UPDATE ON book_metadata
+BEGIN
+    DELETE FROM book_search WHERE rowid = NEW.book_id;
+    INSERT INTO book_search (
+        rowid, display_name, file_path, series_name, tag_names, authors, publisher, description
+    )
+    SELECT
+        book_id, display_name, file_path, series_name, tag_names, authors, publisher, description
+    FROM book_search_source WHERE book_id = NEW.book_id;
+END;
+
+CREATE TRIGGER book_search_metadata_delete AFTER DELETE ON book_metadata
+BEGIN
+    DELETE FROM book_search WHERE rowid = OLD.book_id;
+    INSERT INTO book_search (
+        rowid, display_name, file_path, series_name, tag_names, authors, publisher, description
+    )
+    SELECT
+        book_id, display_name, file_path, series_name, tag_names, authors, publisher, description
+    FROM book_search_source WHERE book_id = OLD.book_id;
+END;
@@ -0,0 +1,73 @@
+-- The search index holds normalized text (see `search_text::normalize`), which only the
+-- application can compute. The triggers now only record which books need re-indexing,
+-- and the repositories re-index them whenever books, tags, series or creators are written.
+DROP TRIGGER IF EXISTS book_search_books_insert;
+DROP TRIGGER IF EXISTS book_search_books_update;
+DROP TRIGGER IF EXISTS book_search_books_delete;
+DROP TRIGGER IF EXISTS book_search_book_tags_insert;
+DROP TRIGGER IF EXISTS book_search_book_tags_delete;
+DROP TRIGGER IF EXISTS book_search_tags_update;
+DROP TRIGGER IF EXISTS book_search_series_update;
+DROP TRIGGER IF EXISTS book_search_metadata_insert;
+DROP TRIGGER IF EXISTS book_search_metadata_update;
+DROP TRIGGER IF EXISTS book_search_metadata_delete;
+
+-- Books whose row in `book_search` is out of date.
+CREATE TABLE book_search_dirty (
+    book_id INTEGER PRIMARY KEY NOT NULL
+);
+
+CREATE TRIGGER book_search_books_insert AFTER INSERT ON books
+BEGIN
+    INSERT OR IGNORE INTO book_search_dirty (book_id) VALUES (NEW.id);
+END;
+
+CREATE TRIGGER book_search_books_update AFTER UPDATE OF display_name, file_path, series_id ON books
+BEGIN
+    INSERT OR IGNORE INTO book_search_dirty (book_id) VALUES (NEW.id);
+END;
+
+CREATE TRIGGER book_search_books_delete AFTER DELETE ON books
+BEGIN
+    INSERT OR IGNORE INTO book_search_dirty (book_id) VALUES (OLD.id);
+END;
+
+CREATE TRIGGER book_search_book_tags_insert AFTER INSERT ON book_tags
+BEGIN
+    INSERT OR IGNORE INTO book_search_dirty (book_id) VALUES (NEW.book_id);
+END;
+
+CREATE TRIGGER book_search_book_tags_delete AFTER DELETE ON book_tags
+BEGIN
+    INSERT OR IGNORE INTO book_search_dirty (book_id) VALUES (OLD.book_id);
+END;
+
+CREATE TRIGGER book_search_tags_update AFTER UPDATE OF name ON tags
+BEGIN
+    INSERT OR IGNORE INTO book_search_dirty (book_id)
+    SELECT book_id FROM book_tags WHERE tag_id = NEW.id;
+END;
+
+CREATE TRIGGER book_search_series_update AFTER UPDATE OF name ON series
+BEGIN
+    INSERT OR IGNORE INTO book_search_dirty (book_id)
+    SELECT id FROM books WHERE series_id = NEW.id;
+END;
+
+CREATE TRIGGER book_search_metadata_insert AFTER INSERT ON book_metadata
+BEGIN
+    INSERT OR IGNORE INTO book_search_dirty (book_id) VALUES (NEW.book_id);
+END;
+
+CREATE TRIGGER book_search_metadata_update AFTER UPDATE ON book_metadata
+BEGIN
+    INSERT OR IGNORE INTO book_search_dirty (book_id) VALUES (NEW.book_id);
+END;
+
+CREATE TRIGGER book_search_metadata_delete AFTER DELETE ON book_metadata
+BEGIN
+    INSERT OR IGNORE INTO book_search_dirty (book_id) VALUES (OLD.book_id);
+END;
+
+-- Re-index every book with normalized text on the next search.
+INSERT INTO book_search_dirty (book_id) SELECT id FROM books;
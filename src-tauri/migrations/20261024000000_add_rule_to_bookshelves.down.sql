@@ -0,0 +1 @@
+ALTER TABLE bookshelves DROP COLUMN rule;
@@ -0,0 +1,5 @@
+-- The saved rule of a smart bookshelf, whose books are selected by the rule instead of
+-- `bookshelf_items`. The column holds a JSON object with the optional conditions
+-- (`tag_ids`, `tag_match`, `series_id`, `read_status`, `added_within_days`,
+-- `path_prefix`, `formats`) and is NULL for bookshelves whose books are added by hand.
+ALTER TABLE bookshelves ADD COLUMN rule TEXT;
@@ -0,0 +1,43 @@
+DROP TRIGGER IF EXISTS book_search_book_creators_insert;
+DROP TRIGGER IF EXISTS book_search_book_creators_delete;
+DROP TRIGGER IF EXISTS book_search_creators_update;
+
+-- Store the authors joined with ", " again.
+ALTER TABLE book_metadata ADD COLUMN authors TEXT;
+UPDATE book_metadata
+SET authors = (
+    SELECT GROUP_CONCAT(name, ', ')
+    FROM (
+        SELECT c.name
+        FROM book_creators bc
+        JOIN creators c ON c.id = bc.creator_id
+        WHERE bc.book_id = book_metadata.book_id AND bc.role = 'author'
+        ORDER BY bc.position
+    )
+);
+
+DROP VIEW book_search_source;
+CREATE VIEW book_search_source AS
+SELECT
+    b.id AS book_id,
+    b.display_name,
+    b.file_path,
+    s.name AS series_name,
+    (
+        SELECT GROUP_CONCAT(t.name, ' ')
+        FROM book_tags bt
+        JOIN tags t ON t.id = bt.tag_id
+        WHERE bt.book_id = b.id
+    ) AS tag_names,
+    m.authors,
+    m.publisher,
+    m.description
+FROM books b
+LEFT JOIN series s ON s.id = b.series_id
+LEFT JOIN book_metadata m ON m.book_id = b.id;
+
+DROP TABLE IF EXISTS creator_aliases;
+DROP TABLE IF EXISTS book_creators;
+DROP TABLE IF EXISTS creators;
+
+INSERT OR IGNORE INTO book_search_dirty (book_id) SELECT id FROM books;
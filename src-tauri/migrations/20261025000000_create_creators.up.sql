@@ -0,0 +1,108 @@
+-- People credited for books, read from the book metadata.
+CREATE TABLE creators (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    name TEXT UNIQUE NOT NULL
+);
+
+-- The creators of each book and their role. A creator can have several roles on the
+-- same book (e.g. author and illustrator).
+CREATE TABLE book_creators (
+    book_id INTEGER NOT NULL,
+    creator_id INTEGER NOT NULL,
+    role TEXT NOT NULL CHECK (role IN ('author', 'illustrator', 'translator')),
+    -- The order the book metadata lists the creators in.
+    position INTEGER NOT NULL,
+    PRIMARY KEY (book_id, creator_id, role),
+    FOREIGN KEY (book_id) REFERENCES books(id) ON DELETE CASCADE,
+    FOREIGN KEY (creator_id) REFERENCES creators(id) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_book_creators_creator_id ON book_creators(creator_id);
+
+-- Names that stand for another creator: the former names of renamed creators and the
+-- names of creators merged into another one. Metadata naming an alias is credited to
+-- its creator, so renames and merges survive re-reading the book files.
+CREATE TABLE creator_aliases (
+    name TEXT PRIMARY KEY NOT NULL,
+    creator_id INTEGER NOT NULL,
+    FOREIGN KEY (creator_id) REFERENCES creators(id) ON DELETE CASCADE
+);
+
+-- Move the authors read so far into the new tables. They were stored joined with ", ".
+WITH RECURSIVE split(book_id, position, name, rest) AS (
+    SELECT book_id, -1, NULL, authors || ', '
+    FROM book_metadata
+    WHERE authors IS NOT NULL
+    UNION ALL
+    SELECT
+        book_id,
+        position + 1,
+        substr(rest, 1, instr(rest, ', ') - 1),
+        substr(rest, instr(rest, ', ') + 2)
+    FROM split
+    WHERE rest <> ''
+)
+INSERT OR IGNORE INTO creators (name)
+SELECT name FROM split WHERE name <> '' ORDER BY book_id, position;
+
+WITH RECURSIVE split(book_id, position, name, rest) AS (
+    SELECT book_id, -1, NULL, authors || ', '
+    FROM book_metadata
+    WHERE authors IS NOT NULL
+    UNION ALL
+    SELECT
+        book_id,
+        position + 1,
+        substr(rest, 1, instr(rest, ', ') - 1),
+        substr(rest, instr(rest, ', ') + 2)
+    FROM split
+    WHERE rest <> ''
+)
+INSERT OR IGNORE INTO book_creators (book_id, creator_id, role, position)
+SELECT split.book_id, creators.id, 'author', split.position
+FROM split
+JOIN creators ON creators.name = split.name;
+
+-- Index the names of every creator instead of the stored authors.
+DROP VIEW book_search_source;
+CREATE VIEW book_search_source AS
+SELECT
+    b.id AS book_id,
+    b.display_name,
+    b.file_path,
+    s.name AS series_name,
+    (
+        SELECT GROUP_CONCAT(t.name, ' ')
+        FROM book_tags bt
+        JOIN tags t ON t.id = bt.tag_id
+        WHERE bt.book_id = b.id
+    ) AS tag_names,
+    (
+        SELECT GROUP_CONCAT(c.name, ', ')
+        FROM book_creators bc
+        JOIN creators c ON c.id = bc.creator_id
+        WHERE bc.book_id = b.id
+    ) AS authors,
+    m.publisher,
+    m.description
+FROM books b
+LEFT JOIN series s ON s.id = b.series_id
+LEFT JOIN book_metadata m ON m.book_id = b.id;
+
+ALTER TABLE book_metadata DROP COLUMN authors;
+
+CREATE TRIGGER book_search_book_creators_insert AFTER INSERT ON book_creators
+BEGIN
+    INSERT OR IGNORE INTO book_search_dirty (book_id) VALUES (NEW.book_id);
+END;
+
+CREATE TRIGGER book_search_book_creators_delete AFTER DELETE ON book_creators
+BEGIN
+    INSERT OR IGNORE INTO book_search_dirty (book_id) VALUES (OLD.book_id);
+END;
+
+CREATE TRIGGER book_search_creators_update AFTER UPDATE OF name ON creators
+BEGIN
+    INSERT OR IGNORE INTO book_search_dirty (book_id)
+    SELECT book_id FROM book_creators WHERE creator_id = NEW.id;
+END;
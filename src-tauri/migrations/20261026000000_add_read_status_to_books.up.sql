@@ -0,0 +1,45 @@
+-- How far the reader got with a book, set by the reader or advanced automatically as the
+-- book is read.
+ALTER TABLE books ADD COLUMN read_status TEXT NOT NULL DEFAULT 'unread'
+    CHECK (read_status IN ('unread', 'want_to_read', 'reading', 'read', 'abandoned'));
+-- The reader's rating from 1 to 5 stars, or 0 while the book is not rated.
+ALTER TABLE books ADD COLUMN rating INTEGER NOT NULL DEFAULT 0 CHECK (rating BETWEEN 0 AND 5);
+ALTER TABLE books ADD COLUMN is_favorite BOOLEAN NOT NULL DEFAULT 0;
+
+-- The status used to be derived from the reading state; keep what it showed. A book
+-- without pages (e.g. one whose page count is not known yet) is never read through.
+UPDATE books
+SET read_status = (
+    SELECT CASE
+        WHEN books.total_pages > 0 AND r.last_read_page_index >= books.total_pages - 1 THEN 'read'
+        ELSE 'reading'
+    END
+    FROM reading_state r
+    WHERE r.book_id = books.id
+)
+WHERE id IN (SELECT book_id FROM reading_state);
+
+-- Recreate the view so the status, rating and favorite flag are surfaced alongside the
+-- other book columns.
+DROP VIEW IF EXISTS book_with_state_view;
+CREATE VIEW book_with_state_view AS
+SELECT
+    b.id,
+    b.file_path,
+    b.item_type,
+    b.display_name,
+    b.total_pages,
+    b.series_id,
+    b.series_order,
+    b.thumbnail_path,
+    b.created_at,
+    b.damage_code,
+    b.missing_since,
+    b.read_status,
+    b.rating,
+    b.is_favorite,
+    r.last_read_page_index,
+    r.last_opened_at,
+    CAST((SELECT GROUP_CONCAT(tag_id) FROM book_tags WHERE book_id = b.id) AS TEXT) AS tag_ids_str
+FROM books b
+LEFT JOIN reading_state r ON b.id = r.book_id;
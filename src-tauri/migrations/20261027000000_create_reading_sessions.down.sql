@@ -0,0 +1,4 @@
+ALTER TABLE books DROP COLUMN finished_utc_offset_seconds;
+ALTER TABLE books DROP COLUMN finished_at;
+
+DROP TABLE reading_sessions;
@@ -0,0 +1,32 @@
+-- A stretch of continuous reading of a book. A session starts when the book is opened
+-- (or when reading resumes after a pause) and is extended by each page turn.
+CREATE TABLE reading_sessions (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    book_id INTEGER NOT NULL,
+    started_at DATETIME NOT NULL,
+    ended_at DATETIME NOT NULL,
+    start_page INTEGER NOT NULL,
+    end_page INTEGER NOT NULL,
+    -- The pages read forward during the session; paging back is not counted.
+    pages_turned INTEGER NOT NULL DEFAULT 0,
+    -- The offset of the local time zone from UTC when the session started, so it is
+    -- counted on the right local day after a daylight saving change. NULL if unknown.
+    utc_offset_seconds INTEGER,
+    FOREIGN KEY (book_id) REFERENCES books(id) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_reading_sessions_book_id ON reading_sessions(book_id, ended_at);
+CREATE INDEX idx_reading_sessions_started_at ON reading_sessions(started_at);
+
+-- When the book was last finished, so finished books can be counted per month. NULL
+-- while the book is not marked as read.
+ALTER TABLE books ADD COLUMN finished_at DATETIME;
+-- The offset of the local time zone from UTC when the book was finished. NULL if
+-- unknown.
+ALTER TABLE books ADD COLUMN finished_utc_offset_seconds INTEGER;
+
+-- The finish time of books read before was not recorded; the last time they were
+-- opened is the closest there is.
+UPDATE books
+SET finished_at = (SELECT r.last_opened_at FROM reading_state r WHERE r.book_id = books.id)
+WHERE read_status = 'read';
@@ -0,0 +1,17 @@
+-- Positions in a book the reader wants to come back to. A bookmark points at a page of
+-- an image book or, in a novel, at an EPUB CFI.
+CREATE TABLE bookmarks (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    book_id INTEGER NOT NULL,
+    page_index INTEGER CHECK (page_index >= 0),
+    cfi TEXT,
+    label TEXT NOT NULL DEFAULT '',
+    note TEXT NOT NULL DEFAULT '',
+    -- NULL for a bookmark without a color.
+    color_code TEXT,
+    created_at DATETIME NOT NULL,
+    CHECK ((page_index IS NULL) <> (cfi IS NULL)),
+    FOREIGN KEY (book_id) REFERENCES books(id) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_bookmarks_book_id ON bookmarks(book_id);
@@ -0,0 +1,42 @@
+-- The reading position of a novel as an EPUB CFI, which stays valid when the font size
+-- or window size changes the pagination. NULL for image books.
+ALTER TABLE reading_state ADD COLUMN last_read_cfi TEXT;
+
+-- Highlighted passages of novels, with an optional note.
+CREATE TABLE annotations (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    book_id INTEGER NOT NULL,
+    -- The EPUB CFI range of the highlighted passage.
+    cfi_range TEXT NOT NULL CHECK (cfi_range <> ''),
+    selected_text TEXT NOT NULL,
+    note TEXT NOT NULL DEFAULT '',
+    color_code TEXT NOT NULL,
+    created_at DATETIME NOT NULL,
+    FOREIGN KEY (book_id) REFERENCES books(id) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_annotations_book_id ON annotations(book_id);
+
+DROP VIEW IF EXISTS book_with_state_view;
+CREATE VIEW book_with_state_view AS
+SELECT
+    b.id,
+    b.file_path,
+    b.item_type,
+    b.display_name,
+    b.total_pages,
+    b.series_id,
+    b.series_order,
+    b.thumbnail_path,
+    b.created_at,
+    b.damage_code,
+    b.missing_since,
+    b.read_status,
+    b.rating,
+    b.is_favorite,
+    r.last_read_page_index,
+    r.last_read_cfi,
+    r.last_opened_at,
+    CAST((SELECT GROUP_CONCAT(tag_id) FROM book_tags WHERE book_id = b.id) AS TEXT) AS tag_ids_str
+FROM books b
+LEFT JOIN reading_state r ON b.id = r.book_id;
@@ -0,0 +1,284 @@
+use std::sync::Arc;
+use tauri::Emitter;
+use tauri::State;
+
+use crate::domain::annotation::entity::{Annotation, AnnotationExportFormat, NewAnnotation};
+use crate::domain::annotation::export;
+use crate::domain::annotation::repository::AnnotationRepository;
+use crate::domain::book::repository::BookRepository;
+use crate::error::{Error, Result};
+
+/// Highlights a passage of a novel and returns the complete annotation.
+///
+/// # Arguments
+///
+/// * `annotation` - The annotation to add.
+/// * `repo` - The managed annotation repository state.
+///
+/// # Returns
+///
+/// A `Result` containing the newly created `Annotation` entity.
+///
+/// # Errors
+///
+/// This function will return an `Err` if:
+/// * The CFI range is empty (`Error::InvalidArgument`).
+/// * The underlying repository operation fails.
+#[tauri::command]
+#[specta::specta]
+pub async fn create_annotation<R: tauri::Runtime>(
+    annotation: NewAnnotation,
+    repo: State<'_, Arc<dyn AnnotationRepository>>,
+    app: tauri::AppHandle<R>,
+) -> Result<Annotation> {
+    log::debug!("Create annotation. (annotation:{:?})", annotation);
+    annotation.validate()?;
+    let annotation = repo.add(&annotation).await?;
+    app.emit("history-changed", ())?;
+    Ok(annotation)
+}
+
+/// Retrieves the annotations of a book, in reading order.
+///
+/// # Arguments
+///
+/// * `book_id` - The ID of the book.
+/// * `repo` - The managed annotation repository state.
+///
+/// # Returns
+///
+/// A `Result` containing a vector of `Annotation` entities.
+///
+/// # Errors
+///
+/// This function will return an `Err` if the underlying repository operation fails.
+#[tauri::command]
+#[specta::specta]
+pub async fn get_annotations(
+    book_id: i64,
+    repo: State<'_, Arc<dyn AnnotationRepository>>,
+) -> Result<Vec<Annotation>> {
+    log::debug!("Get annotations. (book_id:{})", book_id);
+    repo.get_for_book(book_id).await
+}
+
+/// Deletes an annotation.
+///
+/// # Arguments
+///
+/// * `id` - The ID of the annotation to delete.
+/// * `repo` - The managed annotation repository state.
+///
+/// # Errors
+///
+/// This function will return an `Err` if the underlying repository operation fails.
+#[tauri::command]
+#[specta::specta]
+pub async fn delete_annotation<R: tauri::Runtime>(
+    id: i64,
+    repo: State<'_, Arc<dyn AnnotationRepository>>,
+    app: tauri::AppHandle<R>,
+) -> Result<()> {
+    log::debug!("Delete annotation. (id:{})", id);
+    repo.delete(id).await?;
+    app.emit("history-changed", ())?;
+    Ok(())
+}
+
+/// Exports the annotations of a book, in reading order, as a Markdown or JSON document.
+///
+/// # Arguments
+///
+/// * `book_id` - The ID of the book.
+/// * `format` - The format of the document.
+/// * `repo` - The managed annotation repository state.
+/// * `book_repo` - The managed book repository state.
+///
+/// # Returns
+///
+/// A `Result` containing the document, ready to be saved to a file.
+///
+/// # Errors
+///
+/// This function will return an `Err` if:
+/// * The book does not exist (`Error::InvalidArgument`).
+/// * The underlying repository operation fails.
+#[tauri::command]
+#[specta::specta]
+pub async fn export_annotations(
+    book_id: i64,
+    format: AnnotationExportFormat,
+    repo: State<'_, Arc<dyn AnnotationRepository>>,
+    book_repo: State<'_, Arc<dyn BookRepository>>,
+) -> Result<String> {
+    log::debug!(
+        "Export annotations. (book_id:{}, format:{:?})",
+        book_id,
+        format
+    );
+    let book = book_repo
+        .get_by_id(book_id)
+        .await?
+        .ok_or_else(|| Error::InvalidArgument(format!("Book {} does not exist", book_id)))?;
+    let annotations = repo.get_for_book(book_id).await?;
+    export::export_annotations(book.id, &book.display_name, &annotations, format)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::annotation::repository::MockAnnotationRepository;
+    use crate::domain::book::entity::Book;
+    use crate::domain::book::repository::MockBookRepository;
+    use tauri::Manager;
+
+    fn annotation(id: i64, selected_text: &str) -> Annotation {
+        Annotation {
+            id,
+            book_id: 1,
+            cfi_range: "epubcfi(/6/4!/4/2,/1:0,/1:8)".to_string(),
+            selected_text: selected_text.to_string(),
+            note: String::new(),
+            color_code: "#FFFF00".to_string(),
+            created_at: chrono::Utc::now().naive_utc(),
+        }
+    }
+
+    fn book(id: i64) -> Book {
+        Book {
+            id,
+            file_path: "/books/novel.epub".to_string(),
+            item_type: "file".to_string(),
+            display_name: "Novel".to_string(),
+            total_pages: 0,
+            series_id: None,
+            series_order: None,
+            thumbnail_path: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_annotation() {
+        let mut mock_repo = MockAnnotationRepository::new();
+        mock_repo
+            .expect_add()
+            .withf(|annotation| annotation.book_id == 1 && annotation.selected_text == "Passage")
+            .times(1)
+            .returning(|_| Ok(annotation(5, "Passage")));
+
+        let app = tauri::test::mock_app();
+        app.manage(Arc::new(mock_repo) as Arc<dyn AnnotationRepository>);
+        let state = app.state::<Arc<dyn AnnotationRepository>>();
+
+        let new_annotation = NewAnnotation {
+            book_id: 1,
+            cfi_range: "epubcfi(/6/4!/4/2,/1:0,/1:8)".to_string(),
+            selected_text: "Passage".to_string(),
+            color_code: "#FFFF00".to_string(),
+            ..Default::default()
+        };
+        let created = create_annotation(new_annotation, state, app.handle().clone())
+            .await
+            .unwrap();
+        assert_eq!(created.id, 5);
+    }
+
+    #[tokio::test]
+    async fn test_create_annotation_rejects_empty_cfi_range() {
+        let mut mock_repo = MockAnnotationRepository::new();
+        mock_repo.expect_add().never();
+
+        let app = tauri::test::mock_app();
+        app.manage(Arc::new(mock_repo) as Arc<dyn AnnotationRepository>);
+        let state = app.state::<Arc<dyn AnnotationRepository>>();
+
+        let new_annotation = NewAnnotation {
+            book_id: 1,
+            selected_text: "Passage".to_string(),
+            ..Default::default()
+        };
+        let result = create_annotation(new_annotation, state, app.handle().clone()).await;
+        assert!(matches!(result, Err(Error::InvalidArgument(_))));
+    }
+
+    #[tokio::test]
+    async fn test_get_annotations() {
+        let mut mock_repo = MockAnnotationRepository::new();
+        mock_repo
+            .expect_get_for_book()
+            .with(mockall::predicate::eq(1))
+            .times(1)
+            .returning(|_| Ok(vec![annotation(1, "One"), annotation(2, "Two")]));
+
+        let app = tauri::test::mock_app();
+        app.manage(Arc::new(mock_repo) as Arc<dyn AnnotationRepository>);
+        let state = app.state::<Arc<dyn AnnotationRepository>>();
+
+        let annotations = get_annotations(1, state).await.unwrap();
+        assert_eq!(annotations.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_delete_annotation() {
+        let mut mock_repo = MockAnnotationRepository::new();
+        mock_repo
+            .expect_delete()
+            .with(mockall::predicate::eq(5))
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let app = tauri::test::mock_app();
+        app.manage(Arc::new(mock_repo) as Arc<dyn AnnotationRepository>);
+        let state = app.state::<Arc<dyn AnnotationRepository>>();
+
+        let result = delete_annotation(5, state, app.handle().clone()).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_export_annotations_as_markdown() {
+        let mut mock_repo = MockAnnotationRepository::new();
+        mock_repo
+            .expect_get_for_book()
+            .with(mockall::predicate::eq(1))
+            .times(1)
+            .returning(|_| Ok(vec![annotation(1, "Passage")]));
+        let mut mock_book_repo = MockBookRepository::new();
+        mock_book_repo
+            .expect_get_by_id()
+            .with(mockall::predicate::eq(1))
+            .times(1)
+            .returning(|id| Ok(Some(book(id))));
+
+        let app = tauri::test::mock_app();
+        app.manage(Arc::new(mock_repo) as Arc<dyn AnnotationRepository>);
+        app.manage(Arc::new(mock_book_repo) as Arc<dyn BookRepository>);
+        let state = app.state::<Arc<dyn AnnotationRepository>>();
+        let book_state = app.state::<Arc<dyn BookRepository>>();
+
+        let markdown = export_annotations(1, AnnotationExportFormat::Markdown, state, book_state)
+            .await
+            .unwrap();
+        assert_eq!(markdown, "# Novel\n\n> Passage\n");
+    }
+
+    #[tokio::test]
+    async fn test_export_annotations_of_missing_book() {
+        let mut mock_repo = MockAnnotationRepository::new();
+        mock_repo.expect_get_for_book().never();
+        let mut mock_book_repo = MockBookRepository::new();
+        mock_book_repo
+            .expect_get_by_id()
+            .times(1)
+            .returning(|_| Ok(None));
+
+        let app = tauri::test::mock_app();
+        app.manage(Arc::new(mock_repo) as Arc<dyn AnnotationRepository>);
+        app.manage(Arc::new(mock_book_repo) as Arc<dyn BookRepository>);
+        let state = app.state::<Arc<dyn AnnotationRepository>>();
+        let book_state = app.state::<Arc<dyn BookRepository>>();
+
+        let result = export_annotations(9, AnnotationExportFormat::Json, state, book_state).await;
+        assert!(matches!(result, Err(Error::InvalidArgument(_))));
+    }
+}
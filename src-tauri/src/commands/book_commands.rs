@@ -1,20 +1,28 @@
+use std::collections::HashSet;
 use std::fs;
 use std::hash::{DefaultHasher, Hash, Hasher};
+use std::path::PathBuf;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
 use tauri::Emitter;
 use tauri::State;
 
-use crate::container::factory::{create_container, ContainerConfig};
+use crate::container::entry_filter::ExclusionRule;
+use crate::container::factory::create_container;
+use crate::container::metadata::read_metadata;
 use crate::container::traits::Container;
-use crate::domain::book::entity::{Book, BookWithState, ReadBook, ReadingState};
+use crate::domain::book::entity::{
+    Book, BookMetadata, BookQuery, BookQueryResult, BookSearchFilters, BookWithState, ReadBook,
+    ReadStatus, ReadingState,
+};
 use crate::domain::book::repository::BookRepository;
 use crate::domain::bookshelf::repository::BookshelfRepository;
 use crate::domain::series::repository::SeriesRepository;
 use crate::domain::tag::repository::TagRepository;
-use crate::error::{Error, Result};
+use crate::error::{Error, ErrorCode, Result};
 use crate::state::app_state::AppState;
+use crate::state::container_settings::ContainerSettings;
 
 /// Event emitted when a book's reading progress changes (a page turn). It carries the
 /// updated `ReadingState` so the frontend can patch the affected book in place, instead
@@ -147,6 +155,12 @@ pub async fn register_book<R: tauri::Runtime>(
             thumbnail_path,
         )
         .await?;
+    if let Some(damage_code) = resolve_damage_code(&state, &file_path).await {
+        repo.set_damage_code(book_id, damage_code).await?;
+    }
+    if let Some(metadata) = resolve_metadata(&file_path).await {
+        repo.set_metadata(book_id, &metadata).await?;
+    }
 
     app.emit("history-changed", ())?;
 
@@ -155,6 +169,9 @@ pub async fn register_book<R: tauri::Runtime>(
 
 /// Records the event of a book being opened, updating its last opened time.
 ///
+/// The metadata stored in the book file is read only when the book is new to the
+/// library; library scans refresh it when the file changes.
+///
 /// # Arguments
 ///
 /// * `file_path` - The unique file or directory path.
@@ -192,6 +209,7 @@ pub async fn record_book_opened<R: tauri::Runtime>(
         total_pages
     );
 
+    let is_new = repo.get_by_path(&file_path).await?.is_none();
     let thumbnail_path = resolve_thumbnail(&state, &app, &file_path).await;
 
     let book_id = repo
@@ -203,6 +221,14 @@ pub async fn record_book_opened<R: tauri::Runtime>(
             thumbnail_path,
         )
         .await?;
+    if let Some(damage_code) = resolve_damage_code(&state, &file_path).await {
+        repo.set_damage_code(book_id, damage_code).await?;
+    }
+    if is_new {
+        if let Some(metadata) = resolve_metadata(&file_path).await {
+            repo.set_metadata(book_id, &metadata).await?;
+        }
+    }
 
     app.emit("history-changed", ())?;
 
@@ -282,6 +308,103 @@ pub async fn update_reading_progress<R: tauri::Runtime>(
     Ok(())
 }
 
+/// Sets the read status of several books at once.
+///
+/// # Arguments
+///
+/// * `book_ids` - The IDs of the books.
+/// * `status` - The new read status.
+/// * `repo` - The managed book repository state.
+/// * `app` - The Tauri AppHandle.
+///
+/// # Errors
+///
+/// This function will return an `Err` if the underlying repository operation fails.
+#[tauri::command]
+#[specta::specta]
+pub async fn set_books_read_status<R: tauri::Runtime>(
+    book_ids: Vec<i64>,
+    status: ReadStatus,
+    repo: State<'_, Arc<dyn BookRepository>>,
+    app: tauri::AppHandle<R>,
+) -> Result<()> {
+    log::debug!(
+        "Set books read status. (book_ids:{:?}, status:{:?})",
+        book_ids,
+        status
+    );
+    repo.set_read_status(&book_ids, status).await?;
+    app.emit("history-changed", ())?;
+    Ok(())
+}
+
+/// Sets the rating of several books at once.
+///
+/// # Arguments
+///
+/// * `book_ids` - The IDs of the books.
+/// * `rating` - The rating from 1 to 5, or 0 to remove the rating.
+/// * `repo` - The managed book repository state.
+/// * `app` - The Tauri AppHandle.
+///
+/// # Errors
+///
+/// This function will return an `Err` if:
+/// * The rating is outside 0 to 5 (`Error::InvalidArgument`).
+/// * The underlying repository operation fails.
+#[tauri::command]
+#[specta::specta]
+pub async fn set_books_rating<R: tauri::Runtime>(
+    book_ids: Vec<i64>,
+    rating: i64,
+    repo: State<'_, Arc<dyn BookRepository>>,
+    app: tauri::AppHandle<R>,
+) -> Result<()> {
+    log::debug!(
+        "Set books rating. (book_ids:{:?}, rating:{})",
+        book_ids,
+        rating
+    );
+    if !(0..=5).contains(&rating) {
+        return Err(Error::InvalidArgument(format!(
+            "The rating must be between 0 and 5, but was {rating}"
+        )));
+    }
+    repo.set_rating(&book_ids, rating).await?;
+    app.emit("history-changed", ())?;
+    Ok(())
+}
+
+/// Marks several books as favorites at once, or unmarks them.
+///
+/// # Arguments
+///
+/// * `book_ids` - The IDs of the books.
+/// * `is_favorite` - Whether the books are favorites.
+/// * `repo` - The managed book repository state.
+/// * `app` - The Tauri AppHandle.
+///
+/// # Errors
+///
+/// This function will return an `Err` if the underlying repository operation fails.
+#[tauri::command]
+#[specta::specta]
+pub async fn set_books_favorite<R: tauri::Runtime>(
+    book_ids: Vec<i64>,
+    is_favorite: bool,
+    repo: State<'_, Arc<dyn BookRepository>>,
+    app: tauri::AppHandle<R>,
+) -> Result<()> {
+    log::debug!(
+        "Set books favorite. (book_ids:{:?}, is_favorite:{})",
+        book_ids,
+        is_favorite
+    );
+    repo.set_favorite(&book_ids, is_favorite).await?;
+    app.emit("history-changed", ())?;
+    Ok(())
+}
+
 /// Retrieves recently read books, ordered by the most recently opened.
 ///
 /// # Arguments
@@ -330,8 +453,78 @@ pub async fn get_all_books_with_state(
     repo.get_all_books_with_state().await
 }
 
+/// Searches the library by display name, file path, series name, tag names and the
+/// metadata read from the book files (creator names, publisher, description).
+///
+/// # Arguments
+///
+/// * `query` - The search terms. Every whitespace-separated term must match; an empty
+///   query matches every book that passes the filters.
+/// * `filters` - Further conditions the books must meet.
+/// * `limit` - The maximum number of books to return.
+/// * `offset` - The number of matching books to skip, for paging.
+/// * `repo` - The managed book repository state.
+///
+/// # Returns
+///
+/// A `Result` containing the matching books ranked by relevance, best match first.
+///
+/// # Errors
+///
+/// This function will return an `Err` if the underlying repository operation fails
+/// (e.g., due to a database error, connection issue, or query execution failure).
+#[tauri::command]
+#[specta::specta]
+pub async fn search_books(
+    query: String,
+    filters: BookSearchFilters,
+    limit: u32,
+    offset: u32,
+    repo: State<'_, Arc<dyn BookRepository>>,
+) -> Result<Vec<BookWithState>> {
+    log::debug!(
+        "Search books. (query: {}, filters: {:?}, limit: {}, offset: {})",
+        query,
+        filters,
+        limit,
+        offset
+    );
+    repo.search_books(&query, &filters, limit, offset).await
+}
+
+/// Retrieves one page of the books matching a query, with their reading states.
+///
+/// Unlike the listing commands, the filtering, sorting and paging happen in the
+/// database, so only the requested page crosses the IPC boundary.
+///
+/// # Arguments
+///
+/// * `query` - The conditions, sort order and page of the books to return.
+/// * `repo` - The managed book repository state.
+///
+/// # Returns
+///
+/// A `Result` containing the requested page of books and the total number of matching
+/// books.
+///
+/// # Errors
+///
+/// This function will return an `Err` if the underlying repository operation fails
+/// (e.g., due to a database error, connection issue, or query execution failure).
+#[tauri::command]
+#[specta::specta]
+pub async fn query_books(
+    query: BookQuery,
+    repo: State<'_, Arc<dyn BookRepository>>,
+) -> Result<BookQueryResult> {
+    log::debug!("Query books. (query: {:?})", query);
+    repo.query_books(&query).await
+}
+
 /// Retrieves all books contained within a specific bookshelf, including their reading states.
 ///
+/// The books of a smart bookshelf are the books matching its rule.
+///
 /// # Arguments
 ///
 /// * `bookshelf_id` - The ID of the bookshelf.
@@ -460,6 +653,123 @@ pub async fn update_book_tags<R: tauri::Runtime>(
     Ok(())
 }
 
+/// Retrieves the exclusion rules of a book.
+///
+/// # Arguments
+///
+/// * `book_id` - The unique identifier of the book.
+/// * `repo` - The managed book repository state.
+///
+/// # Returns
+///
+/// A `Result` which is `Ok` containing the book's own rules, which apply on top of the
+/// global rules from the settings.
+///
+/// # Errors
+///
+/// This function will return an `Err` if the underlying repository operation fails.
+#[tauri::command]
+#[specta::specta]
+pub async fn get_book_exclusion_rules(
+    book_id: i64,
+    repo: State<'_, Arc<dyn BookRepository>>,
+) -> Result<Vec<ExclusionRule>> {
+    log::debug!("Get exclusion rules of book {}.", book_id);
+    repo.get_exclusion_rules(book_id).await
+}
+
+/// Replaces the exclusion rules of a book.
+///
+/// The rules take effect the next time the book is opened.
+///
+/// # Arguments
+///
+/// * `book_id` - The unique identifier of the book.
+/// * `rules` - The new rules. An empty list removes the book's rules.
+/// * `repo` - The managed book repository state.
+///
+/// # Errors
+///
+/// This function will return an `Err` if:
+/// * A rule has an empty or invalid pattern (`Error::InvalidArgument`).
+/// * The underlying repository operation fails.
+#[tauri::command]
+#[specta::specta]
+pub async fn set_book_exclusion_rules(
+    book_id: i64,
+    rules: Vec<ExclusionRule>,
+    repo: State<'_, Arc<dyn BookRepository>>,
+) -> Result<()> {
+    log::debug!("Set exclusion rules of book {}: {:?}", book_id, rules);
+    for rule in &rules {
+        rule.compile()?;
+    }
+    repo.set_exclusion_rules(book_id, &rules).await
+}
+
+/// Retrieves the manual page order of a book.
+///
+/// # Arguments
+///
+/// * `book_id` - The unique identifier of the book.
+/// * `repo` - The managed book repository state.
+///
+/// # Returns
+///
+/// A `Result` which is `Ok` containing the entry names in page order, or an empty list
+/// if the book follows the sort mode from the settings.
+///
+/// # Errors
+///
+/// This function will return an `Err` if the underlying repository operation fails.
+#[tauri::command]
+#[specta::specta]
+pub async fn get_book_page_order(
+    book_id: i64,
+    repo: State<'_, Arc<dyn BookRepository>>,
+) -> Result<Vec<String>> {
+    log::debug!("Get page order of book {}.", book_id);
+    repo.get_page_order(book_id).await
+}
+
+/// Replaces the manual page order of a book.
+///
+/// The order is applied after the pages are sorted and filtered, the next time the book
+/// is opened: the named pages come first, in this order, and any other page follows in
+/// its sorted order.
+///
+/// # Arguments
+///
+/// * `book_id` - The unique identifier of the book.
+/// * `page_order` - The entry names in page order. An empty list removes the manual order.
+/// * `repo` - The managed book repository state.
+///
+/// # Errors
+///
+/// This function will return an `Err` if:
+/// * An entry name appears more than once (`Error::InvalidArgument`).
+/// * The underlying repository operation fails.
+#[tauri::command]
+#[specta::specta]
+pub async fn set_book_page_order(
+    book_id: i64,
+    page_order: Vec<String>,
+    repo: State<'_, Arc<dyn BookRepository>>,
+) -> Result<()> {
+    log::debug!(
+        "Set page order of book {} ({} pages).",
+        book_id,
+        page_order.len()
+    );
+    let mut seen = HashSet::new();
+    if let Some(duplicate) = page_order.iter().find(|entry| !seen.insert(*entry)) {
+        return Err(Error::InvalidArgument(format!(
+            "Page {duplicate} appears more than once in the page order"
+        )));
+    }
+    repo.set_page_order(book_id, &page_order).await
+}
+
 /// Deletes a book by its unique ID.
 ///
 /// # Arguments
@@ -536,7 +846,45 @@ pub async fn update_series_orders<R: tauri::Runtime>(
     Ok(())
 }
 
-/// Reads the current app state to extract the pdfium library path and, if available,
+/// Returns the error code of the damage found when the book at `file_path` was opened
+/// (see `Container::damage`), `Some(None)` if it opened cleanly, or `None` if it is not
+/// the open book and its damage is therefore unknown.
+async fn resolve_damage_code(
+    state: &State<'_, RwLock<AppState>>,
+    file_path: &str,
+) -> Option<Option<i64>> {
+    let state_lock = state.read().await;
+    let container_state = &state_lock.container_state;
+    let is_open_book = container_state
+        .image_loader
+        .as_ref()
+        .is_some_and(|loader| loader.book_id() == file_path);
+    if !is_open_book {
+        return None;
+    }
+
+    let damage = container_state.container.as_ref()?.damage();
+    Some(damage.map(|damage| i64::from(ErrorCode::from(&damage).code())))
+}
+
+/// Reads the metadata stored in the book file at `file_path` (see `read_metadata`), or
+/// returns `None` if the file cannot be read.
+pub(crate) async fn resolve_metadata(file_path: &str) -> Option<BookMetadata> {
+    let path = file_path.to_string();
+    match tauri::async_runtime::spawn_blocking(move || read_metadata(&path)).await {
+        Ok(Ok(metadata)) => Some(metadata),
+        Ok(Err(e)) => {
+            log::warn!("Failed to read the metadata of {}: {}", file_path, e);
+            None
+        }
+        Err(e) => {
+            log::error!("Spawn blocking failed: {}", e);
+            None
+        }
+    }
+}
+
+/// Reads the current app state to extract the container settings and, if available,
 /// the currently loaded container for the given `file_path`. Then generates and saves
 /// a thumbnail, returning its path or `None` on failure.
 async fn resolve_thumbnail<R: tauri::Runtime>(
@@ -544,13 +892,9 @@ async fn resolve_thumbnail<R: tauri::Runtime>(
     app: &tauri::AppHandle<R>,
     file_path: &str,
 ) -> Option<String> {
-    let (pdfium_path, container) = {
+    let (settings, container) = {
         let state_lock = state.read().await;
-        let pdfium_path = state_lock
-            .container_state
-            .settings
-            .pdfium_library_path
-            .clone();
+        let settings = state_lock.container_state.settings.clone();
 
         let container = if let Some(ref c) = state_lock.container_state.container {
             let matches = state_lock
@@ -568,10 +912,10 @@ async fn resolve_thumbnail<R: tauri::Runtime>(
             None
         };
 
-        (pdfium_path, container)
+        (settings, container)
     };
 
-    generate_and_save_thumbnail(app.clone(), file_path.to_string(), pdfium_path, container)
+    generate_and_save_thumbnail(app.clone(), file_path.to_string(), settings, container)
         .await
         .unwrap_or_else(|e| {
             log::warn!("Thumbnail of {} generation failed: {}", file_path, e);
@@ -579,6 +923,27 @@ async fn resolve_thumbnail<R: tauri::Runtime>(
         })
 }
 
+/// Returns the path the thumbnail of the book at `file_path` is saved at, creating the
+/// app's thumbnail directory if needed.
+///
+/// The file name is derived from the hash of `file_path`, so a book keeps its thumbnail
+/// until the file is removed.
+pub(crate) fn thumbnail_file_path<R: tauri::Runtime>(
+    app: &tauri::AppHandle<R>,
+    file_path: &str,
+) -> Result<PathBuf> {
+    let mut hasher = DefaultHasher::new();
+    file_path.hash(&mut hasher);
+    let hash = hasher.finish();
+
+    let thumbnails_dir = crate::setup::app_data_dir(app)?.join("thumbnails");
+    if !thumbnails_dir.exists() {
+        fs::create_dir_all(&thumbnails_dir)?;
+    }
+
+    Ok(thumbnails_dir.join(format!("thumbnail_{}.jpg", hash)))
+}
+
 /// Helper function to generate and save a thumbnail for a given file path.
 ///
 /// If a thumbnail corresponding to the hash of the `file_path` already exists
@@ -589,31 +954,21 @@ async fn resolve_thumbnail<R: tauri::Runtime>(
 ///
 /// * `app` - The Tauri AppHandle for resolving app data directories.
 /// * `file_path` - The unique file or directory path of the book.
-/// * `pdfium_path` - Optional path to the pdfium library.
+/// * `settings` - The container settings used to open the book if it isn't loaded.
 /// * `container` - An optional pre-loaded container to avoid re-opening the file.
 ///
 /// # Returns
 ///
 /// A `Result` containing an `Option<String>` which is the absolute path to the
 /// generated or existing thumbnail file, or `None` if the book contains no images.
-async fn generate_and_save_thumbnail<R: tauri::Runtime>(
+pub(crate) async fn generate_and_save_thumbnail<R: tauri::Runtime>(
     app: tauri::AppHandle<R>,
     file_path: String,
-    pdfium_path: Option<String>,
+    settings: ContainerSettings,
     container: Option<Arc<dyn Container>>,
 ) -> Result<Option<String>> {
     tauri::async_runtime::spawn_blocking(move || {
-        let mut hasher = DefaultHasher::new();
-        file_path.hash(&mut hasher);
-        let hash = hasher.finish();
-
-        let thumbnails_dir = crate::setup::app_data_dir(&app)?.join("thumbnails");
-        if !thumbnails_dir.exists() {
-            fs::create_dir_all(&thumbnails_dir)?;
-        }
-
-        let thumbnail_filename = format!("thumbnail_{}.jpg", hash);
-        let thumbnail_path = thumbnails_dir.join(&thumbnail_filename);
+        let thumbnail_path = thumbnail_file_path(&app, &file_path)?;
 
         if thumbnail_path.exists() {
             return Ok(Some(thumbnail_path.to_string_lossy().to_string()));
@@ -622,11 +977,7 @@ async fn generate_and_save_thumbnail<R: tauri::Runtime>(
         let container: Arc<dyn Container> = if let Some(c) = container {
             c
         } else {
-            let config = ContainerConfig {
-                pdfium_library_path: pdfium_path,
-                ..ContainerConfig::default()
-            };
-            create_container(&file_path, config)?
+            create_container(&file_path, settings.container_config())?
         };
 
         let first_image_entry = container.get_entries().first();
@@ -649,11 +1000,13 @@ async fn generate_and_save_thumbnail<R: tauri::Runtime>(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::book::entity::BookSortKey;
     use crate::domain::book::repository::MockBookRepository;
     use crate::domain::bookshelf::repository::MockBookshelfRepository;
     use crate::domain::series::repository::MockSeriesRepository;
     use crate::domain::tag::repository::MockTagRepository;
     use crate::error::ErrorCode;
+    use rstest::rstest;
     use tauri::Manager;
 
     #[tokio::test]
@@ -822,12 +1175,83 @@ mod tests {
         let state_data = ReadingState {
             book_id: 1,
             last_read_page_index: 7,
+            last_read_cfi: None,
             last_opened_at: None,
         };
         let result = update_reading_progress(state_data, repo, app.handle().clone()).await;
         assert!(result.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_set_books_read_status() {
+        let mut mock_repo = MockBookRepository::new();
+        mock_repo
+            .expect_set_read_status()
+            .withf(|book_ids, status| book_ids == [1, 2] && *status == ReadStatus::Abandoned)
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let app = tauri::test::mock_app();
+        app.manage(Arc::new(mock_repo) as Arc<dyn BookRepository>);
+        let repo = app.state::<Arc<dyn BookRepository>>();
+
+        let result = set_books_read_status(
+            vec![1, 2],
+            ReadStatus::Abandoned,
+            repo,
+            app.handle().clone(),
+        )
+        .await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_set_books_rating() {
+        let mut mock_repo = MockBookRepository::new();
+        mock_repo
+            .expect_set_rating()
+            .withf(|book_ids, rating| book_ids == [3] && *rating == 5)
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let app = tauri::test::mock_app();
+        app.manage(Arc::new(mock_repo) as Arc<dyn BookRepository>);
+        let repo = app.state::<Arc<dyn BookRepository>>();
+
+        let result = set_books_rating(vec![3], 5, repo, app.handle().clone()).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_set_books_rating_rejects_out_of_range() {
+        let mut mock_repo = MockBookRepository::new();
+        mock_repo.expect_set_rating().never();
+
+        let app = tauri::test::mock_app();
+        app.manage(Arc::new(mock_repo) as Arc<dyn BookRepository>);
+        let repo = app.state::<Arc<dyn BookRepository>>();
+
+        let result = set_books_rating(vec![3], 6, repo, app.handle().clone()).await;
+        assert!(matches!(result, Err(Error::InvalidArgument(_))));
+    }
+
+    #[tokio::test]
+    async fn test_set_books_favorite() {
+        let mut mock_repo = MockBookRepository::new();
+        mock_repo
+            .expect_set_favorite()
+            .withf(|book_ids, is_favorite| book_ids == [1] && *is_favorite)
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let app = tauri::test::mock_app();
+        app.manage(Arc::new(mock_repo) as Arc<dyn BookRepository>);
+        let repo = app.state::<Arc<dyn BookRepository>>();
+
+        let result = set_books_favorite(vec![1], true, repo, app.handle().clone()).await;
+        assert!(result.is_ok());
+    }
+
     #[tokio::test]
     async fn test_clear_all_reading_history() {
         let mut mock_repo = MockBookRepository::new();
@@ -864,6 +1288,54 @@ mod tests {
         assert_eq!(tags, vec![1, 2, 3]);
     }
 
+    #[tokio::test]
+    async fn test_set_book_exclusion_rules() {
+        let mut mock_repo = MockBookRepository::new();
+        mock_repo
+            .expect_set_exclusion_rules()
+            .withf(|book_id, rules| *book_id == 1 && rules == [ExclusionRule::glob("*.gif")])
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let app = tauri::test::mock_app();
+        app.manage(Arc::new(mock_repo) as Arc<dyn BookRepository>);
+        let state = app.state::<Arc<dyn BookRepository>>();
+
+        let result = set_book_exclusion_rules(1, vec![ExclusionRule::glob("*.gif")], state).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_set_book_exclusion_rules_rejects_invalid_rules() {
+        let mut mock_repo = MockBookRepository::new();
+        mock_repo.expect_set_exclusion_rules().times(0);
+
+        let app = tauri::test::mock_app();
+        app.manage(Arc::new(mock_repo) as Arc<dyn BookRepository>);
+        let state = app.state::<Arc<dyn BookRepository>>();
+
+        let result = set_book_exclusion_rules(1, vec![ExclusionRule::glob(" ")], state).await;
+        assert!(matches!(result, Err(Error::InvalidArgument(_))));
+    }
+
+    #[tokio::test]
+    async fn test_set_book_page_order_rejects_duplicates() {
+        let mut mock_repo = MockBookRepository::new();
+        mock_repo.expect_set_page_order().times(0);
+
+        let app = tauri::test::mock_app();
+        app.manage(Arc::new(mock_repo) as Arc<dyn BookRepository>);
+        let state = app.state::<Arc<dyn BookRepository>>();
+
+        let page_order = vec![
+            "1.jpg".to_string(),
+            "2.jpg".to_string(),
+            "1.jpg".to_string(),
+        ];
+        let result = set_book_page_order(1, page_order, state).await;
+        assert!(matches!(result, Err(Error::InvalidArgument(_))));
+    }
+
     #[tokio::test]
     async fn test_get_book_with_state_by_id() {
         let mut mock_repo = MockBookRepository::new();
@@ -877,12 +1349,19 @@ mod tests {
                     file_path: "path".to_string(),
                     item_type: "file".to_string(),
                     display_name: "name".to_string(),
+                    sort_key: String::new(),
                     total_pages: 10,
                     series_id: None,
                     series_order: None,
                     thumbnail_path: None,
                     created_at: None,
+                    damage_code: None,
+                    missing_since: None,
+                    read_status: ReadStatus::Reading,
+                    rating: 0,
+                    is_favorite: false,
                     last_read_page_index: Some(5),
+                    last_read_cfi: None,
                     last_opened_at: None,
                     tag_ids_str: None,
                     tag_ids: vec![],
@@ -933,6 +1412,69 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_search_books() {
+        let mut mock_repo = MockBookRepository::new();
+        mock_repo
+            .expect_search_books()
+            .withf(|query, filters, limit, offset| {
+                query == "ワンピース"
+                    && filters.tag_ids == vec![3]
+                    && filters.series_id.is_none()
+                    && *limit == 50
+                    && *offset == 100
+            })
+            .times(1)
+            .returning(|_, _, _, _| Ok(vec![]));
+
+        let app = tauri::test::mock_app();
+        app.manage(Arc::new(mock_repo) as Arc<dyn BookRepository>);
+        let state = app.state::<Arc<dyn BookRepository>>();
+
+        let filters = BookSearchFilters {
+            tag_ids: vec![3],
+            ..Default::default()
+        };
+        let result = search_books("ワンピース".to_string(), filters, 50, 100, state).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_query_books() {
+        let mut mock_repo = MockBookRepository::new();
+        mock_repo
+            .expect_query_books()
+            .withf(|query| {
+                query.exclude_tag_ids == vec![2]
+                    && query.sort == BookSortKey::AddedAt
+                    && query.descending
+                    && query.limit == Some(50)
+                    && query.offset == 100
+            })
+            .times(1)
+            .returning(|_| {
+                Ok(BookQueryResult {
+                    books: vec![],
+                    total_count: 120,
+                })
+            });
+
+        let app = tauri::test::mock_app();
+        app.manage(Arc::new(mock_repo) as Arc<dyn BookRepository>);
+        let state = app.state::<Arc<dyn BookRepository>>();
+
+        let query = BookQuery {
+            exclude_tag_ids: vec![2],
+            sort: BookSortKey::AddedAt,
+            descending: true,
+            limit: Some(50),
+            offset: 100,
+            ..Default::default()
+        };
+        let result = query_books(query, state).await.unwrap();
+        assert_eq!(result.total_count, 120);
+    }
+
     #[tokio::test]
     async fn test_get_books_with_state_by_bookshelf_id() {
         let mut mock_repo = MockBookshelfRepository::new();
@@ -998,6 +1540,8 @@ mod tests {
             )
             .times(1)
             .returning(|_, _, _, _, _| Ok(1));
+        // No book is open, so its damage is unknown and the stored code is kept.
+        mock_repo.expect_set_damage_code().never();
 
         let app = tauri::test::mock_app();
         app.manage(Arc::new(mock_repo) as Arc<dyn BookRepository>);
@@ -1033,6 +1577,13 @@ mod tests {
             )
             .times(1)
             .returning(|_, _, _, _, _| Ok(1));
+        mock_repo
+            .expect_get_by_path()
+            .with(mockall::predicate::eq("path"))
+            .times(1)
+            .returning(|_| Ok(None));
+        // No book is open, so its damage is unknown and the stored code is kept.
+        mock_repo.expect_set_damage_code().never();
 
         let app = tauri::test::mock_app();
         app.manage(Arc::new(mock_repo) as Arc<dyn BookRepository>);
@@ -1054,6 +1605,63 @@ mod tests {
         assert_eq!(result.unwrap(), 1);
     }
 
+    #[rstest]
+    #[case::new_book(false, 1)]
+    #[case::known_book(true, 0)]
+    #[tokio::test]
+    async fn test_record_book_opened_reads_metadata_of_new_books(
+        #[case] known: bool,
+        #[case] metadata_reads: usize,
+    ) {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("ComicInfo.xml"),
+            "<ComicInfo><Writer>Author</Writer></ComicInfo>",
+        )
+        .unwrap();
+        let path = dir.path().to_string_lossy().to_string();
+
+        let mut mock_repo = MockBookRepository::new();
+        let book_path = path.clone();
+        mock_repo.expect_get_by_path().returning(move |_| {
+            Ok(known.then(|| Book {
+                id: 1,
+                file_path: book_path.clone(),
+                item_type: "directory".to_string(),
+                display_name: "name".to_string(),
+                total_pages: 1,
+                series_id: None,
+                series_order: None,
+                thumbnail_path: None,
+            }))
+        });
+        mock_repo
+            .expect_record_book_opened()
+            .returning(|_, _, _, _, _| Ok(1));
+        mock_repo
+            .expect_set_metadata()
+            .times(metadata_reads)
+            .returning(|_, _| Ok(()));
+
+        let app = tauri::test::mock_app();
+        app.manage(Arc::new(mock_repo) as Arc<dyn BookRepository>);
+        app.manage(RwLock::new(AppState::default()));
+        let repo = app.state::<Arc<dyn BookRepository>>();
+        let state = app.state::<RwLock<AppState>>();
+
+        let result = record_book_opened(
+            path,
+            "directory".to_string(),
+            "name".to_string(),
+            1,
+            repo,
+            app.handle().clone(),
+            state,
+        )
+        .await;
+        assert!(result.is_ok());
+    }
+
     #[tokio::test]
     async fn test_get_book_error() {
         let mut mock_repo = MockBookRepository::new();
@@ -1113,8 +1721,13 @@ mod tests {
 
         // Calling it with a fake file path. If it didn't skip, it would return an error
         // because the fake zip file does not exist.
-        let result =
-            generate_and_save_thumbnail(app.app_handle().clone(), file_path, None, None).await;
+        let result = generate_and_save_thumbnail(
+            app.app_handle().clone(),
+            file_path,
+            ContainerSettings::default(),
+            None,
+        )
+        .await;
 
         assert!(result.is_ok());
         let path_opt = result.unwrap();
@@ -0,0 +1,283 @@
+use std::sync::Arc;
+use tauri::Emitter;
+use tauri::State;
+
+use crate::domain::bookmark::entity::{Bookmark, BookmarkUpdate, BookmarkWithBook, NewBookmark};
+use crate::domain::bookmark::repository::BookmarkRepository;
+use crate::error::Result;
+
+/// Adds a bookmark to a book and returns its complete entity.
+///
+/// # Arguments
+///
+/// * `bookmark` - The bookmark to add, pointing at either a page index or a CFI.
+/// * `repo` - The managed bookmark repository state.
+///
+/// # Returns
+///
+/// A `Result` containing the newly created `Bookmark` entity.
+///
+/// # Errors
+///
+/// This function will return an `Err` if:
+/// * The bookmark does not point at exactly one valid position (`Error::InvalidArgument`).
+/// * The underlying repository operation fails.
+#[tauri::command]
+#[specta::specta]
+pub async fn add_bookmark<R: tauri::Runtime>(
+    bookmark: NewBookmark,
+    repo: State<'_, Arc<dyn BookmarkRepository>>,
+    app: tauri::AppHandle<R>,
+) -> Result<Bookmark> {
+    log::debug!("Add bookmark. (bookmark:{:?})", bookmark);
+    bookmark.validate()?;
+    let bookmark = repo.add(&bookmark).await?;
+    app.emit("history-changed", ())?;
+    Ok(bookmark)
+}
+
+/// Retrieves the bookmarks of a book, in reading order.
+///
+/// # Arguments
+///
+/// * `book_id` - The ID of the book.
+/// * `repo` - The managed bookmark repository state.
+///
+/// # Returns
+///
+/// A `Result` containing a vector of `Bookmark` entities.
+///
+/// # Errors
+///
+/// This function will return an `Err` if the underlying repository operation fails.
+#[tauri::command]
+#[specta::specta]
+pub async fn get_bookmarks(
+    book_id: i64,
+    repo: State<'_, Arc<dyn BookmarkRepository>>,
+) -> Result<Vec<Bookmark>> {
+    log::debug!("Get bookmarks. (book_id:{})", book_id);
+    repo.get_for_book(book_id).await
+}
+
+/// Retrieves the bookmarks of every book in the library, most recently added first.
+///
+/// # Arguments
+///
+/// * `repo` - The managed bookmark repository state.
+///
+/// # Returns
+///
+/// A `Result` containing a vector of `BookmarkWithBook` entities.
+///
+/// # Errors
+///
+/// This function will return an `Err` if the underlying repository operation fails.
+#[tauri::command]
+#[specta::specta]
+pub async fn get_all_bookmarks(
+    repo: State<'_, Arc<dyn BookmarkRepository>>,
+) -> Result<Vec<BookmarkWithBook>> {
+    log::debug!("Get all bookmarks.");
+    repo.get_all().await
+}
+
+/// Changes the label, note and color of a bookmark.
+///
+/// # Arguments
+///
+/// * `id` - The ID of the bookmark to update.
+/// * `update` - The new label, note and color.
+/// * `repo` - The managed bookmark repository state.
+///
+/// # Errors
+///
+/// This function will return an `Err` if:
+/// * The color code is not a hex color (`Error::InvalidArgument`).
+/// * The bookmark does not exist or the underlying repository operation fails.
+#[tauri::command]
+#[specta::specta]
+pub async fn update_bookmark<R: tauri::Runtime>(
+    id: i64,
+    update: BookmarkUpdate,
+    repo: State<'_, Arc<dyn BookmarkRepository>>,
+    app: tauri::AppHandle<R>,
+) -> Result<()> {
+    log::debug!("Update bookmark. (id:{}, update:{:?})", id, update);
+    update.validate()?;
+    repo.update(id, &update).await?;
+    app.emit("history-changed", ())?;
+    Ok(())
+}
+
+/// Deletes a bookmark.
+///
+/// # Arguments
+///
+/// * `id` - The ID of the bookmark to delete.
+/// * `repo` - The managed bookmark repository state.
+///
+/// # Errors
+///
+/// This function will return an `Err` if the bookmark does not exist or the underlying
+/// repository operation fails.
+#[tauri::command]
+#[specta::specta]
+pub async fn delete_bookmark<R: tauri::Runtime>(
+    id: i64,
+    repo: State<'_, Arc<dyn BookmarkRepository>>,
+    app: tauri::AppHandle<R>,
+) -> Result<()> {
+    log::debug!("Delete bookmark. (id:{})", id);
+    repo.delete(id).await?;
+    app.emit("history-changed", ())?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::bookmark::repository::MockBookmarkRepository;
+    use crate::error::Error;
+    use tauri::Manager;
+
+    fn bookmark(id: i64, page_index: i64) -> Bookmark {
+        Bookmark {
+            id,
+            book_id: 1,
+            page_index: Some(page_index),
+            cfi: None,
+            label: "Chapter 2".to_string(),
+            note: String::new(),
+            color_code: None,
+            created_at: chrono::Utc::now().naive_utc(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_add_bookmark() {
+        let mut mock_repo = MockBookmarkRepository::new();
+        mock_repo
+            .expect_add()
+            .withf(|bookmark| bookmark.book_id == 1 && bookmark.page_index == Some(12))
+            .times(1)
+            .returning(|_| Ok(bookmark(5, 12)));
+
+        let app = tauri::test::mock_app();
+        app.manage(Arc::new(mock_repo) as Arc<dyn BookmarkRepository>);
+        let state = app.state::<Arc<dyn BookmarkRepository>>();
+
+        let new_bookmark = NewBookmark {
+            book_id: 1,
+            page_index: Some(12),
+            label: "Chapter 2".to_string(),
+            ..Default::default()
+        };
+        let added = add_bookmark(new_bookmark, state, app.handle().clone())
+            .await
+            .unwrap();
+        assert_eq!(added.id, 5);
+    }
+
+    #[tokio::test]
+    async fn test_add_bookmark_rejects_missing_position() {
+        let mut mock_repo = MockBookmarkRepository::new();
+        mock_repo.expect_add().never();
+
+        let app = tauri::test::mock_app();
+        app.manage(Arc::new(mock_repo) as Arc<dyn BookmarkRepository>);
+        let state = app.state::<Arc<dyn BookmarkRepository>>();
+
+        let new_bookmark = NewBookmark {
+            book_id: 1,
+            ..Default::default()
+        };
+        let result = add_bookmark(new_bookmark, state, app.handle().clone()).await;
+        assert!(matches!(result, Err(Error::InvalidArgument(_))));
+    }
+
+    #[tokio::test]
+    async fn test_get_bookmarks() {
+        let mut mock_repo = MockBookmarkRepository::new();
+        mock_repo
+            .expect_get_for_book()
+            .with(mockall::predicate::eq(1))
+            .times(1)
+            .returning(|_| Ok(vec![bookmark(1, 3), bookmark(2, 8)]));
+
+        let app = tauri::test::mock_app();
+        app.manage(Arc::new(mock_repo) as Arc<dyn BookmarkRepository>);
+        let state = app.state::<Arc<dyn BookmarkRepository>>();
+
+        let bookmarks = get_bookmarks(1, state).await.unwrap();
+        assert_eq!(bookmarks.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_get_all_bookmarks() {
+        let mut mock_repo = MockBookmarkRepository::new();
+        mock_repo.expect_get_all().times(1).returning(|| Ok(vec![]));
+
+        let app = tauri::test::mock_app();
+        app.manage(Arc::new(mock_repo) as Arc<dyn BookmarkRepository>);
+        let state = app.state::<Arc<dyn BookmarkRepository>>();
+
+        let result = get_all_bookmarks(state).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_update_bookmark() {
+        let mut mock_repo = MockBookmarkRepository::new();
+        mock_repo
+            .expect_update()
+            .withf(|id, update| *id == 5 && update.note == "Reread this")
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let app = tauri::test::mock_app();
+        app.manage(Arc::new(mock_repo) as Arc<dyn BookmarkRepository>);
+        let state = app.state::<Arc<dyn BookmarkRepository>>();
+
+        let update = BookmarkUpdate {
+            note: "Reread this".to_string(),
+            ..Default::default()
+        };
+        let result = update_bookmark(5, update, state, app.handle().clone()).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_update_bookmark_rejects_invalid_color() {
+        let mut mock_repo = MockBookmarkRepository::new();
+        mock_repo.expect_update().never();
+
+        let app = tauri::test::mock_app();
+        app.manage(Arc::new(mock_repo) as Arc<dyn BookmarkRepository>);
+        let state = app.state::<Arc<dyn BookmarkRepository>>();
+
+        let update = BookmarkUpdate {
+            color_code: Some("green".to_string()),
+            ..Default::default()
+        };
+        let result = update_bookmark(5, update, state, app.handle().clone()).await;
+        assert!(matches!(result, Err(Error::InvalidArgument(_))));
+    }
+
+    #[tokio::test]
+    async fn test_delete_bookmark() {
+        let mut mock_repo = MockBookmarkRepository::new();
+        mock_repo
+            .expect_delete()
+            .with(mockall::predicate::eq(5))
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let app = tauri::test::mock_app();
+        app.manage(Arc::new(mock_repo) as Arc<dyn BookmarkRepository>);
+        let state = app.state::<Arc<dyn BookmarkRepository>>();
+
+        let result = delete_bookmark(5, state, app.handle().clone()).await;
+        assert!(result.is_ok());
+    }
+}
@@ -2,7 +2,7 @@ use std::sync::Arc;
 use tauri::Emitter;
 use tauri::State;
 
-use crate::domain::bookshelf::entity::Bookshelf;
+use crate::domain::bookshelf::entity::{Bookshelf, BookshelfRule};
 use crate::domain::bookshelf::repository::BookshelfRepository;
 use crate::error::Result;
 
@@ -59,6 +59,37 @@ pub async fn get_all_bookshelves(
     repo.get_all().await
 }
 
+/// Turns a bookshelf into a smart bookshelf, whose books are the ones matching a rule,
+/// or back into a bookshelf whose books are added by hand.
+///
+/// # Arguments
+///
+/// * `id` - The ID of the bookshelf.
+/// * `rule` - The rule selecting the books, or `None` to use the books added by hand.
+/// * `repo` - The managed bookshelf repository state.
+///
+/// # Errors
+///
+/// This function will return an `Err` if:
+/// * The rule has a condition that cannot match a book (`Error::InvalidArgument`).
+/// * The underlying repository operation fails.
+#[tauri::command]
+#[specta::specta]
+pub async fn set_bookshelf_rule<R: tauri::Runtime>(
+    id: i64,
+    rule: Option<BookshelfRule>,
+    repo: State<'_, Arc<dyn BookshelfRepository>>,
+    app: tauri::AppHandle<R>,
+) -> Result<()> {
+    log::debug!("Set bookshelf rule. (id:{}, rule:{:?})", id, rule);
+    if let Some(rule) = &rule {
+        rule.validate()?;
+    }
+    repo.set_rule(id, rule).await?;
+    app.emit("history-changed", ())?;
+    Ok(())
+}
+
 /// Adds a book to a specific bookshelf.
 ///
 /// # Arguments
@@ -146,7 +177,7 @@ pub async fn delete_bookshelf<R: tauri::Runtime>(
 mod tests {
     use super::*;
     use crate::domain::bookshelf::repository::MockBookshelfRepository;
-    use crate::error::ErrorCode;
+    use crate::error::{Error, ErrorCode};
     use tauri::Manager;
 
     #[tokio::test]
@@ -165,6 +196,7 @@ mod tests {
                     name: name.to_string(),
                     icon_id: icon_id.to_string(),
                     created_at: None,
+                    rule: None,
                 })
             });
 
@@ -196,12 +228,14 @@ mod tests {
                     name: "shelf1".to_string(),
                     icon_id: "icon1".to_string(),
                     created_at: None,
+                    rule: None,
                 },
                 Bookshelf {
                     id: 2,
                     name: "shelf2".to_string(),
                     icon_id: "icon2".to_string(),
                     created_at: None,
+                    rule: None,
                 },
             ])
         });
@@ -222,6 +256,51 @@ mod tests {
         assert_eq!(shelves[1].icon_id, "icon2");
     }
 
+    #[tokio::test]
+    async fn test_set_bookshelf_rule() {
+        let mut mock_repo = MockBookshelfRepository::new();
+        mock_repo
+            .expect_set_rule()
+            .withf(|id, rule| *id == 1 && rule.as_ref().is_some_and(|rule| rule.tag_ids == vec![3]))
+            .times(1)
+            .returning(|_, _| Ok(()));
+        mock_repo
+            .expect_set_rule()
+            .withf(|id, rule| *id == 2 && rule.is_none())
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let app = tauri::test::mock_app();
+        app.manage(Arc::new(mock_repo) as Arc<dyn BookshelfRepository>);
+        let state = app.state::<Arc<dyn BookshelfRepository>>();
+
+        let rule = BookshelfRule {
+            tag_ids: vec![3],
+            ..Default::default()
+        };
+        let result = set_bookshelf_rule(1, Some(rule), state.clone(), app.handle().clone()).await;
+        assert!(result.is_ok());
+        let result = set_bookshelf_rule(2, None, state, app.handle().clone()).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_set_bookshelf_rule_rejects_invalid_rule() {
+        let mut mock_repo = MockBookshelfRepository::new();
+        mock_repo.expect_set_rule().times(0);
+
+        let app = tauri::test::mock_app();
+        app.manage(Arc::new(mock_repo) as Arc<dyn BookshelfRepository>);
+        let state = app.state::<Arc<dyn BookshelfRepository>>();
+
+        let rule = BookshelfRule {
+            added_within_days: Some(0),
+            ..Default::default()
+        };
+        let result = set_bookshelf_rule(1, Some(rule), state, app.handle().clone()).await;
+        assert!(matches!(result, Err(Error::InvalidArgument(_))));
+    }
+
     #[tokio::test]
     async fn test_add_book_to_bookshelf() {
         let mut mock_repo = MockBookshelfRepository::new();
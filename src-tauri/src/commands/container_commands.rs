@@ -1,10 +1,30 @@
+use std::{
+    collections::BTreeMap,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
 use tokio::sync::{Mutex, RwLock};
 
 use serde::{Deserialize, Serialize};
-use tauri::ipc::Response;
+use tauri::{
+    ipc::{Channel, InvokeResponseBody, Response},
+    AppHandle, Emitter,
+};
 
 use crate::{
-    error::{Error, Result},
+    container::entry_filter::ExclusionRule,
+    domain::book::repository::BookRepository,
+    error::{Error, ErrorCode, Result},
+    image::{
+        cache_stats::CacheStats,
+        loader::ImageLoader,
+        metrics::{self, PerformanceMetrics},
+        strip::StripLayout,
+        tile::TileInfo,
+        types::Image,
+    },
     state::{app_state::AppState, container_state::ContainerState},
 };
 
@@ -15,6 +35,84 @@ use crate::{
 /// open from installing after a newer one.
 static OPEN_CONTAINER_LOCK: Mutex<()> = Mutex::const_new(());
 
+/// Event emitted after the open book was rebuilt with new rendering settings. It carries
+/// the book path so the frontend can re-fetch the visible page of that book.
+const RENDERING_SETTINGS_APPLIED_EVENT: &str = "rendering-settings-applied";
+
+/// Cancellation flags of the in-flight progressive image requests, keyed by the
+/// frontend-issued request ID.
+///
+/// This is a plain `std` mutex: it is only held for a map insert/remove and never
+/// across an `.await`.
+static PROGRESSIVE_REQUESTS: std::sync::Mutex<BTreeMap<u32, Arc<AtomicBool>>> =
+    std::sync::Mutex::new(BTreeMap::new());
+
+/// The stage of an image frame sent over a progressive image channel.
+///
+/// Sent as the first byte of every frame so the frontend can tell a placeholder
+/// preview from the final image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+enum ImageStage {
+    /// A fast, low-resolution preview (the container's thumbnail).
+    Preview = 0,
+    /// The full-resolution image, as `get_image` would return it.
+    Full = 1,
+}
+
+/// A registered progressive image request.
+///
+/// Registering replaces any earlier flag for the same ID; dropping removes the flag
+/// again, but only if it is still this request's (a reused ID must not unregister the
+/// newer request).
+struct ProgressiveRequest {
+    request_id: u32,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl ProgressiveRequest {
+    fn register(request_id: u32) -> Self {
+        let cancelled = Arc::new(AtomicBool::new(false));
+        PROGRESSIVE_REQUESTS
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(request_id, cancelled.clone());
+        Self {
+            request_id,
+            cancelled,
+        }
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+impl Drop for ProgressiveRequest {
+    fn drop(&mut self) {
+        let mut requests = PROGRESSIVE_REQUESTS
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        if requests
+            .get(&self.request_id)
+            .is_some_and(|flag| Arc::ptr_eq(flag, &self.cancelled))
+        {
+            requests.remove(&self.request_id);
+        }
+    }
+}
+
+/// Encodes an image as a progressive channel frame:
+/// `[Stage (1 byte)][Width (4 bytes)][Height (4 bytes)][Image Data...]`.
+fn encode_frame(stage: ImageStage, image: &Image) -> InvokeResponseBody {
+    let mut frame = Vec::with_capacity(9 + image.data.len());
+    frame.push(stage as u8);
+    frame.extend_from_slice(&image.width.to_be_bytes());
+    frame.extend_from_slice(&image.height.to_be_bytes());
+    frame.extend_from_slice(&image.data);
+    InvokeResponseBody::Raw(frame)
+}
+
 /// The result of getting entries in a container.
 #[derive(Serialize, Deserialize, specta::Type)]
 pub struct EntriesResult {
@@ -24,22 +122,33 @@ pub struct EntriesResult {
     is_directory: bool,
     /// Whether the container is an EPUB novel.
     is_novel: bool,
+    /// The error code explaining why the container could only be opened partially (e.g.
+    /// a truncated ZIP whose pages were salvaged), or `None` if it opened cleanly.
+    damage_code: Option<i32>,
+    /// The entry names hidden by the exclusion rules, so the user can see what was left
+    /// out.
+    excluded_entries: Vec<String>,
 }
 
 /// Opens a container file (e.g., ZIP, RAR) and retrieves a list of its contents.
 ///
 /// This function opens the container specified by the `path` and reads the list of file entries
-/// within it.
+/// within it. Entries matching the global exclusion rules or the book's own rules are left
+/// out, and the rest are sorted by the sort mode from the settings, then by the book's
+/// manual page order.
 ///
 /// # Arguments
 ///
 /// * `path` - The file path to the container to open.
+/// * `repo` - The managed book repository state, used to look up the book's own rules and
+///   page order.
 /// * `state` - A `tauri::State` holding the application's global `AppState`.
 ///
 /// # Returns
 ///
 /// A `Result` which is `Ok` with an `EntriesResult` struct containing the list of entry
-/// names, a boolean indicating if the path is a directory, and a boolean indicating if it's a novel.
+/// names, a boolean indicating if the path is a directory, a boolean indicating if it's a novel,
+/// the error code of any damage found while opening it, and the excluded entry names.
 ///
 /// # Errors
 ///
@@ -50,10 +159,21 @@ pub struct EntriesResult {
 #[specta::specta]
 pub async fn get_entries_in_container(
     path: &str,
+    repo: tauri::State<'_, Arc<dyn BookRepository>>,
     state: tauri::State<'_, RwLock<AppState>>,
 ) -> Result<EntriesResult> {
     log::debug!("Get the entries in {}", path);
 
+    let (book_rules, page_order) = match book_listing(repo.inner().as_ref(), path).await {
+        Ok(listing) => listing,
+        Err(e) => {
+            // The book's own rules and order only refine the listing; open it with the
+            // global settings.
+            log::warn!("Failed to get the listing settings of {path}: {e}");
+            (Vec::new(), Vec::new())
+        }
+    };
+
     // Serialize opens so a slower earlier open can't install after a newer one and
     // leave the wrong book's images loaded.
     let _open_guard = OPEN_CONTAINER_LOCK.lock().await;
@@ -61,16 +181,20 @@ pub async fn get_entries_in_container(
     // Snapshot the (cheap-to-clone) settings and cache handle under a brief read lock,
     // then run the heavy build on a blocking thread so it never stalls the async runtime
     // (image fetches, IPC) while opening a large book on slow storage.
-    let (settings, image_cache) = {
+    let (mut settings, image_cache, cache_generation) = {
         let state_lock = state.read().await;
         (
             state_lock.container_state.settings.clone(),
             state_lock.container_state.image_cache.clone(),
+            state_lock.container_state.cache_generation.clone(),
         )
     };
+    settings.listing.exclusion_rules.extend(book_rules);
+    settings.listing.page_order = page_order;
+    let listing = settings.listing.clone();
     let path_owned = path.to_string();
     let built = tauri::async_runtime::spawn_blocking(move || {
-        ContainerState::build_with(&settings, &image_cache, &path_owned)
+        ContainerState::build_with(&settings, &image_cache, &cache_generation, &path_owned)
     })
     .await
     .map_err(|e| Error::Other(format!("Spawn blocking failed: {e}")))
@@ -87,10 +211,16 @@ pub async fn get_entries_in_container(
     let entries = container.get_entries().clone();
     let is_directory = container.is_directory();
     let is_novel = container.is_novel();
+    let damage_code = container.damage().map(|damage| {
+        log::warn!("Opened {} partially: {}", path, damage);
+        ErrorCode::from(&damage).code()
+    });
+    let excluded_entries = container.excluded_entries().to_vec();
 
     {
         let mut state_lock = state.write().await;
         state_lock.container_state.install(container, loader);
+        state_lock.container_state.open_listing = listing;
     }
 
     if !is_novel {
@@ -108,9 +238,111 @@ pub async fn get_entries_in_container(
         entries,
         is_directory,
         is_novel,
+        damage_code,
+        excluded_entries,
     })
 }
 
+/// Returns the book's own exclusion rules and manual page order, or neither if the book
+/// is not registered yet.
+async fn book_listing(
+    repo: &dyn BookRepository,
+    path: &str,
+) -> Result<(Vec<ExclusionRule>, Vec<String>)> {
+    match repo.get_by_path(path).await? {
+        Some(book) => Ok((
+            repo.get_exclusion_rules(book.id).await?,
+            repo.get_page_order(book.id).await?,
+        )),
+        None => Ok((Vec::new(), Vec::new())),
+    }
+}
+
+/// Rebuilds the open container and its image loader with the current rendering settings.
+///
+/// An open `ImageLoader` captures its resize height/method, encoding, PDF resolution and
+/// long-strip slicing at construction; this swaps in one built from the live
+/// `ContainerSettings` so a settings change reaches the book on screen. Only that book's
+/// cache entries are invalidated, preloading resumes around the last requested page, and
+/// a `rendering-settings-applied` event carrying the book path tells the frontend to
+/// re-fetch the visible page.
+///
+/// Does nothing when no book is open, or when a novel is open (its text is not rendered
+/// through the image loader).
+///
+/// # Arguments
+///
+/// * `app` - The Tauri app handle used to emit the event.
+/// * `state` - The application's runtime state.
+///
+/// # Errors
+///
+/// Returns an `Err` if the container cannot be rebuilt or the event cannot be emitted.
+/// The previous container stays installed when the rebuild fails.
+pub async fn reload_open_container<R: tauri::Runtime>(
+    app: &AppHandle<R>,
+    state: &RwLock<AppState>,
+) -> Result<()> {
+    // Ordered with opens so a concurrent open is never overwritten by a reload of the
+    // book it replaced.
+    let _open_guard = OPEN_CONTAINER_LOCK.lock().await;
+
+    let (settings, image_cache, cache_generation, previous) = {
+        let state_lock = state.read().await;
+        let container_state = &state_lock.container_state;
+        let Some(loader) = container_state.image_loader.clone() else {
+            return Ok(());
+        };
+        if container_state
+            .container
+            .as_ref()
+            .is_some_and(|container| container.is_novel())
+        {
+            return Ok(());
+        }
+        // Keep the listing the book was opened with so its page indices stay valid.
+        let mut settings = container_state.settings.clone();
+        settings.listing = container_state.open_listing.clone();
+        (
+            settings,
+            container_state.image_cache.clone(),
+            container_state.cache_generation.clone(),
+            loader,
+        )
+    };
+
+    let path = previous.book_id().to_string();
+    log::debug!("Reload {} with the new rendering settings", path);
+    let path_owned = path.clone();
+    let (container, loader) = tauri::async_runtime::spawn_blocking(move || {
+        ContainerState::build_with(&settings, &image_cache, &cache_generation, &path_owned)
+    })
+    .await
+    .map_err(|e| Error::Other(format!("Spawn blocking failed: {e}")))??;
+
+    {
+        let mut state_lock = state.write().await;
+        // Stop the old loader's preloads first; the invalidation also advances the cache
+        // generation, so pages it is still rendering under the previous settings are
+        // dropped instead of refilling the cache.
+        previous.cancel_preload();
+        state_lock.container_state.invalidate_book_cache(&path);
+        state_lock.container_state.install(container, loader);
+
+        if let Some((center_index, buffer_size)) = previous.last_preload() {
+            if let Some(loader) = state_lock.container_state.image_loader.as_ref() {
+                if let Err(e) = loader.request_preload_around(center_index, buffer_size) {
+                    // Preloading is best-effort; the reload itself has succeeded.
+                    log::warn!("Failed to resume preloading for {path}: {e}");
+                }
+            }
+        }
+    }
+
+    app.emit(RENDERING_SETTINGS_APPLIED_EVENT, &path)?;
+    Ok(())
+}
+
 /// Requests preloading of images around a specific index.
 ///
 /// This command can be called as the user navigates through a book to update
@@ -148,6 +380,33 @@ pub async fn request_preload_around(
     Ok(())
 }
 
+/// Clones the installed image loader out of the app state.
+///
+/// The loader handle is cloned under a brief read lock, which is released right away so
+/// the decode that follows runs without blocking other state access.
+///
+/// Requests that raced a book switch are rejected with `Error::EntryNotFound`: entry
+/// names collide across archives (e.g. 0001.jpg), so resolving against the wrong loader
+/// would silently return another book's page.
+async fn current_image_loader(
+    path: &str,
+    entry_name: &str,
+    state: &tauri::State<'_, RwLock<AppState>>,
+) -> Result<Arc<ImageLoader>> {
+    let image_loader = {
+        let state_lock = state.read().await;
+        state_lock.container_state.image_loader.clone()
+    }
+    .ok_or_else(|| Error::Other("Unexpected error. Container is empty!".to_string()))?;
+
+    if image_loader.book_id() != path {
+        return Err(Error::EntryNotFound(format!(
+            "Container changed while requesting {entry_name} (requested {path})"
+        )));
+    }
+    Ok(image_loader)
+}
+
 /// Retrieves an image from the currently open container.
 ///
 /// This function fetches the binary data for a specified image entry from the container
@@ -177,23 +436,7 @@ pub async fn get_image(
 ) -> Result<Response> {
     log::debug!("Get the binary of {} in {}", entry_name, path);
 
-    // Clone the loader handle out under a brief read lock, then release the lock so the
-    // decode below runs without blocking other state access.
-    let image_loader = {
-        let state_lock = state.read().await;
-        state_lock.container_state.image_loader.clone()
-    }
-    .ok_or_else(|| Error::Other("Unexpected error. Container is empty!".to_string()))?;
-
-    // Reject stale requests that raced a book switch: entry names collide across archives
-    // (e.g. 0001.jpg), so resolving against the wrong loader would silently return another
-    // book's page.
-    if image_loader.book_id() != path {
-        return Err(Error::EntryNotFound(format!(
-            "Container changed while requesting {entry_name} (requested {path})"
-        )));
-    }
-
+    let image_loader = current_image_loader(path, entry_name, &state).await?;
     let entry = entry_name.to_string();
     let image = tauri::async_runtime::spawn_blocking(move || image_loader.get_image(&entry))
         .await
@@ -232,19 +475,7 @@ pub async fn get_image_preview(
 ) -> Result<Response> {
     log::debug!("Get the preview binary of {} in {}", entry_name, path);
 
-    let image_loader = {
-        let state_lock = state.read().await;
-        state_lock.container_state.image_loader.clone()
-    }
-    .ok_or_else(|| Error::Other("Unexpected error. Container is empty!".to_string()))?;
-
-    // Reject stale requests that raced a book switch (see get_image).
-    if image_loader.book_id() != path {
-        return Err(Error::EntryNotFound(format!(
-            "Container changed while requesting {entry_name} (requested {path})"
-        )));
-    }
-
+    let image_loader = current_image_loader(path, entry_name, &state).await?;
     let entry = entry_name.to_string();
     let preview =
         tauri::async_runtime::spawn_blocking(move || image_loader.get_preview_image(&entry))
@@ -259,19 +490,313 @@ pub async fn get_image_preview(
     Ok(image.to_ipc_response())
 }
 
+/// Streams an image from the currently open container: a low-resolution preview first,
+/// then the full-resolution image once it has been decoded (and resized).
+///
+/// Each frame sent over `on_image` uses the binary format
+/// `[Stage (1 byte)][Width (4 bytes)][Height (4 bytes)][Image Data...]`, where the stage
+/// is `0` for the preview and `1` for the full image. The preview is skipped when the
+/// full image is already cached or previews are disabled, and is best-effort: failing
+/// to produce it does not fail the request.
+///
+/// The request can be abandoned with `cancel_image_request` (e.g. once the user has
+/// turned the page); a cancelled request stops before the next frame is sent. A full
+/// image that finished decoding is still cached, so returning to the page is instant.
+///
+/// # Arguments
+///
+/// * `path` - The path of the container, used to reject requests that raced a book switch.
+/// * `entry_name` - The name of the image entry to retrieve (e.g., "image1.png").
+/// * `request_id` - A frontend-issued ID identifying this request for cancellation.
+/// * `on_image` - The channel the image frames are sent over.
+/// * `state` - A `tauri::State` holding the application's global `AppState`.
+///
+/// # Errors
+///
+/// This function will return an `Err` if:
+/// * The `image_loader` within the application state is unexpectedly missing.
+/// * The requested image entry cannot be found or decoded.
+/// * A frame cannot be sent over the channel.
+#[tauri::command]
+pub async fn get_image_progressive(
+    path: &str,
+    entry_name: &str,
+    request_id: u32,
+    on_image: Channel,
+    state: tauri::State<'_, RwLock<AppState>>,
+) -> Result<()> {
+    log::debug!(
+        "Get the progressive binary of {} in {} (request {})",
+        entry_name,
+        path,
+        request_id
+    );
+
+    // Registered before the first await, so a cancellation arriving while the state is
+    // read is not lost.
+    let request = ProgressiveRequest::register(request_id);
+    let image_loader = current_image_loader(path, entry_name, &state).await?;
+    let enable_preview = state.read().await.container_state.settings.enable_preview;
+
+    let entry = entry_name.to_string();
+    tauri::async_runtime::spawn_blocking(move || {
+        if enable_preview {
+            match image_loader.get_preview_image(&entry) {
+                Ok(Some(preview)) => {
+                    if request.is_cancelled() {
+                        return Ok(());
+                    }
+                    on_image.send(encode_frame(ImageStage::Preview, &preview))?;
+                }
+                // The full image is already cached; it is sent right below.
+                Ok(None) => {}
+                Err(e) => log::warn!("Failed to get the preview of {entry}: {e}"),
+            }
+        }
+
+        if request.is_cancelled() {
+            log::debug!("Progressive request {} cancelled.", request.request_id);
+            return Ok(());
+        }
+        let image = image_loader.get_image(&entry)?;
+        if request.is_cancelled() {
+            log::debug!("Progressive request {} cancelled.", request.request_id);
+            return Ok(());
+        }
+        on_image.send(encode_frame(ImageStage::Full, &image))?;
+        Ok(())
+    })
+    .await
+    .map_err(|e| Error::Other(format!("Spawn blocking failed: {e}")))?
+}
+
+/// Cancels an in-flight progressive image request.
+///
+/// Cancelling an unknown or already finished request is a no-op.
+///
+/// # Arguments
+///
+/// * `request_id` - The ID passed to `get_image_progressive`.
+#[tauri::command()]
+#[specta::specta]
+pub async fn cancel_image_request(request_id: u32) -> Result<()> {
+    log::debug!("Cancel progressive image request {}", request_id);
+    if let Some(flag) = PROGRESSIVE_REQUESTS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(&request_id)
+    {
+        flag.store(true, Ordering::Relaxed);
+    }
+    Ok(())
+}
+
+/// Describes the deep-zoom tile pyramid of an image in the currently open container.
+///
+/// The pyramid is built over the image's full-resolution source (for PDF, the page
+/// re-rendered at a higher DPI), not the downscaled page returned by `get_image`.
+///
+/// # Arguments
+///
+/// * `path` - The path of the container, used to reject requests that raced a book switch.
+/// * `entry_name` - The name of the image entry.
+/// * `state` - A `tauri::State` holding the application's global `AppState`.
+///
+/// # Returns
+///
+/// A `Result` which is `Ok` with the `TileInfo` (source dimensions, tile size and
+/// number of levels) of the image.
+///
+/// # Errors
+///
+/// This function will return an `Err` if:
+/// * The `image_loader` within the application state is unexpectedly missing.
+/// * The full-resolution source cannot be loaded or decoded.
+#[tauri::command()]
+#[specta::specta]
+pub async fn get_image_tile_info(
+    path: &str,
+    entry_name: &str,
+    state: tauri::State<'_, RwLock<AppState>>,
+) -> Result<TileInfo> {
+    log::debug!("Get the tile info of {} in {}", entry_name, path);
+
+    let image_loader = current_image_loader(path, entry_name, &state).await?;
+    let entry = entry_name.to_string();
+    tauri::async_runtime::spawn_blocking(move || image_loader.get_tile_info(&entry))
+        .await
+        .map_err(|e| Error::Other(format!("Spawn blocking failed: {e}")))?
+}
+
+/// Retrieves a deep-zoom tile of an image in the currently open container.
+///
+/// Level `0` is the full-resolution source and each further level halves it, so the
+/// loupe and zoom can show real detail without transferring the whole original.
+///
+/// # Arguments
+///
+/// * `path` - The path of the container, used to reject requests that raced a book switch.
+/// * `entry_name` - The name of the image entry.
+/// * `level` - The pyramid level (`0` is the full resolution).
+/// * `x` - The tile column.
+/// * `y` - The tile row.
+/// * `state` - A `tauri::State` holding the application's global `AppState`.
+///
+/// # Returns
+///
+/// A `Result` which is `Ok` with a `tauri::ipc::Response`. The response body contains the
+/// tile in the same binary format as `get_image`:
+/// `[Width (4 bytes)][Height (4 bytes)][Image Data...]`.
+///
+/// # Errors
+///
+/// This function will return an `Err` if:
+/// * The `image_loader` within the application state is unexpectedly missing.
+/// * The tile lies outside the image's pyramid (`Error::InvalidArgument`).
+/// * The full-resolution source cannot be loaded or decoded.
+#[tauri::command]
+pub async fn get_image_tile(
+    path: &str,
+    entry_name: &str,
+    level: u32,
+    x: u32,
+    y: u32,
+    state: tauri::State<'_, RwLock<AppState>>,
+) -> Result<Response> {
+    log::debug!(
+        "Get the tile ({}, {}, {}) of {} in {}",
+        level,
+        x,
+        y,
+        entry_name,
+        path
+    );
+
+    let image_loader = current_image_loader(path, entry_name, &state).await?;
+    let entry = entry_name.to_string();
+    let tile =
+        tauri::async_runtime::spawn_blocking(move || image_loader.get_tile(&entry, level, x, y))
+            .await
+            .map_err(|e| Error::Other(format!("Spawn blocking failed: {e}")))??;
+
+    Ok(tile.to_ipc_response())
+}
+
+/// Lays the currently open container out as one continuous vertical strip.
+///
+/// Used by the long-strip (webtoon) reading mode: tall pages are sliced into
+/// overlapping segments whose entries are fetched through `get_image` like any other
+/// page, and consecutive short pages are stitched directly below each other.
+///
+/// # Arguments
+///
+/// * `path` - The path of the container, used to reject requests that raced a book switch.
+/// * `state` - A `tauri::State` holding the application's global `AppState`.
+///
+/// # Returns
+///
+/// A `Result` which is `Ok` with the `StripLayout` of the container.
+///
+/// # Errors
+///
+/// This function will return an `Err` if:
+/// * The `image_loader` within the application state is unexpectedly missing.
+/// * The dimensions of a page cannot be read.
+#[tauri::command()]
+#[specta::specta]
+pub async fn get_strip_layout(
+    path: &str,
+    state: tauri::State<'_, RwLock<AppState>>,
+) -> Result<StripLayout> {
+    log::debug!("Get the strip layout of {}", path);
+
+    let image_loader = current_image_loader(path, "the strip layout", &state).await?;
+    let layout = tauri::async_runtime::spawn_blocking(move || image_loader.get_strip_layout())
+        .await
+        .map_err(|e| Error::Other(format!("Spawn blocking failed: {e}")))??;
+
+    Ok(StripLayout::clone(&layout))
+}
+
+/// Reports the contents of the image cache and how the open book has used it.
+///
+/// # Arguments
+///
+/// * `state` - A `tauri::State` holding the application's global `AppState`.
+///
+/// # Returns
+///
+/// A `Result` which is `Ok` with the `CacheStats`: resident bytes and entries per book,
+/// plus the hit rate and preload usage of the open book.
+#[tauri::command()]
+#[specta::specta]
+pub async fn get_cache_stats(state: tauri::State<'_, RwLock<AppState>>) -> Result<CacheStats> {
+    let state_lock = state.read().await;
+    Ok(state_lock.container_state.cache_stats())
+}
+
+/// Reports the recent timings of the image pipeline.
+///
+/// Container reads, PDF renders, decodes, resizes and encodes are recorded in a ring
+/// buffer of the most recent samples, so a slow book can be attributed to a step and
+/// `max_image_height` and the cache size tuned per source format.
+///
+/// # Returns
+///
+/// A `Result` which is `Ok` with the recorded samples, oldest first, and their
+/// summaries per step and source format.
+#[tauri::command()]
+#[specta::specta]
+pub async fn get_performance_metrics() -> Result<PerformanceMetrics> {
+    Ok(metrics::snapshot())
+}
+
+/// Evicts cached images, either of one book or of every book.
+///
+/// Evicted pages are reloaded from their container the next time they are requested.
+///
+/// # Arguments
+///
+/// * `book_id` - Optional. The identifier (path) of the book to purge; every book is
+///   purged if `None`.
+/// * `state` - A `tauri::State` holding the application's global `AppState`.
+#[tauri::command()]
+#[specta::specta]
+pub async fn purge_image_cache(
+    book_id: Option<String>,
+    state: tauri::State<'_, RwLock<AppState>>,
+) -> Result<()> {
+    log::debug!("Purge the image cache of {:?}", book_id);
+    let state_lock = state.read().await;
+    state_lock
+        .container_state
+        .purge_image_cache(book_id.as_deref());
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use mockall::predicate::eq;
 
     use super::*;
     use std::{path, sync::Arc};
-    use tauri::{ipc::InvokeResponseBody::Raw, ipc::IpcResponse, Manager};
+    use tauri::{ipc::InvokeResponseBody::Raw, ipc::IpcResponse, Listener, Manager};
     use tokio::sync::RwLock;
 
     use crate::{
         container::traits::MockContainer,
-        image::{loader::ImageLoader, resizer::ResizeFilter, types::Image},
-        state::{container_settings::ContainerSettings, container_state::ContainerState},
+        domain::book::{entity::Book, repository::MockBookRepository},
+        image::{
+            encoder::EncodeOptions,
+            loader::{CacheGeneration, CacheKey, ImageLoader},
+            resizer::ResizeFilter,
+            strip::StripConfig,
+            types::Image,
+        },
+        state::{
+            container_settings::{ContainerSettings, ListingSettings},
+            container_state::ContainerState,
+        },
     };
 
     impl MockContainer {
@@ -306,6 +831,13 @@ mod tests {
         rar_filepath
     }
 
+    /// Manages a book repository in which no book is registered.
+    fn manage_empty_book_repository(app: &tauri::App<tauri::test::MockRuntime>) {
+        let mut mock_repo = MockBookRepository::new();
+        mock_repo.expect_get_by_path().returning(|_| Ok(None));
+        app.manage(Arc::new(mock_repo) as Arc<dyn BookRepository>);
+    }
+
     #[tokio::test]
     async fn test_get_entries_in_container() {
         let dir = tempfile::tempdir().unwrap();
@@ -318,9 +850,14 @@ mod tests {
 
         let app = tauri::test::mock_app();
         app.manage(RwLock::new(AppState::default()));
+        manage_empty_book_repository(&app);
 
-        let result =
-            get_entries_in_container(rar_path.to_string_lossy().as_ref(), app.state()).await;
+        let result = get_entries_in_container(
+            rar_path.to_string_lossy().as_ref(),
+            app.state(),
+            app.state(),
+        )
+        .await;
 
         assert!(result.is_ok());
 
@@ -336,8 +873,9 @@ mod tests {
     async fn test_get_entries_in_container_empty_container() {
         let app = tauri::test::mock_app();
         app.manage(RwLock::new(AppState::default()));
+        manage_empty_book_repository(&app);
 
-        let result = get_entries_in_container("non_existent_path", app.state()).await;
+        let result = get_entries_in_container("non_existent_path", app.state(), app.state()).await;
 
         assert!(result.is_err());
     }
@@ -349,11 +887,16 @@ mod tests {
 
         let app = tauri::test::mock_app();
         app.manage(RwLock::new(AppState::default()));
+        manage_empty_book_repository(&app);
 
         // Open a valid container first; state now holds a container + loader.
-        get_entries_in_container(rar_path.to_string_lossy().as_ref(), app.state())
-            .await
-            .expect("opening a valid container should succeed");
+        get_entries_in_container(
+            rar_path.to_string_lossy().as_ref(),
+            app.state(),
+            app.state(),
+        )
+        .await
+        .expect("opening a valid container should succeed");
         {
             let binding = app.state::<RwLock<AppState>>();
             let guard = binding.read().await;
@@ -363,7 +906,7 @@ mod tests {
 
         // A subsequent failed open must clear the previous container/loader so we
         // never serve images from the old book.
-        let result = get_entries_in_container("non_existent_path", app.state()).await;
+        let result = get_entries_in_container("non_existent_path", app.state(), app.state()).await;
         assert!(result.is_err());
         {
             let binding = app.state::<RwLock<AppState>>();
@@ -383,19 +926,153 @@ mod tests {
 
         let app = tauri::test::mock_app();
         app.manage(RwLock::new(AppState::default()));
+        manage_empty_book_repository(&app);
 
-        let result =
-            get_entries_in_container(rar_path.to_string_lossy().as_ref(), app.state()).await;
+        let result = get_entries_in_container(
+            rar_path.to_string_lossy().as_ref(),
+            app.state(),
+            app.state(),
+        )
+        .await;
 
         let entries_result = result.expect("a valid non-novel open should succeed");
         assert!(!entries_result.is_novel);
         assert_eq!(3, entries_result.entries.len());
+        assert_eq!(entries_result.damage_code, None);
+        assert!(entries_result.excluded_entries.is_empty());
 
         let binding = app.state::<RwLock<AppState>>();
         let guard = binding.read().await;
         assert!(guard.container_state.image_loader.is_some());
     }
 
+    #[tokio::test]
+    async fn test_get_entries_in_container_applies_book_listing() {
+        let dir = tempfile::tempdir().unwrap();
+        let rar_path = create_dummy_rar(dir.path(), "dummy.rar");
+        let rar_path = rar_path.to_string_lossy().to_string();
+
+        let mut mock_repo = MockBookRepository::new();
+        let book_path = rar_path.clone();
+        mock_repo
+            .expect_get_by_path()
+            .with(eq(rar_path.clone()))
+            .returning(move |_| {
+                Ok(Some(Book {
+                    id: 7,
+                    file_path: book_path.clone(),
+                    item_type: "file".to_string(),
+                    display_name: "dummy".to_string(),
+                    total_pages: 3,
+                    series_id: None,
+                    series_order: None,
+                    thumbnail_path: None,
+                }))
+            });
+        mock_repo
+            .expect_get_exclusion_rules()
+            .with(eq(7))
+            .returning(|_| Ok(vec![ExclusionRule::glob("image2.*")]));
+        mock_repo
+            .expect_get_page_order()
+            .with(eq(7))
+            .returning(|_| Ok(vec!["image3.png".to_string()]));
+
+        let app = tauri::test::mock_app();
+        app.manage(RwLock::new(AppState::default()));
+        app.manage(Arc::new(mock_repo) as Arc<dyn BookRepository>);
+
+        let result = get_entries_in_container(&rar_path, app.state(), app.state())
+            .await
+            .expect("opening a valid container should succeed");
+
+        assert_eq!(result.entries, vec!["image3.png", "image1.png"]);
+        assert_eq!(result.excluded_entries, vec!["image2.png"]);
+
+        // The combined listing is kept so a reload lists the same pages.
+        let binding = app.state::<RwLock<AppState>>();
+        let guard = binding.read().await;
+        let open_listing = &guard.container_state.open_listing;
+        assert!(open_listing
+            .exclusion_rules
+            .contains(&ExclusionRule::glob("image2.*")));
+        assert_eq!(open_listing.page_order, vec!["image3.png"]);
+    }
+
+    #[tokio::test]
+    async fn test_reload_open_container_rebuilds_the_loader_and_invalidates_the_book() {
+        let dir = tempfile::tempdir().unwrap();
+        let rar_path = create_dummy_rar(dir.path(), "dummy.rar");
+        let rar_path = rar_path.to_string_lossy().to_string();
+
+        let app = tauri::test::mock_app();
+        app.manage(RwLock::new(AppState::default()));
+        manage_empty_book_repository(&app);
+        get_entries_in_container(&rar_path, app.state(), app.state())
+            .await
+            .expect("opening a valid container should succeed");
+
+        let stale = MockContainer::create_dummy_image();
+        let other_book = CacheKey::page("other.zip", "image1.png");
+        let state = app.state::<RwLock<AppState>>();
+        let (previous, generation) = {
+            let mut guard = state.write().await;
+            let container_state = &mut guard.container_state;
+            container_state
+                .image_cache
+                .insert(CacheKey::page(&rar_path, "image1.png"), stale.clone());
+            container_state
+                .image_cache
+                .insert(other_book.clone(), stale.clone());
+            container_state.settings.max_image_height = 100;
+            (
+                container_state.image_loader.clone().unwrap(),
+                container_state.cache_generation.current(),
+            )
+        };
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        app.listen_any(RENDERING_SETTINGS_APPLIED_EVENT, move |event| {
+            tx.send(event.payload().to_string()).unwrap();
+        });
+
+        reload_open_container(app.handle(), state.inner())
+            .await
+            .unwrap();
+
+        let guard = state.read().await;
+        let container_state = &guard.container_state;
+        let loader = container_state.image_loader.as_ref().unwrap();
+        assert!(!Arc::ptr_eq(loader, &previous));
+        assert_eq!(loader.book_id(), rar_path);
+        // The resumed preload may have cached the page again, but not the stale one.
+        assert!(container_state
+            .image_cache
+            .get(&CacheKey::page(&rar_path, "image1.png"))
+            .is_none_or(|image| !Arc::ptr_eq(&image, &stale)));
+        assert!(container_state.image_cache.contains_key(&other_book));
+        // Loads of the previous loader still in flight are dropped.
+        assert_ne!(container_state.cache_generation.current(), generation);
+        assert_eq!(
+            rx.recv_timeout(std::time::Duration::from_secs(1)).unwrap(),
+            serde_json::to_string(&rar_path).unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_reload_open_container_without_an_open_book() {
+        let app = tauri::test::mock_app();
+        app.manage(RwLock::new(AppState::default()));
+
+        reload_open_container(app.handle(), app.state::<RwLock<AppState>>().inner())
+            .await
+            .unwrap();
+
+        let binding = app.state::<RwLock<AppState>>();
+        let guard = binding.read().await;
+        assert!(guard.container_state.image_loader.is_none());
+    }
+
     #[tokio::test]
     async fn test_get_image_in_container() {
         let app = tauri::test::mock_app();
@@ -422,11 +1099,16 @@ mod tests {
                     arc_mock_container.clone(),
                     2000,
                     ResizeFilter::Bilinear,
+                    StripConfig::default(),
+                    EncodeOptions::default(),
                     mini_moka::sync::Cache::new(100),
                 )
                 .unwrap(),
             )),
             image_cache: mini_moka::sync::Cache::new(100),
+            cache_generation: CacheGeneration::default(),
+            memory_pressure_shrinks: 0,
+            open_listing: ListingSettings::default(),
         };
         let state = AppState {
             container_state: mock_container_state,
@@ -483,11 +1165,16 @@ mod tests {
                     arc_mock_container.clone(),
                     2000,
                     ResizeFilter::Bilinear,
+                    StripConfig::default(),
+                    EncodeOptions::default(),
                     mini_moka::sync::Cache::new(100),
                 )
                 .unwrap(),
             )),
             image_cache: mini_moka::sync::Cache::new(100),
+            cache_generation: CacheGeneration::default(),
+            memory_pressure_shrinks: 0,
+            open_listing: ListingSettings::default(),
         };
         let state = AppState {
             container_state: mock_container_state,
@@ -540,11 +1227,16 @@ mod tests {
                     arc_mock_container.clone(),
                     2000,
                     ResizeFilter::Bilinear,
+                    StripConfig::default(),
+                    EncodeOptions::default(),
                     mini_moka::sync::Cache::new(100),
                 )
                 .unwrap(),
             )),
             image_cache: mini_moka::sync::Cache::new(100),
+            cache_generation: CacheGeneration::default(),
+            memory_pressure_shrinks: 0,
+            open_listing: ListingSettings::default(),
         };
         let state = AppState {
             container_state: mock_container_state,
@@ -581,11 +1273,16 @@ mod tests {
                     arc_mock_container.clone(),
                     2000,
                     ResizeFilter::Bilinear,
+                    StripConfig::default(),
+                    EncodeOptions::default(),
                     mini_moka::sync::Cache::new(100),
                 )
                 .unwrap(),
             )),
             image_cache: mini_moka::sync::Cache::new(100),
+            cache_generation: CacheGeneration::default(),
+            memory_pressure_shrinks: 0,
+            open_listing: ListingSettings::default(),
         };
         let state = AppState {
             container_state: mock_container_state,
@@ -601,4 +1298,334 @@ mod tests {
         };
         assert!(!body.is_empty());
     }
+
+    /// Builds an app whose container state holds `mock_container` behind a loader for
+    /// "dummy_book_id".
+    fn mock_app_with_container(
+        mock_container: MockContainer,
+    ) -> tauri::App<tauri::test::MockRuntime> {
+        let app = tauri::test::mock_app();
+        let arc_mock_container = Arc::new(mock_container);
+        let mock_container_state = ContainerState {
+            container: Some(arc_mock_container.clone()),
+            settings: ContainerSettings::default(),
+            image_loader: Some(Arc::new(
+                ImageLoader::new(
+                    "dummy_book_id".to_string(),
+                    arc_mock_container.clone(),
+                    2000,
+                    ResizeFilter::Bilinear,
+                    StripConfig::default(),
+                    EncodeOptions::default(),
+                    mini_moka::sync::Cache::new(100),
+                )
+                .unwrap(),
+            )),
+            image_cache: mini_moka::sync::Cache::new(100),
+            cache_generation: CacheGeneration::default(),
+            memory_pressure_shrinks: 0,
+            open_listing: ListingSettings::default(),
+        };
+        app.manage(RwLock::new(AppState {
+            container_state: mock_container_state,
+        }));
+        app
+    }
+
+    /// The (stage, width, height) headers of the frames a channel received.
+    type RecordedFrames = Arc<std::sync::Mutex<Vec<(u8, u32, u32)>>>;
+
+    /// Creates a channel that records the (stage, width, height) header of every frame.
+    fn recording_channel() -> (Channel, RecordedFrames) {
+        let frames = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let frames_clone = frames.clone();
+        let channel = Channel::new(move |body| {
+            let Raw(bytes) = body else {
+                panic!("Unexpected channel body type");
+            };
+            let width = u32::from_be_bytes([bytes[1], bytes[2], bytes[3], bytes[4]]);
+            let height = u32::from_be_bytes([bytes[5], bytes[6], bytes[7], bytes[8]]);
+            frames_clone.lock().unwrap().push((bytes[0], width, height));
+            Ok(())
+        });
+        (channel, frames)
+    }
+
+    #[tokio::test]
+    async fn test_get_image_progressive_sends_preview_then_full() {
+        let mut mock_container = MockContainer::new();
+        mock_container
+            .expect_get_thumbnail()
+            .times(1)
+            .returning(|_| {
+                Ok(Arc::new(Image {
+                    data: vec![0u8; 10],
+                    width: 80,
+                    height: 60,
+                }))
+            });
+        mock_container
+            .expect_get_image()
+            .times(1)
+            .returning(|_| Ok(MockContainer::create_dummy_image()));
+        mock_container
+            .expect_get_entries()
+            .return_const(vec!["test1.png".to_string()]);
+        mock_container
+            .expect_is_single_threaded()
+            .return_const(false);
+        let app = mock_app_with_container(mock_container);
+
+        let (channel, frames) = recording_channel();
+        let result =
+            get_image_progressive("dummy_book_id", "test1.png", 1, channel, app.state()).await;
+
+        assert!(result.is_ok());
+        assert_eq!(
+            *frames.lock().unwrap(),
+            vec![
+                (ImageStage::Preview as u8, 80, 60),
+                (ImageStage::Full as u8, 800, 600)
+            ]
+        );
+        assert!(!PROGRESSIVE_REQUESTS.lock().unwrap().contains_key(&1));
+    }
+
+    #[tokio::test]
+    async fn test_get_image_progressive_skips_preview_when_cached() {
+        let mut mock_container = MockContainer::new();
+        mock_container.expect_get_thumbnail().times(0);
+        mock_container
+            .expect_get_image()
+            .times(1)
+            .returning(|_| Ok(MockContainer::create_dummy_image()));
+        mock_container
+            .expect_get_entries()
+            .return_const(vec!["test1.png".to_string()]);
+        mock_container
+            .expect_is_single_threaded()
+            .return_const(false);
+        let app = mock_app_with_container(mock_container);
+
+        // Warm the cache through the regular path first.
+        get_image("dummy_book_id", "test1.png", app.state())
+            .await
+            .unwrap();
+
+        let (channel, frames) = recording_channel();
+        let result =
+            get_image_progressive("dummy_book_id", "test1.png", 2, channel, app.state()).await;
+
+        assert!(result.is_ok());
+        assert_eq!(
+            *frames.lock().unwrap(),
+            vec![(ImageStage::Full as u8, 800, 600)]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_image_progressive_cancelled_before_full() {
+        const REQUEST_ID: u32 = 3;
+        let mut mock_container = MockContainer::new();
+        mock_container
+            .expect_get_thumbnail()
+            .returning(|_| Ok(MockContainer::create_dummy_image()));
+        mock_container.expect_get_image().returning(|_| {
+            // The user moves on while the full image is being decoded.
+            PROGRESSIVE_REQUESTS.lock().unwrap()[&REQUEST_ID].store(true, Ordering::Relaxed);
+            Ok(MockContainer::create_dummy_image())
+        });
+        mock_container
+            .expect_get_entries()
+            .return_const(vec!["test1.png".to_string()]);
+        mock_container
+            .expect_is_single_threaded()
+            .return_const(false);
+        let app = mock_app_with_container(mock_container);
+
+        let (channel, frames) = recording_channel();
+        let result = get_image_progressive(
+            "dummy_book_id",
+            "test1.png",
+            REQUEST_ID,
+            channel,
+            app.state(),
+        )
+        .await;
+
+        assert!(result.is_ok());
+        assert_eq!(
+            *frames.lock().unwrap(),
+            vec![(ImageStage::Preview as u8, 800, 600)]
+        );
+        assert!(!PROGRESSIVE_REQUESTS
+            .lock()
+            .unwrap()
+            .contains_key(&REQUEST_ID));
+    }
+
+    #[tokio::test]
+    async fn test_get_image_progressive_cancelled_while_reading_the_state() {
+        const REQUEST_ID: u32 = 5;
+        let mut mock_container = MockContainer::new();
+        mock_container
+            .expect_get_thumbnail()
+            .returning(|_| Ok(MockContainer::create_dummy_image()));
+        mock_container.expect_get_image().times(0);
+        mock_container
+            .expect_get_entries()
+            .return_const(vec!["test1.png".to_string()]);
+        mock_container
+            .expect_is_single_threaded()
+            .return_const(false);
+        let app = mock_app_with_container(mock_container);
+
+        let (channel, frames) = recording_channel();
+        let state = app.state::<RwLock<AppState>>();
+        let state_lock = state.write().await;
+        let mut request = std::pin::pin!(get_image_progressive(
+            "dummy_book_id",
+            "test1.png",
+            REQUEST_ID,
+            channel,
+            app.state(),
+        ));
+        // Run the request until it waits for the state, then cancel it.
+        std::future::poll_fn(|cx| {
+            assert!(std::future::Future::poll(request.as_mut(), cx).is_pending());
+            std::task::Poll::Ready(())
+        })
+        .await;
+        cancel_image_request(REQUEST_ID).await.unwrap();
+        drop(state_lock);
+
+        assert!(request.await.is_ok());
+        assert!(frames.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_image_progressive_rejects_stale_container_path() {
+        let mut mock_container = MockContainer::new();
+        mock_container
+            .expect_get_entries()
+            .return_const(vec!["test1.png".to_string()]);
+        mock_container
+            .expect_is_single_threaded()
+            .return_const(false);
+        let app = mock_app_with_container(mock_container);
+
+        let (channel, frames) = recording_channel();
+        let result =
+            get_image_progressive("stale_book_id", "test1.png", 4, channel, app.state()).await;
+
+        assert!(matches!(result, Err(Error::EntryNotFound(_))));
+        assert!(frames.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_cancel_unknown_image_request_is_noop() {
+        assert!(cancel_image_request(u32::MAX).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_get_image_tile_and_info() {
+        let img = image::RgbImage::new(600, 1100);
+        let mut src = Vec::new();
+        image::DynamicImage::ImageRgb8(img)
+            .write_to(&mut std::io::Cursor::new(&mut src), image::ImageFormat::Png)
+            .unwrap();
+
+        let mut mock_container = MockContainer::new();
+        mock_container
+            .expect_get_full_resolution_image()
+            .returning(move |_| Ok(Arc::new(Image::new(src.clone()).unwrap())));
+        mock_container
+            .expect_get_entries()
+            .return_const(vec!["test1.png".to_string()]);
+        mock_container
+            .expect_is_single_threaded()
+            .return_const(false);
+        let app = mock_app_with_container(mock_container);
+
+        let info = get_image_tile_info("dummy_book_id", "test1.png", app.state())
+            .await
+            .unwrap();
+        assert_eq!((info.width, info.height, info.max_level), (600, 1100, 2));
+
+        let response = get_image_tile("dummy_book_id", "test1.png", 0, 1, 2, app.state())
+            .await
+            .unwrap();
+        let Raw(body) = response.body().unwrap() else {
+            panic!("Unexpected response body type");
+        };
+        let width = u32::from_be_bytes([body[0], body[1], body[2], body[3]]);
+        let height = u32::from_be_bytes([body[4], body[5], body[6], body[7]]);
+        assert_eq!((width, height), (600 - 512, 1100 - 1024));
+
+        let result = get_image_tile("dummy_book_id", "test1.png", 0, 2, 0, app.state()).await;
+        assert!(matches!(result, Err(Error::InvalidArgument(_))));
+    }
+
+    #[tokio::test]
+    async fn test_get_strip_layout() {
+        let mut mock_container = MockContainer::new();
+        mock_container
+            .expect_get_image()
+            .returning(|_| Ok(MockContainer::create_dummy_image()));
+        mock_container
+            .expect_get_entries()
+            .return_const(vec!["test1.png".to_string(), "test2.png".to_string()]);
+        mock_container
+            .expect_is_single_threaded()
+            .return_const(false);
+        let app = mock_app_with_container(mock_container);
+
+        let layout = get_strip_layout("dummy_book_id", app.state())
+            .await
+            .unwrap();
+        assert_eq!((layout.width, layout.height), (800, 1200));
+        assert_eq!(layout.segments[1].entry, "test2.png");
+        assert_eq!(layout.segments[1].strip_top, 600);
+
+        let result = get_strip_layout("other_book_id", app.state()).await;
+        assert!(matches!(result, Err(Error::EntryNotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_get_cache_stats_and_purge_image_cache() {
+        let mut mock_container = MockContainer::new();
+        mock_container
+            .expect_get_image()
+            .returning(|_| Ok(MockContainer::create_dummy_image()));
+        mock_container
+            .expect_get_entries()
+            .return_const(vec!["test1.png".to_string()]);
+        mock_container
+            .expect_is_single_threaded()
+            .return_const(false);
+        let app = mock_app_with_container(mock_container);
+        {
+            let state = app.state::<RwLock<AppState>>();
+            let state_lock = state.read().await;
+            let loader = state_lock.container_state.image_loader.clone().unwrap();
+            // The mock loader has its own cache; point it at the global one.
+            loader.set_cache(state_lock.container_state.image_cache.clone());
+            loader.get_image("test1.png").unwrap();
+            loader.get_image("test1.png").unwrap();
+        }
+
+        let stats = get_cache_stats(app.state()).await.unwrap();
+        assert_eq!(stats.entry_count, 1);
+        assert_eq!(stats.books[0].book_id, "dummy_book_id");
+        let usage = stats.open_book.unwrap();
+        assert_eq!((usage.hits, usage.misses), (1, 1));
+
+        purge_image_cache(Some("other_book_id".to_string()), app.state())
+            .await
+            .unwrap();
+        assert_eq!(get_cache_stats(app.state()).await.unwrap().entry_count, 1);
+
+        purge_image_cache(None, app.state()).await.unwrap();
+        assert_eq!(get_cache_stats(app.state()).await.unwrap().entry_count, 0);
+    }
 }
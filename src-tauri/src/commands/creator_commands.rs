@@ -0,0 +1,262 @@
+use std::sync::Arc;
+use tauri::Emitter;
+use tauri::State;
+
+use crate::domain::book::entity::BookWithState;
+use crate::domain::creator::entity::{BookCreator, Creator};
+use crate::domain::creator::repository::CreatorRepository;
+use crate::error::{Error, Result};
+
+/// Retrieves every creator credited for at least one book, ordered by name.
+///
+/// # Arguments
+///
+/// * `repo` - The managed creator repository state.
+///
+/// # Returns
+///
+/// A `Result` containing a vector of `Creator` entities.
+///
+/// # Errors
+///
+/// This function will return an `Err` if the underlying repository operation fails
+/// (e.g., due to a database error, connection issue, or query execution failure).
+#[tauri::command]
+#[specta::specta]
+pub async fn get_all_creators(repo: State<'_, Arc<dyn CreatorRepository>>) -> Result<Vec<Creator>> {
+    log::debug!("Get all creators.");
+    repo.get_all().await
+}
+
+/// Retrieves the creators of a book and their roles on it.
+///
+/// # Arguments
+///
+/// * `book_id` - The ID of the book.
+/// * `repo` - The managed creator repository state.
+///
+/// # Returns
+///
+/// A `Result` containing the creators, in the order the book's metadata lists them.
+///
+/// # Errors
+///
+/// This function will return an `Err` if the underlying repository operation fails.
+#[tauri::command]
+#[specta::specta]
+pub async fn get_book_creators(
+    book_id: i64,
+    repo: State<'_, Arc<dyn CreatorRepository>>,
+) -> Result<Vec<BookCreator>> {
+    log::debug!("Get book creators. (book_id:{})", book_id);
+    repo.get_creators_for_book(book_id).await
+}
+
+/// Retrieves all books a specific creator is credited for, with their reading state.
+///
+/// # Arguments
+///
+/// * `creator_id` - The ID of the creator.
+/// * `repo` - The managed creator repository state.
+///
+/// # Returns
+///
+/// A `Result` containing a vector of `BookWithState` entities.
+///
+/// # Errors
+///
+/// This function will return an `Err` if the underlying repository operation fails.
+#[tauri::command]
+#[specta::specta]
+pub async fn get_books_with_state_by_creator_id(
+    creator_id: i64,
+    repo: State<'_, Arc<dyn CreatorRepository>>,
+) -> Result<Vec<BookWithState>> {
+    log::debug!(
+        "Get books with state by creator. (creator_id:{})",
+        creator_id
+    );
+    repo.get_books_by_creator(creator_id).await
+}
+
+/// Renames a creator. Book files still using the former name keep being credited to
+/// the creator.
+///
+/// # Arguments
+///
+/// * `id` - The ID of the creator to rename.
+/// * `name` - The new name. Surrounding whitespace is removed.
+/// * `repo` - The managed creator repository state.
+///
+/// # Errors
+///
+/// This function will return an `Err` if:
+/// * The name is blank or another creator already has it (`Error::InvalidArgument`).
+/// * The underlying repository operation fails.
+#[tauri::command]
+#[specta::specta]
+pub async fn rename_creator<R: tauri::Runtime>(
+    id: i64,
+    name: String,
+    repo: State<'_, Arc<dyn CreatorRepository>>,
+    app: tauri::AppHandle<R>,
+) -> Result<()> {
+    log::debug!("Rename creator. (id:{}, name:{})", id, name);
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(Error::InvalidArgument(
+            "The creator name must not be empty.".to_string(),
+        ));
+    }
+    repo.rename(id, name).await?;
+    app.emit("history-changed", ())?;
+    Ok(())
+}
+
+/// Merges creators into another one, which takes over their books and keeps their
+/// names as aliases.
+///
+/// # Arguments
+///
+/// * `target_id` - The ID of the creator to keep.
+/// * `source_ids` - The IDs of the creators to merge into it.
+/// * `repo` - The managed creator repository state.
+///
+/// # Errors
+///
+/// This function will return an `Err` if the underlying repository operation fails.
+#[tauri::command]
+#[specta::specta]
+pub async fn merge_creators<R: tauri::Runtime>(
+    target_id: i64,
+    source_ids: Vec<i64>,
+    repo: State<'_, Arc<dyn CreatorRepository>>,
+    app: tauri::AppHandle<R>,
+) -> Result<()> {
+    log::debug!(
+        "Merge creators. (target_id:{}, source_ids:{:?})",
+        target_id,
+        source_ids
+    );
+    repo.merge(target_id, &source_ids).await?;
+    app.emit("history-changed", ())?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::creator::entity::CreatorRole;
+    use crate::domain::creator::repository::MockCreatorRepository;
+    use tauri::Manager;
+
+    #[tokio::test]
+    async fn test_get_all_creators() {
+        let mut mock_repo = MockCreatorRepository::new();
+        mock_repo.expect_get_all().times(1).returning(|| {
+            Ok(vec![Creator {
+                id: 1,
+                name: "Author".to_string(),
+                book_count: 2,
+            }])
+        });
+
+        let app = tauri::test::mock_app();
+        app.manage(Arc::new(mock_repo) as Arc<dyn CreatorRepository>);
+        let state = app.state::<Arc<dyn CreatorRepository>>();
+
+        let creators = get_all_creators(state).await.unwrap();
+        assert_eq!(creators.len(), 1);
+        assert_eq!(creators[0].book_count, 2);
+    }
+
+    #[tokio::test]
+    async fn test_get_book_creators() {
+        let mut mock_repo = MockCreatorRepository::new();
+        mock_repo
+            .expect_get_creators_for_book()
+            .with(mockall::predicate::eq(3))
+            .times(1)
+            .returning(|_| {
+                Ok(vec![BookCreator {
+                    creator_id: 1,
+                    name: "Illustrator".to_string(),
+                    role: CreatorRole::Illustrator,
+                }])
+            });
+
+        let app = tauri::test::mock_app();
+        app.manage(Arc::new(mock_repo) as Arc<dyn CreatorRepository>);
+        let state = app.state::<Arc<dyn CreatorRepository>>();
+
+        let creators = get_book_creators(3, state).await.unwrap();
+        assert_eq!(creators[0].role, CreatorRole::Illustrator);
+    }
+
+    #[tokio::test]
+    async fn test_get_books_with_state_by_creator_id() {
+        let mut mock_repo = MockCreatorRepository::new();
+        mock_repo
+            .expect_get_books_by_creator()
+            .with(mockall::predicate::eq(1))
+            .times(1)
+            .returning(|_| Ok(vec![]));
+
+        let app = tauri::test::mock_app();
+        app.manage(Arc::new(mock_repo) as Arc<dyn CreatorRepository>);
+        let state = app.state::<Arc<dyn CreatorRepository>>();
+
+        let result = get_books_with_state_by_creator_id(1, state).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_rename_creator_trims_name() {
+        let mut mock_repo = MockCreatorRepository::new();
+        mock_repo
+            .expect_rename()
+            .with(
+                mockall::predicate::eq(1),
+                mockall::predicate::eq("New Name"),
+            )
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let app = tauri::test::mock_app();
+        app.manage(Arc::new(mock_repo) as Arc<dyn CreatorRepository>);
+        let state = app.state::<Arc<dyn CreatorRepository>>();
+
+        let result = rename_creator(1, " New Name ".to_string(), state, app.handle().clone()).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_rename_creator_rejects_blank_name() {
+        let mut mock_repo = MockCreatorRepository::new();
+        mock_repo.expect_rename().never();
+
+        let app = tauri::test::mock_app();
+        app.manage(Arc::new(mock_repo) as Arc<dyn CreatorRepository>);
+        let state = app.state::<Arc<dyn CreatorRepository>>();
+
+        let result = rename_creator(1, "  ".to_string(), state, app.handle().clone()).await;
+        assert!(matches!(result, Err(Error::InvalidArgument(_))));
+    }
+
+    #[tokio::test]
+    async fn test_merge_creators() {
+        let mut mock_repo = MockCreatorRepository::new();
+        mock_repo
+            .expect_merge()
+            .withf(|target_id, source_ids| *target_id == 1 && source_ids == [2, 3])
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let app = tauri::test::mock_app();
+        app.manage(Arc::new(mock_repo) as Arc<dyn CreatorRepository>);
+        let state = app.state::<Arc<dyn CreatorRepository>>();
+
+        let result = merge_creators(1, vec![2, 3], state, app.handle().clone()).await;
+        assert!(result.is_ok());
+    }
+}
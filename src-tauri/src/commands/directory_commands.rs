@@ -1,13 +1,17 @@
 use std::fs::read_dir;
 use tauri::ipc::Response;
 
+use crate::container::rar_volume;
 use crate::container::traits::Container;
 use crate::error::Result;
+use crate::natural_sort;
 
 /// Reads the contents of a directory and returns a list of its entries.
 ///
 /// This function scans a directory specified by `dir_path` and filters its contents,
 /// returning only subdirectories and files with supported container formats (e.g., .zip, .cbz).
+/// Of a multi-volume RAR set (`name.partN.rar`), only the first volume is listed, as
+/// opening it opens the whole set.
 ///
 /// # Arguments
 ///
@@ -23,6 +27,9 @@ use crate::error::Result;
 /// * `name` (variable): The UTF-8 encoded name of the entry.
 /// * `last_modified` (8 bytes): The last modified timestamp as a Big-Endian `u64`
 ///   (milliseconds since the UNIX epoch).
+/// * `sort_key_length` (4 bytes): The length of the entry's sort key as a Big-Endian `u32`.
+/// * `sort_key` (variable): The ASCII key the name sorts by in natural order (see
+///   `natural_sort::sort_key`).
 ///
 /// # Errors
 ///
@@ -34,14 +41,19 @@ use crate::error::Result;
 pub async fn get_entries_in_dir(dir_path: &str) -> Result<Response> {
     log::debug!("Get the directory entries in {}", dir_path);
     let mut buffer = Vec::new();
+    let mut entries = Vec::new();
     for entry in read_dir(dir_path)? {
-        let entry = match entry {
-            Ok(entry) => entry,
-            Err(e) => {
-                log::warn!("skipping unreadable directory entry: {e}");
-                continue;
-            }
-        };
+        match entry {
+            Ok(entry) => entries.push(entry),
+            Err(e) => log::warn!("skipping unreadable directory entry: {e}"),
+        }
+    }
+    // Whether a RAR volume starts its set depends on the volumes next to it.
+    let file_names: Vec<String> = entries
+        .iter()
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .collect();
+    for entry in entries {
         let file_name = match entry.file_name().into_string() {
             Ok(file_name) => file_name,
             Err(os_name) => {
@@ -73,7 +85,12 @@ pub async fn get_entries_in_dir(dir_path: &str) -> Result<Response> {
             .unwrap_or_default();
         let last_modified_timestamp_ms = since_epoch.as_millis() as u64;
 
-        if (file_type.is_file() && <dyn Container>::is_supported_format(&file_name))
+        if (file_type.is_file()
+            && <dyn Container>::is_supported_format(&file_name)
+            && !rar_volume::is_continuation_volume(
+                &file_name,
+                file_names.iter().map(String::as_str),
+            ))
             || file_type.is_dir()
         {
             // is_directory (1 byte)
@@ -86,6 +103,11 @@ pub async fn get_entries_in_dir(dir_path: &str) -> Result<Response> {
 
             // last_modified (8 bytes)
             buffer.extend_from_slice(&last_modified_timestamp_ms.to_be_bytes());
+
+            // sort_key (len: 4 bytes + content)
+            let sort_key = natural_sort::sort_key(&file_name);
+            buffer.extend_from_slice(&(sort_key.len() as u32).to_be_bytes());
+            buffer.extend_from_slice(sort_key.as_bytes());
         }
     }
     Ok(Response::new(buffer))
@@ -106,6 +128,7 @@ mod tests {
         pub is_directory: bool,
         pub name: String,
         pub last_modified: i64,
+        pub sort_key: String,
     }
 
     // Helper function to parse the binary buffer
@@ -129,10 +152,18 @@ mod tests {
             let last_modified = i64::from_be_bytes(buffer[cursor..cursor + 8].try_into().unwrap());
             cursor += 8;
 
+            // sort_key (len: 4 bytes + content)
+            let key_len =
+                u32::from_be_bytes(buffer[cursor..cursor + 4].try_into().unwrap()) as usize;
+            cursor += 4;
+            let sort_key = String::from_utf8(buffer[cursor..cursor + key_len].to_vec()).unwrap();
+            cursor += key_len;
+
             entries.push(TestDirEntry {
                 is_directory,
                 name,
                 last_modified,
+                sort_key,
             });
         }
 
@@ -168,6 +199,7 @@ mod tests {
         assert_eq!(entries.len(), 1);
         assert!(entries[0].is_directory);
         assert_eq!(entries[0].name, "subdir");
+        assert_eq!(entries[0].sort_key, natural_sort::sort_key("subdir"));
     }
 
     #[tokio::test]
@@ -282,4 +314,36 @@ mod tests {
             );
         }
     }
+
+    #[tokio::test]
+    async fn test_get_entries_in_dir_hides_continuation_volumes() {
+        let temp_dir = TempDir::new().unwrap();
+        for name in ["book.part1.rar", "book.part2.rar", "book.part3.rar"] {
+            fs::File::create(temp_dir.path().join(name)).unwrap();
+        }
+
+        let result = get_entries_in_dir(temp_dir.path().to_string_lossy().as_ref())
+            .await
+            .unwrap();
+        let bytes = get_bytes_from_response(result);
+        let entries = parse_entries(&bytes);
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].name, "book.part1.rar");
+    }
+
+    #[tokio::test]
+    async fn test_get_entries_in_dir_hides_continuation_volumes_of_a_set_from_part0() {
+        let temp_dir = TempDir::new().unwrap();
+        for name in ["book.part0.rar", "book.part1.rar", "book.part2.rar"] {
+            fs::File::create(temp_dir.path().join(name)).unwrap();
+        }
+
+        let result = get_entries_in_dir(temp_dir.path().to_string_lossy().as_ref())
+            .await
+            .unwrap();
+        let bytes = get_bytes_from_response(result);
+        let entries = parse_entries(&bytes);
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].name, "book.part0.rar");
+    }
 }
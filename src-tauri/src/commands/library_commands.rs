@@ -0,0 +1,254 @@
+use std::path::Path;
+use std::sync::Arc;
+use tauri::State;
+
+use crate::domain::library::entity::LibraryRoot;
+use crate::domain::library::repository::LibraryRepository;
+use crate::error::{Error, Result};
+use crate::library::{self, scanner, scanner::LibraryScanReport};
+
+/// Retrieves all library roots.
+///
+/// # Arguments
+///
+/// * `repo` - The managed library repository state.
+///
+/// # Returns
+///
+/// A `Result` containing the library roots, in the order they were added.
+///
+/// # Errors
+///
+/// This function will return an `Err` if the underlying repository operation fails
+/// (e.g., due to a database error, connection issue, or query execution failure).
+#[tauri::command]
+#[specta::specta]
+pub async fn get_library_roots(
+    repo: State<'_, Arc<dyn LibraryRepository>>,
+) -> Result<Vec<LibraryRoot>> {
+    log::debug!("Get library roots.");
+    repo.get_roots().await
+}
+
+/// Adds a folder to the library, starts watching it and scans it in the background.
+///
+/// The folder is stored as its canonical path. Library roots found inside the folder
+/// are scanned and watched along with it.
+///
+/// Progress of the scan is reported through `library-scan-progress` events and its
+/// result through a `library-scan-finished` event.
+///
+/// # Arguments
+///
+/// * `path` - The path of the folder.
+/// * `repo` - The managed library repository state.
+/// * `app` - The Tauri AppHandle.
+///
+/// # Returns
+///
+/// A `Result` containing the ID of the new library root.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidArgument`] if `path` is not a folder or is already inside a
+/// library root, or an `Err` if the watcher cannot be restarted.
+#[tauri::command]
+#[specta::specta]
+pub async fn add_library_root<R: tauri::Runtime>(
+    path: String,
+    repo: State<'_, Arc<dyn LibraryRepository>>,
+    app: tauri::AppHandle<R>,
+) -> Result<i64> {
+    log::debug!("Add library root. (path:{})", path);
+    let folder = library::canonicalize(Path::new(&path))
+        .ok()
+        .filter(|folder| folder.is_dir())
+        .ok_or_else(|| Error::InvalidArgument(format!("Library root is not a folder: {}", path)))?;
+    if let Some(root) = library::containing_root(&repo.get_roots().await?, &folder) {
+        return Err(Error::InvalidArgument(format!(
+            "Folder is already in the library root {}: {}",
+            root.path, path
+        )));
+    }
+
+    let root_id = repo.add_root(&folder.to_string_lossy()).await?;
+    let roots = library::watch_roots(&app).await?;
+    let added: Vec<LibraryRoot> = roots.into_iter().filter(|r| r.id == root_id).collect();
+    tauri::async_runtime::spawn(async move {
+        if let Err(e) = scanner::scan_roots(&app, &added).await {
+            log::error!("Scan of the new library root failed: {e}");
+        }
+    });
+    Ok(root_id)
+}
+
+/// Removes a folder from the library and stops watching it.
+///
+/// The books found in the folder stay in the library.
+///
+/// # Arguments
+///
+/// * `id` - The ID of the library root to remove.
+/// * `repo` - The managed library repository state.
+/// * `app` - The Tauri AppHandle.
+///
+/// # Errors
+///
+/// This function will return an `Err` if the underlying repository operation fails or
+/// the watcher cannot be restarted.
+#[tauri::command]
+#[specta::specta]
+pub async fn remove_library_root<R: tauri::Runtime>(
+    id: i64,
+    repo: State<'_, Arc<dyn LibraryRepository>>,
+    app: tauri::AppHandle<R>,
+) -> Result<()> {
+    log::debug!("Remove library root. (id:{})", id);
+    repo.delete_root(id).await?;
+    library::watch_roots(&app).await?;
+    Ok(())
+}
+
+/// Rescans the library roots, registering new book files, re-reading changed ones and
+/// flagging the ones that are gone as missing.
+///
+/// Progress is reported through `library-scan-progress` events.
+///
+/// # Arguments
+///
+/// * `root_id` - The ID of the library root to rescan, or `None` to rescan all of them.
+/// * `repo` - The managed library repository state.
+/// * `app` - The Tauri AppHandle.
+///
+/// # Returns
+///
+/// A `LibraryScanReport` summarizing the changes.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidArgument`] if `root_id` does not exist, or an `Err` if a
+/// database operation fails.
+#[tauri::command]
+#[specta::specta]
+pub async fn rescan_library<R: tauri::Runtime>(
+    root_id: Option<i64>,
+    repo: State<'_, Arc<dyn LibraryRepository>>,
+    app: tauri::AppHandle<R>,
+) -> Result<LibraryScanReport> {
+    log::debug!("Rescan library. (root_id:{:?})", root_id);
+    let roots = match root_id {
+        Some(id) => vec![repo.get_root(id).await?.ok_or_else(|| {
+            Error::InvalidArgument(format!("Library root {} does not exist", id))
+        })?],
+        None => repo.get_roots().await?,
+    };
+    scanner::scan_roots(&app, &roots).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::library::repository::MockLibraryRepository;
+    use crate::library::watcher::LibraryWatcher;
+    use tauri::Manager;
+
+    #[tokio::test]
+    async fn test_get_library_roots() {
+        let mut mock_repo = MockLibraryRepository::new();
+        let now = chrono::Utc::now().naive_utc();
+        mock_repo.expect_get_roots().times(1).returning(move || {
+            Ok(vec![LibraryRoot {
+                id: 1,
+                path: "/books".to_string(),
+                created_at: now,
+                last_scanned_at: None,
+            }])
+        });
+
+        let app = tauri::test::mock_app();
+        app.manage(Arc::new(mock_repo) as Arc<dyn LibraryRepository>);
+        let state = app.state::<Arc<dyn LibraryRepository>>();
+
+        let roots = get_library_roots(state).await.unwrap();
+        assert_eq!(roots.len(), 1);
+        assert_eq!(roots[0].path, "/books");
+    }
+
+    #[tokio::test]
+    async fn test_add_library_root_rejects_a_non_folder() {
+        let mut mock_repo = MockLibraryRepository::new();
+        mock_repo.expect_add_root().never();
+
+        let app = tauri::test::mock_app();
+        app.manage(Arc::new(mock_repo) as Arc<dyn LibraryRepository>);
+        let state = app.state::<Arc<dyn LibraryRepository>>();
+
+        let result =
+            add_library_root("/no/such/folder".to_string(), state, app.handle().clone()).await;
+        assert!(matches!(result, Err(Error::InvalidArgument(_))));
+    }
+
+    #[tokio::test]
+    async fn test_add_library_root_rejects_a_folder_inside_a_root() {
+        let dir = tempfile::tempdir().unwrap();
+        let inner = dir.path().join("inner");
+        std::fs::create_dir(&inner).unwrap();
+        let outer = library::canonicalize(dir.path()).unwrap();
+
+        let mut mock_repo = MockLibraryRepository::new();
+        mock_repo.expect_get_roots().returning(move || {
+            Ok(vec![LibraryRoot {
+                id: 1,
+                path: outer.to_string_lossy().into_owned(),
+                created_at: chrono::Utc::now().naive_utc(),
+                last_scanned_at: None,
+            }])
+        });
+        mock_repo.expect_add_root().never();
+
+        let app = tauri::test::mock_app();
+        app.manage(Arc::new(mock_repo) as Arc<dyn LibraryRepository>);
+
+        for path in [dir.path().to_path_buf(), inner.join("..").join("inner")] {
+            let state = app.state::<Arc<dyn LibraryRepository>>();
+            let path = path.to_string_lossy().into_owned();
+            let result = add_library_root(path, state, app.handle().clone()).await;
+            assert!(matches!(result, Err(Error::InvalidArgument(_))));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_remove_library_root_restarts_the_watch() {
+        let mut mock_repo = MockLibraryRepository::new();
+        mock_repo
+            .expect_delete_root()
+            .with(mockall::predicate::eq(1))
+            .times(1)
+            .returning(|_| Ok(()));
+        mock_repo
+            .expect_get_roots()
+            .times(1)
+            .returning(|| Ok(Vec::new()));
+
+        let app = tauri::test::mock_app();
+        app.manage(Arc::new(mock_repo) as Arc<dyn LibraryRepository>);
+        app.manage(LibraryWatcher::default());
+        let state = app.state::<Arc<dyn LibraryRepository>>();
+
+        let result = remove_library_root(1, state, app.handle().clone()).await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_rescan_unknown_library_root() {
+        let mut mock_repo = MockLibraryRepository::new();
+        mock_repo.expect_get_root().returning(|_| Ok(None));
+
+        let app = tauri::test::mock_app();
+        app.manage(Arc::new(mock_repo) as Arc<dyn LibraryRepository>);
+        let state = app.state::<Arc<dyn LibraryRepository>>();
+
+        let result = rescan_library(Some(7), state, app.handle().clone()).await;
+        assert!(matches!(result, Err(Error::InvalidArgument(_))));
+    }
+}
@@ -1,9 +1,16 @@
+pub mod annotation_commands;
 pub mod book_commands;
+pub mod bookmark_commands;
 pub mod bookshelf_commands;
 pub mod container_commands;
+pub mod creator_commands;
 pub mod directory_commands;
 pub mod font_commands;
+pub mod library_commands;
+pub mod search_commands;
 pub mod series_commands;
 pub mod settings_commands;
+pub mod statistics_commands;
 pub mod tag_commands;
 pub mod updater_commands;
+pub mod verification_commands;
@@ -0,0 +1,37 @@
+use crate::search_text;
+
+/// Filters names by an AND search, so the frontend matches names the way the book search
+/// does.
+///
+/// Names and keywords are compared after [`search_text::normalize`], so differences in
+/// case, character width and kana are ignored.
+///
+/// # Arguments
+///
+/// * `query` - The user's input, with one or more whitespace-separated keywords.
+/// * `names` - The names to match.
+///
+/// # Returns
+///
+/// The indices of the names that contain every keyword, in ascending order. Every index
+/// is returned if the query has no keywords.
+#[tauri::command]
+#[specta::specta]
+pub async fn filter_by_search(query: String, names: Vec<String>) -> Vec<u32> {
+    log::debug!("Filter by search. (query:{}, names:{})", query, names.len());
+    search_text::filter_names(&query, &names)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_filter_by_search() {
+        let names = vec!["ワンピース 1".to_string(), "Naruto 1".to_string()];
+        assert_eq!(
+            filter_by_search("わんぴーす".to_string(), names).await,
+            vec![0]
+        );
+    }
+}
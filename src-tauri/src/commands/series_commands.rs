@@ -109,16 +109,8 @@ mod tests {
         let now = chrono::Utc::now().naive_utc();
         mock_repo.expect_get_all().times(1).returning(move || {
             Ok(vec![
-                Series {
-                    id: 1,
-                    name: "Series 1".to_string(),
-                    created_at: now,
-                },
-                Series {
-                    id: 2,
-                    name: "Series 2".to_string(),
-                    created_at: now,
-                },
+                Series::new(1, "Series 1".to_string(), now),
+                Series::new(2, "Series 2".to_string(), now),
             ])
         });
 
@@ -2,6 +2,7 @@ use tauri::{AppHandle, Emitter, Manager, WebviewWindow};
 use tokio::sync::RwLock;
 
 use crate::{
+    commands::container_commands,
     error::Result,
     settings::{AppSettings, SettingsFileLock, SettingsFileProvider, SettingsPatch},
     setup,
@@ -38,7 +39,8 @@ pub async fn get_settings(provider: tauri::State<'_, SettingsFileProvider>) -> R
 ///
 /// The `patch` carries only the changed leaves of a single category. The backend
 /// deep-merges it into the current settings, validates the merged whole, persists it,
-/// applies the reader/rendering values to the live container runtime, returns the
+/// applies the reader/rendering values to the live container runtime (rebuilding the
+/// open book when its rendering parameters changed), returns the
 /// full merged settings to the caller, and broadcasts a `settings-changed` event to
 /// every window **except the caller** so other windows re-hydrate.
 ///
@@ -76,14 +78,22 @@ pub async fn set_settings(
 ) -> Result<AppSettings> {
     // Hold the settings file lock across the persist, the runtime apply, and the
     // broadcast so two concurrent calls cannot persist A,B but then apply/announce B,A.
-    // Lock order is SettingsFileLock -> AppState(write); no path takes them the other way.
+    // Lock order is SettingsFileLock -> container open lock -> AppState(write); no path
+    // takes them the other way.
     let _guard = lock.0.lock().await;
 
     let settings = AppSettings::apply_patch(provider.inner(), patch)?;
 
-    {
+    let rendering_changed = {
         let mut locked_state = state.write().await;
-        setup::apply_reader_settings_to_container(&mut locked_state, &settings);
+        setup::apply_reader_settings_to_container(&mut locked_state, &settings)
+    };
+    if rendering_changed {
+        // The settings are already persisted; a book that fails to rebuild keeps being
+        // served with the previous rendering parameters until it is reopened.
+        if let Err(e) = container_commands::reload_open_container(&app, state.inner()).await {
+            log::warn!("Failed to apply the rendering settings to the open book: {e}");
+        }
     }
 
     let caller_label = webview.label().to_string();
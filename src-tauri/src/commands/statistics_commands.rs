@@ -0,0 +1,134 @@
+use chrono::NaiveDate;
+use std::sync::Arc;
+use tauri::State;
+
+use crate::domain::reading_session::entity::{ReadingSession, ReadingStatistics};
+use crate::domain::reading_session::repository::ReadingSessionRepository;
+use crate::error::{Error, Result};
+
+/// Summarizes the reading done between two dates of the local time zone, e.g. for a
+/// yearly reading report.
+///
+/// The current offset of the local time zone is used for the whole period.
+///
+/// # Arguments
+///
+/// * `from` - The first date of the period.
+/// * `to` - The last date of the period, inclusive.
+/// * `repo` - The managed reading session repository state.
+///
+/// # Returns
+///
+/// A `Result` containing the time read and pages read per day, the books finished per
+/// month, the reading streaks and the totals per series.
+///
+/// # Errors
+///
+/// This function will return an `Err` if:
+/// * `from` is after `to` (`Error::InvalidArgument`).
+/// * The underlying repository operation fails.
+#[tauri::command]
+#[specta::specta]
+pub async fn get_reading_statistics(
+    from: NaiveDate,
+    to: NaiveDate,
+    repo: State<'_, Arc<dyn ReadingSessionRepository>>,
+) -> Result<ReadingStatistics> {
+    log::debug!("Get reading statistics. (from:{}, to:{})", from, to);
+    if from > to {
+        return Err(Error::InvalidArgument(format!(
+            "The start date {from} is after the end date {to}."
+        )));
+    }
+    let utc_offset = *chrono::Local::now().offset();
+    repo.get_statistics(from, to, utc_offset).await
+}
+
+/// Retrieves the reading sessions of a book, most recent first.
+///
+/// # Arguments
+///
+/// * `book_id` - The ID of the book.
+/// * `repo` - The managed reading session repository state.
+///
+/// # Returns
+///
+/// A `Result` containing a vector of `ReadingSession` entities.
+///
+/// # Errors
+///
+/// This function will return an `Err` if the underlying repository operation fails.
+#[tauri::command]
+#[specta::specta]
+pub async fn get_reading_sessions(
+    book_id: i64,
+    repo: State<'_, Arc<dyn ReadingSessionRepository>>,
+) -> Result<Vec<ReadingSession>> {
+    log::debug!("Get reading sessions. (book_id:{})", book_id);
+    repo.get_sessions_for_book(book_id).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::reading_session::repository::MockReadingSessionRepository;
+    use tauri::Manager;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_get_reading_statistics() {
+        let mut mock_repo = MockReadingSessionRepository::new();
+        mock_repo
+            .expect_get_statistics()
+            .withf(|from, to, _| *from == date(2026, 1, 1) && *to == date(2026, 12, 31))
+            .times(1)
+            .returning(|_, _, _| {
+                Ok(ReadingStatistics {
+                    total_pages_read: 120,
+                    ..Default::default()
+                })
+            });
+
+        let app = tauri::test::mock_app();
+        app.manage(Arc::new(mock_repo) as Arc<dyn ReadingSessionRepository>);
+        let state = app.state::<Arc<dyn ReadingSessionRepository>>();
+
+        let statistics = get_reading_statistics(date(2026, 1, 1), date(2026, 12, 31), state)
+            .await
+            .unwrap();
+        assert_eq!(statistics.total_pages_read, 120);
+    }
+
+    #[tokio::test]
+    async fn test_get_reading_statistics_rejects_reversed_period() {
+        let mut mock_repo = MockReadingSessionRepository::new();
+        mock_repo.expect_get_statistics().never();
+
+        let app = tauri::test::mock_app();
+        app.manage(Arc::new(mock_repo) as Arc<dyn ReadingSessionRepository>);
+        let state = app.state::<Arc<dyn ReadingSessionRepository>>();
+
+        let result = get_reading_statistics(date(2026, 2, 1), date(2026, 1, 31), state).await;
+        assert!(matches!(result, Err(Error::InvalidArgument(_))));
+    }
+
+    #[tokio::test]
+    async fn test_get_reading_sessions() {
+        let mut mock_repo = MockReadingSessionRepository::new();
+        mock_repo
+            .expect_get_sessions_for_book()
+            .with(mockall::predicate::eq(4))
+            .times(1)
+            .returning(|_| Ok(vec![]));
+
+        let app = tauri::test::mock_app();
+        app.manage(Arc::new(mock_repo) as Arc<dyn ReadingSessionRepository>);
+        let state = app.state::<Arc<dyn ReadingSessionRepository>>();
+
+        let result = get_reading_sessions(4, state).await;
+        assert!(result.is_ok());
+    }
+}
@@ -0,0 +1,303 @@
+use std::{
+    collections::BTreeMap,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+use tokio::sync::RwLock;
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter, Runtime, State};
+
+use crate::{
+    container::verification::{self, VerificationReport},
+    domain::book::repository::BookRepository,
+    error::{Error, Result},
+    state::{app_state::AppState, container_settings::ContainerSettings},
+};
+
+/// Event emitted after each verified page. It carries a `VerificationProgress`.
+const VERIFICATION_PROGRESS_EVENT: &str = "verification-progress";
+
+/// Cancellation flags of the running verification jobs, keyed by the frontend-issued
+/// job ID.
+///
+/// This is a plain `std` mutex: it is only held for a map insert/remove and never
+/// across an `.await`.
+static VERIFICATION_JOBS: std::sync::Mutex<BTreeMap<u32, Arc<AtomicBool>>> =
+    std::sync::Mutex::new(BTreeMap::new());
+
+/// A registered verification job.
+///
+/// Registering replaces any earlier flag for the same ID; dropping removes the flag
+/// again, but only if it is still this job's (a reused ID must not unregister the
+/// newer job).
+struct VerificationJob {
+    job_id: u32,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl VerificationJob {
+    fn register(job_id: u32) -> Self {
+        let cancelled = Arc::new(AtomicBool::new(false));
+        VERIFICATION_JOBS
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(job_id, cancelled.clone());
+        Self { job_id, cancelled }
+    }
+}
+
+impl Drop for VerificationJob {
+    fn drop(&mut self) {
+        let mut jobs = VERIFICATION_JOBS.lock().unwrap_or_else(|e| e.into_inner());
+        if jobs
+            .get(&self.job_id)
+            .is_some_and(|flag| Arc::ptr_eq(flag, &self.cancelled))
+        {
+            jobs.remove(&self.job_id);
+        }
+    }
+}
+
+/// The progress of a verification job, emitted as a `verification-progress` event.
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "camelCase")]
+pub struct VerificationProgress {
+    /// The ID of the job.
+    pub job_id: u32,
+    /// The path of the book being verified.
+    pub path: String,
+    /// The number of pages of the book checked so far.
+    pub pages_checked: u32,
+    /// The number of pages in the book.
+    pub total_pages: u32,
+    /// The number of books finished before this one.
+    pub books_checked: u32,
+    /// The number of books the job verifies (1 for `verify_book`).
+    pub total_books: u32,
+}
+
+/// The result of verifying every book in the library.
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "camelCase")]
+pub struct LibraryVerificationReport {
+    /// The number of books in the library.
+    pub total_books: u32,
+    /// The number of books fully verified before the job finished or was cancelled.
+    pub books_checked: u32,
+    /// The reports of the books that failed to open, were opened partially, or have
+    /// failing pages. Healthy books are left out.
+    pub reports: Vec<VerificationReport>,
+    /// Whether the job was cancelled before every book was checked.
+    pub cancelled: bool,
+}
+
+/// Verifies one book of a job, emitting progress after each page.
+///
+/// Runs on the calling (blocking) thread.
+fn verify_with_progress<R: Runtime>(
+    app: &AppHandle<R>,
+    job_id: u32,
+    path: &str,
+    settings: &ContainerSettings,
+    cancelled: &AtomicBool,
+    (books_checked, total_books): (u32, u32),
+) -> VerificationReport {
+    log::info!("Verifying {}", path);
+    verification::verify_book(
+        path,
+        settings.container_config(),
+        cancelled,
+        |pages_checked, total_pages| {
+            let progress = VerificationProgress {
+                job_id,
+                path: path.to_string(),
+                pages_checked,
+                total_pages,
+                books_checked,
+                total_books,
+            };
+            // Progress is informational; a failed emit must not abort the job.
+            if let Err(e) = app.emit(VERIFICATION_PROGRESS_EVENT, &progress) {
+                log::warn!("Failed to emit verification progress: {e}");
+            }
+        },
+    )
+}
+
+/// Verifies the integrity of a book as a cancellable background job.
+///
+/// Every page is read to the end (checking the entry CRCs of ZIP and RAR archives)
+/// and fully decoded. The book is opened separately from the one shown in the reader,
+/// which stays untouched. A `verification-progress` event is emitted after each page.
+///
+/// # Arguments
+///
+/// * `path` - The path of the book to verify.
+/// * `job_id` - A frontend-issued ID, used to cancel the job with `cancel_verification`.
+/// * `app` - The Tauri app handle used to emit progress events.
+/// * `state` - The application's runtime state.
+///
+/// # Returns
+///
+/// A `VerificationReport` listing the unreadable, truncated and undecodable pages. A
+/// book that cannot be opened at all is reported through its `openError`.
+///
+/// # Errors
+///
+/// Returns an `Err` only if the background task cannot be run.
+#[tauri::command]
+#[specta::specta]
+pub async fn verify_book<R: Runtime>(
+    path: String,
+    job_id: u32,
+    app: AppHandle<R>,
+    state: State<'_, RwLock<AppState>>,
+) -> Result<VerificationReport> {
+    log::debug!("Verify book {} (job {})", path, job_id);
+    let job = VerificationJob::register(job_id);
+    let settings = state.read().await.container_state.settings.clone();
+
+    tauri::async_runtime::spawn_blocking(move || {
+        verify_with_progress(&app, job_id, &path, &settings, &job.cancelled, (0, 1))
+    })
+    .await
+    .map_err(|e| Error::Other(format!("Spawn blocking failed: {e}")))
+}
+
+/// Verifies the integrity of every book in the library as a cancellable background job.
+///
+/// Books are verified one after another, the same way `verify_book` verifies a single
+/// book. A `verification-progress` event is emitted after each page.
+///
+/// # Arguments
+///
+/// * `job_id` - A frontend-issued ID, used to cancel the job with `cancel_verification`.
+/// * `app` - The Tauri app handle used to emit progress events.
+/// * `repo` - The managed book repository state.
+/// * `state` - The application's runtime state.
+///
+/// # Returns
+///
+/// A `LibraryVerificationReport` with the reports of the books that have problems.
+///
+/// # Errors
+///
+/// Returns an `Err` if the books cannot be listed or the background task cannot be run.
+#[tauri::command]
+#[specta::specta]
+pub async fn verify_library<R: Runtime>(
+    job_id: u32,
+    app: AppHandle<R>,
+    repo: State<'_, Arc<dyn BookRepository>>,
+    state: State<'_, RwLock<AppState>>,
+) -> Result<LibraryVerificationReport> {
+    log::debug!("Verify library (job {})", job_id);
+    let job = VerificationJob::register(job_id);
+    let paths: Vec<String> = repo
+        .get_all_books_with_state()
+        .await?
+        .into_iter()
+        .map(|book| book.file_path)
+        .collect();
+    let total_books = paths.len() as u32;
+    let settings = state.read().await.container_state.settings.clone();
+
+    let (reports, books_checked, cancelled) = tauri::async_runtime::spawn_blocking(move || {
+        let mut reports = Vec::new();
+        let mut books_checked = 0;
+        for path in &paths {
+            // Books not yet opened are skipped outright once the job is cancelled.
+            if job.cancelled.load(Ordering::Relaxed) {
+                break;
+            }
+            let report = verify_with_progress(
+                &app,
+                job_id,
+                path,
+                &settings,
+                &job.cancelled,
+                (books_checked, total_books),
+            );
+            if !report.cancelled {
+                books_checked += 1;
+            }
+            reports.push(report);
+        }
+        (
+            reports,
+            books_checked,
+            job.cancelled.load(Ordering::Relaxed),
+        )
+    })
+    .await
+    .map_err(|e| Error::Other(format!("Spawn blocking failed: {e}")))?;
+    let reports: Vec<VerificationReport> = reports
+        .into_iter()
+        .filter(|report| !report.is_healthy())
+        .collect();
+
+    log::info!(
+        "Verified {} of {} books, {} with problems",
+        books_checked,
+        total_books,
+        reports.len()
+    );
+    Ok(LibraryVerificationReport {
+        total_books,
+        books_checked,
+        reports,
+        cancelled,
+    })
+}
+
+/// Cancels a running verification job.
+///
+/// The job stops after the page being checked and returns a report marked as
+/// cancelled. Cancelling an unknown or already finished job is a no-op.
+///
+/// # Arguments
+///
+/// * `job_id` - The ID passed to `verify_book` or `verify_library`.
+#[tauri::command]
+#[specta::specta]
+pub async fn cancel_verification(job_id: u32) -> Result<()> {
+    log::debug!("Cancel verification job {}", job_id);
+    if let Some(flag) = VERIFICATION_JOBS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(&job_id)
+    {
+        flag.store(true, Ordering::Relaxed);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_cancel_verification_sets_the_registered_flag() {
+        let job = VerificationJob::register(9001);
+        cancel_verification(9001).await.unwrap();
+        assert!(job.cancelled.load(Ordering::Relaxed));
+    }
+
+    #[tokio::test]
+    async fn test_cancel_unknown_verification_is_noop() {
+        assert!(cancel_verification(9002).await.is_ok());
+    }
+
+    #[test]
+    fn test_dropping_a_job_keeps_a_newer_job_with_the_same_id() {
+        let old = VerificationJob::register(9003);
+        let new = VerificationJob::register(9003);
+        drop(old);
+        assert!(VERIFICATION_JOBS.lock().unwrap().contains_key(&9003));
+        drop(new);
+        assert!(!VERIFICATION_JOBS.lock().unwrap().contains_key(&9003));
+    }
+}
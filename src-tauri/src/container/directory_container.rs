@@ -3,10 +3,13 @@ use std::{
     io::Read,
     path,
     sync::Arc,
+    time::UNIX_EPOCH,
 };
 
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+
 use crate::{
-    container::traits::Container,
+    container::{entry_order::ListedEntry, listing::ListingOptions, traits::Container},
     error::{Error, Result},
     image::{thumbnail::generate_thumbnail, types::Image},
 };
@@ -15,8 +18,10 @@ use crate::{
 pub struct DirectoryContainer {
     /// The absolute path to the directory.
     path: String,
-    /// A naturally sorted list of image file names within the directory.
+    /// The image file names within the directory, in page order.
     entries: Vec<String>,
+    /// The image file names hidden by exclusion rules.
+    excluded: Vec<String>,
 }
 
 impl Container for DirectoryContainer {
@@ -35,9 +40,20 @@ impl Container for DirectoryContainer {
         create_thumbnail(&self.path, entry)
     }
 
+    fn get_image_sizes(&self) -> Result<Vec<(u32, u32)>> {
+        self.entries
+            .par_iter()
+            .map(|entry| read_dimensions(&self.path, entry))
+            .collect()
+    }
+
     fn is_directory(&self) -> bool {
         true
     }
+
+    fn excluded_entries(&self) -> &[String] {
+        &self.excluded
+    }
 }
 
 impl DirectoryContainer {
@@ -84,10 +100,27 @@ impl DirectoryContainer {
     ///
     /// Returns an `Err` if the directory cannot be read. A file whose name is not
     /// valid Unicode is skipped (logged), not treated as an error.
+    #[cfg(test)]
     pub fn new(path: &str) -> Result<Self> {
+        Self::with_listing(path, &ListingOptions::default())
+    }
+
+    /// Creates a new `DirectoryContainer`, listing its images with the given options.
+    ///
+    /// The `ModifiedTime` sort mode orders the images by their file modification time.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the directory to open.
+    /// * `options` - How the image file names are sorted and filtered.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`DirectoryContainer::new`].
+    pub fn with_listing(path: &str, options: &ListingOptions) -> Result<Self> {
         let dir_entries = read_dir(path)?;
 
-        let mut entries: Vec<String> = Vec::new();
+        let mut entries: Vec<ListedEntry> = Vec::new();
         for entry_result in dir_entries {
             let entry = entry_result?;
             let file_type = entry.file_type()?;
@@ -102,15 +135,25 @@ impl DirectoryContainer {
             };
 
             if Image::is_supported_format(&file_name) {
-                entries.push(file_name);
+                let modified = entry
+                    .metadata()
+                    .and_then(|metadata| metadata.modified())
+                    .ok()
+                    .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+                    .map(|duration| duration.as_secs() as i64);
+                entries.push(ListedEntry {
+                    name: file_name,
+                    modified,
+                });
             }
         }
 
-        entries.sort_by(|a, b| natord::compare_ignore_case(a, b));
+        let (entries, excluded) = options.arrange(entries);
 
         Ok(Self {
             path: path.to_string(),
             entries,
+            excluded,
         })
     }
 }
@@ -124,6 +167,12 @@ fn load_image(path: &str, entry: &str) -> Result<Arc<Image>> {
     Ok(Arc::new(Image::new(buffer)?))
 }
 
+/// Helper function to read the width and height of an image file from its header.
+fn read_dimensions(path: &str, entry: &str) -> Result<(u32, u32)> {
+    let file_path = path::Path::new(&path).join(entry);
+    Ok(Image::read_dimensions(File::open(file_path)?)?)
+}
+
 /// Helper function to create a JPEG thumbnail for an image file.
 fn create_thumbnail(path: &str, entry: &str) -> Result<Arc<Image>> {
     let file_path = path::Path::new(&path).join(entry);
@@ -285,6 +334,17 @@ mod tests {
         assert_eq!(image.height, 1);
     }
 
+    #[test]
+    fn test_get_image_sizes_match_images() {
+        let dir = tempdir().expect("failed to create tempdir");
+        create_dummy_image(dir.path(), "1.png");
+        create_dummy_image(dir.path(), "2.png");
+        let container = DirectoryContainer::new(dir.path().to_string_lossy().as_ref())
+            .expect("failed to create DirectoryContainer");
+
+        assert_eq!(container.get_image_sizes().unwrap(), vec![(1, 1), (1, 1)]);
+    }
+
     #[test]
     fn test_get_image_non_existing() {
         let dir = tempdir().expect("failed to create tempdir");
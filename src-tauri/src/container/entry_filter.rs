@@ -0,0 +1,232 @@
+//! Exclusion rules hiding junk entries (resource forks, dotfiles, scanner credit pages)
+//! from the pages of a container.
+
+use regex::{Regex, RegexBuilder};
+use serde::{Deserialize, Serialize};
+
+use crate::error::{Error, Result};
+
+/// How the pattern of an [`ExclusionRule`] is interpreted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "camelCase")]
+pub enum PatternSyntax {
+    /// A case-insensitive glob: `*` matches within one path component, `**` across
+    /// components and `?` one character. A glob without a `/` is matched against every
+    /// component of the entry path, so `__MACOSX` hides the whole folder; one with a `/`
+    /// is matched against the whole path.
+    #[default]
+    Glob,
+    /// A regular expression searched for anywhere in the entry path.
+    Regex,
+}
+
+/// A rule hiding the entries whose path matches its pattern.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ExclusionRule {
+    /// The pattern matched against the entry path.
+    pub pattern: String,
+    /// How the pattern is interpreted.
+    #[serde(default)]
+    pub syntax: PatternSyntax,
+}
+
+impl ExclusionRule {
+    /// Creates a glob rule.
+    pub fn glob(pattern: &str) -> Self {
+        Self {
+            pattern: pattern.to_string(),
+            syntax: PatternSyntax::Glob,
+        }
+    }
+
+    /// Compiles the rule into the regular expression entry paths are matched with.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidArgument`] if the pattern is empty or not a valid
+    /// regular expression.
+    pub fn compile(&self) -> Result<CompiledRule> {
+        if self.pattern.trim().is_empty() {
+            return Err(Error::InvalidArgument(
+                "Exclusion pattern must not be empty".to_string(),
+            ));
+        }
+        let (source, per_component) = match self.syntax {
+            PatternSyntax::Glob => (glob_to_regex(&self.pattern), !self.pattern.contains('/')),
+            PatternSyntax::Regex => (self.pattern.clone(), false),
+        };
+        let regex = RegexBuilder::new(&source)
+            .case_insensitive(self.syntax == PatternSyntax::Glob)
+            .build()
+            .map_err(|e| {
+                Error::InvalidArgument(format!(
+                    "Invalid exclusion pattern '{}': {}",
+                    self.pattern, e
+                ))
+            })?;
+        Ok(CompiledRule {
+            regex,
+            per_component,
+        })
+    }
+}
+
+/// The exclusion rules every library starts with: macOS resource forks and dotfiles.
+pub fn default_exclusion_rules() -> Vec<ExclusionRule> {
+    vec![ExclusionRule::glob("__MACOSX"), ExclusionRule::glob(".*")]
+}
+
+/// A compiled [`ExclusionRule`].
+#[derive(Debug, Clone)]
+pub struct CompiledRule {
+    regex: Regex,
+    /// Whether the rule is matched against each path component instead of the path.
+    per_component: bool,
+}
+
+impl CompiledRule {
+    fn is_match(&self, entry: &str) -> bool {
+        if self.per_component {
+            entry
+                .split(['/', '\\'])
+                .any(|component| self.regex.is_match(component))
+        } else {
+            self.regex.is_match(entry)
+        }
+    }
+}
+
+/// A set of exclusion rules applied while a container collects its entries.
+///
+/// The default filter has no rules and keeps every entry.
+#[derive(Debug, Clone, Default)]
+pub struct EntryFilter {
+    rules: Vec<CompiledRule>,
+}
+
+impl EntryFilter {
+    /// Compiles the given rules into a filter.
+    ///
+    /// Rules are validated when they are saved; one that still fails to compile (e.g.
+    /// from a hand-edited settings file) is skipped with a warning rather than failing
+    /// every book.
+    pub fn new<'a>(rules: impl IntoIterator<Item = &'a ExclusionRule>) -> Self {
+        let rules = rules
+            .into_iter()
+            .filter_map(|rule| match rule.compile() {
+                Ok(compiled) => Some(compiled),
+                Err(e) => {
+                    log::warn!("Skipping exclusion rule: {e}");
+                    None
+                }
+            })
+            .collect();
+        Self { rules }
+    }
+
+    /// Returns `true` if any rule matches the entry path.
+    pub fn is_excluded(&self, entry: &str) -> bool {
+        self.rules.iter().any(|rule| rule.is_match(entry))
+    }
+
+    /// Splits entry paths into the ones kept and the ones excluded, preserving their
+    /// order.
+    pub fn partition(&self, entries: Vec<String>) -> (Vec<String>, Vec<String>) {
+        entries
+            .into_iter()
+            .partition(|entry| !self.is_excluded(entry))
+    }
+}
+
+/// Translates a glob into an anchored regular expression.
+fn glob_to_regex(glob: &str) -> String {
+    let mut source = String::from("^");
+    let mut chars = glob.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '*' if chars.peek() == Some(&'*') => {
+                chars.next();
+                source.push_str(".*");
+            }
+            '*' => source.push_str("[^/]*"),
+            '?' => source.push_str("[^/]"),
+            c => source.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    source.push('$');
+    source
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn regex(pattern: &str) -> ExclusionRule {
+        ExclusionRule {
+            pattern: pattern.to_string(),
+            syntax: PatternSyntax::Regex,
+        }
+    }
+
+    #[rstest]
+    #[case("__MACOSX/._page1.jpg", true)]
+    #[case("book/__MACOSX/page1.jpg", true)]
+    #[case("book/.hidden.png", true)]
+    #[case(".DS_Store", true)]
+    #[case("book/page1.jpg", false)]
+    #[case("page.1.jpg", false)]
+    fn test_default_rules(#[case] entry: &str, #[case] excluded: bool) {
+        let filter = EntryFilter::new(&default_exclusion_rules());
+        assert_eq!(filter.is_excluded(entry), excluded, "{entry}");
+    }
+
+    #[rstest]
+    #[case("cover_small.jpg", "cover_small.jpg", true)]
+    #[case("COVER_SMALL.JPG", "vol1/cover_small.jpg", true)]
+    #[case("credit*.png", "vol1/credits.png", true)]
+    #[case("credit?.png", "credits2.png", false)]
+    #[case("vol1/*.png", "vol1/a.png", true)]
+    #[case("vol1/*.png", "vol1/sub/a.png", false)]
+    #[case("vol1/**.png", "vol1/sub/a.png", true)]
+    #[case("a+b.png", "a+b.png", true)]
+    fn test_glob_rules(#[case] pattern: &str, #[case] entry: &str, #[case] excluded: bool) {
+        let filter = EntryFilter::new(&[ExclusionRule::glob(pattern)]);
+        assert_eq!(filter.is_excluded(entry), excluded, "{pattern} / {entry}");
+    }
+
+    #[test]
+    fn test_regex_rules_search_the_whole_path() {
+        let filter = EntryFilter::new(&[regex(r"scan(ner)?_?credits?")]);
+        assert!(filter.is_excluded("vol1/zz_scanner_credits.jpg"));
+        assert!(!filter.is_excluded("vol1/001.jpg"));
+    }
+
+    #[test]
+    fn test_partition_keeps_order() {
+        let filter = EntryFilter::new(&default_exclusion_rules());
+        let (kept, excluded) = filter.partition(vec![
+            "001.jpg".to_string(),
+            "__MACOSX/._001.jpg".to_string(),
+            "002.jpg".to_string(),
+            ".thumb.jpg".to_string(),
+        ]);
+        assert_eq!(kept, vec!["001.jpg", "002.jpg"]);
+        assert_eq!(excluded, vec!["__MACOSX/._001.jpg", ".thumb.jpg"]);
+    }
+
+    #[test]
+    fn test_invalid_rules_are_rejected_and_skipped() {
+        assert!(matches!(
+            regex("(unclosed").compile(),
+            Err(Error::InvalidArgument(_))
+        ));
+        assert!(ExclusionRule::glob("  ").compile().is_err());
+
+        let filter = EntryFilter::new(&[regex("(unclosed"), ExclusionRule::glob("*.gif")]);
+        assert!(filter.is_excluded("a.gif"));
+        assert!(!filter.is_excluded("a.png"));
+    }
+}
@@ -0,0 +1,178 @@
+//! The orders a container can list its pages in, and a book's manual page order.
+
+use std::cmp::Ordering;
+
+use serde::{Deserialize, Serialize};
+
+use crate::natural_sort;
+
+/// How the pages of a container are ordered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "camelCase")]
+pub enum EntrySortMode {
+    /// Natural order of the entry paths, ignoring case and width and reading kanji
+    /// numerals (`2.jpg` before `10.jpg`, `第二巻` before `第十巻`).
+    #[default]
+    Natural,
+    /// The order the entries are stored in the archive, or listed by the file system.
+    ArchiveOrder,
+    /// Oldest modification time first. Entries without a time (e.g. salvaged from a
+    /// damaged archive) follow in natural order.
+    ModifiedTime,
+    /// Natural order, with pages named like a cover (`cover`, `front`, `表紙`) moved to
+    /// the front.
+    CoverFirst,
+}
+
+/// An entry found by a container, before it is sorted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ListedEntry {
+    /// The entry path.
+    pub name: String,
+    /// A key ordering the entries by modification time, if the container records one.
+    /// Keys are only comparable within one container.
+    pub modified: Option<i64>,
+}
+
+impl ListedEntry {
+    /// Creates an entry without a modification time.
+    pub fn new(name: String) -> Self {
+        Self {
+            name,
+            modified: None,
+        }
+    }
+}
+
+/// File name stems (lowercase) that mark a cover page.
+const COVER_MARKERS: [&str; 3] = ["cover", "front", "表紙"];
+
+/// Compares two entry paths in Japanese-aware natural order, ignoring case.
+fn natural(a: &str, b: &str) -> Ordering {
+    natural_sort::compare(a, b)
+}
+
+/// Returns `true` if the file name of the entry looks like a cover page.
+fn is_cover(entry: &str) -> bool {
+    let file_name = entry.rsplit(['/', '\\']).next().unwrap_or(entry);
+    let stem = file_name
+        .rsplit_once('.')
+        .map_or(file_name, |(stem, _)| stem)
+        .to_lowercase();
+    COVER_MARKERS.iter().any(|marker| stem.contains(marker))
+}
+
+/// Sorts the entries of a container.
+///
+/// # Arguments
+///
+/// * `entries` - The entries in the order the container found them.
+/// * `mode` - How to order them.
+///
+/// # Returns
+///
+/// The entry paths in page order.
+pub fn sort_entries(mut entries: Vec<ListedEntry>, mode: EntrySortMode) -> Vec<String> {
+    match mode {
+        EntrySortMode::Natural => entries.sort_by(|a, b| natural(&a.name, &b.name)),
+        EntrySortMode::ArchiveOrder => {}
+        EntrySortMode::ModifiedTime => entries.sort_by(|a, b| match (a.modified, b.modified) {
+            (Some(x), Some(y)) => x.cmp(&y).then_with(|| natural(&a.name, &b.name)),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => natural(&a.name, &b.name),
+        }),
+        EntrySortMode::CoverFirst => entries.sort_by(|a, b| {
+            is_cover(&b.name)
+                .cmp(&is_cover(&a.name))
+                .then_with(|| natural(&a.name, &b.name))
+        }),
+    }
+    entries.into_iter().map(|entry| entry.name).collect()
+}
+
+/// Reorders pages by a book's manual page order.
+///
+/// The pages named in `page_order` come first, in that order; names that are not pages
+/// (any more) are ignored. Pages missing from `page_order` (e.g. added to the archive
+/// later) follow in their current order.
+///
+/// # Arguments
+///
+/// * `entries` - The pages in their sorted order.
+/// * `page_order` - The manual page order. An empty order leaves `entries` unchanged.
+pub fn apply_page_order(entries: Vec<String>, page_order: &[String]) -> Vec<String> {
+    if page_order.is_empty() {
+        return entries;
+    }
+    let mut remaining: Vec<Option<String>> = entries.into_iter().map(Some).collect();
+    let mut ordered = Vec::with_capacity(remaining.len());
+    for name in page_order {
+        if let Some(slot) = remaining
+            .iter_mut()
+            .find(|slot| slot.as_deref() == Some(name.as_str()))
+        {
+            ordered.extend(slot.take());
+        }
+    }
+    ordered.extend(remaining.into_iter().flatten());
+    ordered
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn listed(entries: &[(&str, Option<i64>)]) -> Vec<ListedEntry> {
+        entries
+            .iter()
+            .map(|(name, modified)| ListedEntry {
+                name: name.to_string(),
+                modified: *modified,
+            })
+            .collect()
+    }
+
+    #[rstest]
+    #[case(EntrySortMode::Natural, vec!["001.jpg", "002.jpg", "10.jpg", "cover.jpg"])]
+    #[case(EntrySortMode::ArchiveOrder, vec!["10.jpg", "cover.jpg", "002.jpg", "001.jpg"])]
+    #[case(EntrySortMode::ModifiedTime, vec!["cover.jpg", "002.jpg", "10.jpg", "001.jpg"])]
+    #[case(EntrySortMode::CoverFirst, vec!["cover.jpg", "001.jpg", "002.jpg", "10.jpg"])]
+    fn test_sort_entries(#[case] mode: EntrySortMode, #[case] expected: Vec<&str>) {
+        let entries = listed(&[
+            ("10.jpg", Some(30)),
+            ("cover.jpg", Some(10)),
+            ("002.jpg", Some(20)),
+            ("001.jpg", None),
+        ]);
+        assert_eq!(sort_entries(entries, mode), expected);
+    }
+
+    #[rstest]
+    #[case("vol1/Cover.png", true)]
+    #[case("front_page.jpg", true)]
+    #[case("表紙.jpg", true)]
+    #[case("covers/001.jpg", false)]
+    #[case("001.jpg", false)]
+    fn test_is_cover(#[case] entry: &str, #[case] expected: bool) {
+        assert_eq!(is_cover(entry), expected, "{entry}");
+    }
+
+    #[test]
+    fn test_apply_page_order() {
+        let entries: Vec<String> = ["p1.jpg", "p1a.jpg", "p1b.jpg", "p2.jpg", "new.jpg"]
+            .map(String::from)
+            .to_vec();
+        let page_order: Vec<String> = ["p2.jpg", "p1b.jpg", "gone.jpg", "p1a.jpg", "p1.jpg"]
+            .map(String::from)
+            .to_vec();
+
+        assert_eq!(
+            apply_page_order(entries.clone(), &page_order),
+            vec!["p2.jpg", "p1b.jpg", "p1a.jpg", "p1.jpg", "new.jpg"]
+        );
+        assert_eq!(apply_page_order(entries.clone(), &[]), entries);
+    }
+}
@@ -8,7 +8,11 @@ use rbook::Epub;
 use scraper::{Html, Selector};
 
 use crate::{
-    container::traits::Container,
+    container::{
+        entry_order::{self, ListedEntry},
+        listing::ListingOptions,
+        traits::Container,
+    },
     error::{Error, Result},
     image::{thumbnail::generate_thumbnail, types::Image},
 };
@@ -16,8 +20,10 @@ use crate::{
 /// An implementation of the `Container` trait for reading content from EPUB files.
 pub struct EpubContainer {
     /// A list of image resource IDs (keys) found in the EPUB's manifest,
-    /// sorted according to their appearance in the spine.
+    /// sorted according to their appearance in the spine, then by the manual page order.
     entries: Vec<String>,
+    /// The IDs of the image resources hidden by exclusion rules, named like `entries`.
+    excluded: Vec<String>,
     /// The opened EPUB archive, protected by a Mutex for thread-safe access.
     epub: Mutex<Epub>,
 }
@@ -48,6 +54,10 @@ impl Container for EpubContainer {
         false
     }
 
+    fn excluded_entries(&self) -> &[String] {
+        &self.excluded
+    }
+
     fn is_novel(&self) -> bool {
         let Ok(epub) = self.epub.lock() else {
             return false;
@@ -84,22 +94,50 @@ impl EpubContainer {
     /// # Errors
     ///
     /// Returns an `Err` if the EPUB file cannot be opened or its contents cannot be parsed.
+    #[cfg(test)]
     pub fn new(path: &str) -> Result<Self> {
+        Self::with_listing(path, &ListingOptions::default())
+    }
+
+    /// Creates a new `EpubContainer`, listing its images with the given options.
+    ///
+    /// The exclusion rules are matched against the resource paths (manifest hrefs), as
+    /// the IDs used as entry names are arbitrary; excluded resources are still reported
+    /// by their IDs. The sort mode only applies to EPUBs
+    /// whose spine does not order the images; `ArchiveOrder` follows the manifest.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the EPUB file.
+    /// * `options` - How the image resources are sorted and filtered.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`EpubContainer::new`].
+    pub fn with_listing(path: &str, options: &ListingOptions) -> Result<Self> {
         let mut epub = Epub::options().strict(false).open(path)?;
-        let mut entries: Vec<String> = epub
-            .manifest()
-            .images()
-            .map(|manifest| manifest.id().to_string())
-            .collect();
+        let mut listed: Vec<ListedEntry> = Vec::new();
+        let mut excluded: Vec<String> = Vec::new();
+        for image in epub.manifest().images() {
+            if options.filter.is_excluded(image.href().as_str()) {
+                excluded.push(image.id().to_string());
+            } else {
+                listed.push(ListedEntry::new(image.id().to_string()));
+            }
+        }
 
-        if let Some(order_map) = create_image_order_map(&mut epub) {
+        let entries = if let Some(order_map) = create_image_order_map(&mut epub) {
+            let mut entries: Vec<String> = listed.into_iter().map(|entry| entry.name).collect();
             entries.sort_by_key(|id| *order_map.get(id).unwrap_or(&usize::MAX));
+            entries
         } else {
-            entries.sort_by(|a, b| natord::compare_ignore_case(a, b));
-        }
+            entry_order::sort_entries(listed, options.sort_mode)
+        };
+        let entries = entry_order::apply_page_order(entries, &options.page_order);
 
         Ok(Self {
             entries,
+            excluded,
             epub: Mutex::new(epub),
         })
     }
@@ -275,6 +313,7 @@ mod tests {
     use zip::{write::FileOptions, ZipWriter};
 
     use super::*;
+    use crate::container::entry_filter::{EntryFilter, ExclusionRule};
 
     // A valid 1x1 transparent PNG
     const DUMMY_PNG_DATA: &[u8] = &[
@@ -412,6 +451,38 @@ mod tests {
         assert_eq!(container.entries[1], "cover");
     }
 
+    #[test]
+    fn test_with_listing_reports_excluded_resources_by_id() {
+        let dir = tempdir().expect("failed to create tempdir");
+        let content_opf_str = content_opf(true, false);
+        let chapter1_xhtml_str = chapter1_xhtml(true);
+
+        let epub_path = create_dummy_epub(
+            dir.path(),
+            "test.epub",
+            &[
+                ("mimetype", b"application/epub+zip"),
+                ("META-INF/container.xml", CONTAINER_XML.as_bytes()),
+                ("OEBPS/content.opf", content_opf_str.as_bytes()),
+                ("OEBPS/text/chapter1.xhtml", chapter1_xhtml_str.as_bytes()),
+                ("OEBPS/images/image1.png", DUMMY_PNG_DATA),
+                ("OEBPS/images/cover.png", DUMMY_PNG_DATA),
+                ("OEBPS/data.txt", b"some text data"),
+            ],
+        );
+
+        // The rule matches the href, but the entry is reported by its ID.
+        let options = ListingOptions {
+            filter: EntryFilter::new(&[ExclusionRule::glob("cover.png")]),
+            ..Default::default()
+        };
+        let container = EpubContainer::with_listing(epub_path.to_string_lossy().as_ref(), &options)
+            .expect("failed to create EpubContainer");
+
+        assert_eq!(container.get_entries(), &vec!["image1".to_string()]);
+        assert_eq!(container.excluded_entries(), ["cover".to_string()]);
+    }
+
     #[test]
     fn test_new_epub_no_images_in_spine() {
         let dir = tempdir().expect("failed to create tempdir");
@@ -1,23 +1,28 @@
 use std::{path::Path, sync::Arc};
 
-use pdfium_render::prelude::PdfRenderConfig;
-
 use crate::{
     container::{
         directory_container::DirectoryContainer, epub_container::EpubContainer,
-        pdf_container::PdfContainer, rar_container::RarContainer, traits::Container,
-        zip_container::ZipContainer,
+        listing::ListingOptions, pdf_container::PdfContainer, rar_container::RarContainer,
+        traits::Container, zip_container::ZipContainer,
     },
     error::{Error, Result},
+    image::encoder::EncodeOptions,
 };
 
 /// Configuration options for creating a container.
 #[derive(Default)]
 pub struct ContainerConfig {
-    /// The rendering configuration for PDF containers.
-    pub pdf_render_config: PdfRenderConfig,
+    /// The height in pixels PDF pages are rendered at, or `None` to render them at their
+    /// own size (72 DPI).
+    pub pdf_render_height: Option<i32>,
+    /// How PDF pages are encoded once rendered.
+    pub encoding: EncodeOptions,
     /// An optional path to the directory containing the `pdfium` library.
     pub pdfium_library_path: Option<String>,
+    /// How the entries of archives, directories and EPUBs are sorted and filtered. PDF
+    /// pages have no names to match and always keep their document order.
+    pub listing: ListingOptions,
 }
 
 /// Creates a `Container` from a file path based on its type (directory or file extension).
@@ -45,20 +50,27 @@ pub fn create_container(path: &str, config: ContainerConfig) -> Result<Arc<dyn C
     let file_path = Path::new(path);
 
     if file_path.is_dir() {
-        return Ok(Arc::new(DirectoryContainer::new(path)?));
+        return Ok(Arc::new(DirectoryContainer::with_listing(
+            path,
+            &config.listing,
+        )?));
     }
 
     if let Some(ext) = file_path.extension() {
         let ext_str = ext.to_string_lossy().to_lowercase();
         match ext_str.as_str() {
-            "zip" | "cbz" => Ok(Arc::new(ZipContainer::new(path)?)),
+            "zip" | "cbz" => Ok(Arc::new(ZipContainer::with_listing(path, &config.listing)?)),
             "pdf" => Ok(Arc::new(PdfContainer::new(
                 path,
-                config.pdf_render_config,
+                config.pdf_render_height,
+                config.encoding,
                 config.pdfium_library_path,
             )?)),
-            "rar" | "cbr" => Ok(Arc::new(RarContainer::new(path)?)),
-            "epub" => Ok(Arc::new(EpubContainer::new(path)?)),
+            "rar" | "cbr" => Ok(Arc::new(RarContainer::with_listing(path, &config.listing)?)),
+            "epub" => Ok(Arc::new(EpubContainer::with_listing(
+                path,
+                &config.listing,
+            )?)),
             _ => Err(Error::UnsupportedContainer(format!(
                 "Unsupported Container Type: {}",
                 ext_str
@@ -0,0 +1,66 @@
+//! How a container turns the entries it found into its pages.
+
+use super::{
+    entry_filter::EntryFilter,
+    entry_order::{self, EntrySortMode, ListedEntry},
+};
+
+/// The options a container lists its pages with.
+///
+/// The default options keep every entry in natural order.
+#[derive(Debug, Clone, Default)]
+pub struct ListingOptions {
+    /// The exclusion rules hiding entries.
+    pub filter: EntryFilter,
+    /// How the pages are sorted.
+    pub sort_mode: EntrySortMode,
+    /// The book's manual page order, applied after sorting. Empty if it has none.
+    pub page_order: Vec<String>,
+}
+
+impl ListingOptions {
+    /// Sorts the entries, hides the excluded ones and applies the manual page order.
+    ///
+    /// # Arguments
+    ///
+    /// * `entries` - The entries in the order the container found them.
+    ///
+    /// # Returns
+    ///
+    /// The pages in order, and the excluded entry paths in sorted order.
+    pub fn arrange(&self, entries: Vec<ListedEntry>) -> (Vec<String>, Vec<String>) {
+        let sorted = entry_order::sort_entries(entries, self.sort_mode);
+        let (pages, excluded) = self.filter.partition(sorted);
+        (
+            entry_order::apply_page_order(pages, &self.page_order),
+            excluded,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::container::entry_filter::{default_exclusion_rules, ExclusionRule};
+
+    #[test]
+    fn test_arrange_sorts_filters_then_applies_page_order() {
+        let options = ListingOptions {
+            filter: EntryFilter::new(
+                default_exclusion_rules()
+                    .iter()
+                    .chain(&[ExclusionRule::glob("credits.*")]),
+            ),
+            sort_mode: EntrySortMode::Natural,
+            page_order: vec!["3.jpg".to_string()],
+        };
+        let entries = ["2.jpg", "credits.png", "1.jpg", ".DS_Store.jpg", "3.jpg"]
+            .map(|name| ListedEntry::new(name.to_string()))
+            .to_vec();
+
+        let (pages, excluded) = options.arrange(entries);
+
+        assert_eq!(pages, vec!["3.jpg", "1.jpg", "2.jpg"]);
+        assert_eq!(excluded, vec![".DS_Store.jpg", "credits.png"]);
+    }
+}
@@ -0,0 +1,465 @@
+use std::{
+    collections::HashMap,
+    fs::{self, File},
+    io::Read,
+    path::Path,
+    sync::{LazyLock, Mutex},
+};
+
+use regex::{Captures, Regex};
+use unrar::Archive;
+use zip::ZipArchive;
+
+use crate::{
+    domain::{
+        book::entity::BookMetadata,
+        creator::entity::{CreatorCredit, CreatorRole},
+    },
+    error::Result,
+};
+
+/// The file name of the ComicInfo metadata, compared case-insensitively.
+const COMIC_INFO_FILE_NAME: &str = "ComicInfo.xml";
+
+/// The path of the EPUB container file, which points to the package document.
+const EPUB_CONTAINER_PATH: &str = "META-INF/container.xml";
+
+/// Metadata files larger than this are read only up to this size.
+const MAX_METADATA_BYTES: u64 = 4 * 1024 * 1024;
+
+/// Matches the path of the package document in an EPUB container file.
+static ROOTFILE_PATTERN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"full-path\s*=\s*["']([^"']+)["']"#).expect("valid regex"));
+
+/// Matches a CDATA section, capturing its content.
+static CDATA_PATTERN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?s)<!\[CDATA\[(.*?)\]\]>").expect("valid regex"));
+
+/// Matches a tag.
+static MARKUP_PATTERN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"<[^>]*>").expect("valid regex"));
+
+/// Matches an entity or numeric character reference, capturing its name.
+static ENTITY_PATTERN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"&(#[xX][0-9a-fA-F]+|#[0-9]+|[a-zA-Z]+);").expect("valid regex"));
+
+/// Matches an attribute, capturing its local name and its double- or single-quoted
+/// value.
+static ATTRIBUTE_PATTERN: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"(?:^|\s)(?:[\w.-]+:)?([\w.-]+)\s*=\s*(?:"([^"]*)"|'([^']*)')"#)
+        .expect("valid regex")
+});
+
+/// The patterns matching the elements of each local name, compiled on first use.
+static ELEMENT_PATTERNS: LazyLock<Mutex<HashMap<String, Regex>>> = LazyLock::new(Mutex::default);
+
+/// Reads the metadata stored in a book file.
+///
+/// The metadata comes from the package document of an EPUB, or from a `ComicInfo.xml`
+/// in a ZIP/RAR archive or directory. Books without either (and PDFs) have empty
+/// metadata.
+///
+/// # Arguments
+///
+/// * `path` - The path to the book file or directory.
+///
+/// # Returns
+///
+/// A `Result` containing the metadata found, which may be empty.
+///
+/// # Errors
+///
+/// Returns an `Err` if the book does not exist or cannot be opened.
+pub fn read_metadata(path: &str) -> Result<BookMetadata> {
+    let file_path = Path::new(path);
+    if fs::metadata(file_path)?.is_dir() {
+        return Ok(read_directory_comic_info(file_path)?
+            .map(|xml| parse_comic_info(&xml))
+            .unwrap_or_default());
+    }
+
+    let extension = file_path
+        .extension()
+        .map(|ext| ext.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    let metadata = match extension.as_str() {
+        "zip" | "cbz" => read_zip_comic_info(path)?.map(|xml| parse_comic_info(&xml)),
+        "rar" | "cbr" => read_rar_comic_info(path)?.map(|xml| parse_comic_info(&xml)),
+        "epub" => read_epub_package(path)?.map(|xml| parse_package(&xml)),
+        _ => None,
+    };
+    Ok(metadata.unwrap_or_default())
+}
+
+/// Returns `true` if the entry name (a path inside an archive) names a `ComicInfo.xml`.
+fn is_comic_info(name: &str) -> bool {
+    name.rsplit(['/', '\\'])
+        .next()
+        .is_some_and(|file_name| file_name.eq_ignore_ascii_case(COMIC_INFO_FILE_NAME))
+}
+
+/// Decodes metadata bytes, replacing invalid UTF-8 rather than failing.
+fn decode(bytes: &[u8]) -> String {
+    String::from_utf8_lossy(bytes).into_owned()
+}
+
+/// Reads the `ComicInfo.xml` directly inside a directory, if any.
+fn read_directory_comic_info(path: &Path) -> Result<Option<String>> {
+    for entry in fs::read_dir(path)? {
+        let entry = entry?;
+        if entry.file_type()?.is_file() && is_comic_info(&entry.file_name().to_string_lossy()) {
+            let mut bytes = Vec::new();
+            File::open(entry.path())?
+                .take(MAX_METADATA_BYTES)
+                .read_to_end(&mut bytes)?;
+            return Ok(Some(decode(&bytes)));
+        }
+    }
+    Ok(None)
+}
+
+/// Reads an entry of a ZIP archive as text.
+fn read_zip_text(archive: &mut ZipArchive<File>, name: &str) -> Result<String> {
+    let mut bytes = Vec::new();
+    archive
+        .by_name(name)?
+        .take(MAX_METADATA_BYTES)
+        .read_to_end(&mut bytes)?;
+    Ok(decode(&bytes))
+}
+
+/// Reads the `ComicInfo.xml` of a ZIP archive, preferring the one closest to the root.
+fn read_zip_comic_info(path: &str) -> Result<Option<String>> {
+    let mut archive = ZipArchive::new(File::open(path)?)?;
+    let Some(name) = archive
+        .file_names()
+        .filter(|name| is_comic_info(name))
+        .min_by_key(|name| name.len())
+        .map(str::to_string)
+    else {
+        return Ok(None);
+    };
+    read_zip_text(&mut archive, &name).map(Some)
+}
+
+/// Reads the first `ComicInfo.xml` of a RAR archive.
+fn read_rar_comic_info(path: &str) -> Result<Option<String>> {
+    let mut archive = Archive::new(path).open_for_processing()?;
+    while let Some(header) = archive.read_header()? {
+        if is_comic_info(&header.entry().filename.to_string_lossy()) {
+            let (data, _) = header.read()?;
+            return Ok(Some(decode(&data)));
+        }
+        archive = header.skip()?;
+    }
+    Ok(None)
+}
+
+/// Reads the package document (OPF) of an EPUB, located through its container file.
+fn read_epub_package(path: &str) -> Result<Option<String>> {
+    let mut archive = ZipArchive::new(File::open(path)?)?;
+    let container = read_zip_text(&mut archive, EPUB_CONTAINER_PATH)?;
+    let Some(package_path) = ROOTFILE_PATTERN
+        .captures(&container)
+        .map(|c| c[1].to_string())
+    else {
+        return Ok(None);
+    };
+    read_zip_text(&mut archive, &package_path).map(Some)
+}
+
+/// The `ComicInfo.xml` fields crediting creators, with the role each one stands for.
+const COMIC_INFO_CREDITS: [(&str, CreatorRole); 5] = [
+    ("Writer", CreatorRole::Author),
+    ("Penciller", CreatorRole::Illustrator),
+    ("Inker", CreatorRole::Illustrator),
+    ("CoverArtist", CreatorRole::Illustrator),
+    ("Translator", CreatorRole::Translator),
+];
+
+/// Extracts the metadata of a `ComicInfo.xml`.
+///
+/// The comma-separated credit fields give the creators (`Writer` the authors,
+/// `Penciller`, `Inker` and `CoverArtist` the illustrators, `Translator` the
+/// translators), `Publisher` the publisher and `Summary` the description.
+fn parse_comic_info(xml: &str) -> BookMetadata {
+    let mut creators = Vec::new();
+    for (field, role) in COMIC_INFO_CREDITS {
+        for names in element_texts(xml, field) {
+            creators.extend(
+                names
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|name| !name.is_empty())
+                    .map(|name| CreatorCredit::new(name, role)),
+            );
+        }
+    }
+    BookMetadata {
+        creators: dedup(creators),
+        publisher: element_texts(xml, "Publisher").into_iter().next(),
+        description: element_texts(xml, "Summary").into_iter().next(),
+    }
+}
+
+/// Extracts the metadata of an EPUB package document from its Dublin Core elements.
+///
+/// The role of a `dc:creator` or `dc:contributor` is read from its `opf:role` attribute
+/// (EPUB 2) or a `role` meta element refining it (EPUB 3). Creators without a role are
+/// authors; contributors without a role, and roles other than author, illustrator and
+/// translator, are left out.
+fn parse_package(xml: &str) -> BookMetadata {
+    let refined_roles: HashMap<String, String> = elements(xml, "meta")
+        .into_iter()
+        .filter(|(attributes, _)| attribute(attributes, "property").as_deref() == Some("role"))
+        .filter_map(|(attributes, role)| {
+            let id = attribute(&attributes, "refines")?;
+            Some((id.trim_start_matches('#').to_string(), role))
+        })
+        .collect();
+    let credits = |name: &str, default_role: Option<CreatorRole>| {
+        elements(xml, name)
+            .into_iter()
+            .filter_map(|(attributes, creator)| {
+                let role = attribute(&attributes, "role").or_else(|| {
+                    attribute(&attributes, "id").and_then(|id| refined_roles.get(&id).cloned())
+                });
+                let role = match role {
+                    Some(code) => relator_role(&code)?,
+                    None => default_role?,
+                };
+                Some(CreatorCredit::new(&creator, role))
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let mut creators = credits("creator", Some(CreatorRole::Author));
+    creators.extend(credits("contributor", None));
+    BookMetadata {
+        creators: dedup(creators),
+        publisher: element_texts(xml, "publisher").into_iter().next(),
+        description: element_texts(xml, "description").into_iter().next(),
+    }
+}
+
+/// Maps a MARC relator code (as used for EPUB roles) to a creator role, or `None` for
+/// the roles that are not tracked.
+fn relator_role(code: &str) -> Option<CreatorRole> {
+    match code.trim().to_ascii_lowercase().as_str() {
+        "aut" => Some(CreatorRole::Author),
+        "ill" | "art" => Some(CreatorRole::Illustrator),
+        "trl" => Some(CreatorRole::Translator),
+        _ => None,
+    }
+}
+
+/// Removes repeated items, keeping the first occurrence of each.
+fn dedup<T: PartialEq>(items: impl IntoIterator<Item = T>) -> Vec<T> {
+    let mut unique: Vec<T> = Vec::new();
+    for item in items {
+        if !unique.contains(&item) {
+            unique.push(item);
+        }
+    }
+    unique
+}
+
+/// Returns the non-empty text of every element with the given local name, with or
+/// without a namespace prefix (`dc:creator` and `creator` both match `creator`).
+///
+/// Markup inside the text (e.g. an HTML description) is removed and entities are
+/// decoded. Element names are matched case-insensitively.
+fn element_texts(xml: &str, name: &str) -> Vec<String> {
+    elements(xml, name)
+        .into_iter()
+        .map(|(_, text)| text)
+        .collect()
+}
+
+/// Returns the raw attributes and the non-empty text of every element with the given
+/// local name, as [`element_texts`] matches them. Empty (self-closing) elements are
+/// skipped.
+fn elements(xml: &str, name: &str) -> Vec<(String, String)> {
+    // Compiled once per name: the regex crate has no backreferences to match the
+    // closing tag of any element with a single pattern.
+    let element = ELEMENT_PATTERNS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .entry(name.to_string())
+        .or_insert_with(|| {
+            Regex::new(&format!(
+                r"(?is)<(?:[\w.-]+:)?{name}(\s(?:[^>]*[^/>])?)?>(.*?)</(?:[\w.-]+:)?{name}\s*>"
+            ))
+            .expect("valid regex")
+        })
+        .clone();
+    element
+        .captures_iter(xml)
+        .map(|c| {
+            let attributes = c.get(1).map_or("", |m| m.as_str()).to_string();
+            (attributes, element_text(&c[2]))
+        })
+        .filter(|(_, text)| !text.is_empty())
+        .collect()
+}
+
+/// Returns the decoded value of an attribute, with or without a namespace prefix
+/// (`opf:role` and `role` both match `role`).
+fn attribute(attributes: &str, name: &str) -> Option<String> {
+    ATTRIBUTE_PATTERN
+        .captures_iter(attributes)
+        .find(|c| c[1].eq_ignore_ascii_case(name))
+        .and_then(|c| c.get(2).or_else(|| c.get(3)))
+        .map(|value| unescape(value.as_str()))
+}
+
+/// Converts the raw content of an element to plain text.
+fn element_text(raw: &str) -> String {
+    // Escaped markup (an HTML description) only becomes visible once decoded.
+    let text = unescape(&CDATA_PATTERN.replace_all(raw, "$1"));
+    let text = MARKUP_PATTERN.replace_all(&text, " ");
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Decodes the predefined XML entities and numeric character references.
+fn unescape(text: &str) -> String {
+    ENTITY_PATTERN
+        .replace_all(text, |c: &Captures| {
+            let name = &c[1];
+            let decoded = match name {
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "amp" => Some('&'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                _ => name
+                    .strip_prefix("#x")
+                    .or_else(|| name.strip_prefix("#X"))
+                    .map(|hex| u32::from_str_radix(hex, 16))
+                    .or_else(|| name.strip_prefix('#').map(str::parse))
+                    .and_then(|code| code.ok())
+                    .and_then(char::from_u32),
+            };
+            decoded.map_or_else(|| c[0].to_string(), String::from)
+        })
+        .into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use tempfile::tempdir;
+    use zip::write::{FileOptions, ZipWriter};
+
+    use super::*;
+
+    const COMIC_INFO: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+        <ComicInfo xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance">
+            <Title>第1巻</Title>
+            <Writer>尾田栄一郎, Jane Doe ,尾田栄一郎</Writer>
+            <Penciller>尾田栄一郎</Penciller>
+            <Translator>John Smith</Translator>
+            <Publisher>集英社 &amp; Co.</Publisher>
+            <Summary>A &lt;b&gt;pirate&lt;/b&gt; story.</Summary>
+        </ComicInfo>"#;
+
+    const PACKAGE: &str = r##"<?xml version="1.0" encoding="UTF-8"?>
+        <package xmlns="http://www.idpf.org/2007/opf" version="3.0">
+            <metadata xmlns:dc="http://purl.org/dc/elements/1.1/">
+                <dc:title>Test Book</dc:title>
+                <dc:creator id="author1">Author One</dc:creator>
+                <dc:creator opf:role="ill">Illustrator &#x41;</dc:creator>
+                <dc:contributor id="trl1">Translator One</dc:contributor>
+                <meta refines="#trl1" property="role" scheme="marc:relators">trl</meta>
+                <dc:contributor>Editor</dc:contributor>
+                <dc:publisher>Publisher</dc:publisher>
+                <dc:description><![CDATA[<p>Line one</p><p>Line two</p>]]></dc:description>
+            </metadata>
+        </package>"##;
+
+    #[test]
+    fn test_parse_comic_info() {
+        let metadata = parse_comic_info(COMIC_INFO);
+        assert_eq!(
+            metadata.creators,
+            vec![
+                CreatorCredit::new("尾田栄一郎", CreatorRole::Author),
+                CreatorCredit::new("Jane Doe", CreatorRole::Author),
+                CreatorCredit::new("尾田栄一郎", CreatorRole::Illustrator),
+                CreatorCredit::new("John Smith", CreatorRole::Translator),
+            ]
+        );
+        assert_eq!(metadata.publisher.as_deref(), Some("集英社 & Co."));
+        assert_eq!(metadata.description.as_deref(), Some("A pirate story."));
+    }
+
+    #[test]
+    fn test_parse_package() {
+        let metadata = parse_package(PACKAGE);
+        // Contributors without a known role are skipped.
+        assert_eq!(
+            metadata.creators,
+            vec![
+                CreatorCredit::new("Author One", CreatorRole::Author),
+                CreatorCredit::new("Illustrator A", CreatorRole::Illustrator),
+                CreatorCredit::new("Translator One", CreatorRole::Translator),
+            ]
+        );
+        assert_eq!(metadata.publisher.as_deref(), Some("Publisher"));
+        assert_eq!(metadata.description.as_deref(), Some("Line one Line two"));
+    }
+
+    #[test]
+    fn test_parse_without_metadata() {
+        assert_eq!(parse_comic_info("<ComicInfo/>"), BookMetadata::default());
+        assert_eq!(parse_package("<package/>"), BookMetadata::default());
+    }
+
+    #[test]
+    fn test_unescape() {
+        assert_eq!(
+            unescape("&lt;&#38;&#x263A;&unknown;"),
+            "<&\u{263A}&unknown;"
+        );
+    }
+
+    #[test]
+    fn test_read_metadata_from_zip_and_directory() {
+        let dir = tempdir().unwrap();
+        let zip_path = dir.path().join("comic.cbz");
+        let mut zip = ZipWriter::new(File::create(&zip_path).unwrap());
+        zip.start_file("01.png", FileOptions::<()>::default())
+            .unwrap();
+        zip.start_file("comicinfo.xml", FileOptions::<()>::default())
+            .unwrap();
+        zip.write_all(COMIC_INFO.as_bytes()).unwrap();
+        zip.finish().unwrap();
+
+        let metadata = read_metadata(zip_path.to_str().unwrap()).unwrap();
+        assert_eq!(
+            metadata.creators,
+            vec![
+                CreatorCredit::new("尾田栄一郎", CreatorRole::Author),
+                CreatorCredit::new("Jane Doe", CreatorRole::Author),
+                CreatorCredit::new("尾田栄一郎", CreatorRole::Illustrator),
+                CreatorCredit::new("John Smith", CreatorRole::Translator),
+            ]
+        );
+
+        let book_dir = dir.path().join("book");
+        fs::create_dir(&book_dir).unwrap();
+        fs::write(book_dir.join("ComicInfo.xml"), COMIC_INFO).unwrap();
+        let metadata = read_metadata(book_dir.to_str().unwrap()).unwrap();
+        assert_eq!(metadata.publisher.as_deref(), Some("集英社 & Co."));
+
+        fs::remove_file(book_dir.join("ComicInfo.xml")).unwrap();
+        let metadata = read_metadata(book_dir.to_str().unwrap()).unwrap();
+        assert_eq!(metadata, BookMetadata::default());
+    }
+
+    #[test]
+    fn test_read_metadata_of_missing_book_fails() {
+        assert!(read_metadata("missing_book.zip").is_err());
+    }
+}
@@ -1,7 +1,14 @@
 pub mod directory_container;
+pub mod entry_filter;
+pub mod entry_order;
 pub mod epub_container;
 pub mod factory;
+pub mod listing;
+pub mod metadata;
 pub mod pdf_container;
 pub mod rar_container;
+pub mod rar_volume;
 pub mod traits;
+pub mod verification;
 pub mod zip_container;
+pub mod zip_salvage;
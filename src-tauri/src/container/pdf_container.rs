@@ -1,17 +1,30 @@
-use image::codecs::jpeg::JpegEncoder;
+use image::{DynamicImage, ImageFormat};
 use pdfium_render::prelude::{PdfDocument, PdfPageRenderRotation, PdfRenderConfig, Pdfium};
-use std::sync::Arc;
+use std::{sync::Arc, time::Instant};
 
 use crate::{
     container::traits::Container,
     error::{Error, Result},
     image::{
+        encoder::{encode_for_display, encode_jpeg, EncodeOptions},
+        metrics::{self, Stage},
         resizer::{shrink_to_fit, ResizeFilter},
         thumbnail::THUMBNAIL_SIZE,
         types::Image,
     },
 };
 
+/// The resolution at which pages are re-rendered for deep zoom.
+const DEEP_ZOOM_DPI: f32 = 300.0;
+/// PDF user space units per inch; a page rendered at scale 1.0 is 72 DPI.
+const PDF_POINTS_PER_INCH: f32 = 72.0;
+/// The upper bound on either dimension of a deep-zoom render, keeping oversized pages
+/// (e.g. posters) from allocating gigabytes of pixels.
+const DEEP_ZOOM_MAX_DIMENSION: i32 = 16384;
+/// The lowest JPEG quality of deep-zoom renders; higher than regular pages since the
+/// loupe magnifies compression artifacts.
+const DEEP_ZOOM_JPEG_QUALITY: u8 = 92;
+
 /// An implementation of the `Container` trait for reading content from PDF files.
 ///
 /// This container treats each page of a PDF document as an entry, which can be
@@ -25,12 +38,18 @@ pub struct PdfContainer {
     path: String,
     /// A list of page numbers (as zero-padded strings) representing the entries.
     entries: Vec<String>,
+    /// The height in pixels pages are rendered at, or `None` for their own size.
+    render_height: Option<i32>,
+    /// How rendered pages are encoded.
+    encoding: EncodeOptions,
     /// The configuration used for rendering full-sized page images.
     render_config: Arc<PdfRenderConfig>,
     /// The path to the directory containing the `pdfium` dynamic library.
     library_path: Option<String>,
     /// The configuration used for rendering smaller thumbnail images.
     thumbnail_render_config: Arc<PdfRenderConfig>,
+    /// The configuration used for re-rendering pages at a higher DPI for deep zoom.
+    zoom_render_config: Arc<PdfRenderConfig>,
 }
 
 impl Container for PdfContainer {
@@ -42,10 +61,38 @@ impl Container for PdfContainer {
         let pdfium = get_pdfium(&self.library_path)?;
         let pdf = pdfium.load_pdf_from_file(&self.path, None)?;
 
-        let image_arc = load_image(&pdf, &self.render_config, entry)?;
+        let image_arc = load_image(&pdf, &self.render_config, entry, self.encoding)?;
         Ok(image_arc)
     }
 
+    fn get_full_resolution_image(&self, entry: &str) -> Result<Arc<Image>> {
+        let pdfium = get_pdfium(&self.library_path)?;
+        let pdf = pdfium.load_pdf_from_file(&self.path, None)?;
+
+        let encoding = EncodeOptions {
+            quality: self.encoding.quality.max(DEEP_ZOOM_JPEG_QUALITY),
+            ..self.encoding
+        };
+        load_image(&pdf, &self.zoom_render_config, entry, encoding)
+    }
+
+    fn get_image_sizes(&self) -> Result<Vec<(u32, u32)>> {
+        let pdfium = get_pdfium(&self.library_path)?;
+        let pdf = pdfium.load_pdf_from_file(&self.path, None)?;
+
+        // The page boxes give the render sizes without rendering a single page.
+        pdf.pages()
+            .iter()
+            .map(|page| {
+                Ok(rendered_size(
+                    page.width().value,
+                    page.height().value,
+                    self.render_height,
+                ))
+            })
+            .collect()
+    }
+
     fn get_thumbnail(&self, entry: &str) -> Result<Arc<Image>> {
         let pdfium = get_pdfium(&self.library_path)?;
         let pdf = pdfium.load_pdf_from_file(&self.path, None)?;
@@ -74,7 +121,9 @@ impl PdfContainer {
     /// # Arguments
     ///
     /// * `path` - The path to the PDF file.
-    /// * `render_config` - The base configuration for rendering pages.
+    /// * `render_height` - The height in pixels pages are rendered at, or `None` to
+    ///   render them at their own size (72 DPI).
+    /// * `encoding` - How rendered pages are encoded.
     /// * `library_path` - An optional path to the directory containing the `pdfium` library.
     ///
     /// # Returns
@@ -86,9 +135,14 @@ impl PdfContainer {
     /// Returns an `Err` if `pdfium` cannot be initialized or the PDF file cannot be opened.
     pub fn new(
         path: &str,
-        render_config: PdfRenderConfig,
+        render_height: Option<i32>,
+        encoding: EncodeOptions,
         library_path: Option<String>,
     ) -> Result<Self> {
+        let render_config = match render_height {
+            Some(height) => PdfRenderConfig::default().set_target_height(height),
+            None => PdfRenderConfig::default(),
+        };
         let mut entries: Vec<String> = Vec::new();
         {
             let pdfium = get_pdfium(&library_path)?;
@@ -102,6 +156,8 @@ impl PdfContainer {
         Ok(Self {
             path: path.to_string(),
             entries,
+            render_height,
+            encoding,
             render_config: Arc::new(render_config),
             library_path,
             thumbnail_render_config: Arc::new(
@@ -113,27 +169,51 @@ impl PdfContainer {
                     .render_annotations(false)
                     .render_form_data(false),
             ),
+            zoom_render_config: Arc::new(
+                PdfRenderConfig::default()
+                    .scale_page_by_factor(DEEP_ZOOM_DPI / PDF_POINTS_PER_INCH)
+                    .set_maximum_width(DEEP_ZOOM_MAX_DIMENSION)
+                    .set_maximum_height(DEEP_ZOOM_MAX_DIMENSION)
+                    .rotate(PdfPageRenderRotation::None, false),
+            ),
         })
     }
 }
 
+/// Returns the size in pixels a page of `width` x `height` points renders at, scaled to
+/// `render_height` as `PdfRenderConfig::set_target_height` does.
+fn rendered_size(width: f32, height: f32, render_height: Option<i32>) -> (u32, u32) {
+    let scale = match render_height {
+        Some(target) if height > 0.0 => target as f32 / height,
+        _ => 1.0,
+    };
+    (
+        (width * scale).round() as u32,
+        (height * scale).round() as u32,
+    )
+}
+
 /// Helper function to render a PDF page to an image using a specific config.
 fn load_image(
     pdf: &PdfDocument,
     render_config: &PdfRenderConfig,
     entry: &str,
+    encoding: EncodeOptions,
 ) -> Result<Arc<Image>> {
     let index: u16 = entry.parse()?;
 
     let page = pdf.pages().get(index).map_err(Error::from)?;
+    let started = Instant::now();
     let img = page.render_with_config(render_config)?.as_image();
+    metrics::record(Stage::PdfRender, entry, Some("pdf"), started.elapsed());
 
-    let mut buffer = Vec::new();
-    let mut encoder = JpegEncoder::new_with_quality(&mut buffer, 80);
-    encoder.encode_image(&img)?;
-
+    // Pages render opaque onto white, so the alpha channel is dropped rather than
+    // forcing a PNG. A render has no stored format; passthrough keeps the JPEG pages
+    // have always been rendered to.
+    let img = DynamicImage::ImageRgb8(img.to_rgb8());
+    let encoding = encoding.for_source(Some(ImageFormat::Jpeg));
     let image = Image {
-        data: buffer,
+        data: encode_for_display(&img, encoding)?,
         width: img.width(),
         height: img.height(),
     };
@@ -159,13 +239,8 @@ fn create_thumbnail(
     // page still exceeds the width cap). Other containers already uphold this.
     let img = shrink_to_fit(&img, THUMBNAIL_SIZE, THUMBNAIL_SIZE, ResizeFilter::Bilinear)?;
 
-    let mut buffer = Vec::new();
-    // Use a lower quality for thumbnails to make them smaller and faster to encode.
-    let mut encoder = JpegEncoder::new_with_quality(&mut buffer, 10);
-    encoder.encode_image(&img)?;
-
     let image = Image {
-        data: buffer,
+        data: encode_jpeg(&img, EncodeOptions::THUMBNAIL)?,
         width: img.width(),
         height: img.height(),
     };
@@ -227,10 +302,11 @@ mod tests {
         let dir = tempdir().unwrap();
         let pdf_path = create_dummy_pdf(dir.path(), "test.pdf");
 
-        let render_config = PdfRenderConfig::default();
+        let render_height = None;
         let container = PdfContainer::new(
             pdf_path.to_string_lossy().as_ref(),
-            render_config,
+            render_height,
+            EncodeOptions::default(),
             Some(get_pdfium_lib_path()),
         )
         .unwrap();
@@ -242,10 +318,11 @@ mod tests {
     #[test]
     fn test_new_non_existent_pdf() {
         let non_existent_path = String::from("/non/existent/file.pdf");
-        let render_config = PdfRenderConfig::default();
+        let render_height = None;
         let container = PdfContainer::new(
             &non_existent_path,
-            render_config,
+            render_height,
+            EncodeOptions::default(),
             Some(get_pdfium_lib_path()),
         );
 
@@ -256,10 +333,11 @@ mod tests {
     fn test_get_entries() {
         let dir = tempdir().unwrap();
         let pdf_path = create_dummy_pdf(dir.path(), "test.pdf");
-        let render_config = PdfRenderConfig::default();
+        let render_height = None;
         let container = PdfContainer::new(
             pdf_path.to_string_lossy().as_ref(),
-            render_config,
+            render_height,
+            EncodeOptions::default(),
             Some(get_pdfium_lib_path()),
         )
         .unwrap();
@@ -275,10 +353,11 @@ mod tests {
         let pdf_path = create_dummy_pdf(dir.path(), "test.pdf");
 
         let rendering_height: u32 = 100;
-        let render_config = PdfRenderConfig::default().set_target_height(rendering_height as i32);
+        let render_height = Some(rendering_height as i32);
         let container = PdfContainer::new(
             pdf_path.to_string_lossy().as_ref(),
-            render_config,
+            render_height,
+            EncodeOptions::default(),
             Some(get_pdfium_lib_path()),
         )
         .unwrap();
@@ -290,16 +369,67 @@ mod tests {
         assert!(!image.data.is_empty());
     }
 
+    #[test]
+    fn test_get_image_sizes_match_rendered_pages() {
+        let dir = tempdir().unwrap();
+        let pdf_path = create_dummy_pdf(dir.path(), "test.pdf");
+
+        let container = PdfContainer::new(
+            pdf_path.to_string_lossy().as_ref(),
+            Some(100),
+            EncodeOptions::default(),
+            Some(get_pdfium_lib_path()),
+        )
+        .unwrap();
+
+        let image = container.get_image("0000").unwrap();
+        assert_eq!(
+            container.get_image_sizes().unwrap(),
+            vec![(image.width, image.height)]
+        );
+    }
+
+    #[test]
+    fn test_rendered_size() {
+        assert_eq!(rendered_size(612.0, 792.0, Some(1584)), (1224, 1584));
+        assert_eq!(rendered_size(612.0, 792.0, None), (612, 792));
+        assert_eq!(rendered_size(0.0, 0.0, Some(100)), (0, 0));
+    }
+
+    #[test]
+    fn test_get_full_resolution_image_renders_at_deep_zoom_dpi() {
+        let dir = tempdir().unwrap();
+        let pdf_path = create_dummy_pdf(dir.path(), "test.pdf");
+
+        let render_height = Some(100);
+        let container = PdfContainer::new(
+            pdf_path.to_string_lossy().as_ref(),
+            render_height,
+            EncodeOptions::default(),
+            Some(get_pdfium_lib_path()),
+        )
+        .unwrap();
+
+        // The 612x792pt page re-renders at 300 DPI regardless of the page render height.
+        let image = container.get_full_resolution_image("0000").unwrap();
+        assert_eq!(
+            image.height,
+            (792.0 * DEEP_ZOOM_DPI / PDF_POINTS_PER_INCH) as u32
+        );
+        assert!(image.width > image.height / 2);
+        assert!(!image.data.is_empty());
+    }
+
     #[test]
     fn test_get_image_non_existing() {
         let dir = tempdir().unwrap();
         let pdf_path = create_dummy_pdf(dir.path(), "test.pdf");
 
-        let rendering_height = 100;
-        let render_config = PdfRenderConfig::default().set_target_height(rendering_height);
+        let render_height = Some(100);
         let container = PdfContainer::new(
             pdf_path.to_string_lossy().as_ref(),
-            render_config,
+            render_height,
+            EncodeOptions::default(),
             Some(get_pdfium_lib_path()),
         )
         .unwrap();
@@ -312,10 +442,11 @@ mod tests {
     fn test_get_thumbnail() {
         let dir = tempdir().unwrap();
         let pdf_path = create_dummy_pdf(dir.path(), "test.pdf");
-        let render_config = PdfRenderConfig::default();
+        let render_height = None;
         let container = PdfContainer::new(
             pdf_path.to_string_lossy().as_ref(),
-            render_config,
+            render_height,
+            EncodeOptions::default(),
             Some(get_pdfium_lib_path()),
         )
         .unwrap();
@@ -337,7 +468,8 @@ mod tests {
 
         let container = PdfContainer::new(
             filepath.to_string_lossy().as_ref(),
-            PdfRenderConfig::default(),
+            None,
+            EncodeOptions::default(),
             Some(get_pdfium_lib_path()),
         )
         .unwrap();
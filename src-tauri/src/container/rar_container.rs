@@ -6,12 +6,15 @@
 //! This prevents sharing a single opened archive instance across multiple threads (e.g., inside a Mutex).
 //! Consequently, the archive has to be opened and scanned sequentially for every image request.
 
-use unrar::{Archive, CursorBeforeHeader, OpenArchive, Process};
+use unrar::{error::Code, Archive, CursorBeforeHeader, OpenArchive, Process};
 
-use std::sync::Arc;
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
 
 use crate::{
-    container::traits::Container,
+    container::{entry_order::ListedEntry, listing::ListingOptions, rar_volume, traits::Container},
     error::{Error, Result},
     image::{thumbnail::generate_thumbnail, types::Image},
 };
@@ -22,10 +25,15 @@ use crate::{
 /// which prevents us from sharing a single opened archive instance across threads using a Mutex.
 /// As a result, this implementation currently opens the archive for each image request.
 pub struct RarContainer {
-    /// The file path of the RAR container.
+    /// The file path of the RAR container. For a multi-volume set, this is the path of
+    /// its first volume.
     path: String,
-    /// A naturally sorted list of image file names found within the archive.
+    /// Whether the container is a multi-volume set.
+    is_multi_volume: bool,
+    /// The image file names found within the archive, in page order.
     entries: Vec<String>,
+    /// The image file names hidden by exclusion rules.
+    excluded: Vec<String>,
 }
 
 impl Container for RarContainer {
@@ -34,16 +42,24 @@ impl Container for RarContainer {
     }
 
     fn get_image(&self, entry: &str) -> Result<Arc<Image>> {
-        load_image(&self.path, entry)
+        load_image(&self.path, entry).map_err(|e| self.volume_error(e))
     }
 
     fn get_thumbnail(&self, entry: &str) -> Result<Arc<Image>> {
-        create_thumbnail(&self.path, entry)
+        create_thumbnail(&self.path, entry).map_err(|e| self.volume_error(e))
+    }
+
+    fn get_image_sizes(&self) -> Result<Vec<(u32, u32)>> {
+        read_sizes(&self.path, &self.entries).map_err(|e| self.volume_error(e))
     }
 
     fn is_directory(&self) -> bool {
         false
     }
+
+    fn excluded_entries(&self) -> &[String] {
+        &self.excluded
+    }
 }
 
 impl RarContainer {
@@ -52,9 +68,12 @@ impl RarContainer {
     /// This constructor opens the RAR archive, filters for supported image formats,
     /// and sorts the resulting file list in natural order.
     ///
+    /// Any volume of a multi-volume set (`name.partN.rar`) opens the whole set, read
+    /// from its first volume.
+    ///
     /// # Arguments
     ///
-    /// * `path` - The path to the RAR file.
+    /// * `path` - The path to the RAR file, or to any volume of a multi-volume set.
     ///
     /// # Returns
     ///
@@ -63,28 +82,76 @@ impl RarContainer {
     /// # Errors
     ///
     /// Returns an `Err` if the RAR file cannot be opened or an error occurs
-    /// while reading its entries.
+    /// while reading its entries, and [`Error::MissingVolume`] if a volume of a
+    /// multi-volume set is missing.
+    #[cfg(test)]
     pub fn new(path: &str) -> Result<Self> {
-        let archive = Archive::new(path).open_for_listing()?;
+        Self::with_listing(path, &ListingOptions::default())
+    }
 
-        let mut filenames: Vec<String> = Vec::new();
+    /// Creates a new `RarContainer`, listing its images with the given options.
+    ///
+    /// The `ModifiedTime` sort mode orders the images by the file times stored in the
+    /// archive.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the RAR file, or to any volume of a multi-volume set.
+    /// * `options` - How the image file names are sorted and filtered.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`RarContainer::new`].
+    pub fn with_listing(path: &str, options: &ListingOptions) -> Result<Self> {
+        let (path, last_volume) = rar_volume::resolve_first_volume(path)?;
+        let mut container = Self {
+            path,
+            is_multi_volume: last_volume.is_some(),
+            entries: Vec::new(),
+            excluded: Vec::new(),
+        };
+
+        let archive = Archive::new(&container.path)
+            .open_for_listing()
+            .map_err(|e| container.volume_error(e.into()))?;
+
+        let mut files: Vec<ListedEntry> = Vec::new();
         for entry_result in archive {
-            let entry = entry_result?;
+            let entry = entry_result.map_err(|e| container.volume_error(e.into()))?;
             if entry.is_file() {
-                filenames.push(entry.filename.to_string_lossy().to_string());
+                files.push(ListedEntry {
+                    name: entry.filename.to_string_lossy().to_string(),
+                    // A DOS date and time, which orders chronologically as a number.
+                    modified: Some(i64::from(entry.file_time)),
+                });
             }
         }
 
-        let entries = collect_entries(filenames.into_iter());
+        (container.entries, container.excluded) =
+            options.arrange(collect_entries(files.into_iter()));
+        Ok(container)
+    }
 
-        Ok(Self {
-            path: path.to_string(),
-            entries,
-        })
+    /// Reports a volume that could not be opened while following a multi-volume set
+    /// as a missing volume.
+    ///
+    /// The volumes up to the last one present are checked when the container is
+    /// created; this catches the volumes after it (or ones removed since), which
+    /// `unrar` only asks for once a file spans into them.
+    fn volume_error(&self, error: Error) -> Error {
+        match error {
+            Error::Unrar(e) if self.is_multi_volume && matches!(e.code, Code::EOpen) => {
+                Error::MissingVolume(format!(
+                    "A volume following {} could not be opened: {}",
+                    self.path, e
+                ))
+            }
+            e => e,
+        }
     }
 }
 
-/// Builds the naturally-sorted image entry list from raw RAR entry filenames.
+/// Builds the image entry list from raw RAR entries, in archive order.
 ///
 /// RAR permits duplicate entry names, and lossy filename decoding can also collide;
 /// [`load_image`] returns the first match, so only the first occurrence of each name is
@@ -93,20 +160,19 @@ impl RarContainer {
 ///
 /// # Arguments
 ///
-/// * `filenames` - An iterator of (lossily decoded) entry filenames.
+/// * `files` - An iterator of the files in the archive, with (lossily decoded) names.
 ///
 /// # Returns
 ///
-/// The supported image names, deduplicated (first occurrence wins) and naturally sorted.
-fn collect_entries(filenames: impl Iterator<Item = String>) -> Vec<String> {
-    let mut entries: Vec<String> = Vec::new();
+/// The supported images, deduplicated by name (first occurrence wins).
+fn collect_entries(files: impl Iterator<Item = ListedEntry>) -> Vec<ListedEntry> {
+    let mut entries: Vec<ListedEntry> = Vec::new();
     let mut seen = std::collections::HashSet::new();
-    for filename in filenames {
-        if Image::is_supported_format(&filename) && seen.insert(filename.clone()) {
-            entries.push(filename);
+    for file in files {
+        if Image::is_supported_format(&file.name) && seen.insert(file.name.clone()) {
+            entries.push(file);
         }
     }
-    entries.sort_by(|a, b| natord::compare_ignore_case(a, b));
     entries
 }
 
@@ -142,6 +208,42 @@ fn load_image(path: &str, entry: &str) -> Result<Arc<Image>> {
     Err(Error::EntryNotFound(format!("Entry not found: {}", entry)))
 }
 
+/// Helper function to read the width and height of the given entries in one pass over
+/// the archive.
+///
+/// `unrar` only extracts whole entries, so each image is still decompressed, but the
+/// archive is scanned once instead of once per page as [`load_image`] would. As there,
+/// the first entry with a given name wins.
+fn read_sizes(path: &str, entries: &[String]) -> Result<Vec<(u32, u32)>> {
+    let wanted: HashSet<&str> = entries.iter().map(String::as_str).collect();
+    let mut sizes: HashMap<&str, (u32, u32)> = HashMap::with_capacity(wanted.len());
+    let mut archive = open(path)?;
+    while sizes.len() < wanted.len() {
+        let Some(header) = archive.read_header()? else {
+            break;
+        };
+        let filename = header.entry().filename.to_string_lossy().to_string();
+        archive = match wanted.get(filename.as_str()) {
+            Some(&name) if !sizes.contains_key(name) => {
+                let (data, rest) = header.read()?;
+                sizes.insert(name, Image::read_dimensions(data.as_slice())?);
+                rest
+            }
+            _ => header.skip()?,
+        };
+    }
+
+    entries
+        .iter()
+        .map(|entry| {
+            sizes
+                .get(entry.as_str())
+                .copied()
+                .ok_or_else(|| Error::EntryNotFound(format!("Entry not found: {}", entry)))
+        })
+        .collect()
+}
+
 /// Helper function to load an image and generate a JPEG thumbnail for it.
 fn create_thumbnail(path: &str, entry: &str) -> Result<Arc<Image>> {
     let img = load_image(path, entry)?;
@@ -206,6 +308,7 @@ mod tests {
             .expect("failed to create RarContainer");
 
         assert_eq!(container.path, rar_path.to_string_lossy().to_string());
+        assert!(!container.is_multi_volume);
         // Expecting 2 entries based on the dummy.rar creation instructions
         assert_eq!(container.entries.len(), 3);
         assert_eq!(container.entries[0], "image1.png");
@@ -220,16 +323,18 @@ mod tests {
         // from RAR duplicate entries / lossy-decode collisions without needing a
         // duplicate-entry RAR fixture.
         let out = collect_entries(
-            vec![
-                "b.png".to_string(),
-                "a.png".to_string(),
-                "b.png".to_string(),     // duplicate → skipped
-                "notes.txt".to_string(), // unsupported → skipped
+            [
+                "b.png",
+                "a.png",
+                "b.png",     // duplicate → skipped
+                "notes.txt", // unsupported → skipped
             ]
-            .into_iter(),
+            .into_iter()
+            .map(|name| ListedEntry::new(name.to_string())),
         );
+        let (entries, _) = ListingOptions::default().arrange(out);
 
-        assert_eq!(out, vec!["a.png".to_string(), "b.png".to_string()]);
+        assert_eq!(entries, vec!["a.png".to_string(), "b.png".to_string()]);
     }
 
     #[test]
@@ -270,6 +375,24 @@ mod tests {
         assert_eq!(image.data, DUMMY_PNG_DATA);
     }
 
+    #[test]
+    fn test_get_image_sizes_match_images() {
+        let dir = tempdir().expect("failed to create tempdir");
+        let rar_path = create_dummy_rar(dir.path(), "dummy.rar");
+        let container = RarContainer::new(rar_path.to_string_lossy().as_ref())
+            .expect("failed to create RarContainer");
+
+        let expected: Vec<(u32, u32)> = container
+            .get_entries()
+            .iter()
+            .map(|entry| {
+                let image = container.get_image(entry).unwrap();
+                (image.width, image.height)
+            })
+            .collect();
+        assert_eq!(container.get_image_sizes().unwrap(), expected);
+    }
+
     #[test]
     fn test_get_image_non_existing() {
         let dir = tempdir().expect("failed to create tempdir");
@@ -292,4 +415,29 @@ mod tests {
         assert!(thumbnail.height <= crate::image::thumbnail::THUMBNAIL_SIZE);
         assert!(!thumbnail.data.is_empty());
     }
+
+    #[test]
+    fn test_new_opens_a_later_volume_from_the_first_one() {
+        let dir = tempdir().expect("failed to create tempdir");
+        let part1 = create_dummy_rar(dir.path(), "book.part1.rar");
+        let part2 = create_dummy_rar(dir.path(), "book.part2.rar");
+
+        let container = RarContainer::new(part2.to_string_lossy().as_ref())
+            .expect("failed to create RarContainer");
+
+        assert_eq!(container.path, part1.to_string_lossy().to_string());
+        assert!(container.is_multi_volume);
+        assert_eq!(container.entries.len(), 3);
+    }
+
+    #[test]
+    fn test_new_reports_a_missing_volume() {
+        let dir = tempdir().expect("failed to create tempdir");
+        let part1 = create_dummy_rar(dir.path(), "book.part1.rar");
+        create_dummy_rar(dir.path(), "book.part3.rar");
+
+        let result = RarContainer::new(part1.to_string_lossy().as_ref());
+
+        assert!(matches!(result, Err(Error::MissingVolume(_))));
+    }
 }
@@ -0,0 +1,281 @@
+//! Multi-volume (split) RAR sets named in the `name.partN.rar` scheme.
+//!
+//! `unrar` follows a set across its volumes on its own once it is opened from the
+//! first one; this module finds that first volume and checks that none of the volumes
+//! up to the last one present is missing, so the gap is reported up front instead of as
+//! an opaque open error halfway through a page.
+
+use std::path::{Path, PathBuf};
+
+use crate::error::{Error, Result};
+
+/// A volume of a multi-volume RAR set, parsed from its file name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RarVolume {
+    /// The file name up to the `.partN` marker (e.g. `Book` for `Book.part2.rar`).
+    stem: String,
+    /// The `.part` marker as written, keeping its case.
+    marker: String,
+    /// The 1-based volume number.
+    number: u32,
+    /// The number of digits of the volume number (e.g. 2 for `part01`).
+    width: usize,
+    /// The extension as written, keeping its case.
+    extension: String,
+}
+
+impl RarVolume {
+    /// Parses a file name in the `name.partN.rar` scheme (case-insensitive).
+    ///
+    /// # Returns
+    ///
+    /// The parsed volume, or `None` if the name is not a volume of a multi-volume set.
+    pub fn parse(file_name: &str) -> Option<Self> {
+        let (rest, extension) = file_name.rsplit_once('.')?;
+        if !extension.eq_ignore_ascii_case("rar") {
+            return None;
+        }
+        let digits_start = rest
+            .char_indices()
+            .rev()
+            .take_while(|(_, c)| c.is_ascii_digit())
+            .last()
+            .map(|(i, _)| i)?;
+        let (head, digits) = rest.split_at(digits_start);
+        let marker_start = head.len().checked_sub(".part".len())?;
+        let marker = head.get(marker_start..)?;
+        if !marker.eq_ignore_ascii_case(".part") || marker_start == 0 {
+            return None;
+        }
+        Some(Self {
+            stem: head[..marker_start].to_string(),
+            marker: marker.to_string(),
+            number: digits.parse().ok()?,
+            width: digits.len(),
+            extension: extension.to_string(),
+        })
+    }
+
+    /// Returns the 1-based volume number.
+    pub fn number(&self) -> u32 {
+        self.number
+    }
+
+    /// Returns the file name of another volume of the same set, keeping this volume's
+    /// spelling and digit width.
+    pub fn file_name(&self, number: u32) -> String {
+        format!(
+            "{}{}{:0width$}.{}",
+            self.stem,
+            self.marker,
+            number,
+            self.extension,
+            width = self.width
+        )
+    }
+
+    /// Returns `true` if `other` belongs to the same set as this volume.
+    fn is_same_set(&self, other: &RarVolume) -> bool {
+        self.stem == other.stem
+            && self.marker.eq_ignore_ascii_case(&other.marker)
+            && self.extension.eq_ignore_ascii_case(&other.extension)
+    }
+}
+
+/// Returns the numbers of the volumes of `volume`'s set among `file_names`.
+fn set_numbers<'a>(volume: &RarVolume, file_names: impl IntoIterator<Item = &'a str>) -> Vec<u32> {
+    file_names
+        .into_iter()
+        .filter_map(RarVolume::parse)
+        .filter(|sibling| volume.is_same_set(sibling))
+        .map(|sibling| sibling.number())
+        .collect()
+}
+
+/// Returns the number of the volume a set is opened from, given the numbers present.
+///
+/// Sets are numbered from 1, but some tools start at `part0`.
+fn first_number(present: &[u32]) -> u32 {
+    if present.contains(&0) {
+        0
+    } else {
+        1
+    }
+}
+
+/// Returns `true` if the file name is a volume of a multi-volume set other than the
+/// first one. Such volumes are not books of their own and are hidden from listings.
+///
+/// Whether `part1` is the first volume depends on the set, so the decision is made
+/// against the names of the files next to it.
+///
+/// # Arguments
+///
+/// * `file_name` - The name of the file.
+/// * `siblings` - The names of the files in the same directory.
+pub fn is_continuation_volume<'a>(
+    file_name: &str,
+    siblings: impl IntoIterator<Item = &'a str>,
+) -> bool {
+    RarVolume::parse(file_name).is_some_and(|volume| {
+        volume.number() > 1
+            || (volume.number() == 1 && first_number(&set_numbers(&volume, siblings)) == 0)
+    })
+}
+
+/// Resolves the volume a multi-volume set has to be opened from.
+///
+/// A path that is not a volume of a multi-volume set is returned unchanged. For a
+/// volume, the sibling volumes are listed and the path of the first one is returned.
+///
+/// # Arguments
+///
+/// * `path` - The path of any volume of the set, or of a plain RAR file.
+///
+/// # Returns
+///
+/// The path to open, and the last volume number present (`None` for a plain RAR file).
+///
+/// # Errors
+///
+/// Returns [`Error::MissingVolume`] if a volume from the first up to the last one
+/// present is missing, or an `Err` if the directory cannot be read.
+pub fn resolve_first_volume(path: &str) -> Result<(String, Option<u32>)> {
+    let file_path = Path::new(path);
+    let Some(volume) = file_path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .and_then(RarVolume::parse)
+    else {
+        return Ok((path.to_string(), None));
+    };
+    let dir = match file_path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+
+    let file_names: Vec<String> = std::fs::read_dir(dir)?
+        .flatten()
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .collect();
+    let present = set_numbers(&volume, file_names.iter().map(String::as_str));
+
+    let first_number = first_number(&present);
+    let last_number = present.iter().copied().max().unwrap_or(first_number);
+    if let Some(missing) = (first_number..=last_number).find(|n| !present.contains(n)) {
+        return Err(Error::MissingVolume(format!(
+            "{} is missing from the set opened as {}",
+            volume.file_name(missing),
+            path
+        )));
+    }
+
+    let first_path: PathBuf = dir.join(volume.file_name(first_number));
+    Ok((first_path.to_string_lossy().to_string(), Some(last_number)))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+    use tempfile::tempdir;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_volume_names() {
+        let volume = RarVolume::parse("Book Vol.1.part02.rar").unwrap();
+        assert_eq!(volume.number(), 2);
+        assert_eq!(volume.file_name(1), "Book Vol.1.part01.rar");
+
+        let volume = RarVolume::parse("BOOK.PART1.RAR").unwrap();
+        assert_eq!(volume.number(), 1);
+        assert_eq!(volume.file_name(3), "BOOK.PART3.RAR");
+
+        assert_eq!(RarVolume::parse("book.rar"), None);
+        assert_eq!(RarVolume::parse("book.part.rar"), None);
+        assert_eq!(RarVolume::parse("book.part2.zip"), None);
+        assert_eq!(RarVolume::parse(".part2.rar"), None);
+    }
+
+    #[test]
+    fn test_is_continuation_volume() {
+        let none: [&str; 0] = [];
+        assert!(is_continuation_volume("book.part2.rar", none));
+        assert!(is_continuation_volume("book.part10.rar", none));
+        assert!(!is_continuation_volume("book.part1.rar", none));
+        assert!(!is_continuation_volume("book.part01.rar", none));
+        assert!(!is_continuation_volume("book.rar", none));
+        assert!(!is_continuation_volume("part2.cbz", none));
+
+        // A set numbered from `part0` is opened from it.
+        let siblings = ["book.part0.rar", "book.part1.rar", "other.part0.rar"];
+        assert!(!is_continuation_volume("book.part0.rar", siblings));
+        assert!(is_continuation_volume("book.part1.rar", siblings));
+        assert!(!is_continuation_volume("another.part1.rar", siblings));
+    }
+
+    #[test]
+    fn test_resolve_first_volume_of_plain_rar() {
+        let (path, last) = resolve_first_volume("/some/dir/book.rar").unwrap();
+        assert_eq!(path, "/some/dir/book.rar");
+        assert_eq!(last, None);
+    }
+
+    #[test]
+    fn test_resolve_first_volume_from_a_later_volume() {
+        let dir = tempdir().expect("failed to create tempdir");
+        for name in [
+            "book.part1.rar",
+            "book.part2.rar",
+            "book.part3.rar",
+            "other.part1.rar",
+        ] {
+            File::create(dir.path().join(name)).unwrap();
+        }
+
+        let part2 = dir.path().join("book.part2.rar");
+        let (path, last) = resolve_first_volume(part2.to_str().unwrap()).unwrap();
+
+        assert_eq!(path, dir.path().join("book.part1.rar").to_string_lossy());
+        assert_eq!(last, Some(3));
+    }
+
+    #[test]
+    fn test_resolve_first_volume_of_a_set_numbered_from_zero() {
+        let dir = tempdir().expect("failed to create tempdir");
+        for name in ["book.part0.rar", "book.part1.rar"] {
+            File::create(dir.path().join(name)).unwrap();
+        }
+
+        let part1 = dir.path().join("book.part1.rar");
+        let (path, last) = resolve_first_volume(part1.to_str().unwrap()).unwrap();
+
+        assert_eq!(path, dir.path().join("book.part0.rar").to_string_lossy());
+        assert_eq!(last, Some(1));
+    }
+
+    #[test]
+    fn test_resolve_first_volume_reports_a_missing_volume() {
+        let dir = tempdir().expect("failed to create tempdir");
+        for name in ["book.part01.rar", "book.part03.rar"] {
+            File::create(dir.path().join(name)).unwrap();
+        }
+
+        let part1 = dir.path().join("book.part01.rar");
+        let err = resolve_first_volume(part1.to_str().unwrap()).unwrap_err();
+
+        assert!(matches!(err, Error::MissingVolume(_)));
+        assert!(err.to_string().contains("book.part02.rar"));
+    }
+
+    #[test]
+    fn test_resolve_first_volume_reports_a_missing_first_volume() {
+        let dir = tempdir().expect("failed to create tempdir");
+        File::create(dir.path().join("book.part2.rar")).unwrap();
+
+        let part2 = dir.path().join("book.part2.rar");
+        let err = resolve_first_volume(part2.to_str().unwrap()).unwrap_err();
+
+        assert!(err.to_string().contains("book.part1.rar"));
+    }
+}
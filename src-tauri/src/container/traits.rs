@@ -1,6 +1,11 @@
 use std::sync::Arc;
 
-use crate::{error::Result, image::types::Image};
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+
+use crate::{
+    error::{Error, Result},
+    image::types::Image,
+};
 
 #[cfg(test)]
 use mockall::{automock, predicate::*};
@@ -54,6 +59,48 @@ pub trait Container: Send + Sync + 'static {
     /// generated or decoded.
     fn get_thumbnail(&self, entry: &str) -> Result<Arc<Image>>;
 
+    /// Retrieves the most detailed image available for a given entry, used for deep zoom.
+    ///
+    /// Containers that read pages at a reduced resolution (e.g. PDF pages rendered at the
+    /// configured height) override this to produce a higher-resolution rendition. By
+    /// default it is the same image as `get_image`.
+    ///
+    /// # Arguments
+    ///
+    /// * `entry` - The name of the entry corresponding to the image to be retrieved.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a shared pointer (`Arc`) to the `Image` data on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the entry cannot be found, read, or decoded into an image.
+    fn get_full_resolution_image(&self, entry: &str) -> Result<Arc<Image>> {
+        self.get_image(entry)
+    }
+
+    /// Returns the width and height in pixels of the image `get_image` returns for each
+    /// entry, in entry order.
+    ///
+    /// By default every image is loaded (in parallel) and only its header is read.
+    /// Containers that can tell the sizes without producing the images override this:
+    /// PDF from its page boxes, ZIP archives and directories from the first bytes of
+    /// each file, and RAR archives in a single pass over the archive.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the size of an entry cannot be read.
+    fn get_image_sizes(&self) -> Result<Vec<(u32, u32)>> {
+        self.get_entries()
+            .par_iter()
+            .map(|entry| {
+                let image = self.get_image(entry)?;
+                Ok((image.width, image.height))
+            })
+            .collect()
+    }
+
     /// Checks whether the container corresponds to a directory on the filesystem.
     ///
     /// # Returns
@@ -74,6 +121,18 @@ pub trait Container: Send + Sync + 'static {
         false
     }
 
+    /// Returns the entries hidden by exclusion rules while the container collected its
+    /// entries, so the user can see what was left out.
+    fn excluded_entries(&self) -> &[String] {
+        &[]
+    }
+
+    /// Returns why this container could only be opened partially (e.g. a truncated
+    /// archive whose pages were salvaged), or `None` if it opened cleanly.
+    fn damage(&self) -> Option<Error> {
+        None
+    }
+
     /// Returns whether this container renders pages at its own controlled resolution,
     /// making the generic max-image-height resize unnecessary (e.g. PDF).
     fn controls_own_resolution(&self) -> bool {
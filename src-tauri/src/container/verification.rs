@@ -0,0 +1,411 @@
+//! Integrity verification of books: reads every page of a container to the end and
+//! fully decodes it, collecting the pages that could not be read or decoded.
+
+use std::{
+    io,
+    sync::atomic::{AtomicBool, Ordering},
+};
+
+use image::ImageError;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    container::{
+        factory::{create_container, ContainerConfig},
+        traits::Container,
+    },
+    error::{Error, ErrorCode},
+};
+
+/// Why a page failed verification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "camelCase")]
+pub enum PageProblemKind {
+    /// The entry could not be read from the container (e.g. a CRC mismatch in a ZIP or
+    /// RAR archive, or a PDF page that failed to render).
+    Unreadable,
+    /// The entry or its image data ends prematurely.
+    Truncated,
+    /// The entry was read, but its image data could not be decoded.
+    Undecodable,
+}
+
+/// A page that failed verification.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "camelCase")]
+pub struct PageProblem {
+    /// The entry name of the page.
+    pub entry: String,
+    /// Why the page failed.
+    pub kind: PageProblemKind,
+    /// The underlying error message.
+    pub message: String,
+}
+
+/// The result of verifying one book.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "camelCase")]
+pub struct VerificationReport {
+    /// The path of the verified book.
+    pub path: String,
+    /// The number of pages in the book.
+    pub total_pages: u32,
+    /// The number of pages checked before the verification finished or was cancelled.
+    pub pages_checked: u32,
+    /// The pages that failed verification, in entry order.
+    pub problems: Vec<PageProblem>,
+    /// The error code of damage found while opening the book (e.g. a truncated ZIP
+    /// whose pages were salvaged), or `None` if it opened cleanly.
+    pub damage_code: Option<i32>,
+    /// The error that kept the book from opening at all, or `None` if it opened.
+    pub open_error: Option<String>,
+    /// Whether the verification was cancelled before every page was checked.
+    pub cancelled: bool,
+}
+
+impl VerificationReport {
+    /// Returns `true` if the book opened cleanly and every page passed.
+    pub fn is_healthy(&self) -> bool {
+        self.problems.is_empty() && self.damage_code.is_none() && self.open_error.is_none()
+    }
+}
+
+/// Opens the book at `path` and verifies every page of it.
+///
+/// A book that cannot be opened is reported through `open_error` rather than returned
+/// as an error, so a library-wide verification can carry on with the next book.
+///
+/// # Arguments
+///
+/// * `path` - The path of the book to verify.
+/// * `config` - The configuration used to open the container.
+/// * `cancelled` - Checked before each page; once set, the remaining pages are skipped.
+/// * `on_progress` - Called with `(pages_checked, total_pages)` after each page.
+pub fn verify_book(
+    path: &str,
+    config: ContainerConfig,
+    cancelled: &AtomicBool,
+    on_progress: impl FnMut(u32, u32),
+) -> VerificationReport {
+    match create_container(path, config) {
+        Ok(container) => verify_container(path, container.as_ref(), cancelled, on_progress),
+        Err(e) => VerificationReport {
+            path: path.to_string(),
+            total_pages: 0,
+            pages_checked: 0,
+            problems: Vec::new(),
+            damage_code: None,
+            open_error: Some(e.to_string()),
+            cancelled: false,
+        },
+    }
+}
+
+/// Verifies every page of an open container.
+///
+/// Each page is read in full through [`Container::get_image`], which makes the ZIP and
+/// RAR readers check the entry's CRC, and is then fully decoded; probing only the
+/// header (as opening a page does) would miss truncated image data. EPUB novels have
+/// no pages and always pass.
+///
+/// # Arguments
+///
+/// * `path` - The path of the book, recorded in the report.
+/// * `container` - The container to verify.
+/// * `cancelled` - Checked before each page; once set, the remaining pages are skipped.
+/// * `on_progress` - Called with `(pages_checked, total_pages)` after each page.
+pub fn verify_container(
+    path: &str,
+    container: &dyn Container,
+    cancelled: &AtomicBool,
+    mut on_progress: impl FnMut(u32, u32),
+) -> VerificationReport {
+    let entries: &[String] = if container.is_novel() {
+        &[]
+    } else {
+        container.get_entries()
+    };
+    let total_pages = entries.len() as u32;
+
+    let mut report = VerificationReport {
+        path: path.to_string(),
+        total_pages,
+        pages_checked: 0,
+        problems: Vec::new(),
+        damage_code: container
+            .damage()
+            .map(|damage| ErrorCode::from(&damage).code()),
+        open_error: None,
+        cancelled: false,
+    };
+
+    for entry in entries {
+        if cancelled.load(Ordering::Relaxed) {
+            report.cancelled = true;
+            break;
+        }
+        if let Err(problem) = verify_page(container, entry) {
+            log::warn!(
+                "Verification of {path} failed at {entry}: {}",
+                problem.message
+            );
+            report.problems.push(problem);
+        }
+        report.pages_checked += 1;
+        on_progress(report.pages_checked, total_pages);
+    }
+
+    report
+}
+
+/// Reads and fully decodes one page.
+fn verify_page(container: &dyn Container, entry: &str) -> Result<(), PageProblem> {
+    let problem = |kind, message: String| PageProblem {
+        entry: entry.to_string(),
+        kind,
+        message,
+    };
+
+    let image = container.get_image(entry).map_err(|e| {
+        // The container already probes the image header while reading; an image error
+        // here means the data was read but is not a decodable image.
+        let kind = match &e {
+            Error::Image(image_error) => decode_problem_kind(image_error),
+            _ if is_truncation(&e) => PageProblemKind::Truncated,
+            _ => PageProblemKind::Unreadable,
+        };
+        problem(kind, e.to_string())
+    })?;
+
+    image::load_from_memory(&image.data)
+        .map(drop)
+        .map_err(|e| problem(decode_problem_kind(&e), e.to_string()))
+}
+
+/// Classifies an image decoding error.
+fn decode_problem_kind(error: &ImageError) -> PageProblemKind {
+    let truncated = match error {
+        ImageError::IoError(e) => e.kind() == io::ErrorKind::UnexpectedEof,
+        _ => mentions_truncation(&error.to_string()),
+    };
+    if truncated {
+        PageProblemKind::Truncated
+    } else {
+        PageProblemKind::Undecodable
+    }
+}
+
+/// Returns `true` if a read error reports data that ends prematurely.
+fn is_truncation(error: &Error) -> bool {
+    match error {
+        Error::Io(e) => e.kind() == io::ErrorKind::UnexpectedEof,
+        Error::Zip(zip::result::ZipError::Io(e)) => e.kind() == io::ErrorKind::UnexpectedEof,
+        _ => mentions_truncation(&error.to_string()),
+    }
+}
+
+/// Returns `true` if an error message reports data that ends prematurely.
+///
+/// The decoders behind `image` and `unrar` report truncation as a formatted message
+/// rather than a distinct error kind.
+fn mentions_truncation(message: &str) -> bool {
+    let message = message.to_lowercase();
+    ["unexpected end", "unexpected eof", "truncated"]
+        .iter()
+        .any(|needle| message.contains(needle))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs::File, io::Write, path};
+
+    use image::{ImageFormat, RgbImage};
+    use tempfile::tempdir;
+    use zip::write::{FileOptions, ZipWriter};
+
+    use super::*;
+
+    // Helper function to encode a noisy PNG, so its compressed data is not trivially short.
+    fn create_png(seed: u32) -> Vec<u8> {
+        let mut state = seed | 1;
+        let image = RgbImage::from_fn(32, 32, |_, _| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            let [r, g, b, _] = state.to_le_bytes();
+            image::Rgb([r, g, b])
+        });
+        let mut data = Vec::new();
+        image
+            .write_to(&mut io::Cursor::new(&mut data), ImageFormat::Png)
+            .expect("failed to encode png");
+        data
+    }
+
+    // Helper function to create a ZIP file with stored (uncompressed) entries.
+    fn create_stored_zip(
+        dir: &path::Path,
+        filename: &str,
+        entries: &[(&str, &[u8])],
+    ) -> path::PathBuf {
+        let zip_filepath = dir.join(filename);
+        let file = File::create(&zip_filepath).expect("failed to create zip file");
+        let mut zip = ZipWriter::new(file);
+        let options =
+            FileOptions::<()>::default().compression_method(zip::CompressionMethod::Stored);
+        for (entry_name, content) in entries {
+            zip.start_file(entry_name, options)
+                .expect("failed to start zip entry");
+            zip.write_all(content)
+                .expect("failed to write zip entry content");
+        }
+        zip.finish().expect("failed to finish zip file");
+        zip_filepath
+    }
+
+    #[test]
+    fn test_verify_book_healthy_zip() {
+        let dir = tempdir().expect("failed to create tempdir");
+        let page1 = create_png(1);
+        let page2 = create_png(2);
+        let zip_path = create_stored_zip(
+            dir.path(),
+            "healthy.zip",
+            &[("01.png", &page1), ("02.png", &page2)],
+        );
+
+        let mut progress = Vec::new();
+        let report = verify_book(
+            zip_path.to_str().unwrap(),
+            ContainerConfig::default(),
+            &AtomicBool::new(false),
+            |checked, total| progress.push((checked, total)),
+        );
+
+        assert!(report.is_healthy(), "{report:?}");
+        assert_eq!(report.total_pages, 2);
+        assert_eq!(report.pages_checked, 2);
+        assert!(!report.cancelled);
+        assert_eq!(progress, vec![(1, 2), (2, 2)]);
+    }
+
+    #[test]
+    fn test_verify_book_reports_crc_mismatch_as_unreadable() {
+        let dir = tempdir().expect("failed to create tempdir");
+        let good = create_png(1);
+        let bad = create_png(2);
+        let zip_path = create_stored_zip(
+            dir.path(),
+            "crc.zip",
+            &[("01.png", &good), ("02.png", &bad)],
+        );
+
+        // Flip a byte in the middle of the second (stored) entry's data, leaving the
+        // recorded CRC untouched.
+        let mut bytes = std::fs::read(&zip_path).unwrap();
+        let offset = bytes
+            .windows(bad.len())
+            .position(|window| window == bad.as_slice())
+            .expect("stored entry data not found");
+        bytes[offset + bad.len() / 2] ^= 0xFF;
+        std::fs::write(&zip_path, bytes).unwrap();
+
+        let report = verify_book(
+            zip_path.to_str().unwrap(),
+            ContainerConfig::default(),
+            &AtomicBool::new(false),
+            |_, _| {},
+        );
+
+        assert_eq!(report.pages_checked, 2);
+        assert_eq!(report.problems.len(), 1, "{report:?}");
+        assert_eq!(report.problems[0].entry, "02.png");
+        assert_eq!(report.problems[0].kind, PageProblemKind::Unreadable);
+    }
+
+    #[test]
+    fn test_verify_book_reports_truncated_image_data() {
+        let dir = tempdir().expect("failed to create tempdir");
+        let good = create_png(1);
+        let full = create_png(2);
+        // Keeps the header, so the page still opens, but cuts the image data short.
+        let truncated = &full[..full.len() / 2];
+        let zip_path = create_stored_zip(
+            dir.path(),
+            "truncated.zip",
+            &[("01.png", &good), ("02.png", truncated)],
+        );
+
+        let report = verify_book(
+            zip_path.to_str().unwrap(),
+            ContainerConfig::default(),
+            &AtomicBool::new(false),
+            |_, _| {},
+        );
+
+        assert_eq!(report.problems.len(), 1, "{report:?}");
+        assert_eq!(report.problems[0].entry, "02.png");
+        assert_eq!(report.problems[0].kind, PageProblemKind::Truncated);
+    }
+
+    #[test]
+    fn test_verify_book_reports_garbage_as_undecodable() {
+        let dir = tempdir().expect("failed to create tempdir");
+        let zip_path = create_stored_zip(
+            dir.path(),
+            "garbage.zip",
+            &[("01.png", b"this is not an image at all")],
+        );
+
+        let report = verify_book(
+            zip_path.to_str().unwrap(),
+            ContainerConfig::default(),
+            &AtomicBool::new(false),
+            |_, _| {},
+        );
+
+        assert_eq!(report.problems.len(), 1, "{report:?}");
+        assert_eq!(report.problems[0].kind, PageProblemKind::Undecodable);
+    }
+
+    #[test]
+    fn test_verify_book_stops_when_cancelled() {
+        let dir = tempdir().expect("failed to create tempdir");
+        let page = create_png(1);
+        let zip_path = create_stored_zip(
+            dir.path(),
+            "cancel.zip",
+            &[("01.png", &page), ("02.png", &page), ("03.png", &page)],
+        );
+
+        let cancelled = AtomicBool::new(false);
+        let report = verify_book(
+            zip_path.to_str().unwrap(),
+            ContainerConfig::default(),
+            &cancelled,
+            |checked, _| {
+                if checked == 1 {
+                    cancelled.store(true, Ordering::Relaxed);
+                }
+            },
+        );
+
+        assert!(report.cancelled);
+        assert_eq!(report.pages_checked, 1);
+        assert_eq!(report.total_pages, 3);
+    }
+
+    #[test]
+    fn test_verify_book_reports_open_error() {
+        let report = verify_book(
+            "/nonexistent/book.zip",
+            ContainerConfig::default(),
+            &AtomicBool::new(false),
+            |_, _| {},
+        );
+
+        assert!(report.open_error.is_some());
+        assert!(!report.is_healthy());
+        assert_eq!(report.total_pages, 0);
+    }
+}
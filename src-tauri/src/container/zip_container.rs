@@ -1,16 +1,22 @@
 use std::{
     collections::HashMap,
     fs::File,
-    io::{Read, Seek},
+    io::{self, Read, Seek, SeekFrom},
     sync::Arc,
-    sync::Mutex,
 };
 
-use zip::ZipArchive;
+use flate2::read::DeflateDecoder;
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use zip::{result::ZipError, ZipArchive};
 
 use crate::{
-    container::traits::Container,
-    error::Result,
+    container::{
+        entry_order::ListedEntry,
+        listing::ListingOptions,
+        traits::Container,
+        zip_salvage::{self, LocalEntry, Method},
+    },
+    error::{Error, Result},
     image::{thumbnail::generate_thumbnail, types::Image},
 };
 
@@ -128,6 +134,103 @@ fn read_entry_checked<R: Read + Seek>(
     read_within_declared(&mut file, declared, capacity, entry)
 }
 
+/// The handle of the file clones of a [`SharedFile`] read from.
+#[cfg(any(unix, windows))]
+type SharedHandle = File;
+
+/// The handle of the file clones of a [`SharedFile`] read from. Without positional I/O,
+/// each read seeks the one cursor under a lock.
+#[cfg(not(any(unix, windows)))]
+type SharedHandle = std::sync::Mutex<File>;
+
+/// A cloneable, seekable reader over a file that is opened once.
+///
+/// Every clone keeps its own position and reads with positional I/O (`pread` on Unix,
+/// `ReadFile` at an offset on Windows), so clones never share a cursor and can read
+/// concurrently without locking. Other platforms fall back to seeking a shared handle
+/// under a lock. This is what makes `ZipArchive<SharedFile>` cloneable: a cloned archive
+/// shares the parsed central directory and differs only in its position.
+#[derive(Clone)]
+struct SharedFile {
+    /// The underlying file, shared by all clones.
+    file: Arc<SharedHandle>,
+    /// The length of the file, used to resolve `SeekFrom::End`.
+    len: u64,
+    /// The position of this clone.
+    pos: u64,
+}
+
+impl SharedFile {
+    /// Opens the file at `path` for shared positional reads.
+    fn open(path: &str) -> io::Result<Self> {
+        let file = File::open(path)?;
+        let len = file.metadata()?.len();
+        Ok(Self {
+            file: Arc::new(SharedHandle::from(file)),
+            len,
+            pos: 0,
+        })
+    }
+}
+
+impl Read for SharedFile {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        #[cfg(unix)]
+        let read = std::os::unix::fs::FileExt::read_at(self.file.as_ref(), buf, self.pos)?;
+        #[cfg(windows)]
+        let read = std::os::windows::fs::FileExt::seek_read(self.file.as_ref(), buf, self.pos)?;
+        #[cfg(not(any(unix, windows)))]
+        let read = {
+            // A poisoned lock only means another read panicked; each read seeks anew.
+            let mut file = self.file.lock().unwrap_or_else(|e| e.into_inner());
+            file.seek(SeekFrom::Start(self.pos))?;
+            file.read(buf)?
+        };
+        self.pos += read as u64;
+        Ok(read)
+    }
+}
+
+impl Seek for SharedFile {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let target = match pos {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::End(offset) => self.len.checked_add_signed(offset),
+            SeekFrom::Current(offset) => self.pos.checked_add_signed(offset),
+        };
+        self.pos = target.ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )
+        })?;
+        Ok(self.pos)
+    }
+}
+
+/// Reads the bytes of an entry rebuilt by [`zip_salvage::scan`].
+///
+/// # Arguments
+///
+/// * `file` - The archive file.
+/// * `local` - The salvaged entry.
+/// * `entry` - The entry name, for error messages.
+///
+/// # Errors
+///
+/// Returns an error if the entry exceeds its declared size or cannot be read.
+fn read_salvaged_entry(mut file: SharedFile, local: &LocalEntry, entry: &str) -> Result<Vec<u8>> {
+    file.seek(SeekFrom::Start(local.data_offset))?;
+    let data = file.take(local.compressed_size);
+    let capacity = prealloc_capacity(local.size, local.compressed_size);
+    match local.method {
+        Method::Stored => read_within_declared(data, local.size, capacity, entry),
+        Method::Deflated => {
+            read_within_declared(DeflateDecoder::new(data), local.size, capacity, entry)
+        }
+    }
+}
+
 /// Decodes a raw ZIP entry name as UTF-8, falling back to Shift-JIS for archives
 /// produced by legacy Japanese tooling.
 ///
@@ -148,7 +251,7 @@ fn decode_entry_name(raw_name: &[u8]) -> String {
     }
 }
 
-/// Builds the naturally-sorted image entry list and the name→archive-index map.
+/// Builds the image entry list, in archive order, and the name→archive-index map.
 ///
 /// Each raw name is decoded ([`decode_entry_name`]) and filtered to supported image
 /// formats. The first occurrence of a decoded name wins; later duplicates — legal in
@@ -159,40 +262,76 @@ fn decode_entry_name(raw_name: &[u8]) -> String {
 ///
 /// # Arguments
 ///
-/// * `raw_names` - An iterator of `(archive_index, raw_name_bytes)` pairs.
+/// * `raw_names` - An iterator of `(archive_index, raw_name_bytes, modified)` tuples,
+///   where `modified` orders the entries by modification time.
 ///
 /// # Returns
 ///
-/// The sorted entry names and a map from each name to its archive index.
+/// The entries and a map from each name to its archive index.
 fn collect_entries(
-    raw_names: impl Iterator<Item = (usize, Vec<u8>)>,
-) -> (Vec<String>, HashMap<String, usize>) {
-    let mut entries: Vec<String> = Vec::new();
+    raw_names: impl Iterator<Item = (usize, Vec<u8>, Option<i64>)>,
+) -> (Vec<ListedEntry>, HashMap<String, usize>) {
+    let mut entries: Vec<ListedEntry> = Vec::new();
     let mut name_to_index: HashMap<String, usize> = HashMap::new();
 
-    for (i, raw_name) in raw_names {
+    for (i, raw_name, modified) in raw_names {
         let name = decode_entry_name(&raw_name);
         if Image::is_supported_format(&name) {
             if name_to_index.contains_key(&name) {
                 continue;
             }
-            entries.push(name.clone());
+            entries.push(ListedEntry {
+                name: name.clone(),
+                modified,
+            });
             name_to_index.insert(name, i);
         }
     }
 
-    entries.sort_by(|a, b| natord::compare_ignore_case(a, b));
     (entries, name_to_index)
 }
 
+/// Turns the modification time of a ZIP entry into a key that orders chronologically.
+fn modified_key(time: zip::DateTime) -> i64 {
+    [
+        i64::from(time.month()),
+        i64::from(time.day()),
+        i64::from(time.hour()),
+        i64::from(time.minute()),
+        i64::from(time.second()),
+    ]
+    .into_iter()
+    .fold(i64::from(time.year()), |key, field| key * 100 + field)
+}
+
+/// Where the entries of a `ZipContainer` are read from.
+enum Source {
+    /// A well-formed archive. Each read works on its own clone, which shares the parsed
+    /// central directory and the open file but has its own position, so entries are
+    /// inflated concurrently instead of one at a time.
+    Archive(ZipArchive<SharedFile>),
+    /// A damaged archive whose entries were rebuilt from their local file headers.
+    Salvaged {
+        /// The archive file.
+        file: SharedFile,
+        /// The recovered entries.
+        entries: Vec<LocalEntry>,
+    },
+}
+
 /// An implementation of the `Container` trait for reading content from ZIP archive files.
 pub struct ZipContainer {
-    /// A naturally sorted list of image file names found within the archive.
+    /// The image file names found within the archive, in page order.
     entries: Vec<String>,
-    /// A mapping from (possibly garbled) entry names to their indices in the ZIP archive.
+    /// The image file names hidden by exclusion rules.
+    excluded: Vec<String>,
+    /// A mapping from (possibly garbled) entry names to their indices in the ZIP archive
+    /// (or in the salvaged entries).
     name_to_index: HashMap<String, usize>,
-    /// The ZIP archive, protected by a Mutex for thread-safe access to the underlying file.
-    archive: Mutex<ZipArchive<File>>,
+    /// Where the entries are read from.
+    source: Source,
+    /// Why the archive could only be opened partially, or `None` if it opened cleanly.
+    damage: Option<String>,
 }
 
 impl Container for ZipContainer {
@@ -201,44 +340,43 @@ impl Container for ZipContainer {
     }
 
     fn get_image(&self, entry: &str) -> Result<Arc<Image>> {
-        let buffer = {
-            let mut archive = self.archive.lock().map_err(|e| {
-                crate::error::Error::Other(format!("Failed to lock zip archive: {}", e))
-            })?;
-            let index = *self.name_to_index.get(entry).ok_or_else(|| {
-                crate::error::Error::Other(format!("Entry not found in ZIP: {}", entry))
-            })?;
-            read_entry_checked(&mut archive, index, entry)?
-        };
-
+        let buffer = self.read_entry(entry)?;
         let image = Image::new(buffer)?;
         Ok(Arc::new(image))
     }
 
     fn get_thumbnail(&self, entry: &str) -> Result<Arc<Image>> {
-        let buffer = {
-            let mut archive = self.archive.lock().map_err(|e| {
-                crate::error::Error::Other(format!("Failed to lock zip archive: {}", e))
-            })?;
-            let index = *self.name_to_index.get(entry).ok_or_else(|| {
-                crate::error::Error::Other(format!("Entry not found in ZIP: {}", entry))
-            })?;
-            read_entry_checked(&mut archive, index, entry)?
-        };
-
+        let buffer = self.read_entry(entry)?;
         generate_thumbnail(&buffer)
     }
 
+    fn get_image_sizes(&self) -> Result<Vec<(u32, u32)>> {
+        self.entries
+            .par_iter()
+            .map(|entry| self.read_entry_dimensions(entry))
+            .collect()
+    }
+
     fn is_directory(&self) -> bool {
         false
     }
+
+    fn excluded_entries(&self) -> &[String] {
+        &self.excluded
+    }
+
+    fn damage(&self) -> Option<Error> {
+        self.damage.clone().map(Error::DamagedArchive)
+    }
 }
 
 impl ZipContainer {
     /// Creates a new `ZipContainer` from the ZIP file at the specified path.
     ///
     /// This constructor opens the ZIP archive, filters for supported image formats,
-    /// and sorts the resulting file list in natural order.
+    /// and sorts the resulting file list in natural order. If the central directory
+    /// cannot be read (e.g. the file is truncated), the images are salvaged from the
+    /// local file headers instead and the container reports itself as damaged.
     ///
     /// # Arguments
     ///
@@ -250,26 +388,176 @@ impl ZipContainer {
     ///
     /// # Errors
     ///
-    /// Returns an `Err` if the ZIP file cannot be opened or read.
+    /// Returns an `Err` if the ZIP file cannot be opened, or if its central directory
+    /// cannot be read and no image can be salvaged either.
+    #[cfg(test)]
     pub fn new(path: &str) -> Result<Self> {
-        let file = File::open(path)?;
-        let mut archive = ZipArchive::new(file)?;
+        Self::with_listing(path, &ListingOptions::default())
+    }
+
+    /// Creates a new `ZipContainer`, listing its images with the given options.
+    ///
+    /// The `ModifiedTime` sort mode orders the images by the modification times stored
+    /// in the archive.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the ZIP file.
+    /// * `options` - How the image file names are sorted and filtered.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`ZipContainer::new`].
+    pub fn with_listing(path: &str, options: &ListingOptions) -> Result<Self> {
+        let file = SharedFile::open(path)?;
+        let mut archive = match ZipArchive::new(file.clone()) {
+            Ok(archive) => archive,
+            Err(e) => return Self::salvage(path, file, e, options),
+        };
 
         let len = archive.len();
-        let mut raw_names: Vec<(usize, Vec<u8>)> = Vec::with_capacity(len);
+        let mut raw_names: Vec<(usize, Vec<u8>, Option<i64>)> = Vec::with_capacity(len);
         for i in 0..len {
             let file = archive.by_index(i)?;
-            raw_names.push((i, file.name_raw().to_vec()));
+            raw_names.push((
+                i,
+                file.name_raw().to_vec(),
+                file.last_modified().map(modified_key),
+            ));
         }
 
         let (entries, name_to_index) = collect_entries(raw_names.into_iter());
+        let (entries, excluded) = options.arrange(entries);
+
+        Ok(Self {
+            entries,
+            excluded,
+            name_to_index,
+            source: Source::Archive(archive),
+            damage: None,
+        })
+    }
+
+    /// Opens a ZIP file whose central directory could not be read, rebuilding the entry
+    /// index from the local file headers.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the ZIP file.
+    /// * `file` - The opened ZIP file.
+    /// * `error` - Why the central directory could not be read.
+    /// * `options` - How the image file names are sorted and filtered. The local file
+    ///   headers are not read for modification times.
+    ///
+    /// # Errors
+    ///
+    /// Returns `error` if no image can be recovered, so a file that is not a ZIP archive
+    /// at all still fails to open.
+    fn salvage(
+        path: &str,
+        file: SharedFile,
+        error: ZipError,
+        options: &ListingOptions,
+    ) -> Result<Self> {
+        log::warn!("Failed to read the central directory of {path} ({error}); salvaging it");
+        let local_entries = zip_salvage::scan(&mut file.clone(), file.len, MAX_PREALLOC_BYTES)?;
+        let (entries, name_to_index) = collect_entries(
+            local_entries
+                .iter()
+                .enumerate()
+                .map(|(i, local)| (i, local.raw_name.clone(), None)),
+        );
+        if entries.is_empty() {
+            return Err(error.into());
+        }
+        let (entries, excluded) = options.arrange(entries);
 
+        log::warn!("Recovered {} images from {path}", entries.len());
+        let damage = format!(
+            "The central directory of the archive is unreadable ({error}); {} images were \
+             recovered from the local file headers",
+            entries.len()
+        );
         Ok(Self {
             entries,
+            excluded,
             name_to_index,
-            archive: Mutex::new(archive),
+            source: Source::Salvaged {
+                file,
+                entries: local_entries,
+            },
+            damage: Some(damage),
         })
     }
+
+    /// Reads the bytes of an entry, through a private clone of the archive or from its
+    /// salvaged location.
+    ///
+    /// # Arguments
+    ///
+    /// * `entry` - The entry name.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the entry does not exist or cannot be read.
+    fn read_entry(&self, entry: &str) -> Result<Vec<u8>> {
+        let index = self.index_of(entry)?;
+        match &self.source {
+            Source::Archive(archive) => read_entry_checked(&mut archive.clone(), index, entry),
+            Source::Salvaged { file, entries } => {
+                read_salvaged_entry(file.clone(), &entries[index], entry)
+            }
+        }
+    }
+
+    /// Reads the width and height of an entry's image from the start of its data,
+    /// without inflating the rest of the entry.
+    ///
+    /// # Arguments
+    ///
+    /// * `entry` - The entry name.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the entry does not exist, cannot be read, or is not a
+    /// supported image.
+    fn read_entry_dimensions(&self, entry: &str) -> Result<(u32, u32)> {
+        let index = self.index_of(entry)?;
+        let dimensions = match &self.source {
+            Source::Archive(archive) => {
+                let mut archive = archive.clone();
+                let file = archive.by_index(index)?;
+                let declared = file.size().min(MAX_PREALLOC_BYTES);
+                Image::read_dimensions(file.take(declared))?
+            }
+            Source::Salvaged { file, entries } => {
+                let local = &entries[index];
+                let mut file = file.clone();
+                file.seek(SeekFrom::Start(local.data_offset))?;
+                let data = file.take(local.compressed_size);
+                let declared = local.size.min(MAX_PREALLOC_BYTES);
+                match local.method {
+                    Method::Stored => Image::read_dimensions(data.take(declared))?,
+                    Method::Deflated => {
+                        Image::read_dimensions(DeflateDecoder::new(data).take(declared))?
+                    }
+                }
+            }
+        };
+        Ok(dimensions)
+    }
+
+    /// Returns the archive index (or salvaged entry index) of an entry.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the entry does not exist.
+    fn index_of(&self, entry: &str) -> Result<usize> {
+        self.name_to_index
+            .get(entry)
+            .copied()
+            .ok_or_else(|| crate::error::Error::Other(format!("Entry not found in ZIP: {}", entry)))
+    }
 }
 
 #[cfg(test)]
@@ -279,6 +567,10 @@ mod tests {
     use zip::write::{FileOptions, ZipWriter};
 
     use super::*;
+    use crate::container::{
+        entry_filter::{default_exclusion_rules, EntryFilter},
+        entry_order::EntrySortMode,
+    };
 
     // Helper function to create a dummy ZIP file with specified image entries.
     fn create_dummy_zip(
@@ -345,15 +637,84 @@ mod tests {
         assert_eq!(container.entries[1], "image2.png");
     }
 
+    #[test]
+    fn test_with_listing_hides_excluded_images() {
+        let dir = tempdir().expect("failed to create tempdir");
+        let zip_path = create_dummy_zip(
+            dir.path(),
+            "filtered.zip",
+            &[
+                ("image1.png", DUMMY_PNG_DATA),
+                ("__MACOSX/._image1.png", DUMMY_PNG_DATA),
+                (".cover_small.png", DUMMY_PNG_DATA),
+                ("image2.png", DUMMY_PNG_DATA),
+            ],
+        );
+
+        let options = ListingOptions {
+            filter: EntryFilter::new(&default_exclusion_rules()),
+            ..Default::default()
+        };
+        let container = ZipContainer::with_listing(zip_path.to_str().unwrap(), &options)
+            .expect("failed to create ZipContainer");
+
+        assert_eq!(container.get_entries(), &vec!["image1.png", "image2.png"]);
+        assert_eq!(
+            container.excluded_entries(),
+            &[
+                ".cover_small.png".to_string(),
+                "__MACOSX/._image1.png".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_with_listing_sorts_by_mode() {
+        let dir = tempdir().expect("failed to create tempdir");
+        let zip_path = dir.path().join("sorted.zip");
+        let mut zip = ZipWriter::new(File::create(&zip_path).unwrap());
+        for (name, day) in [("b.png", 3), ("c.png", 1), ("a.png", 2)] {
+            let time = zip::DateTime::from_date_and_time(2024, 5, day, 12, 0, 0).unwrap();
+            let options = FileOptions::<()>::default().last_modified_time(time);
+            zip.start_file(name, options).unwrap();
+            zip.write_all(DUMMY_PNG_DATA).unwrap();
+        }
+        zip.finish().unwrap();
+
+        let entries = |sort_mode| {
+            let options = ListingOptions {
+                sort_mode,
+                ..Default::default()
+            };
+            ZipContainer::with_listing(zip_path.to_str().unwrap(), &options)
+                .expect("failed to create ZipContainer")
+                .entries
+        };
+
+        assert_eq!(entries(EntrySortMode::Natural), ["a.png", "b.png", "c.png"]);
+        assert_eq!(
+            entries(EntrySortMode::ArchiveOrder),
+            ["b.png", "c.png", "a.png"]
+        );
+        assert_eq!(
+            entries(EntrySortMode::ModifiedTime),
+            ["c.png", "a.png", "b.png"]
+        );
+    }
+
     #[test]
     fn test_collect_entries_deduplicates_identical_names() {
         // Two archive members with identical raw names (legal in the ZIP format, even
         // though our writer forbids it) must collapse to a single entry; the first wins.
         let (entries, name_to_index) = collect_entries(
-            vec![(0usize, b"a.png".to_vec()), (1usize, b"a.png".to_vec())].into_iter(),
+            vec![
+                (0usize, b"a.png".to_vec(), None),
+                (1usize, b"a.png".to_vec(), None),
+            ]
+            .into_iter(),
         );
 
-        assert_eq!(entries, vec!["a.png".to_string()]);
+        assert_eq!(entries, vec![ListedEntry::new("a.png".to_string())]);
         assert_eq!(name_to_index.get("a.png"), Some(&0));
     }
 
@@ -367,9 +728,9 @@ mod tests {
         assert_ne!(utf8_name, sjis_name, "raw bytes must genuinely differ");
 
         let (entries, name_to_index) =
-            collect_entries(vec![(0usize, utf8_name), (1usize, sjis_name)].into_iter());
+            collect_entries(vec![(0usize, utf8_name, None), (1usize, sjis_name, None)].into_iter());
 
-        assert_eq!(entries, vec!["ファイル.png".to_string()]);
+        assert_eq!(entries, vec![ListedEntry::new("ファイル.png".to_string())]);
         // First occurrence (archive index 0) wins.
         assert_eq!(name_to_index.get("ファイル.png"), Some(&0));
     }
@@ -437,6 +798,22 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_get_image_sizes_match_images() {
+        let dir = tempdir().unwrap();
+        let zip_path = create_dummy_zip(
+            dir.path(),
+            "test.zip",
+            &[
+                ("image1.png", DUMMY_PNG_DATA),
+                ("image2.png", DUMMY_PNG_DATA),
+            ],
+        );
+        let container = ZipContainer::new(zip_path.to_string_lossy().to_string().as_str()).unwrap();
+
+        assert_eq!(container.get_image_sizes().unwrap(), vec![(1, 1), (1, 1)]);
+    }
+
     #[test]
     fn test_get_image_capacity_cap_does_not_truncate() {
         // The preallocation is bounded; reading a normal entry must still return its
@@ -526,4 +903,124 @@ mod tests {
         assert!(thumbnail.height <= crate::image::thumbnail::THUMBNAIL_SIZE);
         assert!(!thumbnail.data.is_empty());
     }
+
+    #[test]
+    fn test_new_salvages_truncated_zip() {
+        let dir = tempdir().unwrap();
+        let zip_path = create_dummy_zip(
+            dir.path(),
+            "truncated.zip",
+            &[
+                ("image1.png", DUMMY_PNG_DATA),
+                ("image2.png", DUMMY_PNG_DATA),
+                ("notes.txt", b"hello"),
+            ],
+        );
+        // Drop the end of central directory record, as in a partial download.
+        let bytes = std::fs::read(&zip_path).unwrap();
+        std::fs::write(&zip_path, &bytes[..bytes.len() - 40]).unwrap();
+
+        let container = ZipContainer::new(zip_path.to_str().unwrap())
+            .expect("a truncated ZIP should be salvaged");
+
+        assert_eq!(container.get_entries(), &vec!["image1.png", "image2.png"]);
+        let image = container.get_image("image2.png").unwrap();
+        assert_eq!(image.data, DUMMY_PNG_DATA);
+        assert_eq!(container.get_image_sizes().unwrap(), vec![(1, 1), (1, 1)]);
+        let damage = container
+            .damage()
+            .expect("a salvaged ZIP should be damaged");
+        assert!(matches!(damage, Error::DamagedArchive(_)));
+        assert!(damage.to_string().contains("2 images were recovered"));
+    }
+
+    #[test]
+    fn test_new_intact_zip_is_not_damaged() {
+        let dir = tempdir().unwrap();
+        let zip_path = create_dummy_zip(dir.path(), "test.zip", &[("image1.png", DUMMY_PNG_DATA)]);
+
+        let container = ZipContainer::new(zip_path.to_str().unwrap()).unwrap();
+
+        assert!(container.damage().is_none());
+    }
+
+    #[test]
+    fn test_new_without_recoverable_images_fails() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("not_a_zip.zip");
+        std::fs::write(&path, b"this is not a zip archive").unwrap();
+
+        let result = ZipContainer::new(path.to_str().unwrap());
+
+        assert!(matches!(result, Err(Error::Zip(_))));
+    }
+
+    #[test]
+    fn test_shared_file_clones_keep_their_own_position() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("data.bin");
+        std::fs::write(&path, b"0123456789").unwrap();
+
+        let mut first = SharedFile::open(path.to_str().unwrap()).unwrap();
+        first.seek(SeekFrom::Start(2)).unwrap();
+        let mut second = first.clone();
+        second.seek(SeekFrom::End(-3)).unwrap();
+
+        let mut buf = [0u8; 3];
+        first.read_exact(&mut buf).unwrap();
+        assert_eq!(&buf, b"234");
+        second.read_exact(&mut buf).unwrap();
+        assert_eq!(&buf, b"789");
+        assert_eq!(first.stream_position().unwrap(), 5);
+        assert!(first.seek(SeekFrom::Current(-6)).is_err());
+    }
+
+    #[test]
+    fn test_parallel_reads_return_identical_bytes() {
+        // Distinct, poorly-compressible payloads so a read served from the wrong offset
+        // (e.g. two readers sharing a cursor) could not go unnoticed.
+        let payloads: Vec<(String, Vec<u8>)> = (0..32u32)
+            .map(|i| {
+                let mut state = i.wrapping_mul(2_654_435_761).wrapping_add(1);
+                let data = (0..64 * 1024)
+                    .map(|_| {
+                        state ^= state << 13;
+                        state ^= state >> 17;
+                        state ^= state << 5;
+                        state as u8
+                    })
+                    .collect();
+                (format!("page{i:02}.png"), data)
+            })
+            .collect();
+        let entries: Vec<(&str, &[u8])> = payloads
+            .iter()
+            .map(|(name, data)| (name.as_str(), data.as_slice()))
+            .collect();
+        let dir = tempdir().unwrap();
+        let zip_path = create_dummy_zip(dir.path(), "parallel.zip", &entries);
+        let container = ZipContainer::new(zip_path.to_str().unwrap()).unwrap();
+
+        let sequential: Vec<Vec<u8>> = payloads
+            .iter()
+            .map(|(name, _)| container.read_entry(name).unwrap())
+            .collect();
+        let parallel: Vec<Vec<u8>> = std::thread::scope(|scope| {
+            let handles: Vec<_> = payloads
+                .iter()
+                .map(|(name, _)| scope.spawn(|| container.read_entry(name).unwrap()))
+                .collect();
+            handles
+                .into_iter()
+                .map(|handle| handle.join().unwrap())
+                .collect()
+        });
+
+        for (((name, expected), sequential), parallel) in
+            payloads.iter().zip(&sequential).zip(&parallel)
+        {
+            assert_eq!(sequential, expected, "sequential read of {name}");
+            assert_eq!(parallel, expected, "parallel read of {name}");
+        }
+    }
 }
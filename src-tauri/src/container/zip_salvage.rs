@@ -0,0 +1,430 @@
+use std::io::{self, Read, Seek, SeekFrom};
+
+use flate2::{read::DeflateDecoder, Crc};
+
+/// The signature that starts every local file header (`PK\x03\x04`).
+const LOCAL_FILE_HEADER_SIGNATURE: [u8; 4] = [0x50, 0x4B, 0x03, 0x04];
+
+/// The optional signature that starts a data descriptor (`PK\x07\x08`).
+const DATA_DESCRIPTOR_SIGNATURE: [u8; 4] = [0x50, 0x4B, 0x07, 0x08];
+
+/// The length of the fixed part of a local file header.
+const LOCAL_FILE_HEADER_LEN: usize = 30;
+
+/// General purpose flag: the entry is encrypted.
+const FLAG_ENCRYPTED: u16 = 1;
+
+/// General purpose flag: the CRC and sizes follow the data in a data descriptor.
+const FLAG_DATA_DESCRIPTOR: u16 = 1 << 3;
+
+/// A size field that defers to the ZIP64 extra field.
+const ZIP64_SIZE: u32 = u32::MAX;
+
+/// The chunk size used to search for signatures and to inflate entries.
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// How an entry's data is stored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Method {
+    /// Stored without compression.
+    Stored,
+    /// Compressed with DEFLATE.
+    Deflated,
+}
+
+/// An entry rebuilt from its local file header, whose data was verified to be complete.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocalEntry {
+    /// The raw bytes of the entry name.
+    pub raw_name: Vec<u8>,
+    /// The offset of the entry data from the start of the file.
+    pub data_offset: u64,
+    /// The length of the (possibly compressed) entry data.
+    pub compressed_size: u64,
+    /// The length of the decompressed entry data.
+    pub size: u64,
+    /// How the entry data is stored.
+    pub method: Method,
+}
+
+/// The fields of a local file header that salvaging relies on.
+struct LocalHeader {
+    flags: u16,
+    method: u16,
+    crc32: u32,
+    compressed_size: u32,
+    size: u32,
+    raw_name: Vec<u8>,
+    data_offset: u64,
+}
+
+/// Rebuilds the entry index of a ZIP archive whose central directory is missing or
+/// corrupt (e.g. a partially downloaded file), by scanning for local file headers.
+///
+/// Every candidate entry is read through and its CRC checked, so only entries whose
+/// data is complete are returned; a truncated last entry, encrypted entries, and
+/// compression methods other than stored and DEFLATE are skipped. When a header turns
+/// out to be bogus (e.g. signature bytes inside compressed data), the scan resumes right
+/// after its signature.
+///
+/// # Arguments
+///
+/// * `reader` - The archive file.
+/// * `len` - The length of the archive file.
+/// * `max_entry_size` - The largest decompressed size accepted for an entry.
+///
+/// # Returns
+///
+/// The recoverable entries, in the order they appear in the file.
+///
+/// # Errors
+///
+/// Returns an error if reading the file fails for a reason other than reaching its end.
+pub fn scan<R: Read + Seek>(
+    reader: &mut R,
+    len: u64,
+    max_entry_size: u64,
+) -> io::Result<Vec<LocalEntry>> {
+    let mut entries = Vec::new();
+    let mut position = 0;
+
+    while let Some(header_offset) = find_signature(reader, position, len)? {
+        let Some(header) = read_local_header(reader, header_offset, len)? else {
+            break;
+        };
+        match verify_entry(reader, &header, len, max_entry_size)? {
+            Some((entry, end)) => {
+                entries.push(entry);
+                position = end;
+            }
+            None => position = header_offset + LOCAL_FILE_HEADER_SIGNATURE.len() as u64,
+        }
+    }
+
+    Ok(entries)
+}
+
+/// Finds the next local file header signature at or after `from`.
+fn find_signature<R: Read + Seek>(reader: &mut R, from: u64, len: u64) -> io::Result<Option<u64>> {
+    let overlap = LOCAL_FILE_HEADER_SIGNATURE.len() - 1;
+    let mut buf = vec![0u8; CHUNK_SIZE];
+    let mut start = from;
+
+    while start < len {
+        reader.seek(SeekFrom::Start(start))?;
+        let want = CHUNK_SIZE.min((len - start) as usize);
+        let read = read_up_to(reader, &mut buf[..want])?;
+        if read < LOCAL_FILE_HEADER_SIGNATURE.len() {
+            return Ok(None);
+        }
+        if let Some(index) = buf[..read]
+            .windows(LOCAL_FILE_HEADER_SIGNATURE.len())
+            .position(|window| window == LOCAL_FILE_HEADER_SIGNATURE)
+        {
+            return Ok(Some(start + index as u64));
+        }
+        if read < want {
+            return Ok(None);
+        }
+        // Keep the last bytes so a signature spanning two chunks is still found.
+        start += (read - overlap) as u64;
+    }
+
+    Ok(None)
+}
+
+/// Reads the local file header at `offset`, or returns `None` if the file ends inside it.
+fn read_local_header<R: Read + Seek>(
+    reader: &mut R,
+    offset: u64,
+    len: u64,
+) -> io::Result<Option<LocalHeader>> {
+    let fixed_end = offset + LOCAL_FILE_HEADER_LEN as u64;
+    if fixed_end > len {
+        return Ok(None);
+    }
+    reader.seek(SeekFrom::Start(offset))?;
+    let mut fixed = [0u8; LOCAL_FILE_HEADER_LEN];
+    reader.read_exact(&mut fixed)?;
+
+    let u16_at = |at: usize| u16::from_le_bytes([fixed[at], fixed[at + 1]]);
+    let u32_at = |at: usize| u32::from_le_bytes(fixed[at..at + 4].try_into().unwrap());
+    let name_len = u64::from(u16_at(26));
+    let extra_len = u64::from(u16_at(28));
+    let data_offset = fixed_end + name_len + extra_len;
+    if data_offset > len {
+        return Ok(None);
+    }
+
+    let mut raw_name = vec![0u8; name_len as usize];
+    reader.read_exact(&mut raw_name)?;
+
+    Ok(Some(LocalHeader {
+        flags: u16_at(6),
+        method: u16_at(8),
+        crc32: u32_at(14),
+        compressed_size: u32_at(18),
+        size: u32_at(22),
+        raw_name,
+        data_offset,
+    }))
+}
+
+/// Reads an entry's data through, checking that it is complete and matches its CRC.
+///
+/// # Returns
+///
+/// The verified entry and the offset right after its data (and data descriptor), or
+/// `None` if the entry cannot be recovered.
+fn verify_entry<R: Read + Seek>(
+    reader: &mut R,
+    header: &LocalHeader,
+    len: u64,
+    max_entry_size: u64,
+) -> io::Result<Option<(LocalEntry, u64)>> {
+    if header.flags & FLAG_ENCRYPTED != 0 {
+        return Ok(None);
+    }
+    let has_descriptor = header.flags & FLAG_DATA_DESCRIPTOR != 0;
+    // With a data descriptor (or ZIP64) the header sizes are placeholders.
+    let declared_compressed = (!has_descriptor && header.compressed_size != ZIP64_SIZE)
+        .then_some(u64::from(header.compressed_size));
+    let available = len - header.data_offset;
+
+    reader.seek(SeekFrom::Start(header.data_offset))?;
+    let (method, consumed, checked) = match header.method {
+        0 => {
+            // Stored data has no end marker, so its size must come from the header.
+            let Some(size) = declared_compressed else {
+                return Ok(None);
+            };
+            if size > available || size > max_entry_size {
+                return Ok(None);
+            }
+            let Some(checked) = checksum(reader.by_ref().take(size), max_entry_size)? else {
+                return Ok(None);
+            };
+            (Method::Stored, size, checked)
+        }
+        8 => {
+            let limit = declared_compressed.unwrap_or(available).min(available);
+            let mut decoder = DeflateDecoder::new(reader.by_ref().take(limit));
+            let Some(checked) = checksum(&mut decoder, max_entry_size)? else {
+                return Ok(None);
+            };
+            (Method::Deflated, decoder.total_in(), checked)
+        }
+        _ => return Ok(None),
+    };
+    let (crc32, size) = checked;
+
+    let mut end = header.data_offset + consumed;
+    let expected_crc = if has_descriptor {
+        let Some((descriptor_crc, descriptor_len)) = read_data_descriptor(reader, end, len)? else {
+            return Ok(None);
+        };
+        end += descriptor_len;
+        descriptor_crc
+    } else {
+        if declared_compressed != Some(consumed)
+            || (header.size != ZIP64_SIZE && u64::from(header.size) != size)
+        {
+            return Ok(None);
+        }
+        header.crc32
+    };
+    if crc32 != expected_crc {
+        return Ok(None);
+    }
+
+    Ok(Some((
+        LocalEntry {
+            raw_name: header.raw_name.clone(),
+            data_offset: header.data_offset,
+            compressed_size: consumed,
+            size,
+            method,
+        },
+        end,
+    )))
+}
+
+/// Reads a stream to its end, computing its CRC-32 and length.
+///
+/// Returns `None` if the stream is corrupt or truncated, or yields more than
+/// `max_size` bytes.
+fn checksum<R: Read>(mut reader: R, max_size: u64) -> io::Result<Option<(u32, u64)>> {
+    let mut crc = Crc::new();
+    let mut size = 0u64;
+    let mut buf = vec![0u8; CHUNK_SIZE];
+    loop {
+        let read = match reader.read(&mut buf) {
+            Ok(0) => break,
+            Ok(read) => read,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            // A corrupt or truncated DEFLATE stream; the entry is not recoverable.
+            Err(e) if is_damaged_data(&e) => return Ok(None),
+            Err(e) => return Err(e),
+        };
+        size += read as u64;
+        if size > max_size {
+            return Ok(None);
+        }
+        crc.update(&buf[..read]);
+    }
+    Ok(Some((crc.sum(), size)))
+}
+
+/// Returns whether an I/O error reports bad or missing data rather than a failed read.
+fn is_damaged_data(error: &io::Error) -> bool {
+    matches!(
+        error.kind(),
+        io::ErrorKind::InvalidInput | io::ErrorKind::InvalidData | io::ErrorKind::UnexpectedEof
+    )
+}
+
+/// Reads the CRC-32 out of the data descriptor at `offset`.
+///
+/// # Returns
+///
+/// The CRC and the length of the descriptor, or `None` if the file ends inside it.
+fn read_data_descriptor<R: Read + Seek>(
+    reader: &mut R,
+    offset: u64,
+    len: u64,
+) -> io::Result<Option<(u32, u64)>> {
+    // The signature is optional; the CRC and the two 32-bit sizes always follow.
+    const FIELDS_LEN: u64 = 12;
+    if offset + FIELDS_LEN > len {
+        return Ok(None);
+    }
+    reader.seek(SeekFrom::Start(offset))?;
+    let mut first = [0u8; 4];
+    reader.read_exact(&mut first)?;
+    if first != DATA_DESCRIPTOR_SIGNATURE {
+        return Ok(Some((u32::from_le_bytes(first), FIELDS_LEN)));
+    }
+    if offset + 4 + FIELDS_LEN > len {
+        return Ok(None);
+    }
+    let mut crc = [0u8; 4];
+    reader.read_exact(&mut crc)?;
+    Ok(Some((u32::from_le_bytes(crc), 4 + FIELDS_LEN)))
+}
+
+/// Reads into `buf` until it is full or the reader ends, returning the bytes read.
+fn read_up_to<R: Read>(reader: &mut R, buf: &mut [u8]) -> io::Result<usize> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match reader.read(&mut buf[filled..]) {
+            Ok(0) => break,
+            Ok(read) => filled += read,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(filled)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Write};
+
+    use zip::write::{FileOptions, ZipWriter};
+
+    use super::*;
+
+    fn build_zip(entries: &[(&str, &[u8], zip::CompressionMethod)]) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        {
+            let mut zip = ZipWriter::new(Cursor::new(&mut bytes));
+            for (name, content, method) in entries {
+                let options = FileOptions::<()>::default().compression_method(*method);
+                zip.start_file(*name, options).unwrap();
+                zip.write_all(content).unwrap();
+            }
+            zip.finish().unwrap();
+        }
+        bytes
+    }
+
+    fn scan_bytes(bytes: &[u8]) -> Vec<LocalEntry> {
+        scan(&mut Cursor::new(bytes), bytes.len() as u64, u64::MAX).unwrap()
+    }
+
+    #[test]
+    fn test_scan_recovers_entries_without_central_directory() {
+        let first = vec![7u8; 5000];
+        let second = b"stored bytes".to_vec();
+        let bytes = build_zip(&[
+            ("a.png", &first, zip::CompressionMethod::DEFLATE),
+            ("b.png", &second, zip::CompressionMethod::STORE),
+        ]);
+        // Cut the file in the middle of the central directory.
+        let truncated = &bytes[..bytes.len() - 30];
+
+        let entries = scan_bytes(truncated);
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].raw_name, b"a.png");
+        assert_eq!(entries[0].method, Method::Deflated);
+        assert_eq!(entries[0].size, first.len() as u64);
+        assert_eq!(entries[1].raw_name, b"b.png");
+        assert_eq!(entries[1].method, Method::Stored);
+        let data = &truncated[entries[1].data_offset as usize..][..second.len()];
+        assert_eq!(data, second.as_slice());
+    }
+
+    #[test]
+    fn test_scan_skips_truncated_last_entry() {
+        let first = vec![1u8; 100];
+        let second: Vec<u8> = (0..20_000u32).map(|i| (i * 31 % 251) as u8).collect();
+        let bytes = build_zip(&[
+            ("a.png", &first, zip::CompressionMethod::STORE),
+            ("b.png", &second, zip::CompressionMethod::DEFLATE),
+        ]);
+        let entries = scan_bytes(&bytes);
+        assert_eq!(entries.len(), 2);
+
+        // Cut the file inside the data of the second entry.
+        let cut = (entries[1].data_offset + entries[1].compressed_size / 2) as usize;
+        let entries = scan_bytes(&bytes[..cut]);
+
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].raw_name, b"a.png");
+    }
+
+    #[test]
+    fn test_scan_rejects_corrupted_data() {
+        let bytes = build_zip(&[("a.png", b"0123456789", zip::CompressionMethod::STORE)]);
+        let entries = scan_bytes(&bytes);
+        let mut corrupted = bytes.clone();
+        corrupted[entries[0].data_offset as usize] ^= 0xFF;
+
+        assert!(scan_bytes(&corrupted).is_empty());
+    }
+
+    #[test]
+    fn test_scan_rejects_entries_over_the_size_limit() {
+        let bytes = build_zip(&[("a.png", &[0u8; 1000], zip::CompressionMethod::DEFLATE)]);
+
+        let entries = scan(&mut Cursor::new(&bytes), bytes.len() as u64, 999).unwrap();
+
+        assert!(entries.is_empty());
+    }
+
+    #[test]
+    fn test_find_signature_across_chunks() {
+        let mut bytes = vec![0u8; CHUNK_SIZE + 10];
+        bytes[CHUNK_SIZE - 2..CHUNK_SIZE + 2].copy_from_slice(&LOCAL_FILE_HEADER_SIGNATURE);
+        let len = bytes.len() as u64;
+
+        let found = find_signature(&mut Cursor::new(&bytes), 0, len).unwrap();
+
+        assert_eq!(found, Some(CHUNK_SIZE as u64 - 2));
+        assert_eq!(
+            find_signature(&mut Cursor::new(&bytes), found.unwrap() + 1, len).unwrap(),
+            None
+        );
+    }
+}
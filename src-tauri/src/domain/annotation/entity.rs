@@ -0,0 +1,205 @@
+use std::cmp::Ordering;
+
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+use crate::error::{Error, Result};
+
+/// A highlighted passage of a novel, with an optional note.
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize, specta::Type)]
+pub struct Annotation {
+    /// The unique identifier for the annotation.
+    pub id: i64,
+    /// The identifier of the annotated book.
+    pub book_id: i64,
+    /// The EPUB CFI range of the highlighted passage.
+    pub cfi_range: String,
+    /// The highlighted text, as it was selected.
+    pub selected_text: String,
+    /// A free-form note. May be empty.
+    pub note: String,
+    /// The color code of the highlight (e.g., "#FFFF00").
+    pub color_code: String,
+    /// The timestamp when the annotation was created.
+    pub created_at: NaiveDateTime,
+}
+
+/// An annotation to add to a novel.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct NewAnnotation {
+    /// The identifier of the book to annotate.
+    pub book_id: i64,
+    /// The EPUB CFI range of the highlighted passage.
+    pub cfi_range: String,
+    /// The highlighted text, as it was selected.
+    pub selected_text: String,
+    /// A free-form note. May be empty.
+    pub note: String,
+    /// The color code of the highlight (e.g., "#FFFF00").
+    pub color_code: String,
+}
+
+impl NewAnnotation {
+    /// Checks that the annotation points at a passage.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidArgument` if the CFI range is blank.
+    pub fn validate(&self) -> Result<()> {
+        if self.cfi_range.trim().is_empty() {
+            return Err(Error::InvalidArgument(
+                "The CFI range of an annotation must not be empty".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// The file format annotations are exported in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "snake_case")]
+pub enum AnnotationExportFormat {
+    /// A Markdown document quoting each passage, followed by its note.
+    Markdown,
+    /// A JSON document with the book name and every annotation.
+    Json,
+}
+
+/// Compares two EPUB CFIs (or the starts of two CFI ranges) by their position in the
+/// book.
+///
+/// The steps of the paths are compared first, then the character offset at their end,
+/// so a position inside a node sorts before the positions in its later siblings and
+/// children. ID assertions such as `[chap01]` are ignored.
+pub fn compare_cfi(a: &str, b: &str) -> Ordering {
+    cfi_position(a).cmp(&cfi_position(b))
+}
+
+/// Returns the steps of the path to the start of a CFI and its character offset, e.g.
+/// `([6, 4, 4, 10, 3], 5)` for `epubcfi(/6/4!/4/10,/3:5,/3:12)`.
+///
+/// A CFI without an offset is at offset `0`. Temporal and spatial offsets (`~`, `@`)
+/// are ignored.
+fn cfi_position(cfi: &str) -> (Vec<u64>, u64) {
+    /// What the number being read stands for.
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    enum Part {
+        Step,
+        Offset,
+        Ignored,
+    }
+
+    let inner = cfi
+        .strip_prefix("epubcfi(")
+        .and_then(|inner| inner.strip_suffix(')'))
+        .unwrap_or(cfi);
+    // A range is `parent,start,end`, and starts at `parent` followed by `start`.
+    let mut parts = inner.splitn(3, ',');
+    let parent = parts.next().unwrap_or_default();
+    let start = parts.next().unwrap_or_default();
+
+    let mut steps = Vec::new();
+    let mut offset = 0;
+    let mut number: Option<u64> = None;
+    let mut part = Part::Ignored;
+    let mut in_assertion = false;
+    let mut flush = |number: Option<u64>, part: Part| match (number, part) {
+        (Some(number), Part::Step) => steps.push(number),
+        (Some(number), Part::Offset) => offset = number,
+        _ => {}
+    };
+    for c in parent.chars().chain(start.chars()) {
+        match c {
+            ']' => in_assertion = false,
+            _ if in_assertion => {}
+            '0'..='9' => {
+                let digit = u64::from(c) - u64::from('0');
+                number = Some(number.unwrap_or(0).saturating_mul(10).saturating_add(digit));
+            }
+            _ => {
+                flush(number.take(), part);
+                part = match c {
+                    '/' => Part::Step,
+                    ':' => Part::Offset,
+                    // An assertion belongs to the step or offset before it.
+                    '[' => part,
+                    _ => Part::Ignored,
+                };
+                in_assertion = c == '[';
+            }
+        }
+    }
+    flush(number, part);
+    (steps, offset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compare_cfi_orders_by_position() {
+        let mut cfis = vec![
+            "epubcfi(/6/12!/4/2,/1:0,/1:8)",
+            "epubcfi(/6/4!/4/10,/3:5,/3:12)",
+            "epubcfi(/6/4!/4/2/1:40)",
+            "epubcfi(/6/4!/4/10,/3:1,/3:4)",
+        ];
+        cfis.sort_by(|a, b| compare_cfi(a, b));
+        assert_eq!(
+            cfis,
+            vec![
+                "epubcfi(/6/4!/4/2/1:40)",
+                "epubcfi(/6/4!/4/10,/3:1,/3:4)",
+                "epubcfi(/6/4!/4/10,/3:5,/3:12)",
+                "epubcfi(/6/12!/4/2,/1:0,/1:8)",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_compare_cfi_compares_offsets_after_steps() {
+        // A large offset in a node still comes before that node's next sibling and
+        // its children.
+        assert_eq!(
+            compare_cfi("epubcfi(/6/4!/4/2/1:40)", "epubcfi(/6/4!/4/2/3:0)"),
+            Ordering::Less
+        );
+        assert_eq!(
+            compare_cfi("epubcfi(/6/4!/4/2:40)", "epubcfi(/6/4!/4/2/1:0)"),
+            Ordering::Less
+        );
+        assert_eq!(
+            compare_cfi("epubcfi(/6/4!/4/2/1:3)", "epubcfi(/6/4!/4/2/1:12)"),
+            Ordering::Less
+        );
+    }
+
+    #[test]
+    fn test_compare_cfi_ignores_assertions() {
+        assert_eq!(
+            compare_cfi("epubcfi(/6/4[chap01]!/4/2:3)", "epubcfi(/6/4!/4/2:3)"),
+            Ordering::Equal
+        );
+        assert_eq!(
+            compare_cfi("epubcfi(/6/4[chap99]!/4/2:3)", "epubcfi(/6/4!/4/2:7)"),
+            Ordering::Less
+        );
+    }
+
+    #[test]
+    fn test_new_annotation_needs_cfi_range() {
+        let annotation = NewAnnotation {
+            book_id: 1,
+            cfi_range: "epubcfi(/6/4!/4/2,/1:0,/1:8)".to_string(),
+            ..Default::default()
+        };
+        assert!(annotation.validate().is_ok());
+        let blank = NewAnnotation {
+            cfi_range: " ".to_string(),
+            ..annotation
+        };
+        assert!(matches!(blank.validate(), Err(Error::InvalidArgument(_))));
+    }
+}
@@ -0,0 +1,111 @@
+use serde::Serialize;
+
+use super::entity::{Annotation, AnnotationExportFormat};
+use crate::error::Result;
+
+/// The document written when annotations are exported as JSON.
+#[derive(Serialize)]
+struct AnnotationExport<'a> {
+    book_id: i64,
+    display_name: &'a str,
+    annotations: &'a [Annotation],
+}
+
+/// Renders the annotations of a book as a document in the given format.
+///
+/// # Arguments
+///
+/// * `book_id` - The ID of the annotated book.
+/// * `display_name` - The name of the book, used as the document title.
+/// * `annotations` - The annotations to export, in the order they should appear.
+/// * `format` - The format of the document.
+///
+/// # Errors
+///
+/// Returns `Error::SerdeJson` if the JSON document cannot be serialized.
+pub fn export_annotations(
+    book_id: i64,
+    display_name: &str,
+    annotations: &[Annotation],
+    format: AnnotationExportFormat,
+) -> Result<String> {
+    match format {
+        AnnotationExportFormat::Markdown => Ok(to_markdown(display_name, annotations)),
+        AnnotationExportFormat::Json => Ok(serde_json::to_string_pretty(&AnnotationExport {
+            book_id,
+            display_name,
+            annotations,
+        })?),
+    }
+}
+
+/// Renders each annotation as a block quote of the passage, followed by its note.
+fn to_markdown(display_name: &str, annotations: &[Annotation]) -> String {
+    let mut markdown = format!("# {}\n", display_name);
+    for annotation in annotations {
+        markdown.push('\n');
+        for line in annotation.selected_text.trim().lines() {
+            let line = line.trim_end();
+            if line.is_empty() {
+                markdown.push_str(">\n");
+            } else {
+                markdown.push_str(&format!("> {}\n", line));
+            }
+        }
+        let note = annotation.note.trim();
+        if !note.is_empty() {
+            markdown.push_str(&format!("\n{}\n", note));
+        }
+    }
+    markdown
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn annotation(id: i64, selected_text: &str, note: &str) -> Annotation {
+        Annotation {
+            id,
+            book_id: 1,
+            cfi_range: format!("epubcfi(/6/4!/4/{},/1:0,/1:8)", id * 2),
+            selected_text: selected_text.to_string(),
+            note: note.to_string(),
+            color_code: "#FFFF00".to_string(),
+            created_at: chrono::NaiveDate::from_ymd_opt(2026, 10, 1)
+                .unwrap()
+                .and_hms_opt(12, 0, 0)
+                .unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_export_markdown_quotes_passages_and_notes() {
+        let annotations = vec![
+            annotation(1, "It was a dark\n\nand stormy night.", "Classic opening"),
+            annotation(2, "  The end.  ", ""),
+        ];
+        let markdown =
+            export_annotations(1, "Novel", &annotations, AnnotationExportFormat::Markdown).unwrap();
+        assert_eq!(
+            markdown,
+            "# Novel\n\n> It was a dark\n>\n> and stormy night.\n\nClassic opening\n\n> The end.\n"
+        );
+    }
+
+    #[test]
+    fn test_export_json_includes_every_annotation() {
+        let annotations = vec![annotation(1, "Passage", "Note")];
+        let json =
+            export_annotations(1, "Novel", &annotations, AnnotationExportFormat::Json).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["book_id"], 1);
+        assert_eq!(value["display_name"], "Novel");
+        assert_eq!(value["annotations"][0]["selected_text"], "Passage");
+        assert_eq!(value["annotations"][0]["note"], "Note");
+        assert_eq!(
+            value["annotations"][0]["cfi_range"],
+            "epubcfi(/6/4!/4/2,/1:0,/1:8)"
+        );
+    }
+}
@@ -0,0 +1,3 @@
+pub mod entity;
+pub mod export;
+pub mod repository;
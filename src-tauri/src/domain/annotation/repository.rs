@@ -0,0 +1,55 @@
+use crate::error::Result;
+use async_trait::async_trait;
+
+use super::entity::{Annotation, NewAnnotation};
+
+/// Defines the data access operations for the `Annotation` aggregate.
+///
+/// Annotations are deleted along with their book, but are kept when its reading history
+/// is cleared.
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait AnnotationRepository: Send + Sync {
+    /// Adds an annotation and returns its complete entity.
+    ///
+    /// # Arguments
+    ///
+    /// * `annotation` - The annotation to add. It is expected to be valid (see
+    ///   [`NewAnnotation::validate`]).
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the newly created `Annotation` entity.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the database insertion fails (e.g., the book does not exist).
+    async fn add(&self, annotation: &NewAnnotation) -> Result<Annotation>;
+
+    /// Retrieves the annotations of a specific book, in reading order (see
+    /// [`compare_cfi`](super::entity::compare_cfi)).
+    ///
+    /// # Arguments
+    ///
+    /// * `book_id` - The unique identifier of the book.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a vector of `Annotation` entities.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the database query fails.
+    async fn get_for_book(&self, book_id: i64) -> Result<Vec<Annotation>>;
+
+    /// Deletes an annotation.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the annotation to delete.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the database execution fails.
+    async fn delete(&self, id: i64) -> Result<()>;
+}
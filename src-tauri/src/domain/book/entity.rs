@@ -2,6 +2,11 @@ use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 
+use crate::domain::creator::entity::CreatorCredit;
+
+/// The rules hiding entries of a book, stored with the book.
+pub use crate::container::entry_filter::{ExclusionRule, PatternSyntax};
+
 /// Represents a book entity in the database.
 #[derive(Debug, Clone, FromRow, Serialize, Deserialize, specta::Type)]
 pub struct Book {
@@ -30,6 +35,10 @@ pub struct ReadingState {
     pub book_id: i64,
     /// The last read page index.
     pub last_read_page_index: i64,
+    /// The EPUB CFI of the last read location of a novel, which stays valid when the
+    /// pagination changes. `None` for image books.
+    #[serde(default)]
+    pub last_read_cfi: Option<String>,
     /// The timestamp when the book was last opened.
     pub last_opened_at: Option<NaiveDateTime>,
 }
@@ -72,6 +81,10 @@ pub struct BookWithState {
     pub item_type: String,
     /// The display name of the book.
     pub display_name: String,
+    /// The key the display name sorts by in natural order (see `natural_sort::sort_key`).
+    #[serde(default)]
+    #[sqlx(skip)]
+    pub sort_key: String,
     /// The total number of pages in the book.
     pub total_pages: i64,
     /// The optional identifier of the series this book belongs to.
@@ -82,8 +95,21 @@ pub struct BookWithState {
     pub thumbnail_path: Option<String>,
     /// The timestamp when the book was created (registered).
     pub created_at: Option<NaiveDateTime>,
+    /// The error code explaining why the book could only be opened partially
+    /// (e.g. a truncated ZIP), or `None` if it opened cleanly.
+    pub damage_code: Option<i64>,
+    /// When the library scanner found the book file gone, or `None` while it exists.
+    pub missing_since: Option<NaiveDateTime>,
+    /// How far the book has been read.
+    pub read_status: ReadStatus,
+    /// The rating from 1 to 5, or 0 if the book is not rated.
+    pub rating: i64,
+    /// Whether the book is marked as a favorite.
+    pub is_favorite: bool,
     /// The last read page index, if the book has been opened.
     pub last_read_page_index: Option<i64>,
+    /// The EPUB CFI of the last read location of a novel, if any.
+    pub last_read_cfi: Option<String>,
     /// The timestamp when the book was last opened, if any.
     pub last_opened_at: Option<NaiveDateTime>,
     /// Comma-separated list of tag IDs associated with this book.
@@ -125,6 +151,137 @@ impl BookWithState {
     }
 }
 
+/// Represents the metadata read from a book file (the EPUB package metadata or a
+/// `ComicInfo.xml`).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BookMetadata {
+    /// The people credited for the book, in the order the file lists them.
+    pub creators: Vec<CreatorCredit>,
+    /// The publisher of the book, if the file names one.
+    pub publisher: Option<String>,
+    /// The description or summary of the book, if the file has one.
+    pub description: Option<String>,
+}
+
+/// Narrows the results of a library search. Filters that are not set match every book.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, specta::Type)]
+pub struct BookSearchFilters {
+    /// Only match books that have all of these tags.
+    pub tag_ids: Vec<i64>,
+    /// Only match books in this series.
+    pub series_id: Option<i64>,
+    /// Only match books on this bookshelf.
+    pub bookshelf_id: Option<i64>,
+    /// Only match books of this type ('file' or 'directory').
+    pub item_type: Option<String>,
+}
+
+/// Whether a book has to have all or any of the tags it is filtered by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "snake_case")]
+pub enum TagMatch {
+    /// The book has every one of the tags.
+    #[default]
+    All,
+    /// The book has at least one of the tags.
+    Any,
+}
+
+/// How far a book has been read.
+///
+/// The status is set by the reader, and advances by itself as the book is read: opening
+/// an unread or wanted book makes it `Reading`, and reaching the last page makes it
+/// `Read`.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, sqlx::Type, specta::Type,
+)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(type_name = "TEXT", rename_all = "snake_case")]
+pub enum ReadStatus {
+    /// The book has not been read yet.
+    #[default]
+    Unread,
+    /// The reader wants to read the book.
+    WantToRead,
+    /// The book is being read.
+    Reading,
+    /// The book has been finished.
+    Read,
+    /// The reader stopped reading the book without finishing it.
+    Abandoned,
+}
+
+/// The key books are sorted by in a [`BookQuery`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "snake_case")]
+pub enum BookSortKey {
+    /// The display name, in Japanese-aware natural order.
+    #[default]
+    Name,
+    /// The time the book was added to the library.
+    AddedAt,
+    /// The time the book was last opened. Books never opened come last.
+    LastOpenedAt,
+    /// The number of pages.
+    TotalPages,
+    /// The series name in Japanese-aware natural order, then the order within the
+    /// series. Books outside a series come last.
+    SeriesOrder,
+}
+
+/// Selects, sorts and pages the books of the library.
+///
+/// Conditions that are not set match every book; the conditions that are set must all
+/// hold.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, specta::Type)]
+pub struct BookQuery {
+    /// Only match books with these tags, as selected by `tag_match`.
+    pub include_tag_ids: Vec<i64>,
+    /// Whether a book needs all of `include_tag_ids` or any one of them.
+    pub tag_match: TagMatch,
+    /// Exclude books with any of these tags.
+    pub exclude_tag_ids: Vec<i64>,
+    /// Only match books on this bookshelf.
+    pub bookshelf_id: Option<i64>,
+    /// Only match books in this series.
+    pub series_id: Option<i64>,
+    /// Only match books with this read status.
+    pub read_status: Option<ReadStatus>,
+    /// Only match books added at or after this time.
+    pub added_after: Option<NaiveDateTime>,
+    /// Only match books added before this time.
+    pub added_before: Option<NaiveDateTime>,
+    /// Only match books last opened at or after this time.
+    pub opened_after: Option<NaiveDateTime>,
+    /// Only match books last opened before this time.
+    pub opened_before: Option<NaiveDateTime>,
+    /// Only match books of this type ('file' or 'directory').
+    pub item_type: Option<String>,
+    /// Only match books inside this folder. Whole path components are compared, so
+    /// "/comics" does not match "/comics2".
+    pub path_prefix: Option<String>,
+    /// Only match files with one of these extensions (e.g. "zip", "epub"), compared
+    /// case-insensitively.
+    pub formats: Vec<String>,
+    /// The key the books are sorted by. Ties are broken by display name.
+    pub sort: BookSortKey,
+    /// Whether to sort in descending instead of ascending order.
+    pub descending: bool,
+    /// The maximum number of books to return, or `None` for all of them.
+    pub limit: Option<u32>,
+    /// The number of matching books to skip, for paging.
+    pub offset: u32,
+}
+
+/// One page of the books matching a [`BookQuery`].
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+pub struct BookQueryResult {
+    /// The books of the requested page, in sort order.
+    pub books: Vec<BookWithState>,
+    /// The number of books matching the query across all pages.
+    pub total_count: i64,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -136,12 +293,19 @@ mod tests {
             file_path: "path".to_string(),
             item_type: "file".to_string(),
             display_name: "name".to_string(),
+            sort_key: String::new(),
             total_pages: 10,
             series_id: None,
             series_order: None,
             thumbnail_path: None,
             created_at: None,
+            damage_code: None,
+            missing_since: None,
+            read_status: ReadStatus::Unread,
+            rating: 0,
+            is_favorite: false,
             last_read_page_index: None,
+            last_read_cfi: None,
             last_opened_at: None,
             tag_ids_str: Some("1,2,3".to_string()),
             tag_ids: vec![],
@@ -1,7 +1,11 @@
+use crate::container::entry_filter::ExclusionRule;
 use crate::error::Result;
 use async_trait::async_trait;
 
-use super::entity::{Book, BookWithState, ReadBook, ReadingState};
+use super::entity::{
+    Book, BookMetadata, BookQuery, BookQueryResult, BookSearchFilters, BookWithState, ReadBook,
+    ReadStatus, ReadingState,
+};
 
 /// Defines the data access operations for the `Book` aggregate.
 #[cfg_attr(test, mockall::automock)]
@@ -85,6 +89,9 @@ pub trait BookRepository: Send + Sync {
     /// If the book is new, it is inserted into the `books` table with no tags or bookshelves,
     /// and a new `reading_state` is created with `last_read_page_index` set to 0.
     /// If the book already exists, only the `last_opened_at` timestamp in `reading_state` is updated.
+    /// A book that was unread or wanted to be read becomes `ReadStatus::Reading`.
+    /// Opening the book also starts a reading session, or continues the latest one if
+    /// the book was read moments ago.
     ///
     /// # Arguments
     ///
@@ -110,10 +117,115 @@ pub trait BookRepository: Send + Sync {
         thumbnail_path: Option<String>,
     ) -> Result<i64>;
 
+    /// Records whether a book could only be opened partially.
+    ///
+    /// # Arguments
+    ///
+    /// * `book_id` - The unique identifier of the book.
+    /// * `damage_code` - The error code explaining why the book is damaged, or `None` to
+    ///   mark it as intact.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating the success of the operation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the database execution fails.
+    async fn set_damage_code(&self, book_id: i64, damage_code: Option<i64>) -> Result<()>;
+
+    /// Stores the metadata read from a book file, replacing what was stored before.
+    ///
+    /// The credited creators are looked up by name (or by an alias left by a rename or
+    /// merge) and created if they are new; creators no book credits anymore are removed.
+    /// The metadata is indexed for [`BookRepository::search_books`].
+    ///
+    /// # Arguments
+    ///
+    /// * `book_id` - The unique identifier of the book.
+    /// * `metadata` - The metadata read from the book file.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating the success of the operation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the database execution fails.
+    async fn set_metadata(&self, book_id: i64, metadata: &BookMetadata) -> Result<()>;
+
+    /// Retrieves the exclusion rules of a book.
+    ///
+    /// These rules apply to this book only, on top of the global rules from the settings.
+    ///
+    /// # Arguments
+    ///
+    /// * `book_id` - The unique identifier of the book.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the book's rules, or an empty list if it has none.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the database query fails or the stored rules cannot be parsed.
+    async fn get_exclusion_rules(&self, book_id: i64) -> Result<Vec<ExclusionRule>>;
+
+    /// Replaces the exclusion rules of a book.
+    ///
+    /// # Arguments
+    ///
+    /// * `book_id` - The unique identifier of the book.
+    /// * `rules` - The new rules. An empty list removes the book's rules.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating the success of the operation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the book does not exist or the database execution fails.
+    async fn set_exclusion_rules(&self, book_id: i64, rules: &[ExclusionRule]) -> Result<()>;
+
+    /// Retrieves the manual page order of a book.
+    ///
+    /// # Arguments
+    ///
+    /// * `book_id` - The unique identifier of the book.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the entry names in page order, or an empty list if the book
+    /// has no manual order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the database query fails or the stored order cannot be parsed.
+    async fn get_page_order(&self, book_id: i64) -> Result<Vec<String>>;
+
+    /// Replaces the manual page order of a book.
+    ///
+    /// # Arguments
+    ///
+    /// * `book_id` - The unique identifier of the book.
+    /// * `page_order` - The entry names in page order. An empty list removes the manual
+    ///   order.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating the success of the operation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the book does not exist or the database execution fails.
+    async fn set_page_order(&self, book_id: i64, page_order: &[String]) -> Result<()>;
+
     /// Clears the reading history for a specific book.
     ///
     /// This removes the reading state entry entirely, which effectively resets
-    /// the progress and last opened timestamp to `None` (unread state).
+    /// the progress and last opened timestamp to `None` (unread state). A book being read
+    /// becomes `ReadStatus::Unread`; other read statuses are kept, and so are the
+    /// bookmarks, annotations and reading sessions (the reading statistics) of the book.
     ///
     /// # Arguments
     ///
@@ -132,6 +244,8 @@ pub trait BookRepository: Send + Sync {
     ///
     /// This removes all entries from the `reading_state` table, effectively resetting
     /// the progress and last opened timestamp to `None` (unread state) for the entire library.
+    /// Books being read become `ReadStatus::Unread`; other read statuses and all
+    /// bookmarks, annotations and reading sessions are kept.
     ///
     /// # Returns
     ///
@@ -142,7 +256,13 @@ pub trait BookRepository: Send + Sync {
     /// Returns an `Err` if the database execution fails.
     async fn clear_all_reading_history(&self) -> Result<()>;
 
-    /// Updates the reading progress/state for a book.
+    /// Updates the reading progress/state for a book. The CFI of a novel is stored along
+    /// with the page index.
+    ///
+    /// Reaching the last page marks the book as `ReadStatus::Read` and records when it
+    /// was finished. Otherwise a book that was unread or wanted to be read becomes
+    /// `ReadStatus::Reading`. The pages read forward are added to the current reading
+    /// session, or to a new one after a break of more than 30 minutes.
     ///
     /// # Arguments
     ///
@@ -157,6 +277,56 @@ pub trait BookRepository: Send + Sync {
     /// Returns an `Err` if the database execution fails.
     async fn update_reading_progress(&self, state: &ReadingState) -> Result<()>;
 
+    /// Sets the read status of several books. Books becoming `ReadStatus::Read` are
+    /// recorded as finished now; books leaving it are no longer counted as finished.
+    ///
+    /// # Arguments
+    ///
+    /// * `book_ids` - The unique identifiers of the books.
+    /// * `status` - The new read status.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating the success of the operation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the database execution fails.
+    async fn set_read_status(&self, book_ids: &[i64], status: ReadStatus) -> Result<()>;
+
+    /// Sets the rating of several books.
+    ///
+    /// # Arguments
+    ///
+    /// * `book_ids` - The unique identifiers of the books.
+    /// * `rating` - The rating from 1 to 5, or 0 to remove the rating.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating the success of the operation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the database execution fails, including for a rating outside
+    /// 0 to 5.
+    async fn set_rating(&self, book_ids: &[i64], rating: i64) -> Result<()>;
+
+    /// Marks several books as favorites, or unmarks them.
+    ///
+    /// # Arguments
+    ///
+    /// * `book_ids` - The unique identifiers of the books.
+    /// * `is_favorite` - Whether the books are favorites.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating the success of the operation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the database execution fails.
+    async fn set_favorite(&self, book_ids: &[i64], is_favorite: bool) -> Result<()>;
+
     /// Retrieves books that have been opened, ordered by the last opened time in descending order.
     ///
     /// # Arguments
@@ -183,6 +353,54 @@ pub trait BookRepository: Send + Sync {
     /// Returns an `Err` if the database query fails.
     async fn get_all_books_with_state(&self) -> Result<Vec<BookWithState>>;
 
+    /// Searches the books by display name, file path, series name, tag names and the
+    /// metadata read from the book files.
+    ///
+    /// Every whitespace-separated term of `query` must occur in one of those fields.
+    /// Terms match anywhere in a word. Both the fields and the query are normalized with
+    /// [`crate::search_text::normalize`], so differences in case, character width,
+    /// katakana/hiragana and small kana are ignored. Results are ranked by relevance,
+    /// with matches in the display name weighted highest. An empty query matches every
+    /// book that passes the filters, ordered by display name.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The search terms.
+    /// * `filters` - Further conditions the books must meet.
+    /// * `limit` - The maximum number of books to return.
+    /// * `offset` - The number of matching books to skip, for paging.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the matching books, best match first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the database query fails.
+    async fn search_books(
+        &self,
+        query: &str,
+        filters: &BookSearchFilters,
+        limit: u32,
+        offset: u32,
+    ) -> Result<Vec<BookWithState>>;
+
+    /// Retrieves the books matching a query, sorted and paged as it requests.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The conditions, sort order and page of the books to return.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the requested page of books and the total number of
+    /// matching books.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the database query fails.
+    async fn query_books(&self, query: &BookQuery) -> Result<BookQueryResult>;
+
     /// Deletes a book by its unique ID.
     ///
     /// # Arguments
@@ -0,0 +1,215 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+use crate::error::{Error, Result};
+
+/// A position in a book the reader wants to come back to.
+///
+/// A bookmark has either a `page_index` (image books) or a `cfi` (novels), never both.
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize, specta::Type)]
+pub struct Bookmark {
+    /// The unique identifier for the bookmark.
+    pub id: i64,
+    /// The identifier of the bookmarked book.
+    pub book_id: i64,
+    /// The bookmarked page index, for image books.
+    pub page_index: Option<i64>,
+    /// The EPUB CFI of the bookmarked location, for novels.
+    pub cfi: Option<String>,
+    /// The short label shown in bookmark lists. May be empty.
+    pub label: String,
+    /// A free-form note. May be empty.
+    pub note: String,
+    /// The color code of the bookmark (e.g., "#FF0000"), or `None` for no color.
+    pub color_code: Option<String>,
+    /// The timestamp when the bookmark was created.
+    pub created_at: NaiveDateTime,
+}
+
+/// A bookmark along with the book it belongs to, for the library-wide bookmark list.
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize, specta::Type)]
+pub struct BookmarkWithBook {
+    /// The unique identifier for the bookmark.
+    pub id: i64,
+    /// The identifier of the bookmarked book.
+    pub book_id: i64,
+    /// The bookmarked page index, for image books.
+    pub page_index: Option<i64>,
+    /// The EPUB CFI of the bookmarked location, for novels.
+    pub cfi: Option<String>,
+    /// The short label shown in bookmark lists. May be empty.
+    pub label: String,
+    /// A free-form note. May be empty.
+    pub note: String,
+    /// The color code of the bookmark (e.g., "#FF0000"), or `None` for no color.
+    pub color_code: Option<String>,
+    /// The timestamp when the bookmark was created.
+    pub created_at: NaiveDateTime,
+    /// The display name of the book.
+    pub display_name: String,
+    /// The unique file path or directory path of the book.
+    pub file_path: String,
+    /// The type of the book ('file' or 'directory').
+    pub item_type: String,
+    /// The optional file path to the thumbnail image of the book.
+    pub thumbnail_path: Option<String>,
+}
+
+/// A bookmark to add to a book.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct NewBookmark {
+    /// The identifier of the book to bookmark.
+    pub book_id: i64,
+    /// The page index to bookmark, for image books.
+    pub page_index: Option<i64>,
+    /// The EPUB CFI of the location to bookmark, for novels.
+    pub cfi: Option<String>,
+    /// The short label shown in bookmark lists. May be empty.
+    pub label: String,
+    /// A free-form note. May be empty.
+    pub note: String,
+    /// The color code of the bookmark (e.g., "#FF0000"), or `None` for no color.
+    pub color_code: Option<String>,
+}
+
+impl NewBookmark {
+    /// Checks that the bookmark points at exactly one valid position and has a valid
+    /// color.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidArgument` if neither or both of `page_index` and `cfi` are
+    /// set, the page index is negative, the CFI is blank, or the color code is not a
+    /// hex color.
+    pub fn validate(&self) -> Result<()> {
+        validate_color_code(self.color_code.as_deref())?;
+        match (self.page_index, self.cfi.as_deref()) {
+            (Some(page_index), None) if page_index < 0 => Err(Error::InvalidArgument(format!(
+                "Invalid bookmark page index {page_index}: it must not be negative"
+            ))),
+            (None, Some(cfi)) if cfi.trim().is_empty() => Err(Error::InvalidArgument(
+                "The CFI of a bookmark must not be empty".to_string(),
+            )),
+            (Some(_), None) | (None, Some(_)) => Ok(()),
+            _ => Err(Error::InvalidArgument(
+                "A bookmark needs either a page index or a CFI".to_string(),
+            )),
+        }
+    }
+}
+
+/// The fields of a bookmark the reader can change after adding it.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct BookmarkUpdate {
+    /// The short label shown in bookmark lists. May be empty.
+    pub label: String,
+    /// A free-form note. May be empty.
+    pub note: String,
+    /// The color code of the bookmark (e.g., "#FF0000"), or `None` for no color.
+    pub color_code: Option<String>,
+}
+
+impl BookmarkUpdate {
+    /// Checks that the color code, if any, is a hex color.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidArgument` if the color code is not a hex color.
+    pub fn validate(&self) -> Result<()> {
+        validate_color_code(self.color_code.as_deref())
+    }
+}
+
+/// Checks that a bookmark color is a `#RGB` or `#RRGGBB` hex color.
+fn validate_color_code(color_code: Option<&str>) -> Result<()> {
+    let Some(color_code) = color_code else {
+        return Ok(());
+    };
+    let is_hex_color = color_code.strip_prefix('#').is_some_and(|digits| {
+        matches!(digits.len(), 3 | 6) && digits.chars().all(|c| c.is_ascii_hexdigit())
+    });
+    if is_hex_color {
+        Ok(())
+    } else {
+        Err(Error::InvalidArgument(format!(
+            "Invalid bookmark color {color_code}: it must be a hex color such as #FF0000"
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_bookmark_needs_one_position() {
+        let page = NewBookmark {
+            book_id: 1,
+            page_index: Some(3),
+            ..Default::default()
+        };
+        assert!(page.validate().is_ok());
+        let cfi = NewBookmark {
+            book_id: 1,
+            cfi: Some("epubcfi(/6/4!/4/2/1:0)".to_string()),
+            ..Default::default()
+        };
+        assert!(cfi.validate().is_ok());
+
+        let neither = NewBookmark {
+            book_id: 1,
+            ..Default::default()
+        };
+        assert!(matches!(neither.validate(), Err(Error::InvalidArgument(_))));
+        let both = NewBookmark {
+            page_index: Some(3),
+            ..cfi.clone()
+        };
+        assert!(matches!(both.validate(), Err(Error::InvalidArgument(_))));
+    }
+
+    #[test]
+    fn test_new_bookmark_rejects_invalid_position() {
+        let negative = NewBookmark {
+            book_id: 1,
+            page_index: Some(-1),
+            ..Default::default()
+        };
+        assert!(matches!(
+            negative.validate(),
+            Err(Error::InvalidArgument(_))
+        ));
+        let blank = NewBookmark {
+            book_id: 1,
+            cfi: Some("  ".to_string()),
+            ..Default::default()
+        };
+        assert!(matches!(blank.validate(), Err(Error::InvalidArgument(_))));
+    }
+
+    #[test]
+    fn test_bookmark_color_must_be_hex() {
+        let bookmark = |color_code: &str| NewBookmark {
+            book_id: 1,
+            page_index: Some(0),
+            color_code: Some(color_code.to_string()),
+            ..Default::default()
+        };
+        assert!(bookmark("#FF0000").validate().is_ok());
+        assert!(bookmark("#0f0").validate().is_ok());
+        for invalid in ["FF0000", "#FF00", "#GG0000", "red", ""] {
+            assert!(matches!(
+                bookmark(invalid).validate(),
+                Err(Error::InvalidArgument(_))
+            ));
+        }
+
+        let update = BookmarkUpdate {
+            color_code: Some("#12345".to_string()),
+            ..Default::default()
+        };
+        assert!(matches!(update.validate(), Err(Error::InvalidArgument(_))));
+        assert!(BookmarkUpdate::default().validate().is_ok());
+    }
+}
@@ -0,0 +1,78 @@
+use crate::error::Result;
+use async_trait::async_trait;
+
+use super::entity::{Bookmark, BookmarkUpdate, BookmarkWithBook, NewBookmark};
+
+/// Defines the data access operations for the `Bookmark` aggregate.
+///
+/// Bookmarks are deleted along with their book, but are kept when its reading history
+/// is cleared.
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait BookmarkRepository: Send + Sync {
+    /// Adds a bookmark and returns its complete entity.
+    ///
+    /// # Arguments
+    ///
+    /// * `bookmark` - The bookmark to add. It is expected to be valid (see
+    ///   [`NewBookmark::validate`]).
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the newly created `Bookmark` entity.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the database insertion fails (e.g., the book does not exist).
+    async fn add(&self, bookmark: &NewBookmark) -> Result<Bookmark>;
+
+    /// Retrieves the bookmarks of a specific book, in reading order: page bookmarks by
+    /// page, then novel bookmarks by their position in the book.
+    ///
+    /// # Arguments
+    ///
+    /// * `book_id` - The unique identifier of the book.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a vector of `Bookmark` entities.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the database query fails.
+    async fn get_for_book(&self, book_id: i64) -> Result<Vec<Bookmark>>;
+
+    /// Retrieves the bookmarks of every book in the library, most recently added first.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a vector of `BookmarkWithBook` entities.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the database query fails.
+    async fn get_all(&self) -> Result<Vec<BookmarkWithBook>>;
+
+    /// Changes the label, note and color of a bookmark.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the bookmark to update.
+    /// * `update` - The new label, note and color.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the bookmark does not exist or the database execution fails.
+    async fn update(&self, id: i64, update: &BookmarkUpdate) -> Result<()>;
+
+    /// Deletes a bookmark.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the bookmark to delete.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the bookmark does not exist or the database execution fails.
+    async fn delete(&self, id: i64) -> Result<()>;
+}
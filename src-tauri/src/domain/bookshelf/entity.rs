@@ -1,9 +1,11 @@
 use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
-use sqlx::FromRow;
+
+use crate::domain::book::entity::{ReadStatus, TagMatch};
+use crate::error::{Error, Result};
 
 /// Represents a bookshelf entity used to organize books.
-#[derive(Debug, Clone, Serialize, Deserialize, FromRow, specta::Type)]
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
 pub struct Bookshelf {
     /// The unique identifier for the bookshelf.
     pub id: i64,
@@ -13,4 +15,145 @@ pub struct Bookshelf {
     pub icon_id: String,
     /// The timestamp when the bookshelf was created.
     pub created_at: Option<NaiveDateTime>,
+    /// The rule selecting the books of a smart bookshelf, or `None` for a bookshelf
+    /// whose books are added by hand.
+    pub rule: Option<BookshelfRule>,
+}
+
+/// The saved rule of a smart bookshelf. The bookshelf holds every book meeting all of
+/// the conditions that are set. Conditions left out of the JSON are unset.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+#[serde(default, deny_unknown_fields)]
+pub struct BookshelfRule {
+    /// Only include books with these tags, as selected by `tag_match`.
+    pub tag_ids: Vec<i64>,
+    /// Whether a book needs all of `tag_ids` or any one of them.
+    pub tag_match: TagMatch,
+    /// Only include books in this series.
+    pub series_id: Option<i64>,
+    /// Only include books with this read status.
+    pub read_status: Option<ReadStatus>,
+    /// Only include books added to the library within this many days.
+    pub added_within_days: Option<u32>,
+    /// Only include books inside this folder. Whole path components are compared, so
+    /// "/comics" does not match "/comics2".
+    pub path_prefix: Option<String>,
+    /// Only include files with one of these extensions (e.g. "zip", "epub"), compared
+    /// case-insensitively.
+    pub formats: Vec<String>,
+}
+
+impl BookshelfRule {
+    /// Parses a rule saved as JSON and checks that it is valid.
+    ///
+    /// # Arguments
+    ///
+    /// * `json` - The saved rule.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::SerdeJson` if the JSON is not a rule (including unknown fields),
+    /// or `Error::InvalidArgument` if the rule fails [`BookshelfRule::validate`].
+    pub fn from_json(json: &str) -> Result<Self> {
+        let rule: Self = serde_json::from_str(json)?;
+        rule.validate()?;
+        Ok(rule)
+    }
+
+    /// Checks that every condition of the rule can match a book.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidArgument` if `added_within_days` is zero, `path_prefix` is
+    /// blank, or a format is not a plain file extension.
+    pub fn validate(&self) -> Result<()> {
+        if self.added_within_days == Some(0) {
+            return Err(Error::InvalidArgument(
+                "The number of days a smart bookshelf looks back must be at least 1".to_string(),
+            ));
+        }
+        if self
+            .path_prefix
+            .as_ref()
+            .is_some_and(|prefix| prefix.trim().is_empty())
+        {
+            return Err(Error::InvalidArgument(
+                "The path prefix of a smart bookshelf must not be empty".to_string(),
+            ));
+        }
+        if let Some(format) = self
+            .formats
+            .iter()
+            .find(|format| format.is_empty() || !format.chars().all(|c| c.is_ascii_alphanumeric()))
+        {
+            return Err(Error::InvalidArgument(format!(
+                "Invalid smart bookshelf format '{format}': expected a file extension such as 'zip'"
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ErrorCode;
+
+    #[test]
+    fn test_rule_from_json() {
+        let rule = BookshelfRule::from_json(
+            r#"{
+                "tag_ids": [1, 2],
+                "tag_match": "any",
+                "series_id": null,
+                "read_status": "unread",
+                "added_within_days": 30,
+                "path_prefix": "/comics",
+                "formats": ["cbz", "ZIP"]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(rule.tag_match, TagMatch::Any);
+        assert_eq!(rule.read_status, Some(ReadStatus::Unread));
+        assert_eq!(rule.formats, vec!["cbz", "ZIP"]);
+
+        let rule = BookshelfRule::from_json(r#"{"series_id": 3}"#).unwrap();
+        assert_eq!(
+            rule,
+            BookshelfRule {
+                series_id: Some(3),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_rule_from_json_rejects_malformed_rules() {
+        let code = |json: &str| ErrorCode::from(&BookshelfRule::from_json(json).unwrap_err());
+        assert_eq!(code("not json"), ErrorCode::SerdeJson);
+        assert_eq!(code(r#"{"tag_ids": "1"}"#), ErrorCode::SerdeJson);
+
+        let valid = serde_json::to_value(BookshelfRule::default()).unwrap();
+        let with = |key: &str, value: serde_json::Value| {
+            let mut json = valid.clone();
+            json[key] = value;
+            json.to_string()
+        };
+        assert_eq!(
+            code(&with("unknown", serde_json::json!(1))),
+            ErrorCode::SerdeJson
+        );
+        assert_eq!(
+            code(&with("added_within_days", serde_json::json!(0))),
+            ErrorCode::InvalidArgument
+        );
+        assert_eq!(
+            code(&with("path_prefix", serde_json::json!(" "))),
+            ErrorCode::InvalidArgument
+        );
+        assert_eq!(
+            code(&with("formats", serde_json::json!([".zip"]))),
+            ErrorCode::InvalidArgument
+        );
+    }
 }
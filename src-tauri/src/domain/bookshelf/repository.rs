@@ -2,7 +2,7 @@ use crate::domain::book::entity::BookWithState;
 use crate::error::Result;
 use async_trait::async_trait;
 
-use super::entity::Bookshelf;
+use super::entity::{Bookshelf, BookshelfRule};
 
 /// Defines the data access operations for the `Bookshelf` aggregate.
 #[cfg_attr(test, mockall::automock)]
@@ -35,8 +35,25 @@ pub trait BookshelfRepository: Send + Sync {
     /// Returns an `Err` if the database query fails.
     async fn get_all(&self) -> Result<Vec<Bookshelf>>;
 
+    /// Turns a bookshelf into a smart bookshelf with the given rule, or back into a
+    /// bookshelf whose books are added by hand.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the bookshelf.
+    /// * `rule` - The rule selecting the books, or `None` to use the books added by hand.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the database execution fails.
+    async fn set_rule(&self, id: i64, rule: Option<BookshelfRule>) -> Result<()>;
+
     /// Retrieves all books contained within a specific bookshelf, including their reading state.
     ///
+    /// The books of a smart bookshelf are the books matching its rule, most recently
+    /// added first; books added to it by hand are ignored. The books of other
+    /// bookshelves are the ones added to them, most recently added first.
+    ///
     /// # Arguments
     ///
     /// * `bookshelf_id` - The ID of the bookshelf to filter by.
@@ -47,7 +64,8 @@ pub trait BookshelfRepository: Send + Sync {
     ///
     /// # Errors
     ///
-    /// Returns an `Err` if the database query fails.
+    /// Returns an `Err` if the database query fails, or if the saved rule of a smart
+    /// bookshelf is not a valid rule.
     async fn get_books_by_bookshelf(&self, bookshelf_id: i64) -> Result<Vec<BookWithState>>;
 
     /// Adds a book to a specific bookshelf.
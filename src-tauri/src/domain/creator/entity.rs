@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+/// Represents a person credited for books (an author, illustrator or translator).
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize, specta::Type)]
+pub struct Creator {
+    /// The unique identifier for the creator.
+    pub id: i64,
+    /// The unique name of the creator.
+    pub name: String,
+    /// The number of books the creator is credited for.
+    pub book_count: i64,
+}
+
+/// The part a creator had in making a book.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, specta::Type)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(type_name = "TEXT", rename_all = "snake_case")]
+pub enum CreatorRole {
+    /// Wrote the book.
+    Author,
+    /// Drew the art or illustrations.
+    Illustrator,
+    /// Translated the book.
+    Translator,
+}
+
+/// A creator credited in the metadata of a book file, before it is stored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreatorCredit {
+    /// The name of the creator, as the file spells it.
+    pub name: String,
+    /// The part the creator had in making the book.
+    pub role: CreatorRole,
+}
+
+impl CreatorCredit {
+    /// Creates a credit for the named creator.
+    pub fn new(name: &str, role: CreatorRole) -> Self {
+        Self {
+            name: name.to_string(),
+            role,
+        }
+    }
+}
+
+/// A creator credited for a specific book, with their role on it.
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize, specta::Type)]
+pub struct BookCreator {
+    /// The unique identifier of the creator.
+    pub creator_id: i64,
+    /// The name of the creator.
+    pub name: String,
+    /// The part the creator had in making the book.
+    pub role: CreatorRole,
+}
@@ -0,0 +1,82 @@
+use crate::domain::book::entity::BookWithState;
+use crate::error::Result;
+use async_trait::async_trait;
+
+use super::entity::{BookCreator, Creator};
+
+/// Defines the data access operations for the `Creator` aggregate.
+///
+/// Creators are added as the metadata of book files is stored (see
+/// `BookRepository::set_metadata`), so there is no operation to create one directly.
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait CreatorRepository: Send + Sync {
+    /// Retrieves every creator credited for at least one book, ordered by name.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a vector of `Creator` entities.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the database query fails.
+    async fn get_all(&self) -> Result<Vec<Creator>>;
+
+    /// Retrieves the creators of a specific book, in the order its metadata lists them.
+    ///
+    /// # Arguments
+    ///
+    /// * `book_id` - The unique identifier of the book.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the creators and their roles on the book.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the database query fails.
+    async fn get_creators_for_book(&self, book_id: i64) -> Result<Vec<BookCreator>>;
+
+    /// Retrieves all books a specific creator is credited for, in any role, including
+    /// their reading state.
+    ///
+    /// # Arguments
+    ///
+    /// * `creator_id` - The ID of the creator to filter by.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a vector of `BookWithState` entities.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the database query fails.
+    async fn get_books_by_creator(&self, creator_id: i64) -> Result<Vec<BookWithState>>;
+
+    /// Renames a creator. The former name is kept as an alias, so book files still
+    /// spelling the creator that way are credited to the renamed creator.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the creator to rename.
+    /// * `name` - The new name.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidArgument` if another creator already has the name (merge
+    /// them instead), or an `Err` if the database execution fails.
+    async fn rename(&self, id: i64, name: &str) -> Result<()>;
+
+    /// Merges creators into another one, which takes over their books. The names of the
+    /// merged creators are kept as aliases of the remaining one.
+    ///
+    /// # Arguments
+    ///
+    /// * `target_id` - The ID of the creator to keep.
+    /// * `source_ids` - The IDs of the creators to merge into it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the database execution fails.
+    async fn merge(&self, target_id: i64, source_ids: &[i64]) -> Result<()>;
+}
@@ -0,0 +1,30 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+/// Represents a folder watched for books.
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize, specta::Type)]
+pub struct LibraryRoot {
+    /// The unique identifier for the library root.
+    pub id: i64,
+    /// The unique path of the watched folder.
+    pub path: String,
+    /// The timestamp when the folder was added to the library.
+    pub created_at: NaiveDateTime,
+    /// The timestamp when the folder was last scanned completely, if ever.
+    pub last_scanned_at: Option<NaiveDateTime>,
+}
+
+/// Represents a book file as the library scanner last indexed it.
+#[derive(Debug, Clone, PartialEq, Eq, FromRow)]
+pub struct IndexedBook {
+    /// The unique identifier for the book.
+    pub id: i64,
+    /// The unique file path of the book.
+    pub file_path: String,
+    /// The modification time (seconds since the Unix epoch) of the file when it was last
+    /// indexed, or `None` if the scanner never indexed it.
+    pub file_modified_at: Option<i64>,
+    /// Whether the scanner found the file gone.
+    pub missing: bool,
+}
@@ -0,0 +1,132 @@
+use crate::error::Result;
+use async_trait::async_trait;
+
+use super::entity::{IndexedBook, LibraryRoot};
+
+/// Defines the data access operations for library roots and the book files found in
+/// them.
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait LibraryRepository: Send + Sync {
+    /// Adds a folder to the library.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the folder. Must be unique.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the ID of the new library root.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the database insertion fails (e.g., UNIQUE constraint violation).
+    async fn add_root(&self, path: &str) -> Result<i64>;
+
+    /// Retrieves all library roots, in the order they were added.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a vector of `LibraryRoot` entities.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the database query fails.
+    async fn get_roots(&self) -> Result<Vec<LibraryRoot>>;
+
+    /// Retrieves a library root by its unique ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The unique identifier of the library root.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing an `Option<LibraryRoot>`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the database query fails.
+    async fn get_root(&self, id: i64) -> Result<Option<LibraryRoot>>;
+
+    /// Removes a folder from the library.
+    ///
+    /// The books found in the folder stay in the library.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The unique identifier of the library root to remove.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating the success of the operation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the database execution fails.
+    async fn delete_root(&self, id: i64) -> Result<()>;
+
+    /// Records that a library root was scanned completely just now.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The unique identifier of the library root.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating the success of the operation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the database execution fails.
+    async fn mark_root_scanned(&self, id: i64) -> Result<()>;
+
+    /// Retrieves the book files at or below a path.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of a folder or a single file.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `IndexedBook`s whose file path is `path` or lies inside
+    /// it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the database query fails.
+    async fn get_indexed_books(&self, path: &str) -> Result<Vec<IndexedBook>>;
+
+    /// Records that a book file was indexed, clearing its missing flag.
+    ///
+    /// # Arguments
+    ///
+    /// * `book_id` - The unique identifier of the book.
+    /// * `file_modified_at` - The modification time of the indexed file, in seconds
+    ///   since the Unix epoch.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating the success of the operation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the database execution fails.
+    async fn set_file_indexed(&self, book_id: i64, file_modified_at: Option<i64>) -> Result<()>;
+
+    /// Flags a book whose file is gone. A book already flagged keeps the time it went
+    /// missing.
+    ///
+    /// # Arguments
+    ///
+    /// * `book_id` - The unique identifier of the book.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating the success of the operation.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the database execution fails.
+    async fn mark_missing(&self, book_id: i64) -> Result<()>;
+}
@@ -1,4 +1,9 @@
+pub mod annotation;
 pub mod book;
+pub mod bookmark;
 pub mod bookshelf;
+pub mod creator;
+pub mod library;
+pub mod reading_session;
 pub mod series;
 pub mod tag;
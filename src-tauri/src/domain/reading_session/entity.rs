@@ -0,0 +1,170 @@
+use chrono::{NaiveDate, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+/// A stretch of time a book was read without a long break.
+///
+/// A session starts when the book is opened or a page is turned after a break, and
+/// grows as pages keep being turned.
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize, specta::Type)]
+pub struct ReadingSession {
+    /// The unique identifier for the session.
+    pub id: i64,
+    /// The identifier of the book that was read.
+    pub book_id: i64,
+    /// When the session started.
+    pub started_at: NaiveDateTime,
+    /// When a page was last turned in the session.
+    pub ended_at: NaiveDateTime,
+    /// The page index the session started on.
+    pub start_page: i64,
+    /// The page index the session ended on.
+    pub end_page: i64,
+    /// The number of pages read forward in the session. Paging back is not counted.
+    pub pages_turned: i64,
+}
+
+/// The reading done on one day.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct DailyReading {
+    /// The local date.
+    pub date: NaiveDate,
+    /// The time spent reading, in seconds.
+    pub seconds_read: i64,
+    /// The number of pages read.
+    pub pages_read: i64,
+}
+
+/// The number of books finished in one month.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct MonthlyFinished {
+    /// The year, e.g. 2026.
+    pub year: i32,
+    /// The month, from 1 to 12.
+    pub month: u32,
+    /// The number of books finished in the month.
+    pub books_finished: i64,
+}
+
+/// The reading done in the books of one series.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct SeriesReading {
+    /// The identifier of the series.
+    pub series_id: i64,
+    /// The name of the series.
+    pub series_name: String,
+    /// The time spent reading the series, in seconds.
+    pub seconds_read: i64,
+    /// The number of pages read in the series.
+    pub pages_read: i64,
+    /// The number of books of the series finished.
+    pub books_finished: i64,
+}
+
+/// A summary of the reading done over a period, e.g. for a yearly reading report.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct ReadingStatistics {
+    /// The time spent reading in the period, in seconds.
+    pub total_seconds_read: i64,
+    /// The number of pages read in the period.
+    pub total_pages_read: i64,
+    /// The number of books finished in the period.
+    pub total_books_finished: i64,
+    /// The number of days in a row, up to today, with some reading. Reading yesterday
+    /// keeps the streak going until the end of today.
+    pub current_streak_days: i64,
+    /// The most days in a row with some reading, at any time.
+    pub longest_streak_days: i64,
+    /// The reading done on each day of the period with some reading, in date order.
+    pub daily: Vec<DailyReading>,
+    /// The books finished in each month of the period with a finished book, in date
+    /// order.
+    pub monthly_finished: Vec<MonthlyFinished>,
+    /// The reading done in each series during the period, most time read first.
+    pub series: Vec<SeriesReading>,
+}
+
+/// Returns the most consecutive days in `days`.
+///
+/// # Arguments
+///
+/// * `days` - The days with some reading, in ascending order without duplicates.
+pub fn longest_streak(days: &[NaiveDate]) -> i64 {
+    let mut longest = 0;
+    let mut current = 0;
+    let mut previous: Option<NaiveDate> = None;
+    for &day in days {
+        current = match previous {
+            Some(previous) if previous.succ_opt() == Some(day) => current + 1,
+            _ => 1,
+        };
+        longest = longest.max(current);
+        previous = Some(day);
+    }
+    longest
+}
+
+/// Returns the number of consecutive days in `days` ending today, or ending yesterday
+/// if there was no reading today yet.
+///
+/// # Arguments
+///
+/// * `days` - The days with some reading, in ascending order without duplicates.
+/// * `today` - The current local date.
+pub fn current_streak(days: &[NaiveDate], today: NaiveDate) -> i64 {
+    let mut expected = match days.last() {
+        Some(&last) if last == today || today.pred_opt() == Some(last) => last,
+        _ => return 0,
+    };
+    let mut streak = 0;
+    for &day in days.iter().rev() {
+        if day != expected {
+            break;
+        }
+        streak += 1;
+        match day.pred_opt() {
+            Some(previous) => expected = previous,
+            None => break,
+        }
+    }
+    streak
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 3, d).unwrap()
+    }
+
+    #[test]
+    fn test_longest_streak() {
+        assert_eq!(longest_streak(&[]), 0);
+        assert_eq!(longest_streak(&[day(1)]), 1);
+        assert_eq!(
+            longest_streak(&[day(1), day(2), day(4), day(5), day(6), day(8)]),
+            3
+        );
+    }
+
+    #[test]
+    fn test_longest_streak_across_months() {
+        let days = [
+            NaiveDate::from_ymd_opt(2026, 2, 27).unwrap(),
+            NaiveDate::from_ymd_opt(2026, 2, 28).unwrap(),
+            day(1),
+        ];
+        assert_eq!(longest_streak(&days), 3);
+    }
+
+    #[test]
+    fn test_current_streak() {
+        let days = [day(1), day(3), day(4), day(5)];
+        assert_eq!(current_streak(&days, day(5)), 3);
+        // Not having read yet today keeps yesterday's streak.
+        assert_eq!(current_streak(&days, day(6)), 3);
+        assert_eq!(current_streak(&days, day(7)), 0);
+        assert_eq!(current_streak(&[], day(5)), 0);
+    }
+}
@@ -0,0 +1,57 @@
+use crate::error::Result;
+use async_trait::async_trait;
+use chrono::{FixedOffset, NaiveDate};
+
+use super::entity::{ReadingSession, ReadingStatistics};
+
+/// Defines the data access operations for reading sessions and the statistics built
+/// from them.
+///
+/// Sessions are recorded as books are opened and read (see
+/// `BookRepository::record_book_opened` and `BookRepository::update_reading_progress`),
+/// so there is no operation to add one directly.
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait ReadingSessionRepository: Send + Sync {
+    /// Retrieves the reading sessions of a specific book, most recent first.
+    ///
+    /// # Arguments
+    ///
+    /// * `book_id` - The unique identifier of the book.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a vector of `ReadingSession` entities.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the database query fails.
+    async fn get_sessions_for_book(&self, book_id: i64) -> Result<Vec<ReadingSession>>;
+
+    /// Summarizes the reading done between two local dates.
+    ///
+    /// Sessions are counted on the local day they started, in the UTC offset they were
+    /// recorded at, so a daylight saving change does not move past sessions to another
+    /// day. Streaks look at all recorded sessions, not only those of the period.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The first local date of the period.
+    /// * `to` - The last local date of the period, inclusive.
+    /// * `utc_offset` - The offset of the local time zone from UTC, used for the sessions
+    ///   and finish times recorded without their own offset.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `ReadingStatistics` of the period.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the database query fails.
+    async fn get_statistics(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+        utc_offset: FixedOffset,
+    ) -> Result<ReadingStatistics>;
+}
@@ -2,6 +2,8 @@ use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 
+use crate::natural_sort;
+
 /// Represents a series entity that groups multiple books together.
 #[derive(Debug, Clone, FromRow, Serialize, Deserialize, specta::Type)]
 pub struct Series {
@@ -9,6 +11,22 @@ pub struct Series {
     pub id: i64,
     /// The unique name of the series.
     pub name: String,
+    /// The key the name sorts by in natural order (see `natural_sort::sort_key`).
+    #[serde(default)]
+    #[sqlx(skip)]
+    pub sort_key: String,
     /// The timestamp when the series was created.
     pub created_at: NaiveDateTime,
 }
+
+impl Series {
+    /// Creates a series entity, deriving its sort key from the name.
+    pub fn new(id: i64, name: String, created_at: NaiveDateTime) -> Self {
+        Self {
+            id,
+            sort_key: natural_sort::sort_key(&name),
+            name,
+            created_at,
+        }
+    }
+}
@@ -39,9 +39,16 @@ pub enum Error {
     /// An error originating from the `unrar` library.
     #[error("Unrar Error: {0}")]
     Unrar(#[from] UnrarError),
+    /// An error for a multi-volume RAR set with a volume missing.
+    #[error("Missing Volume Error: {0}")]
+    MissingVolume(String),
     /// An error originating from the `zip` crate.
     #[error("Zip Error: {0}")]
     Zip(#[from] ZipError),
+    /// An error for an archive that is damaged (e.g. truncated) and could only be read
+    /// partially.
+    #[error("Damaged Archive Error: {0}")]
+    DamagedArchive(String),
     /// An error originating from the `rbook` (EPUB) library.
     #[error("Epub Error: {0}")]
     Epub(#[from] EbookError),
@@ -56,6 +63,9 @@ pub enum Error {
     /// An error related to file paths (e.g., invalid format).
     #[error("Path Error: {0}")]
     Path(String),
+    /// An error originating from the `notify` file-system watcher.
+    #[error("Watch Error: {0}")]
+    Watch(#[from] notify_debouncer_full::notify::Error),
 
     // 3xxxx: Application Framework
     /// An error originating from the Tauri framework itself.
@@ -89,6 +99,9 @@ pub enum Error {
     SettingsValidation(Vec<crate::settings::SettingsValidationViolation>),
 
     // 6xxxx: Application Logic & State
+    /// An error for a request argument outside its valid range (e.g., a tile outside the page).
+    #[error("Invalid Argument Error: {0}")]
+    InvalidArgument(String),
 
     // 7xxxx: Database
     /// An error related to database operations.
@@ -123,13 +136,16 @@ impl ErrorCode {
             ErrorCode::EntryNotFound => 10002,
             ErrorCode::Pdfium => 10101,
             ErrorCode::Unrar => 10301,
+            ErrorCode::MissingVolume => 10302,
             ErrorCode::Zip => 10401,
+            ErrorCode::DamagedArchive => 10402,
             ErrorCode::Epub => 10501,
             ErrorCode::EpubArchive => 10502,
 
             // 2xxxx: File System & I/O
             ErrorCode::Io => 20001,
             ErrorCode::Path => 20101,
+            ErrorCode::Watch => 20201,
 
             // 3xxxx: Application Framework
             ErrorCode::Tauri => 30001,
@@ -145,6 +161,7 @@ impl ErrorCode {
             ErrorCode::SettingsValidation => 50002,
 
             // 6xxxx: Application Logic & State
+            ErrorCode::InvalidArgument => 60001,
 
             // 7xxxx: Database
             ErrorCode::Database => 70001,
@@ -0,0 +1,247 @@
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, HashSet},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::image::loader::Cache;
+
+/// A snapshot of the global image cache.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "camelCase")]
+pub struct CacheStats {
+    /// The configured capacity of the cache in bytes.
+    pub capacity_bytes: u64,
+    /// The total size in bytes of the cached images.
+    pub resident_bytes: u64,
+    /// The number of cached images.
+    pub entry_count: u64,
+    /// The cached images per book, largest first.
+    pub books: Vec<BookCacheStats>,
+    /// How the open book has used the cache, or `None` if no book is open.
+    pub open_book: Option<CacheUsage>,
+    /// How many times the cache was shrunk because available memory ran low.
+    pub memory_pressure_shrinks: u64,
+}
+
+impl CacheStats {
+    /// Collects the resident entries of `cache`, grouped per book.
+    ///
+    /// # Arguments
+    ///
+    /// * `cache` - The global image cache.
+    /// * `capacity_bytes` - The configured capacity of the cache.
+    /// * `open_book` - The usage counters of the open book, if any.
+    /// * `memory_pressure_shrinks` - How many times the cache was shrunk so far.
+    pub fn collect(
+        cache: &Cache,
+        capacity_bytes: u64,
+        open_book: Option<CacheUsage>,
+        memory_pressure_shrinks: u64,
+    ) -> Self {
+        let mut books: BTreeMap<String, BookCacheStats> = BTreeMap::new();
+        for entry in cache.iter() {
+            let book = books
+                .entry(entry.key().book_id.clone())
+                .or_insert_with(|| BookCacheStats {
+                    book_id: entry.key().book_id.clone(),
+                    entry_count: 0,
+                    bytes: 0,
+                });
+            book.entry_count += 1;
+            book.bytes += entry.value().data.len() as u64;
+        }
+
+        let mut books: Vec<_> = books.into_values().collect();
+        books.sort_by_key(|book| Reverse(book.bytes));
+
+        Self {
+            capacity_bytes,
+            resident_bytes: books.iter().map(|book| book.bytes).sum(),
+            entry_count: books.iter().map(|book| book.entry_count).sum(),
+            books,
+            open_book,
+            memory_pressure_shrinks,
+        }
+    }
+}
+
+/// The cached images of one book.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "camelCase")]
+pub struct BookCacheStats {
+    /// The identifier (path) of the book.
+    pub book_id: String,
+    /// The number of cached images of the book.
+    pub entry_count: u64,
+    /// The total size in bytes of the cached images of the book.
+    pub bytes: u64,
+}
+
+/// How one book has used the image cache since it was opened.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "camelCase")]
+pub struct CacheUsage {
+    /// The identifier (path) of the book.
+    pub book_id: String,
+    /// The number of page requests served from the cache.
+    pub hits: u64,
+    /// The number of page requests that had to load the page.
+    pub misses: u64,
+    /// The share of page requests served from the cache (`0.0` when none were made).
+    pub hit_rate: f64,
+    /// The number of pages loaded ahead by preloading.
+    pub preloaded_pages: u64,
+    /// The number of preloaded pages that were requested afterwards.
+    pub preloaded_pages_used: u64,
+    /// The number of preloaded pages evicted before they were ever requested.
+    pub preloaded_pages_wasted: u64,
+}
+
+/// Counts the cache hits, misses and preloads of one `ImageLoader`.
+#[derive(Default)]
+pub struct CacheCounters {
+    hits: AtomicU64,
+    misses: AtomicU64,
+    preloaded: AtomicU64,
+    preloaded_used: AtomicU64,
+    preloaded_wasted: AtomicU64,
+    /// The preloaded entries that have not been requested yet.
+    pending_preloads: Mutex<HashSet<String>>,
+}
+
+impl CacheCounters {
+    /// Records a page request for `entry` served from the cache.
+    pub fn record_hit(&self, entry: &str) {
+        self.hits.fetch_add(1, Ordering::Relaxed);
+        if self.pending().remove(entry) {
+            self.preloaded_used.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Records a page request that had to load its page.
+    pub fn record_miss(&self) {
+        self.misses.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Records that `entry` was loaded ahead by preloading.
+    pub fn record_preloaded(&self, entry: &str) {
+        self.preloaded.fetch_add(1, Ordering::Relaxed);
+        self.pending().insert(entry.to_string());
+    }
+
+    /// Returns the usage so far.
+    ///
+    /// # Arguments
+    ///
+    /// * `book_id` - The identifier of the book the counters belong to.
+    /// * `is_cached` - Whether an entry is still in the cache; preloaded entries that
+    ///   are not, and were never requested, are counted as wasted.
+    pub fn usage(&self, book_id: &str, is_cached: impl Fn(&str) -> bool) -> CacheUsage {
+        self.pending().retain(|entry| {
+            let cached = is_cached(entry);
+            if !cached {
+                self.preloaded_wasted.fetch_add(1, Ordering::Relaxed);
+            }
+            cached
+        });
+
+        let hits = self.hits.load(Ordering::Relaxed);
+        let misses = self.misses.load(Ordering::Relaxed);
+        let requests = hits + misses;
+        CacheUsage {
+            book_id: book_id.to_string(),
+            hits,
+            misses,
+            hit_rate: if requests == 0 {
+                0.0
+            } else {
+                hits as f64 / requests as f64
+            },
+            preloaded_pages: self.preloaded.load(Ordering::Relaxed),
+            preloaded_pages_used: self.preloaded_used.load(Ordering::Relaxed),
+            preloaded_pages_wasted: self.preloaded_wasted.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Locks the pending preloads, recovering a poisoned lock (the set stays valid).
+    fn pending(&self) -> std::sync::MutexGuard<'_, HashSet<String>> {
+        self.pending_preloads
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::image::{loader::CacheKey, types::Image};
+
+    #[test]
+    fn test_cache_counters_usage() {
+        let counters = CacheCounters::default();
+        counters.record_preloaded("p1.png");
+        counters.record_preloaded("p2.png");
+        counters.record_preloaded("p3.png");
+        counters.record_miss();
+        counters.record_hit("p1.png");
+        counters.record_hit("p1.png");
+
+        // p2.png was evicted before being requested; p3.png is still cached.
+        let usage = counters.usage("book", |entry| entry != "p2.png");
+
+        assert_eq!(usage.hits, 2);
+        assert_eq!(usage.misses, 1);
+        assert!((usage.hit_rate - 2.0 / 3.0).abs() < f64::EPSILON);
+        assert_eq!(usage.preloaded_pages, 3);
+        assert_eq!(usage.preloaded_pages_used, 1);
+        assert_eq!(usage.preloaded_pages_wasted, 1);
+
+        // A wasted page is counted once.
+        let usage = counters.usage("book", |entry| entry != "p2.png");
+        assert_eq!(usage.preloaded_pages_wasted, 1);
+    }
+
+    #[test]
+    fn test_cache_stats_groups_entries_per_book() {
+        let cache: Cache = mini_moka::sync::Cache::new(100);
+        let image = |size: usize| {
+            Arc::new(Image {
+                data: vec![0; size],
+                width: 1,
+                height: 1,
+            })
+        };
+        cache.insert(CacheKey::page("a.zip", "p1.png"), image(10));
+        cache.insert(CacheKey::page("b.zip", "p1.png"), image(30));
+        cache.insert(CacheKey::page("b.zip", "p2.png"), image(20));
+
+        let stats = CacheStats::collect(&cache, 1024, None, 0);
+
+        assert_eq!(stats.capacity_bytes, 1024);
+        assert_eq!(stats.resident_bytes, 60);
+        assert_eq!(stats.entry_count, 3);
+        assert_eq!(
+            stats.books,
+            vec![
+                BookCacheStats {
+                    book_id: "b.zip".to_string(),
+                    entry_count: 2,
+                    bytes: 50,
+                },
+                BookCacheStats {
+                    book_id: "a.zip".to_string(),
+                    entry_count: 1,
+                    bytes: 10,
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,311 @@
+use std::io::Cursor;
+
+use image::{
+    codecs::webp::WebPEncoder,
+    error::{EncodingError, ImageError},
+    DynamicImage, ImageFormat, RgbImage,
+};
+use jpeg_encoder::{ColorType, Encoder, SamplingFactor};
+
+use crate::error::{Error, Result};
+
+/// The JPEG quality used when re-encoding opaque images for display.
+const DISPLAY_JPEG_QUALITY: u8 = 80;
+
+/// The JPEG quality of generated thumbnails, kept low so they stay small and fast to
+/// encode.
+const THUMBNAIL_JPEG_QUALITY: u8 = 10;
+
+/// The image format re-encoded renditions (resized pages, tiles, strip segments) are
+/// written in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum OutputFormat {
+    /// JPEG, falling back to PNG for images JPEG cannot hold (transparency, or an
+    /// edge above 65535 pixels).
+    #[default]
+    Jpeg,
+    /// Lossless WebP.
+    WebpLossless,
+    /// PNG.
+    Png,
+    /// The format of the source image, see [`EncodeOptions::for_source`].
+    Passthrough,
+}
+
+impl From<crate::settings::ImageOutputFormat> for OutputFormat {
+    fn from(format: crate::settings::ImageOutputFormat) -> Self {
+        use crate::settings::ImageOutputFormat;
+        match format {
+            ImageOutputFormat::Jpeg => Self::Jpeg,
+            ImageOutputFormat::WebpLossless => Self::WebpLossless,
+            ImageOutputFormat::Png => Self::Png,
+            ImageOutputFormat::Passthrough => Self::Passthrough,
+        }
+    }
+}
+
+/// The chroma subsampling of JPEG output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Subsampling {
+    /// Full-resolution chroma; keeps fine screentone and colored line art intact.
+    Yuv444,
+    /// Chroma halved horizontally.
+    Yuv422,
+    /// Chroma halved in both directions; the smallest output.
+    #[default]
+    Yuv420,
+}
+
+impl From<crate::settings::ChromaSubsampling> for Subsampling {
+    fn from(subsampling: crate::settings::ChromaSubsampling) -> Self {
+        use crate::settings::ChromaSubsampling;
+        match subsampling {
+            ChromaSubsampling::Yuv444 => Self::Yuv444,
+            ChromaSubsampling::Yuv422 => Self::Yuv422,
+            ChromaSubsampling::Yuv420 => Self::Yuv420,
+        }
+    }
+}
+
+impl From<Subsampling> for SamplingFactor {
+    fn from(subsampling: Subsampling) -> Self {
+        match subsampling {
+            Subsampling::Yuv444 => SamplingFactor::F_1_1,
+            Subsampling::Yuv422 => SamplingFactor::F_2_1,
+            Subsampling::Yuv420 => SamplingFactor::F_2_2,
+        }
+    }
+}
+
+/// How re-encoded renditions are written.
+///
+/// Part of every image cache key, so changing the options never serves a rendition
+/// encoded with the previous ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EncodeOptions {
+    /// The output format.
+    pub format: OutputFormat,
+    /// The JPEG quality (`1`-`100`).
+    pub quality: u8,
+    /// The JPEG chroma subsampling.
+    pub subsampling: Subsampling,
+}
+
+impl Default for EncodeOptions {
+    fn default() -> Self {
+        Self {
+            format: OutputFormat::default(),
+            quality: DISPLAY_JPEG_QUALITY,
+            subsampling: Subsampling::default(),
+        }
+    }
+}
+
+impl EncodeOptions {
+    /// The options thumbnails are written with.
+    pub const THUMBNAIL: Self = Self {
+        format: OutputFormat::Jpeg,
+        quality: THUMBNAIL_JPEG_QUALITY,
+        subsampling: Subsampling::Yuv420,
+    };
+
+    /// Resolves [`OutputFormat::Passthrough`] to the format of the source image.
+    ///
+    /// JPEG and WebP sources keep their format (WebP is written losslessly); every
+    /// other source, or an unknown one, is written as PNG so no detail is lost.
+    pub fn for_source(self, source: Option<ImageFormat>) -> Self {
+        if self.format != OutputFormat::Passthrough {
+            return self;
+        }
+        let format = match source {
+            Some(ImageFormat::Jpeg) => OutputFormat::Jpeg,
+            Some(ImageFormat::WebP) => OutputFormat::WebpLossless,
+            _ => OutputFormat::Png,
+        };
+        Self { format, ..self }
+    }
+}
+
+/// Encodes a decoded image for transfer to the frontend.
+///
+/// An unresolved [`OutputFormat::Passthrough`] is written as PNG; resolve it with
+/// [`EncodeOptions::for_source`] first to keep the source format.
+///
+/// # Arguments
+///
+/// * `image` - The decoded image to encode.
+/// * `options` - The output format, quality and chroma subsampling.
+///
+/// # Returns
+///
+/// A `Result` containing the encoded image bytes.
+///
+/// # Errors
+///
+/// Returns an `Err` if encoding fails.
+pub fn encode_for_display(image: &DynamicImage, options: EncodeOptions) -> Result<Vec<u8>> {
+    let mut buffer = Vec::new();
+    match options.format {
+        OutputFormat::Jpeg if fits_jpeg(image) => {
+            write_jpeg(&image.to_rgb8(), options, &mut buffer)?
+        }
+        OutputFormat::WebpLossless => {
+            // The WebP encoder only takes 8-bit RGB(A).
+            let image = if image.color().has_alpha() {
+                DynamicImage::ImageRgba8(image.to_rgba8())
+            } else {
+                DynamicImage::ImageRgb8(image.to_rgb8())
+            };
+            image.write_with_encoder(WebPEncoder::new_lossless(&mut buffer))?;
+        }
+        _ => image.write_to(&mut Cursor::new(&mut buffer), ImageFormat::Png)?,
+    }
+    Ok(buffer)
+}
+
+/// Returns whether `image` can be written as JPEG, which has no alpha channel and
+/// 16-bit dimensions.
+fn fits_jpeg(image: &DynamicImage) -> bool {
+    let max = u32::from(u16::MAX);
+    !image.color().has_alpha() && image.width() <= max && image.height() <= max
+}
+
+/// Encodes `image` as JPEG with the quality and chroma subsampling of `options`,
+/// whatever its format, dropping any alpha channel.
+///
+/// # Errors
+///
+/// Returns an `Err` if an edge of the image exceeds 65535 pixels or encoding fails.
+pub fn encode_jpeg(image: &DynamicImage, options: EncodeOptions) -> Result<Vec<u8>> {
+    let mut buffer = Vec::new();
+    write_jpeg(&image.to_rgb8(), options, &mut buffer)?;
+    Ok(buffer)
+}
+
+/// Writes `rgb` as JPEG with the quality and chroma subsampling of `options`.
+fn write_jpeg(rgb: &RgbImage, options: EncodeOptions, buffer: &mut Vec<u8>) -> Result<()> {
+    let (Ok(width), Ok(height)) = (u16::try_from(rgb.width()), u16::try_from(rgb.height())) else {
+        return Err(Error::InvalidArgument(format!(
+            "A {}x{} image is too large for JPEG",
+            rgb.width(),
+            rgb.height()
+        )));
+    };
+    let mut encoder = Encoder::new(buffer, options.quality.clamp(1, 100));
+    encoder.set_sampling_factor(options.subsampling.into());
+    encoder
+        .encode(rgb.as_raw(), width, height, ColorType::Rgb)
+        .map_err(|e| ImageError::Encoding(EncodingError::new(ImageFormat::Jpeg.into(), e)))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{guess_format, RgbaImage};
+    use rstest::rstest;
+
+    fn opaque() -> DynamicImage {
+        // Alternating columns: chroma detail that subsampling visibly averages out.
+        DynamicImage::ImageRgb8(RgbImage::from_fn(64, 64, |x, _| {
+            if x % 2 == 0 {
+                image::Rgb([255, 0, 0])
+            } else {
+                image::Rgb([0, 0, 255])
+            }
+        }))
+    }
+
+    #[test]
+    fn test_encode_for_display_alpha_as_png() {
+        let image = DynamicImage::ImageRgba8(RgbaImage::new(4, 4));
+        let data = encode_for_display(&image, EncodeOptions::default()).unwrap();
+        assert_eq!(guess_format(&data).unwrap(), ImageFormat::Png);
+    }
+
+    #[test]
+    fn test_encode_for_display_opaque_as_jpeg() {
+        let image = DynamicImage::ImageRgb8(RgbImage::new(4, 4));
+        let data = encode_for_display(&image, EncodeOptions::default()).unwrap();
+        assert_eq!(guess_format(&data).unwrap(), ImageFormat::Jpeg);
+    }
+
+    #[rstest]
+    #[case(OutputFormat::Jpeg, ImageFormat::Jpeg)]
+    #[case(OutputFormat::WebpLossless, ImageFormat::WebP)]
+    #[case(OutputFormat::Png, ImageFormat::Png)]
+    #[case(OutputFormat::Passthrough, ImageFormat::Png)]
+    fn test_encode_for_display_format(#[case] format: OutputFormat, #[case] expected: ImageFormat) {
+        let options = EncodeOptions {
+            format,
+            ..EncodeOptions::default()
+        };
+        let data = encode_for_display(&opaque(), options).unwrap();
+        assert_eq!(guess_format(&data).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_encode_for_display_webp_is_lossless() {
+        let source = opaque();
+        let options = EncodeOptions {
+            format: OutputFormat::WebpLossless,
+            ..EncodeOptions::default()
+        };
+        let data = encode_for_display(&source, options).unwrap();
+        let decoded = image::load_from_memory(&data).unwrap();
+        assert_eq!(decoded.to_rgb8(), source.to_rgb8());
+    }
+
+    #[test]
+    fn test_encode_for_display_quality_and_subsampling() {
+        let encode = |quality, subsampling| {
+            let options = EncodeOptions {
+                format: OutputFormat::Jpeg,
+                quality,
+                subsampling,
+            };
+            encode_for_display(&opaque(), options).unwrap().len()
+        };
+
+        assert!(encode(95, Subsampling::Yuv444) > encode(30, Subsampling::Yuv444));
+        assert!(encode(80, Subsampling::Yuv444) > encode(80, Subsampling::Yuv420));
+    }
+
+    #[test]
+    fn test_encode_jpeg_drops_alpha() {
+        let image = DynamicImage::ImageRgba8(RgbaImage::new(4, 4));
+        let data = encode_jpeg(&image, EncodeOptions::THUMBNAIL).unwrap();
+        assert_eq!(guess_format(&data).unwrap(), ImageFormat::Jpeg);
+    }
+
+    #[test]
+    fn test_encode_options_default() {
+        let options = EncodeOptions::default();
+        assert_eq!(options.format, OutputFormat::Jpeg);
+        assert_eq!(options.quality, 80);
+        assert_eq!(options.subsampling, Subsampling::Yuv420);
+    }
+
+    #[rstest]
+    #[case(Some(ImageFormat::Jpeg), OutputFormat::Jpeg)]
+    #[case(Some(ImageFormat::WebP), OutputFormat::WebpLossless)]
+    #[case(Some(ImageFormat::Gif), OutputFormat::Png)]
+    #[case(None, OutputFormat::Png)]
+    fn test_encode_options_for_source(
+        #[case] source: Option<ImageFormat>,
+        #[case] expected: OutputFormat,
+    ) {
+        let passthrough = EncodeOptions {
+            format: OutputFormat::Passthrough,
+            ..EncodeOptions::default()
+        };
+        assert_eq!(passthrough.for_source(source).format, expected);
+
+        // Explicit formats are kept whatever the source.
+        let png = EncodeOptions {
+            format: OutputFormat::Png,
+            ..EncodeOptions::default()
+        };
+        assert_eq!(png.for_source(source), png);
+    }
+}
@@ -1,26 +1,55 @@
 use std::{
     cmp::max,
+    collections::HashMap,
+    hash::{DefaultHasher, Hash, Hasher},
     io::Cursor,
     sync::{
         atomic::{AtomicUsize, Ordering},
-        Arc, RwLock,
+        Arc, Mutex, OnceLock, RwLock,
     },
+    time::{Instant, SystemTime},
 };
 
-use image::{codecs::jpeg::JpegEncoder, ImageFormat, ImageReader};
+use image::{DynamicImage, ImageFormat, ImageReader};
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
 use rayon::ThreadPool;
 use thread_priority::*;
 
 use crate::{
     container::traits::Container,
-    error::Result,
+    error::{Error, Result},
     image::{
+        cache_stats::{CacheCounters, CacheUsage},
+        encoder::{encode_for_display, EncodeOptions},
+        metrics::{self, Stage},
         resizer::{shrink_to_fit, ResizeFilter},
+        strip::{extract_segment, parse_segment_entry, StripConfig, StripLayout},
+        tile::{extract_tile, TileInfo},
         types::Image,
     },
 };
 
+/// Which rendition of an entry a cache slot holds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rendition {
+    /// The page as displayed by the reader.
+    Page,
+    /// The page downscaled to a requested height.
+    Scaled {
+        /// The height in pixels of the rendition.
+        height: u32,
+    },
+    /// A deep-zoom tile cut from the full-resolution source.
+    Tile {
+        /// The pyramid level (`0` is the full resolution).
+        level: u32,
+        /// The tile column.
+        x: u32,
+        /// The tile row.
+        y: u32,
+    },
+}
+
 /// The composite key for the global image cache.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct CacheKey {
@@ -28,11 +57,144 @@ pub struct CacheKey {
     pub book_id: String,
     /// The name of the image entry within the book.
     pub entry: String,
+    /// Which rendition of the entry is cached.
+    pub rendition: Rendition,
+    /// How the rendition is encoded if it had to be re-encoded.
+    pub encoding: EncodeOptions,
+}
+
+impl CacheKey {
+    /// Creates the key of the displayed page of `entry` with the default encoding.
+    pub fn page(book_id: &str, entry: &str) -> Self {
+        Self {
+            book_id: book_id.to_string(),
+            entry: entry.to_string(),
+            rendition: Rendition::Page,
+            encoding: EncodeOptions::default(),
+        }
+    }
 }
 
 /// A thread-safe cache mapping entry names to `Image` data.
 pub type Cache = mini_moka::sync::Cache<CacheKey, Arc<Image>>;
 
+/// Counts the invalidations of the image cache, so a load that was in flight when
+/// entries were invalidated cannot put back what it rendered before.
+///
+/// A load notes the generation before it starts and inserts its result only if no
+/// invalidation happened since. The check and the insert happen under the read lock that
+/// [`CacheGeneration::advance`] takes for writing, so an insert either lands before the
+/// invalidation, which then evicts it, or sees the new generation and is dropped.
+#[derive(Debug, Clone, Default)]
+pub struct CacheGeneration(Arc<RwLock<u64>>);
+
+impl CacheGeneration {
+    /// Returns the current generation, to be noted before a load starts.
+    pub fn current(&self) -> u64 {
+        *self.0.read().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Starts a new generation, dropping the results of every load in flight. Call it
+    /// before evicting the entries that must not come back.
+    pub fn advance(&self) {
+        *self.0.write().unwrap_or_else(|e| e.into_inner()) += 1;
+    }
+
+    /// Inserts `image` into `cache` unless the generation moved on from `generation`.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the image was inserted.
+    pub fn insert_if_current(
+        &self,
+        generation: u64,
+        cache: &Cache,
+        key: CacheKey,
+        image: Arc<Image>,
+    ) -> bool {
+        let current = self.0.read().unwrap_or_else(|e| e.into_inner());
+        if *current != generation {
+            return false;
+        }
+        cache.insert(key, image);
+        true
+    }
+}
+
+/// Holds the decoded source of the most recently requested entry.
+///
+/// Tiles and strip segments of one page are requested in bursts, so keeping the last
+/// source around avoids decoding (or, for PDF, re-rendering) it for every piece. The slot
+/// is only locked to look up or replace the entry; the source loads under a lock of its
+/// own, so concurrent requests for the same page wait and reuse it while requests for
+/// another page are not held up by the decode.
+#[derive(Default)]
+struct DecodedSource(Mutex<Option<(String, SourceCell)>>);
+
+/// The decoded source of one entry, filled by the first request that loads it.
+type SourceCell = Arc<Mutex<Option<Arc<DecodedImage>>>>;
+
+/// A decoded source image along with the format it was stored in.
+struct DecodedImage {
+    /// The decoded pixels.
+    image: DynamicImage,
+    /// The format of the encoded source, if recognized.
+    format: Option<ImageFormat>,
+}
+
+impl DecodedSource {
+    /// Returns the decoded source of `entry`, calling `load` and decoding its result only
+    /// when the slot holds another entry.
+    fn get_or_decode(
+        &self,
+        entry: &str,
+        load: impl FnOnce() -> Result<Arc<Image>>,
+    ) -> Result<Arc<DecodedImage>> {
+        let cell = {
+            // A poisoned lock only means a previous load panicked; the slot is still valid.
+            let mut slot = self.0.lock().unwrap_or_else(|e| e.into_inner());
+            match slot.as_ref() {
+                Some((cached_entry, cell)) if cached_entry == entry => cell.clone(),
+                _ => {
+                    let cell = SourceCell::default();
+                    *slot = Some((entry.to_string(), cell.clone()));
+                    cell
+                }
+            }
+        };
+
+        let mut source = cell.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(source) = source.as_ref() {
+            return Ok(source.clone());
+        }
+        let image = load()?;
+        let reader = ImageReader::new(Cursor::new(&image.data)).with_guessed_format()?;
+        let format = reader.format();
+        let decoded = Arc::new(DecodedImage {
+            image: reader.decode()?,
+            format,
+        });
+        *source = Some(decoded.clone());
+        Ok(decoded)
+    }
+
+    /// Drops the held source, freeing its pixels once no request uses them any more.
+    fn clear(&self) {
+        *self.0.lock().unwrap_or_else(|e| e.into_inner()) = None;
+    }
+
+    /// Drops the held source unless it is the one of `entry`.
+    fn retain(&self, entry: &str) {
+        let mut slot = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        if slot
+            .as_ref()
+            .is_some_and(|(cached_entry, _)| cached_entry != entry)
+        {
+            *slot = None;
+        }
+    }
+}
+
 /// Manages loading, caching, and preloading of images from a `Container`.
 ///
 /// This struct handles concurrent image loading in the background, provides a thread-safe
@@ -45,6 +207,8 @@ pub struct ImageLoader {
     /// Behind an `RwLock` so the handle can be swapped (on a cache-size change) through a
     /// shared `&self`, letting the loader live inside an `Arc` shared across threads.
     cache: RwLock<Cache>,
+    /// The invalidation count of the cache, checked before a finished load is inserted.
+    cache_generation: CacheGeneration,
     /// A Rayon thread pool dedicated to preloading images in the background.
     thread_pool: ThreadPool,
     /// A generation counter used to signal cancellation to active preloading threads.
@@ -55,6 +219,28 @@ pub struct ImageLoader {
     max_image_height: u32,
     /// The filter type to use when resizing images.
     resize_method: ResizeFilter,
+    /// How re-encoded renditions are written.
+    encoding: EncodeOptions,
+    /// The decoded full-resolution source of the most recently zoomed entry, released
+    /// once preloading moves to another page.
+    zoom_source: DecodedSource,
+    /// How tall pages are sliced in the long-strip reading mode.
+    strip_config: StripConfig,
+    /// The decoded page of the most recently sliced long-strip entry.
+    strip_source: DecodedSource,
+    /// The long-strip layout of the book, computed on first request.
+    strip_layout: Mutex<Option<Arc<StripLayout>>>,
+    /// The position of each of the container's entries, for resolving long-strip
+    /// segment entries and tagging pages.
+    entry_indices: OnceLock<HashMap<String, usize>>,
+    /// The modification time of the book when the loader was built, or `None` if it
+    /// could not be read.
+    book_modified: Option<SystemTime>,
+    /// The `(center_index, buffer_size)` of the most recent preload request, so a
+    /// rebuilt loader can resume preloading around the same page.
+    last_preload: Mutex<Option<(usize, usize)>>,
+    /// Cache hits, misses and preloads of this loader, reported by `get_cache_stats`.
+    counters: Arc<CacheCounters>,
 }
 
 impl ImageLoader {
@@ -68,6 +254,8 @@ impl ImageLoader {
     /// * `container` - A shared reference to a `Container` implementation.
     /// * `max_image_height` - The maximum height for loaded images.
     /// * `resize_method` - The algorithm to use for image resizing.
+    /// * `strip_config` - How tall pages are sliced in the long-strip reading mode.
+    /// * `encoding` - How resized pages, tiles and strip segments are re-encoded.
     /// * `cache` - The global moka cache instance.
     ///
     /// # Returns
@@ -78,6 +266,8 @@ impl ImageLoader {
         container: Arc<dyn Container>,
         max_image_height: u32,
         resize_method: ResizeFilter,
+        strip_config: StripConfig,
+        encoding: EncodeOptions,
         cache: Cache,
     ) -> Result<Self> {
         let num_threads = if container.is_single_threaded() {
@@ -98,18 +288,39 @@ impl ImageLoader {
                 let _ = set_current_thread_priority(ThreadPriority::Min);
             })
             .build()?;
+        let book_modified = std::fs::metadata(&book_id)
+            .and_then(|metadata| metadata.modified())
+            .ok();
 
         Ok(Self {
             book_id,
             cache: RwLock::new(cache),
+            cache_generation: CacheGeneration::default(),
             thread_pool,
             preload_generation: Arc::new(AtomicUsize::new(0)),
             container,
             max_image_height,
             resize_method,
+            encoding,
+            zoom_source: DecodedSource::default(),
+            strip_config,
+            strip_source: DecodedSource::default(),
+            strip_layout: Mutex::new(None),
+            entry_indices: OnceLock::new(),
+            book_modified,
+            last_preload: Mutex::new(None),
+            counters: Arc::new(CacheCounters::default()),
         })
     }
 
+    /// Shares the invalidation count of the cache with its owner, so loads in flight when
+    /// the owner invalidates entries are not cached. Without it, the loader caches every
+    /// load it finishes.
+    pub fn with_cache_generation(mut self, cache_generation: CacheGeneration) -> Self {
+        self.cache_generation = cache_generation;
+        self
+    }
+
     /// Sets a new cache instance for the image loader.
     ///
     /// Takes `&self` (not `&mut self`) so the loader can be swapped while shared behind an
@@ -119,6 +330,27 @@ impl ImageLoader {
         *self.cache.write().unwrap_or_else(|e| e.into_inner()) = cache;
     }
 
+    /// Builds the cache key of a rendition of `entry` in this book and encoding.
+    fn cache_key(&self, entry: &str, rendition: Rendition) -> CacheKey {
+        CacheKey {
+            book_id: self.book_id.clone(),
+            entry: entry.to_string(),
+            rendition,
+            encoding: self.encoding,
+        }
+    }
+
+    /// Inserts a loaded rendition into the cache, unless the cache was invalidated since
+    /// `generation` was noted at the start of the load.
+    fn insert_if_current(&self, generation: u64, key: CacheKey, image: Arc<Image>) {
+        if !self
+            .cache_generation
+            .insert_if_current(generation, &self.cache(), key, image)
+        {
+            log::debug!("Drop an image loaded before the cache was invalidated");
+        }
+    }
+
     /// Returns a clone of the current cache handle (mini-moka handles are cheap Arc-like
     /// clones). Recovers a poisoned lock instead of propagating it.
     fn cache(&self) -> Cache {
@@ -135,17 +367,14 @@ impl ImageLoader {
     ///
     /// `Some(Arc<Image>)` if the image is found in the cache, `None` otherwise.
     pub fn get_image_from_cache(&self, entry: &str) -> Option<Arc<Image>> {
-        let key = CacheKey {
-            book_id: self.book_id.clone(),
-            entry: entry.to_string(),
-        };
-        self.cache().get(&key)
+        self.cache().get(&self.cache_key(entry, Rendition::Page))
     }
 
     /// Retrieves an image, loading it from the container if not found in the cache.
     ///
     /// If the image is not in the cache, it is loaded, resized if necessary,
-    /// inserted into the cache, and then returned.
+    /// inserted into the cache, and then returned. Long-strip segment entries (see
+    /// `get_strip_layout`) are cut from their page at full width instead of resized.
     ///
     /// # Arguments
     ///
@@ -161,21 +390,81 @@ impl ImageLoader {
     pub fn get_image(&self, entry: &str) -> Result<Arc<Image>> {
         if let Some(image_arc) = self.get_image_from_cache(entry) {
             log::debug!("Hit cache: {}", entry);
+            self.counters.record_hit(entry);
             return Ok(image_arc);
         }
+        self.counters.record_miss();
+        let generation = self.cache_generation.current();
 
-        let image_arc = load_image(
-            entry,
-            self.container.clone(),
-            self.max_image_height,
-            self.resize_method,
-        )?;
-
-        let key = CacheKey {
-            book_id: self.book_id.clone(),
-            entry: entry.to_string(),
+        let image_arc = match self.strip_segment(entry) {
+            Some((page, index)) => {
+                let source = self
+                    .strip_source
+                    .get_or_decode(page, || self.container.get_image(page))?;
+                Arc::new(extract_segment(
+                    &source.image,
+                    index,
+                    self.strip_config,
+                    self.encoding.for_source(source.format),
+                )?)
+            }
+            None => load_image(
+                entry,
+                self.container.clone(),
+                self.max_image_height,
+                self.resize_method,
+                self.encoding,
+            )?,
         };
-        self.cache().insert(key, image_arc.clone());
+
+        self.insert_if_current(
+            generation,
+            self.cache_key(entry, Rendition::Page),
+            image_arc.clone(),
+        );
+
+        Ok(image_arc)
+    }
+
+    /// Retrieves an image no taller than `height`, downscaling the displayed page if
+    /// needed.
+    ///
+    /// Lets the frontend request lighter renditions (e.g. for `srcset`) than the page
+    /// `get_image` returns. Pages already within `height` are returned unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `entry` - The name of the image entry to retrieve.
+    /// * `height` - The maximum height in pixels of the returned image.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a shared pointer to the `Image`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidArgument` if `height` is `0`, or an `Err` if the image
+    /// cannot be loaded or resized.
+    pub fn get_scaled_image(&self, entry: &str, height: u32) -> Result<Arc<Image>> {
+        if height == 0 {
+            return Err(Error::InvalidArgument(format!(
+                "Requested height of {entry} must be positive"
+            )));
+        }
+
+        let key = self.cache_key(entry, Rendition::Scaled { height });
+        if let Some(image_arc) = self.cache().get(&key) {
+            log::debug!("Hit cache: {} at {}px", entry, height);
+            return Ok(image_arc);
+        }
+        let generation = self.cache_generation.current();
+
+        let page = self.get_image(entry)?;
+        if page.height <= height {
+            return Ok(page);
+        }
+        let image_arc = resize_image(entry, page, height, self.resize_method, self.encoding)?;
+        self.insert_if_current(generation, key, image_arc.clone());
 
         Ok(image_arc)
     }
@@ -192,7 +481,8 @@ impl ImageLoader {
     /// # Returns
     ///
     /// An `Ok(Some(Arc<Image>))` containing the thumbnail if generated.
-    /// An `Ok(None)` if the full image was already cached, skipping thumbnail generation.
+    /// An `Ok(None)` if the full image was already cached or the entry is a long-strip
+    /// segment, skipping thumbnail generation.
     ///
     /// # Errors
     ///
@@ -202,6 +492,10 @@ impl ImageLoader {
             log::debug!("Skip create the thumbnail. Hit cache: {}", entry);
             return Ok(None);
         }
+        if self.strip_segment(entry).is_some() {
+            log::debug!("Skip create the thumbnail. Strip segment: {}", entry);
+            return Ok(None);
+        }
 
         let thumbnail = self.container.get_thumbnail(entry)?;
         Ok(Some(thumbnail))
@@ -217,12 +511,20 @@ impl ImageLoader {
     /// * `center_index` - The current page index around which to preload.
     /// * `buffer_size` - How many pages to preload in each direction.
     pub fn request_preload_around(&self, center_index: usize, buffer_size: usize) -> Result<()> {
+        *self.last_preload.lock().unwrap_or_else(|e| e.into_inner()) =
+            Some((center_index, buffer_size));
+
         let entries = self.container.get_entries();
         let total_pages = entries.len();
 
         if total_pages == 0 {
             return Ok(());
         }
+        // The reader moved on: a full-resolution source of another page would only hold
+        // its pixels until the next zoom.
+        if let Some(center) = entries.get(center_index) {
+            self.zoom_source.retain(center);
+        }
 
         let start = center_index.saturating_sub(buffer_size);
         let end = (center_index + buffer_size + 1).min(total_pages);
@@ -236,15 +538,8 @@ impl ImageLoader {
         // Snapshot the cache handle once (cheap Arc-like clone) and reuse it for the
         // membership filter and the spawned preload, so we take the lock only once.
         let cache = self.cache();
-        let book_id_for_filter = self.book_id.clone();
         let mut target_indices: Vec<usize> = (start..end)
-            .filter(|&i| {
-                let key = CacheKey {
-                    book_id: book_id_for_filter.clone(),
-                    entry: entries[i].clone(),
-                };
-                !cache.contains_key(&key)
-            })
+            .filter(|&i| !cache.contains_key(&self.cache_key(&entries[i], Rendition::Page)))
             .collect();
 
         target_indices.sort_by_key(|&i| {
@@ -270,7 +565,10 @@ impl ImageLoader {
         let cache_clone = cache;
         let max_image_height = self.max_image_height;
         let resize_method = self.resize_method;
+        let encoding = self.encoding;
         let book_id = self.book_id.clone();
+        let counters = self.counters.clone();
+        let cache_generation = self.cache_generation.clone();
 
         // Execute preloading in the Rayon thread pool.
         self.thread_pool.spawn(move || {
@@ -278,15 +576,30 @@ impl ImageLoader {
                 if gen_ref.load(Ordering::Relaxed) != current_gen {
                     return;
                 }
+                let generation = cache_generation.current();
 
-                match load_image(&entry, container.clone(), max_image_height, resize_method) {
+                match load_image(
+                    &entry,
+                    container.clone(),
+                    max_image_height,
+                    resize_method,
+                    encoding,
+                ) {
+                    // Drop a page finished after cancellation: the loader may have
+                    // been replaced with different rendering parameters meanwhile.
+                    Ok(_) if gen_ref.load(Ordering::Relaxed) != current_gen => {}
                     Ok(image) => {
                         log::debug!("Preloaded: {}", entry);
                         let key = CacheKey {
                             book_id: book_id.clone(),
-                            entry,
+                            entry: entry.clone(),
+                            rendition: Rendition::Page,
+                            encoding,
                         };
-                        cache_clone.insert(key, image);
+                        if cache_generation.insert_if_current(generation, &cache_clone, key, image)
+                        {
+                            counters.record_preloaded(&entry);
+                        }
                     }
                     Err(e) => {
                         log::error!("Failed to preload image: {}", e);
@@ -298,6 +611,139 @@ impl ImageLoader {
         Ok(())
     }
 
+    /// Describes the deep-zoom tile pyramid of a given entry.
+    ///
+    /// # Arguments
+    ///
+    /// * `entry` - The name of the image entry.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the `TileInfo` of the entry's full-resolution source.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the full-resolution source cannot be loaded or decoded.
+    pub fn get_tile_info(&self, entry: &str) -> Result<TileInfo> {
+        let source = self.zoom_source(entry)?;
+        Ok(TileInfo::new(source.image.width(), source.image.height()))
+    }
+
+    /// Retrieves a deep-zoom tile of a given entry, cutting it from the full-resolution
+    /// source if it is not cached yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `entry` - The name of the image entry.
+    /// * `level` - The pyramid level (`0` is the full resolution).
+    /// * `x` - The tile column.
+    /// * `y` - The tile row.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a shared pointer to the tile `Image`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidArgument` if the tile lies outside the pyramid, or an `Err`
+    /// if the source cannot be loaded or the tile cannot be encoded.
+    pub fn get_tile(&self, entry: &str, level: u32, x: u32, y: u32) -> Result<Arc<Image>> {
+        let key = self.cache_key(entry, Rendition::Tile { level, x, y });
+        if let Some(tile) = self.cache().get(&key) {
+            log::debug!("Hit cache: {} tile ({}, {}, {})", entry, level, x, y);
+            return Ok(tile);
+        }
+        let generation = self.cache_generation.current();
+
+        let source = self.zoom_source(entry)?;
+        let tile = Arc::new(extract_tile(
+            &source.image,
+            level,
+            x,
+            y,
+            self.resize_method,
+            self.encoding.for_source(source.format),
+        )?);
+        self.insert_if_current(generation, key, tile.clone());
+
+        Ok(tile)
+    }
+
+    /// Returns the decoded full-resolution source of `entry`, reusing the last one when
+    /// it is the same entry.
+    fn zoom_source(&self, entry: &str) -> Result<Arc<DecodedImage>> {
+        self.zoom_source
+            .get_or_decode(entry, || self.container.get_full_resolution_image(entry))
+    }
+
+    /// Lays the whole book out as one continuous vertical strip for webtoon reading.
+    ///
+    /// Pages taller than the configured segment height are sliced into overlapping
+    /// segments, exposed as virtual entries that `get_image` serves like any other
+    /// page; shorter pages are kept whole and stitched directly below the previous one.
+    /// The layout is computed once per loader and shared by later calls.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the shared `StripLayout` of the book.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the dimensions of a page cannot be read.
+    pub fn get_strip_layout(&self) -> Result<Arc<StripLayout>> {
+        if let Some(layout) = self.strip_layout() {
+            return Ok(layout);
+        }
+
+        // The sizes are read without holding the lock, so a slow container does not block
+        // other requests. Concurrent first calls may both read them; the first result is
+        // kept.
+        let container = &self.container;
+        let sizes = self.thread_pool.install(|| container.get_image_sizes())?;
+        let pages: Vec<(String, u32, u32)> = container
+            .get_entries()
+            .iter()
+            .zip(sizes)
+            .map(|(entry, (width, height))| (entry.clone(), width, height))
+            .collect();
+        let layout = Arc::new(StripLayout::new(&pages, self.strip_config));
+
+        // A poisoned lock only means a previous computation panicked; the slot is valid.
+        let mut slot = self.strip_layout.lock().unwrap_or_else(|e| e.into_inner());
+        Ok(slot.get_or_insert(layout).clone())
+    }
+
+    /// Returns the long-strip layout if it has been computed.
+    fn strip_layout(&self) -> Option<Arc<StripLayout>> {
+        self.strip_layout
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// Resolves a long-strip segment entry into its page entry and segment index.
+    ///
+    /// Returns `None` for real entries, including ones whose name merely looks like a
+    /// segment entry.
+    fn strip_segment<'a>(&self, entry: &'a str) -> Option<(&'a str, usize)> {
+        let (page, index) = parse_segment_entry(entry)?;
+        let entries = self.entry_indices();
+        (entries.contains_key(page) && !entries.contains_key(entry)).then_some((page, index))
+    }
+
+    /// Returns the position of each of the container's entries, collected on first use
+    /// so entries are resolved without scanning the entry list.
+    fn entry_indices(&self) -> &HashMap<String, usize> {
+        self.entry_indices.get_or_init(|| {
+            self.container
+                .get_entries()
+                .iter()
+                .enumerate()
+                .map(|(index, entry)| (entry.clone(), index))
+                .collect()
+        })
+    }
+
     /// Signals all active preloading threads to cancel their work.
     ///
     /// This sets an atomic flag that the background threads check periodically.
@@ -306,10 +752,53 @@ impl ImageLoader {
         self.preload_generation.fetch_add(1, Ordering::Relaxed);
     }
 
+    /// Drops the decoded zoom and long-strip sources, which are held outside the image
+    /// cache. They are decoded again on the next tile or segment request.
+    pub fn release_decoded_sources(&self) {
+        self.zoom_source.clear();
+        self.strip_source.clear();
+    }
+
     /// Returns the book identifier (usually the file path) for this loader.
     pub fn book_id(&self) -> &str {
         &self.book_id
     }
+
+    /// Returns a strong entity tag identifying the rendition of `entry` at `height`.
+    ///
+    /// The tag is derived from the book, the page's position in it, the modification
+    /// time of the book when this loader was built and the rendering settings of this
+    /// loader, so it changes whenever the served bytes would, without reading or hashing
+    /// them. A book changed on disk is served by a new loader once it is reopened.
+    pub fn entity_tag(&self, entry: &str, height: Option<u32>) -> String {
+        let mut hasher = DefaultHasher::new();
+        self.book_id.hash(&mut hasher);
+        match self.entry_indices().get(entry) {
+            Some(index) => index.hash(&mut hasher),
+            // Long-strip segments are not entries of the container.
+            None => entry.hash(&mut hasher),
+        }
+        self.book_modified.hash(&mut hasher);
+        height.hash(&mut hasher);
+        self.max_image_height.hash(&mut hasher);
+        self.resize_method.hash(&mut hasher);
+        self.encoding.hash(&mut hasher);
+        format!("\"{:016x}\"", hasher.finish())
+    }
+
+    /// Returns how this book has used the image cache since the loader was built.
+    pub fn cache_usage(&self) -> CacheUsage {
+        let cache = self.cache();
+        self.counters.usage(&self.book_id, |entry| {
+            cache.contains_key(&self.cache_key(entry, Rendition::Page))
+        })
+    }
+
+    /// Returns the `(center_index, buffer_size)` of the most recent preload request, or
+    /// `None` if no preload has been requested yet.
+    pub fn last_preload(&self) -> Option<(usize, usize)> {
+        *self.last_preload.lock().unwrap_or_else(|e| e.into_inner())
+    }
 }
 
 impl Drop for ImageLoader {
@@ -323,59 +812,84 @@ impl Drop for ImageLoader {
 
 /// Helper function to load an image from a container and resize it if necessary.
 ///
+/// Images within `max_image_height` are returned exactly as stored in the container:
+/// only a resize ever re-encodes a page.
+///
 /// # Arguments
 ///
 /// * `entry` - The name of the image entry to load.
 /// * `container` - A shared reference to the container.
 /// * `max_image_height` - The maximum height for the image.
 /// * `resize_method` - The algorithm to use for resizing.
+/// * `encoding` - How a resized image is re-encoded.
 fn load_image(
     entry: &str,
     container: Arc<dyn Container>,
     max_image_height: u32,
     resize_method: ResizeFilter,
+    encoding: EncodeOptions,
 ) -> Result<Arc<Image>> {
+    let started = Instant::now();
     let image = container.get_image(entry)?;
+    let read = started.elapsed();
+    let format = image::guess_format(&image.data)
+        .ok()
+        .and_then(metrics::format_name);
+    metrics::record(Stage::Read, entry, format, read);
 
-    if max_image_height > 0 && image.height > max_image_height {
-        let scaled_image = resize_image(image, max_image_height, resize_method)?;
-        Ok(scaled_image)
+    let image = if max_image_height > 0 && image.height > max_image_height {
+        resize_image(entry, image, max_image_height, resize_method, encoding)?
     } else {
-        Ok(image)
-    }
+        image
+    };
+
+    let total = started.elapsed();
+    metrics::record(Stage::Load, entry, format, total);
+    metrics::log_if_slow(entry, read, total);
+    Ok(image)
 }
 
 /// Helper function to resize an image and re-encode it.
 ///
-/// Images with an alpha channel are re-encoded as PNG to preserve transparency;
-/// opaque images are re-encoded as JPEG (quality 80) to minimize transferred bytes.
+/// The output format follows `encoding`; `OutputFormat::Passthrough` keeps the format
+/// of the original image where it can be encoded.
+///
+/// Each step (decode, resize, encode) is recorded in the performance metrics.
 ///
 /// # Arguments
 ///
+/// * `entry` - The name of the entry, for the performance metrics.
 /// * `image` - A shared pointer to the original `Image`.
 /// * `height` - The target height for the resized image.
 /// * `resize_method` - The algorithm to use for resizing.
-fn resize_image(image: Arc<Image>, height: u32, resize_method: ResizeFilter) -> Result<Arc<Image>> {
+/// * `encoding` - How the resized image is re-encoded.
+fn resize_image(
+    entry: &str,
+    image: Arc<Image>,
+    height: u32,
+    resize_method: ResizeFilter,
+    encoding: EncodeOptions,
+) -> Result<Arc<Image>> {
+    let started = Instant::now();
     let cursor = Cursor::new(&image.data);
     let image_reader = ImageReader::new(cursor).with_guessed_format()?;
+    let source_format = image_reader.format();
+    let format = source_format.and_then(metrics::format_name);
     let dyn_image = image_reader.decode()?;
+    metrics::record(Stage::Decode, entry, format, started.elapsed());
 
     // Use SIMD accelerated resizing
     // max_width is u32::MAX to scale based entirely on height
+    let started = Instant::now();
     let scaled_image = shrink_to_fit(&dyn_image, u32::MAX, height, resize_method)?;
+    metrics::record(Stage::Resize, entry, format, started.elapsed());
 
-    let mut buffer = Vec::new();
-    // Preserve transparency by re-encoding alpha images as PNG; opaque images stay
-    // JPEG (q80) to minimize the bytes sent to the frontend.
-    if scaled_image.color().has_alpha() {
-        scaled_image.write_to(&mut Cursor::new(&mut buffer), ImageFormat::Png)?;
-    } else {
-        let mut encoder = JpegEncoder::new_with_quality(&mut buffer, 80);
-        encoder.encode_image(&scaled_image)?;
-    }
+    let started = Instant::now();
+    let data = encode_for_display(&scaled_image, encoding.for_source(source_format))?;
+    metrics::record(Stage::Encode, entry, format, started.elapsed());
 
     Ok(Arc::new(Image {
-        data: buffer,
+        data,
         width: scaled_image.width(),
         height: scaled_image.height(),
     }))
@@ -384,7 +898,7 @@ fn resize_image(image: Arc<Image>, height: u32, resize_method: ResizeFilter) ->
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::container::traits::MockContainer;
+    use crate::{container::traits::MockContainer, image::encoder::OutputFormat};
     use std::time::Duration;
 
     #[test]
@@ -418,12 +932,16 @@ mod tests {
             container,
             2000,
             ResizeFilter::Bilinear,
+            StripConfig::default(),
+            EncodeOptions::default(),
             cache.clone(),
         )
         .unwrap();
 
         // Start preloading
+        assert_eq!(loader.last_preload(), None);
         loader.request_preload_around(0, 3).unwrap();
+        assert_eq!(loader.last_preload(), Some((0, 3)));
 
         // Cancel it immediately
         loader.cancel_preload();
@@ -431,14 +949,8 @@ mod tests {
         // Wait for thread pool to process
         std::thread::sleep(Duration::from_millis(150));
 
-        let key2 = CacheKey {
-            book_id: "test_book".to_string(),
-            entry: "test2.png".to_string(),
-        };
-        let key3 = CacheKey {
-            book_id: "test_book".to_string(),
-            entry: "test3.png".to_string(),
-        };
+        let key2 = CacheKey::page("test_book", "test2.png");
+        let key3 = CacheKey::page("test_book", "test3.png");
 
         assert!(
             !cache.contains_key(&key2),
@@ -450,6 +962,55 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_load_in_flight_during_invalidation_is_not_cached() {
+        let mut mock_container = MockContainer::new();
+        mock_container.expect_get_image().returning(|entry| {
+            if entry == "slow.png" {
+                std::thread::sleep(Duration::from_millis(100));
+            }
+            Ok(Arc::new(Image {
+                data: vec![0u8; 10],
+                width: 10,
+                height: 10,
+            }))
+        });
+        mock_container
+            .expect_is_single_threaded()
+            .return_const(false);
+
+        let cache = mini_moka::sync::Cache::new(100);
+        let generation = CacheGeneration::default();
+        let loader = ImageLoader::new(
+            "test_book".to_string(),
+            Arc::new(mock_container),
+            2000,
+            ResizeFilter::Bilinear,
+            StripConfig::default(),
+            EncodeOptions::default(),
+            cache.clone(),
+        )
+        .unwrap()
+        .with_cache_generation(generation.clone());
+
+        std::thread::scope(|scope| {
+            let load = scope.spawn(|| loader.get_image("slow.png"));
+            // Invalidate while the page is still loading.
+            std::thread::sleep(Duration::from_millis(30));
+            generation.advance();
+            // The request itself is still answered.
+            assert!(load.join().unwrap().is_ok());
+        });
+        assert!(
+            !cache.contains_key(&CacheKey::page("test_book", "slow.png")),
+            "a page loaded before the invalidation must not be cached"
+        );
+
+        // Loads started after the invalidation are cached as usual.
+        loader.get_image("slow.png").unwrap();
+        assert!(cache.contains_key(&CacheKey::page("test_book", "slow.png")));
+    }
+
     #[test]
     fn test_get_image_from_cache_hit_and_miss() {
         let mut mock_container = MockContainer::new();
@@ -464,6 +1025,8 @@ mod tests {
             container,
             2000,
             ResizeFilter::Bilinear,
+            StripConfig::default(),
+            EncodeOptions::default(),
             cache.clone(),
         )
         .unwrap();
@@ -472,10 +1035,7 @@ mod tests {
         assert!(loader.get_image_from_cache("missing.png").is_none());
 
         // Hit: insert under the loader's composite key, then read it back.
-        let key = CacheKey {
-            book_id: "test_book".to_string(),
-            entry: "cached.png".to_string(),
-        };
+        let key = CacheKey::page("test_book", "cached.png");
         let image = Arc::new(Image {
             data: vec![1, 2, 3],
             width: 1,
@@ -484,6 +1044,12 @@ mod tests {
         cache.insert(key, image);
 
         assert!(loader.get_image_from_cache("cached.png").is_some());
+
+        // Only `get_image` requests count towards the cache usage.
+        assert_eq!(loader.cache_usage().hits, 0);
+        loader.get_image("cached.png").unwrap();
+        let usage = loader.cache_usage();
+        assert_eq!((usage.hits, usage.misses), (1, 0));
     }
 
     #[test]
@@ -496,6 +1062,7 @@ mod tests {
             .write_to(&mut std::io::Cursor::new(&mut src), image::ImageFormat::Png)
             .unwrap();
         let out = resize_image(
+            "page.png",
             Arc::new(Image {
                 data: src,
                 width: 2,
@@ -503,6 +1070,7 @@ mod tests {
             }),
             10,
             ResizeFilter::Bilinear,
+            EncodeOptions::default(),
         )
         .unwrap();
         let decoded = image::load_from_memory(&out.data).unwrap();
@@ -517,6 +1085,7 @@ mod tests {
             .write_to(&mut std::io::Cursor::new(&mut src), image::ImageFormat::Png)
             .unwrap();
         let out = resize_image(
+            "page.png",
             Arc::new(Image {
                 data: src,
                 width: 2,
@@ -524,8 +1093,335 @@ mod tests {
             }),
             10,
             ResizeFilter::Bilinear,
+            EncodeOptions::default(),
         )
         .unwrap();
         assert!(image::load_from_memory(&out.data).is_ok());
     }
+
+    #[test]
+    fn test_get_tile_reuses_zoom_source_and_caches_tiles() {
+        let img = image::RgbImage::from_pixel(1000, 600, image::Rgb([10, 20, 30]));
+        let mut src = Vec::new();
+        image::DynamicImage::ImageRgb8(img)
+            .write_to(&mut std::io::Cursor::new(&mut src), image::ImageFormat::Png)
+            .unwrap();
+
+        let mut mock_container = MockContainer::new();
+        mock_container
+            .expect_is_single_threaded()
+            .return_const(false);
+        // The source is decoded once and shared by the info and every tile of the page.
+        mock_container
+            .expect_get_full_resolution_image()
+            .times(1)
+            .returning(move |_| {
+                Ok(Arc::new(Image {
+                    data: src.clone(),
+                    width: 1000,
+                    height: 600,
+                }))
+            });
+
+        let cache = mini_moka::sync::Cache::new(100);
+        let loader = ImageLoader::new(
+            "test_book".to_string(),
+            Arc::new(mock_container),
+            500,
+            ResizeFilter::Bilinear,
+            StripConfig::default(),
+            EncodeOptions::default(),
+            cache.clone(),
+        )
+        .unwrap();
+
+        let info = loader.get_tile_info("page.png").unwrap();
+        assert_eq!((info.width, info.height, info.max_level), (1000, 600, 1));
+
+        let tile = loader.get_tile("page.png", 0, 1, 1).unwrap();
+        assert_eq!((tile.width, tile.height), (1000 - 512, 600 - 512));
+        let coarse = loader.get_tile("page.png", 1, 0, 0).unwrap();
+        assert_eq!((coarse.width, coarse.height), (500, 300));
+
+        // Tiles are cached separately from the displayed page.
+        let key = CacheKey {
+            rendition: Rendition::Tile {
+                level: 0,
+                x: 1,
+                y: 1,
+            },
+            ..CacheKey::page("test_book", "page.png")
+        };
+        assert!(cache.get(&key).is_some());
+        assert!(loader.get_image_from_cache("page.png").is_none());
+    }
+
+    #[test]
+    fn test_zoom_source_is_released_when_the_page_changes() {
+        let img = image::RgbImage::from_pixel(100, 60, image::Rgb([10, 20, 30]));
+        let mut src = Vec::new();
+        image::DynamicImage::ImageRgb8(img)
+            .write_to(&mut std::io::Cursor::new(&mut src), image::ImageFormat::Png)
+            .unwrap();
+        let image = Arc::new(Image::new(src).unwrap());
+
+        let mut mock_container = MockContainer::new();
+        mock_container
+            .expect_is_single_threaded()
+            .return_const(false);
+        mock_container
+            .expect_get_entries()
+            .return_const(vec!["a.png".to_string(), "b.png".to_string()]);
+        let page = image.clone();
+        mock_container
+            .expect_get_image()
+            .returning(move |_| Ok(page.clone()));
+        // Decoded once while the reader stays on the page, and again after leaving it.
+        mock_container
+            .expect_get_full_resolution_image()
+            .times(2)
+            .returning(move |_| Ok(image.clone()));
+
+        let loader = ImageLoader::new(
+            "test_book".to_string(),
+            Arc::new(mock_container),
+            0,
+            ResizeFilter::Bilinear,
+            StripConfig::default(),
+            EncodeOptions::default(),
+            mini_moka::sync::Cache::new(100),
+        )
+        .unwrap();
+
+        loader.get_tile_info("a.png").unwrap();
+        loader.request_preload_around(0, 0).unwrap();
+        loader.get_tile_info("a.png").unwrap();
+        loader.request_preload_around(1, 0).unwrap();
+        loader.get_tile_info("a.png").unwrap();
+    }
+
+    #[test]
+    fn test_get_strip_layout_and_segments() {
+        let encode = |height: u32| {
+            let img = image::RgbImage::from_pixel(100, height, image::Rgb([10, 20, 30]));
+            let mut data = Vec::new();
+            image::DynamicImage::ImageRgb8(img)
+                .write_to(
+                    &mut std::io::Cursor::new(&mut data),
+                    image::ImageFormat::Png,
+                )
+                .unwrap();
+            Image {
+                data,
+                width: 100,
+                height,
+            }
+        };
+        let tall = encode(2500);
+        let short = encode(300);
+
+        let mut mock_container = MockContainer::new();
+        mock_container
+            .expect_is_single_threaded()
+            .return_const(false);
+        mock_container
+            .expect_get_entries()
+            .return_const(vec!["tall.png".to_string(), "short.png".to_string()]);
+        // The layout only needs the page sizes, not the pages.
+        mock_container
+            .expect_get_image_sizes()
+            .times(1)
+            .returning(|| Ok(vec![(100, 2500), (100, 300)]));
+        mock_container
+            .expect_get_image()
+            .returning(move |entry| match entry {
+                "tall.png" => Ok(Arc::new(tall.clone())),
+                _ => Ok(Arc::new(short.clone())),
+            });
+
+        let cache = mini_moka::sync::Cache::new(100);
+        let loader = ImageLoader::new(
+            "test_book".to_string(),
+            Arc::new(mock_container),
+            500,
+            ResizeFilter::Bilinear,
+            StripConfig {
+                segment_height: 1000,
+                overlap: 50,
+            },
+            EncodeOptions::default(),
+            cache.clone(),
+        )
+        .unwrap();
+
+        let layout = loader.get_strip_layout().unwrap();
+        let entries: Vec<_> = layout.segments.iter().map(|s| s.entry.as_str()).collect();
+        assert_eq!(
+            entries,
+            vec![
+                "tall.png#strip-0",
+                "tall.png#strip-1",
+                "tall.png#strip-2",
+                "short.png"
+            ]
+        );
+        assert_eq!(layout.height, 2800);
+        assert!(Arc::ptr_eq(&layout, &loader.get_strip_layout().unwrap()));
+
+        // Segments keep their full size instead of shrinking to `max_image_height`.
+        let segment = loader.get_image("tall.png#strip-2").unwrap();
+        assert_eq!((segment.width, segment.height), (100, 2500 - 1900));
+        assert!(loader.get_image_from_cache("tall.png#strip-2").is_some());
+        assert!(loader
+            .get_preview_image("tall.png#strip-0")
+            .unwrap()
+            .is_none());
+
+        let err = loader.get_image("tall.png#strip-3").unwrap_err();
+        let code: crate::error::ErrorCode = (&err).into();
+        assert_eq!(code, crate::error::ErrorCode::InvalidArgument);
+    }
+
+    #[test]
+    fn test_entity_tag_resolves_entries_once() {
+        let mut mock_container = MockContainer::new();
+        mock_container
+            .expect_is_single_threaded()
+            .return_const(false);
+        // The entry list is indexed once, not scanned for every tag.
+        mock_container
+            .expect_get_entries()
+            .times(1)
+            .return_const(vec!["01.png".to_string(), "02.png".to_string()]);
+
+        let loader = ImageLoader::new(
+            "test_book".to_string(),
+            Arc::new(mock_container),
+            0,
+            ResizeFilter::Bilinear,
+            StripConfig::default(),
+            EncodeOptions::default(),
+            mini_moka::sync::Cache::new(100),
+        )
+        .unwrap();
+
+        let first = loader.entity_tag("01.png", None);
+        assert_eq!(first, loader.entity_tag("01.png", None));
+        assert_ne!(first, loader.entity_tag("02.png", None));
+        assert_ne!(first, loader.entity_tag("01.png", Some(100)));
+        assert_ne!(first, loader.entity_tag("01.png#strip-0", None));
+    }
+
+    #[test]
+    fn test_get_scaled_image() {
+        let img = image::RgbImage::from_pixel(200, 1000, image::Rgb([10, 20, 30]));
+        let mut src = Vec::new();
+        image::DynamicImage::ImageRgb8(img)
+            .write_to(&mut std::io::Cursor::new(&mut src), image::ImageFormat::Png)
+            .unwrap();
+
+        let mut mock_container = MockContainer::new();
+        mock_container
+            .expect_is_single_threaded()
+            .return_const(false);
+        mock_container
+            .expect_get_image()
+            .times(1)
+            .returning(move |_| Ok(Arc::new(Image::new(src.clone()).unwrap())));
+
+        let cache = mini_moka::sync::Cache::new(100);
+        let loader = ImageLoader::new(
+            "test_book".to_string(),
+            Arc::new(mock_container),
+            0,
+            ResizeFilter::Bilinear,
+            StripConfig::default(),
+            EncodeOptions::default(),
+            cache.clone(),
+        )
+        .unwrap();
+
+        let scaled = loader.get_scaled_image("page.png", 500).unwrap();
+        assert_eq!((scaled.width, scaled.height), (100, 500));
+        let key = CacheKey {
+            rendition: Rendition::Scaled { height: 500 },
+            ..CacheKey::page("test_book", "page.png")
+        };
+        assert!(cache.get(&key).is_some());
+
+        // A height above the page returns the page itself.
+        let page = loader.get_scaled_image("page.png", 4000).unwrap();
+        assert_eq!(page.height, 1000);
+
+        assert!(matches!(
+            loader.get_scaled_image("page.png", 0),
+            Err(Error::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn test_encoding_keeps_unresized_pages_and_keys_the_cache() {
+        let encode = |height: u32| {
+            let img = image::RgbImage::from_pixel(100, height, image::Rgb([10, 20, 30]));
+            let mut data = Vec::new();
+            image::DynamicImage::ImageRgb8(img)
+                .write_to(
+                    &mut std::io::Cursor::new(&mut data),
+                    image::ImageFormat::Png,
+                )
+                .unwrap();
+            Image::new(data).unwrap()
+        };
+        let short = encode(300);
+        let tall = encode(1000);
+        let short_bytes = short.data.clone();
+
+        let cache = mini_moka::sync::Cache::new(100);
+        let loader_with = |format| {
+            let (short, tall) = (short.clone(), tall.clone());
+            let mut mock_container = MockContainer::new();
+            mock_container
+                .expect_is_single_threaded()
+                .return_const(false);
+            mock_container
+                .expect_get_image()
+                .returning(move |entry| match entry {
+                    "short.png" => Ok(Arc::new(short.clone())),
+                    _ => Ok(Arc::new(tall.clone())),
+                });
+            ImageLoader::new(
+                "test_book".to_string(),
+                Arc::new(mock_container),
+                500,
+                ResizeFilter::Bilinear,
+                StripConfig::default(),
+                EncodeOptions {
+                    format,
+                    ..EncodeOptions::default()
+                },
+                cache.clone(),
+            )
+            .unwrap()
+        };
+        let webp = loader_with(OutputFormat::WebpLossless);
+        let passthrough = loader_with(OutputFormat::Passthrough);
+
+        // Pages within the height limit are never re-encoded.
+        assert_eq!(webp.get_image("short.png").unwrap().data, short_bytes);
+
+        // The same page resized under other options gets its own cache slot.
+        let format_of = |image: Arc<Image>| image::guess_format(&image.data).unwrap();
+        assert_eq!(
+            format_of(webp.get_image("tall.png").unwrap()),
+            image::ImageFormat::WebP
+        );
+        assert_eq!(
+            format_of(passthrough.get_image("tall.png").unwrap()),
+            image::ImageFormat::Png
+        );
+        assert_eq!(
+            format_of(webp.get_image_from_cache("tall.png").unwrap()),
+            image::ImageFormat::WebP
+        );
+    }
 }
@@ -0,0 +1,245 @@
+use std::{
+    collections::{BTreeMap, VecDeque},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+    time::Duration,
+};
+
+use image::ImageFormat;
+use serde::{Deserialize, Serialize};
+
+/// The number of timing samples kept; older samples are dropped first.
+const RING_CAPACITY: usize = 2048;
+
+/// The most recent timing samples, oldest first.
+///
+/// A plain `std` mutex: it is only held for a push or a copy, never across an `.await`.
+static SAMPLES: Mutex<VecDeque<TimingSample>> = Mutex::new(VecDeque::new());
+
+/// The load time in milliseconds above which a page is logged (`0` = never).
+static SLOW_PAGE_THRESHOLD_MS: AtomicU64 = AtomicU64::new(0);
+
+/// A step of the image pipeline that is timed.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, specta::Type,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum Stage {
+    /// Reading a page out of its container (for PDF, this includes the render).
+    Read,
+    /// Rasterizing a PDF page.
+    PdfRender,
+    /// Decoding a page before it is resized.
+    Decode,
+    /// Resizing a decoded page.
+    Resize,
+    /// Re-encoding a resized page.
+    Encode,
+    /// The whole load of a page, from the read to the final encode.
+    Load,
+}
+
+/// One timed step of loading a page.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "camelCase")]
+pub struct TimingSample {
+    /// The timed step.
+    pub stage: Stage,
+    /// The entry the step worked on.
+    pub entry: String,
+    /// The format of the source image (e.g. `jpg`, `png`, `pdf`), if known.
+    pub format: Option<String>,
+    /// How long the step took, in milliseconds.
+    pub duration_ms: f64,
+    /// When the step finished, in milliseconds since the Unix epoch.
+    pub recorded_at: i64,
+}
+
+/// The timings of one step for one source format.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "camelCase")]
+pub struct StageSummary {
+    /// The timed step.
+    pub stage: Stage,
+    /// The format of the source images, if known.
+    pub format: Option<String>,
+    /// The number of samples.
+    pub count: u32,
+    /// The mean duration in milliseconds.
+    pub mean_ms: f64,
+    /// The 95th percentile duration in milliseconds.
+    pub p95_ms: f64,
+    /// The longest duration in milliseconds.
+    pub max_ms: f64,
+}
+
+/// The recent timings of the image pipeline.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "camelCase")]
+pub struct PerformanceMetrics {
+    /// The most recent samples, oldest first.
+    pub samples: Vec<TimingSample>,
+    /// The samples aggregated per step and source format.
+    pub summaries: Vec<StageSummary>,
+}
+
+/// Returns the name a source format is reported under.
+pub fn format_name(format: ImageFormat) -> Option<&'static str> {
+    format.extensions_str().first().copied()
+}
+
+/// Records how long a step took, dropping the oldest sample once the buffer is full.
+///
+/// # Arguments
+///
+/// * `stage` - The timed step.
+/// * `entry` - The entry the step worked on.
+/// * `format` - The format of the source image, if known.
+/// * `duration` - How long the step took.
+pub fn record(stage: Stage, entry: &str, format: Option<&str>, duration: Duration) {
+    let sample = TimingSample {
+        stage,
+        entry: entry.to_string(),
+        format: format.map(str::to_string),
+        duration_ms: duration.as_secs_f64() * 1000.0,
+        recorded_at: chrono::Utc::now().timestamp_millis(),
+    };
+    let mut samples = SAMPLES.lock().unwrap_or_else(|e| e.into_inner());
+    if samples.len() == RING_CAPACITY {
+        samples.pop_front();
+    }
+    samples.push_back(sample);
+}
+
+/// Sets the load time above which a page is logged as slow; `Duration::ZERO` disables
+/// the log.
+pub fn set_slow_page_threshold(threshold: Duration) {
+    SLOW_PAGE_THRESHOLD_MS.store(threshold.as_millis() as u64, Ordering::Relaxed);
+}
+
+/// Logs a page whose load took longer than the slow page threshold.
+///
+/// # Arguments
+///
+/// * `entry` - The loaded entry.
+/// * `read` - How long reading the entry out of its container took.
+/// * `total` - How long the whole load took.
+pub fn log_if_slow(entry: &str, read: Duration, total: Duration) {
+    let threshold = SLOW_PAGE_THRESHOLD_MS.load(Ordering::Relaxed);
+    if threshold == 0 || total.as_millis() < u128::from(threshold) {
+        return;
+    }
+    log::warn!(
+        "Slow page {}: {:.1} ms (read {:.1} ms, decode/resize/encode {:.1} ms)",
+        entry,
+        total.as_secs_f64() * 1000.0,
+        read.as_secs_f64() * 1000.0,
+        total.saturating_sub(read).as_secs_f64() * 1000.0
+    );
+}
+
+/// Returns a copy of the recorded samples and their per-step summaries.
+pub fn snapshot() -> PerformanceMetrics {
+    let samples: Vec<TimingSample> = SAMPLES
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .iter()
+        .cloned()
+        .collect();
+    let summaries = summarize(&samples);
+    PerformanceMetrics { samples, summaries }
+}
+
+/// Aggregates samples per step and source format.
+fn summarize(samples: &[TimingSample]) -> Vec<StageSummary> {
+    let mut groups: BTreeMap<(Stage, Option<&str>), Vec<f64>> = BTreeMap::new();
+    for sample in samples {
+        groups
+            .entry((sample.stage, sample.format.as_deref()))
+            .or_default()
+            .push(sample.duration_ms);
+    }
+
+    groups
+        .into_iter()
+        .map(|((stage, format), mut durations)| {
+            durations.sort_by(f64::total_cmp);
+            let count = durations.len();
+            // Nearest-rank percentile.
+            let p95_index = (count * 95).div_ceil(100).saturating_sub(1);
+            StageSummary {
+                stage,
+                format: format.map(str::to_string),
+                count: count as u32,
+                mean_ms: durations.iter().sum::<f64>() / count as f64,
+                p95_ms: durations[p95_index],
+                max_ms: durations[count - 1],
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(stage: Stage, format: Option<&str>, duration_ms: f64) -> TimingSample {
+        TimingSample {
+            stage,
+            entry: "page.jpg".to_string(),
+            format: format.map(str::to_string),
+            duration_ms,
+            recorded_at: 0,
+        }
+    }
+
+    #[test]
+    fn test_summarize_groups_per_stage_and_format() {
+        let mut samples: Vec<_> = (1..=20)
+            .map(|ms| sample(Stage::Decode, Some("jpg"), ms as f64))
+            .collect();
+        samples.push(sample(Stage::Decode, Some("png"), 7.0));
+        samples.push(sample(Stage::Read, None, 3.0));
+
+        let summaries = summarize(&samples);
+
+        assert_eq!(summaries.len(), 3);
+        let read = &summaries[0];
+        assert_eq!((read.stage, read.format.as_deref()), (Stage::Read, None));
+        let jpg = &summaries[1];
+        assert_eq!(
+            (jpg.stage, jpg.format.as_deref()),
+            (Stage::Decode, Some("jpg"))
+        );
+        assert_eq!(jpg.count, 20);
+        assert_eq!(jpg.mean_ms, 10.5);
+        assert_eq!(jpg.p95_ms, 19.0);
+        assert_eq!(jpg.max_ms, 20.0);
+        assert_eq!(summaries[2].count, 1);
+        assert_eq!(summaries[2].p95_ms, 7.0);
+    }
+
+    #[test]
+    fn test_record_is_visible_in_snapshot() {
+        let entry = "test_record_is_visible_in_snapshot.png";
+        record(Stage::Resize, entry, Some("png"), Duration::from_millis(12));
+
+        let metrics = snapshot();
+        let recorded = metrics
+            .samples
+            .iter()
+            .find(|sample| sample.entry == entry)
+            .expect("the sample should be recorded");
+        assert_eq!(recorded.stage, Stage::Resize);
+        assert!((recorded.duration_ms - 12.0).abs() < 1e-6);
+        assert!(metrics.samples.len() <= RING_CAPACITY);
+    }
+
+    #[test]
+    fn test_format_name() {
+        assert_eq!(format_name(ImageFormat::Jpeg), Some("jpg"));
+        assert_eq!(format_name(ImageFormat::WebP), Some("webp"));
+    }
+}
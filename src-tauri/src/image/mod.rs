@@ -1,4 +1,9 @@
+pub mod cache_stats;
+pub mod encoder;
 pub mod loader;
+pub mod metrics;
 pub mod resizer;
+pub mod strip;
 pub mod thumbnail;
+pub mod tile;
 pub mod types;
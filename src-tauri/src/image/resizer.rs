@@ -12,7 +12,7 @@ thread_local! {
 /// The algorithms to use when resampling images.
 ///
 /// This enum wraps the algorithms supported by `fast_image_resize`.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
 #[serde(rename_all = "camelCase")]
 pub enum ResizeFilter {
     /// Nearest neighbor interpolation. Fastest, but lowest quality.
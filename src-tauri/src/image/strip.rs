@@ -0,0 +1,277 @@
+use image::DynamicImage;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::{Error, Result},
+    image::{
+        encoder::{encode_for_display, EncodeOptions},
+        types::Image,
+    },
+};
+
+/// The separator between a page entry and a segment index in a virtual segment entry.
+const SEGMENT_SEPARATOR: &str = "#strip-";
+
+/// How tall pages are sliced for the long-strip (webtoon) reading mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StripConfig {
+    /// The height in pixels of a segment.
+    pub segment_height: u32,
+    /// The height in pixels shared by two consecutive segments of the same page.
+    pub overlap: u32,
+}
+
+impl Default for StripConfig {
+    fn default() -> Self {
+        Self {
+            segment_height: 2048,
+            overlap: 32,
+        }
+    }
+}
+
+/// One piece of the continuous long strip.
+///
+/// Pages no taller than the segment height are a single segment whose `entry` is the
+/// page itself; taller pages are sliced into virtual sub-entries that `ImageLoader`
+/// cuts from the page on request.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "camelCase")]
+pub struct StripSegment {
+    /// The entry to request through the image commands.
+    pub entry: String,
+    /// The page entry the segment is cut from.
+    pub source_entry: String,
+    /// The offset in pixels of the segment within its page.
+    pub source_top: u32,
+    /// The width of the segment in pixels.
+    pub width: u32,
+    /// The height of the segment in pixels.
+    pub height: u32,
+    /// The height in pixels shared with the previous segment of the same page.
+    pub overlap: u32,
+    /// The offset of the segment within the whole strip, in strip pixels.
+    pub strip_top: u32,
+    /// The height of the segment within the whole strip, in strip pixels.
+    pub strip_height: u32,
+}
+
+/// The layout of a book read as one continuous vertical strip.
+///
+/// Consecutive pages are stitched top to bottom. Pages narrower or wider than the
+/// strip are scaled to its width, so `strip_top`/`strip_height` are expressed in
+/// strip pixels rather than source pixels.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "camelCase")]
+pub struct StripLayout {
+    /// The width of the strip in pixels (the widest page).
+    pub width: u32,
+    /// The total height of the strip in strip pixels.
+    pub height: u32,
+    /// The segments in reading order.
+    pub segments: Vec<StripSegment>,
+}
+
+impl StripLayout {
+    /// Stitches the segments of consecutive pages into one strip.
+    ///
+    /// # Arguments
+    ///
+    /// * `pages` - The entry name, width and height of each page, in reading order.
+    /// * `config` - How tall pages are sliced.
+    pub fn new(pages: &[(String, u32, u32)], config: StripConfig) -> Self {
+        let width = pages.iter().map(|(_, w, _)| *w).max().unwrap_or(0);
+        let mut segments = Vec::new();
+        let mut page_top = 0u32;
+
+        for (entry, page_width, page_height) in pages {
+            let scale = |value: u32| -> u32 {
+                if *page_width == 0 {
+                    0
+                } else {
+                    (u64::from(value) * u64::from(width) / u64::from(*page_width)) as u32
+                }
+            };
+            let slices = slice(*page_height, config);
+            let sliced = slices.len() > 1;
+            for (index, (source_top, height, overlap)) in slices.into_iter().enumerate() {
+                segments.push(StripSegment {
+                    entry: if sliced {
+                        segment_entry(entry, index)
+                    } else {
+                        entry.clone()
+                    },
+                    source_entry: entry.clone(),
+                    source_top,
+                    width: *page_width,
+                    height,
+                    overlap,
+                    strip_top: page_top + scale(source_top),
+                    strip_height: scale(height),
+                });
+            }
+            page_top += scale(*page_height);
+        }
+
+        Self {
+            width,
+            height: page_top,
+            segments,
+        }
+    }
+}
+
+/// Splits a page of `height` pixels into `(top, height, overlap)` segments.
+///
+/// Consecutive segments advance by `segment_height - overlap`, so each one repeats the
+/// last `overlap` pixels of the previous one; the final segment holds the remainder.
+fn slice(height: u32, config: StripConfig) -> Vec<(u32, u32, u32)> {
+    if height <= config.segment_height {
+        return vec![(0, height, 0)];
+    }
+
+    // The settings bounds keep the overlap below the segment height; clamp anyway so a
+    // hand-built config cannot stall the loop.
+    let step = config.segment_height.saturating_sub(config.overlap).max(1);
+    let mut slices = Vec::new();
+    let mut top = 0;
+    loop {
+        let segment_height = config.segment_height.min(height - top);
+        let overlap = if top == 0 { 0 } else { config.overlap };
+        slices.push((top, segment_height, overlap));
+        if top + segment_height >= height {
+            return slices;
+        }
+        top += step;
+    }
+}
+
+/// Builds the name of the virtual entry holding segment `index` of `entry`.
+pub fn segment_entry(entry: &str, index: usize) -> String {
+    format!("{entry}{SEGMENT_SEPARATOR}{index}")
+}
+
+/// Splits a virtual segment entry into its page entry and segment index.
+///
+/// Returns `None` if `entry` is not shaped like a segment entry. A real entry may still
+/// carry the suffix, so callers must check the page entry exists.
+pub fn parse_segment_entry(entry: &str) -> Option<(&str, usize)> {
+    let (page, index) = entry.rsplit_once(SEGMENT_SEPARATOR)?;
+    Some((page, index.parse().ok()?))
+}
+
+/// Cuts segment `index` out of a decoded page.
+///
+/// # Arguments
+///
+/// * `source` - The decoded page.
+/// * `index` - The segment index within the page.
+/// * `config` - How tall pages are sliced.
+/// * `encoding` - How the segment is encoded.
+///
+/// # Returns
+///
+/// A `Result` containing the encoded segment.
+///
+/// # Errors
+///
+/// Returns `Error::InvalidArgument` if the page has no such segment, or an `Err` if
+/// encoding fails.
+pub fn extract_segment(
+    source: &DynamicImage,
+    index: usize,
+    config: StripConfig,
+    encoding: EncodeOptions,
+) -> Result<Image> {
+    let slices = slice(source.height(), config);
+    let (top, height, _) = *slices.get(index).ok_or_else(|| {
+        Error::InvalidArgument(format!(
+            "Segment {index} is outside the {}px tall page ({} segments)",
+            source.height(),
+            slices.len()
+        ))
+    })?;
+
+    let segment = source.crop_imm(0, top, source.width(), height);
+    Ok(Image {
+        data: encode_for_display(&segment, encoding)?,
+        width: segment.width(),
+        height: segment.height(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use image::RgbImage;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::error::ErrorCode;
+
+    const CONFIG: StripConfig = StripConfig {
+        segment_height: 1000,
+        overlap: 100,
+    };
+
+    #[rstest]
+    #[case(500, vec![(0, 500, 0)])]
+    #[case(1000, vec![(0, 1000, 0)])]
+    #[case(1900, vec![(0, 1000, 0), (900, 1000, 100)])]
+    #[case(2000, vec![(0, 1000, 0), (900, 1000, 100), (1800, 200, 100)])]
+    fn test_slice(#[case] height: u32, #[case] expected: Vec<(u32, u32, u32)>) {
+        assert_eq!(slice(height, CONFIG), expected);
+    }
+
+    #[rstest]
+    #[case("page.png#strip-3", Some(("page.png", 3)))]
+    #[case("dir/a#strip-b.png#strip-0", Some(("dir/a#strip-b.png", 0)))]
+    #[case("page.png", None)]
+    #[case("page.png#strip-x", None)]
+    fn test_parse_segment_entry(#[case] entry: &str, #[case] expected: Option<(&str, usize)>) {
+        assert_eq!(parse_segment_entry(entry), expected);
+        if let Some((page, index)) = expected {
+            assert_eq!(segment_entry(page, index), entry);
+        }
+    }
+
+    #[test]
+    fn test_strip_layout_stitches_pages() {
+        let pages = vec![
+            ("01.png".to_string(), 800, 600),
+            ("02.png".to_string(), 800, 1900),
+            // Half as wide: scaled up to the strip width.
+            ("03.png".to_string(), 400, 300),
+        ];
+
+        let layout = StripLayout::new(&pages, CONFIG);
+
+        assert_eq!(layout.width, 800);
+        assert_eq!(layout.height, 600 + 1900 + 600);
+        let summary: Vec<_> = layout
+            .segments
+            .iter()
+            .map(|s| (s.entry.as_str(), s.strip_top, s.strip_height, s.overlap))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("01.png", 0, 600, 0),
+                ("02.png#strip-0", 600, 1000, 0),
+                ("02.png#strip-1", 1500, 1000, 100),
+                ("03.png", 2500, 600, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extract_segment() {
+        let source = DynamicImage::ImageRgb8(RgbImage::new(300, 2000));
+
+        let segment = extract_segment(&source, 2, CONFIG, EncodeOptions::default()).unwrap();
+        assert_eq!((segment.width, segment.height), (300, 200));
+
+        let err = extract_segment(&source, 3, CONFIG, EncodeOptions::default()).unwrap_err();
+        let code: ErrorCode = (&err).into();
+        assert_eq!(code, ErrorCode::InvalidArgument);
+    }
+}
@@ -1,11 +1,12 @@
 use std::io::Cursor;
 use std::sync::Arc;
 
-use image::{codecs::jpeg::JpegEncoder, ImageReader};
+use image::ImageReader;
 
 use crate::{
     error::Result,
     image::{
+        encoder::{encode_jpeg, EncodeOptions},
         resizer::{shrink_to_fit, ResizeFilter},
         types::Image,
     },
@@ -17,7 +18,7 @@ pub const THUMBNAIL_SIZE: u32 = 300;
 /// Generates a JPEG thumbnail from raw image data using SIMD-accelerated resizing.
 ///
 /// This function decodes the provided image data, shrinks it to fit within
-/// `THUMBNAIL_SIZE`, and encodes the result as a low-quality JPEG.
+/// `THUMBNAIL_SIZE`, and encodes the result as a JPEG with `EncodeOptions::THUMBNAIL`.
 ///
 /// # Arguments
 ///
@@ -42,13 +43,8 @@ pub fn generate_thumbnail(data: &[u8]) -> Result<Arc<Image>> {
         ResizeFilter::Bilinear,
     )?;
 
-    let mut buffer = Vec::new();
-    // Use a lower quality for thumbnails to make them smaller and faster to encode.
-    let mut encoder = JpegEncoder::new_with_quality(&mut buffer, 10);
-    encoder.encode_image(&thumbnail)?;
-
     Ok(Arc::new(Image {
-        data: buffer,
+        data: encode_jpeg(&thumbnail, EncodeOptions::THUMBNAIL)?,
         width: thumbnail.width(),
         height: thumbnail.height(),
     }))
@@ -0,0 +1,215 @@
+use image::DynamicImage;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::{Error, Result},
+    image::{
+        encoder::{encode_for_display, EncodeOptions},
+        resizer::{resize_exact, ResizeFilter},
+        types::Image,
+    },
+};
+
+/// The edge length in pixels of a deep-zoom tile.
+pub const TILE_SIZE: u32 = 512;
+
+/// Describes the deep-zoom tile pyramid of a page.
+///
+/// Level `0` is the full-resolution source; each further level halves both dimensions,
+/// up to `max_level`, at which the whole page fits in a single tile.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+pub struct TileInfo {
+    /// The width of the full-resolution source in pixels.
+    pub width: u32,
+    /// The height of the full-resolution source in pixels.
+    pub height: u32,
+    /// The edge length of a tile in pixels.
+    pub tile_size: u32,
+    /// The coarsest level of the pyramid.
+    pub max_level: u32,
+}
+
+impl TileInfo {
+    /// Computes the tile pyramid of a source image with the given dimensions.
+    pub fn new(width: u32, height: u32) -> Self {
+        let mut max_level = 0;
+        while width.max(height).div_ceil(1 << max_level) > TILE_SIZE {
+            max_level += 1;
+        }
+        Self {
+            width,
+            height,
+            tile_size: TILE_SIZE,
+            max_level,
+        }
+    }
+
+    /// Returns the number of tile columns and rows at `level`.
+    pub fn grid_size(&self, level: u32) -> (u32, u32) {
+        let covered = TILE_SIZE << level;
+        (self.width.div_ceil(covered), self.height.div_ceil(covered))
+    }
+}
+
+/// Cuts a tile out of a full-resolution source image.
+///
+/// The tile at (`x`, `y`) on `level` covers a `TILE_SIZE << level` square of the source,
+/// which is downscaled by `2^level` so every tile is at most `TILE_SIZE` pixels wide and
+/// high. Tiles on the right and bottom edges are smaller.
+///
+/// # Arguments
+///
+/// * `source` - The decoded full-resolution source image.
+/// * `level` - The pyramid level (`0` is the full resolution).
+/// * `x` - The tile column.
+/// * `y` - The tile row.
+/// * `filter` - The algorithm used to downscale tiles on levels above `0`.
+/// * `encoding` - How the tile is encoded.
+///
+/// # Returns
+///
+/// A `Result` containing the encoded tile.
+///
+/// # Errors
+///
+/// Returns `Error::InvalidArgument` if the level or tile coordinates lie outside the
+/// pyramid, or an `Err` if resizing or encoding fails.
+pub fn extract_tile(
+    source: &DynamicImage,
+    level: u32,
+    x: u32,
+    y: u32,
+    filter: ResizeFilter,
+    encoding: EncodeOptions,
+) -> Result<Image> {
+    let info = TileInfo::new(source.width(), source.height());
+    // Check the level first: `grid_size` shifts by it.
+    let in_range = level <= info.max_level && {
+        let (columns, rows) = info.grid_size(level);
+        x < columns && y < rows
+    };
+    if !in_range {
+        return Err(Error::InvalidArgument(format!(
+            "Tile ({x}, {y}) on level {level} is outside the {}x{} image (max level {})",
+            info.width, info.height, info.max_level
+        )));
+    }
+
+    let covered = TILE_SIZE << level;
+    let src_x = x * covered;
+    let src_y = y * covered;
+    let src_width = covered.min(info.width - src_x);
+    let src_height = covered.min(info.height - src_y);
+    let region = source.crop_imm(src_x, src_y, src_width, src_height);
+
+    let tile = if level == 0 {
+        region
+    } else {
+        let scale = 1 << level;
+        resize_exact(
+            &region,
+            src_width.div_ceil(scale),
+            src_height.div_ceil(scale),
+            filter,
+        )?
+    };
+
+    Ok(Image {
+        data: encode_for_display(&tile, encoding)?,
+        width: tile.width(),
+        height: tile.height(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use image::RgbImage;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::error::ErrorCode;
+
+    #[rstest]
+    #[case(100, 100, 0)]
+    #[case(512, 512, 0)]
+    #[case(513, 100, 1)]
+    #[case(1024, 1024, 1)]
+    #[case(800, 20000, 6)]
+    fn test_tile_info_max_level(#[case] width: u32, #[case] height: u32, #[case] expected: u32) {
+        assert_eq!(TileInfo::new(width, height).max_level, expected);
+    }
+
+    #[test]
+    fn test_tile_info_grid_size() {
+        let info = TileInfo::new(1200, 3000);
+        assert_eq!(info.grid_size(0), (3, 6));
+        assert_eq!(info.grid_size(1), (2, 3));
+        assert_eq!(info.grid_size(info.max_level), (1, 1));
+    }
+
+    #[test]
+    fn test_extract_tile_full_resolution() {
+        let source = DynamicImage::ImageRgb8(RgbImage::new(1200, 700));
+
+        let tile = extract_tile(
+            &source,
+            0,
+            0,
+            0,
+            ResizeFilter::Bilinear,
+            EncodeOptions::default(),
+        )
+        .unwrap();
+        assert_eq!((tile.width, tile.height), (TILE_SIZE, TILE_SIZE));
+
+        // The bottom-right edge tile holds only the remainder.
+        let tile = extract_tile(
+            &source,
+            0,
+            2,
+            1,
+            ResizeFilter::Bilinear,
+            EncodeOptions::default(),
+        )
+        .unwrap();
+        assert_eq!((tile.width, tile.height), (1200 - 1024, 700 - 512));
+        assert!(!tile.data.is_empty());
+    }
+
+    #[test]
+    fn test_extract_tile_downscaled_level() {
+        let source = DynamicImage::ImageRgb8(RgbImage::new(1200, 700));
+
+        let tile = extract_tile(
+            &source,
+            1,
+            1,
+            0,
+            ResizeFilter::Bilinear,
+            EncodeOptions::default(),
+        )
+        .unwrap();
+        assert_eq!((tile.width, tile.height), ((1200 - 1024) / 2, 350));
+    }
+
+    #[rstest]
+    #[case(3, 0, 0)] // Level above the pyramid.
+    #[case(40, 0, 0)] // Level beyond any shift width.
+    #[case(0, 3, 0)] // Column outside the image.
+    #[case(0, 0, 2)] // Row outside the image.
+    fn test_extract_tile_out_of_range(#[case] level: u32, #[case] x: u32, #[case] y: u32) {
+        let source = DynamicImage::ImageRgb8(RgbImage::new(1200, 700));
+
+        let err = extract_tile(
+            &source,
+            level,
+            x,
+            y,
+            ResizeFilter::Bilinear,
+            EncodeOptions::default(),
+        )
+        .unwrap_err();
+        let code: ErrorCode = (&err).into();
+        assert_eq!(code, ErrorCode::InvalidArgument);
+    }
+}
@@ -1,9 +1,14 @@
-use std::io::Cursor;
+use std::io::{Cursor, Read};
 
 use image::ImageReader;
 use serde::{Deserialize, Serialize};
 use tauri::ipc::Response;
 
+/// How many leading bytes of an image file [`Image::read_dimensions`] reads before
+/// falling back to the whole file. The headers of the supported formats fit well within
+/// it unless large metadata (e.g. an embedded JPEG thumbnail) precedes them.
+const DIMENSIONS_PREFIX_BYTES: u64 = 64 * 1024;
+
 /// Represents image data and its dimensions.
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct Image {
@@ -34,9 +39,7 @@ impl Image {
     /// Returns an `image::ImageError` if the provided data cannot be decoded as a
     /// supported image format.
     pub fn new(data: Vec<u8>) -> Result<Self, image::ImageError> {
-        let cursor = Cursor::new(&data);
-        let image_reader = ImageReader::new(cursor).with_guessed_format()?;
-        let (width, height) = image_reader.into_dimensions()?;
+        let (width, height) = dimensions_of(&data)?;
         Ok(Image {
             data,
             width,
@@ -44,6 +47,39 @@ impl Image {
         })
     }
 
+    /// Reads the width and height of an image without reading the whole file.
+    ///
+    /// Only a bounded prefix of `reader` is read first; the rest is read only if the
+    /// image header does not fit in it.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - A reader positioned at the start of the image file. Callers bound it
+    ///   to the size of the file.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the width and height of the image in pixels.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `image::ImageError` if the reader fails or the data is not a
+    /// supported image format.
+    pub fn read_dimensions<R: Read>(mut reader: R) -> Result<(u32, u32), image::ImageError> {
+        let mut data = Vec::new();
+        reader
+            .by_ref()
+            .take(DIMENSIONS_PREFIX_BYTES)
+            .read_to_end(&mut data)?;
+        let dimensions = dimensions_of(&data);
+        if dimensions.is_ok() || (data.len() as u64) < DIMENSIONS_PREFIX_BYTES {
+            return dimensions;
+        }
+
+        reader.read_to_end(&mut data)?;
+        dimensions_of(&data)
+    }
+
     /// Checks if a filename has a supported image file extension.
     ///
     /// Supported formats are based on common web formats like PNG, JPEG, GIF, and WebP.
@@ -87,6 +123,13 @@ impl Image {
     }
 }
 
+/// Reads the width and height from the header of encoded image data.
+fn dimensions_of(data: &[u8]) -> Result<(u32, u32), image::ImageError> {
+    ImageReader::new(Cursor::new(data))
+        .with_guessed_format()?
+        .into_dimensions()
+}
+
 #[cfg(test)]
 mod tests {
     use rstest::*;
@@ -171,6 +214,47 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_read_dimensions_reads_only_the_header() {
+        // A 1x1 PNG followed by far more data than the header prefix.
+        let mut data = vec![
+            0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A, 0x00, 0x00, 0x00, 0x0D, 0x49, 0x48,
+            0x44, 0x52, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x08, 0x02, 0x00, 0x00,
+            0x00, 0x90, 0x77, 0x53, 0xDE, 0x00, 0x00, 0x00, 0x0C, 0x49, 0x44, 0x41, 0x54, 0x08,
+            0x99, 0x63, 0xF8, 0xFF, 0xFF, 0xFF, 0x7F, 0x00, 0x09, 0xFB, 0x03, 0xFD, 0xDE, 0x54,
+            0x4D, 0xEE, 0x00, 0x00, 0x00, 0x00, 0x49, 0x45, 0x4E, 0x44, 0xAE, 0x42, 0x60, 0x82,
+        ];
+        data.resize(1024 * 1024, 0);
+        let mut reader = Cursor::new(data);
+
+        assert_eq!(Image::read_dimensions(&mut reader).unwrap(), (1, 1));
+        assert!(reader.position() <= DIMENSIONS_PREFIX_BYTES);
+    }
+
+    #[test]
+    fn test_read_dimensions_reads_past_large_metadata() {
+        let mut jpeg = Vec::new();
+        image::RgbImage::new(3, 2)
+            .write_to(&mut Cursor::new(&mut jpeg), image::ImageFormat::Jpeg)
+            .unwrap();
+        // Two maximal APP15 segments after the SOI marker push the frame header past
+        // the prefix.
+        let mut data = jpeg[..2].to_vec();
+        for _ in 0..2 {
+            data.extend_from_slice(&[0xFF, 0xEF, 0xFF, 0xFF]);
+            data.resize(data.len() + 0xFFFF - 2, 0);
+        }
+        data.extend_from_slice(&jpeg[2..]);
+        assert!(data.len() as u64 > DIMENSIONS_PREFIX_BYTES * 2);
+
+        assert_eq!(Image::read_dimensions(data.as_slice()).unwrap(), (3, 2));
+    }
+
+    #[test]
+    fn test_read_dimensions_with_invalid_data() {
+        assert!(Image::read_dimensions([0xFF, 0xD8, 0xFF, 0xE0].as_slice()).is_err());
+    }
+
     #[test]
     fn test_image_to_ipc_response() {
         use tauri::ipc::{InvokeResponseBody::Raw, IpcResponse};
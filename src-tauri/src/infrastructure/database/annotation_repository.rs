@@ -0,0 +1,85 @@
+use async_trait::async_trait;
+use sqlx::SqlitePool;
+
+use crate::domain::annotation::entity::{compare_cfi, Annotation, NewAnnotation};
+use crate::domain::annotation::repository::AnnotationRepository;
+use crate::error::Result;
+
+/// SQLite implementation of the `AnnotationRepository`.
+pub struct SqliteAnnotationRepository {
+    /// The connection pool for the SQLite database.
+    pool: SqlitePool,
+}
+
+impl SqliteAnnotationRepository {
+    /// Creates a new `SqliteAnnotationRepository` instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` - The `SqlitePool` to use for database connections.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `SqliteAnnotationRepository`.
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl AnnotationRepository for SqliteAnnotationRepository {
+    async fn add(&self, annotation: &NewAnnotation) -> Result<Annotation> {
+        let now = chrono::Utc::now().naive_utc();
+        let annotation = sqlx::query_as!(
+            Annotation,
+            r#"
+            INSERT INTO annotations (book_id, cfi_range, selected_text, note, color_code, created_at)
+            VALUES (?, ?, ?, ?, ?, ?)
+            RETURNING id, book_id, cfi_range, selected_text, note, color_code, created_at
+            "#,
+            annotation.book_id,
+            annotation.cfi_range,
+            annotation.selected_text,
+            annotation.note,
+            annotation.color_code,
+            now
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(annotation)
+    }
+
+    async fn get_for_book(&self, book_id: i64) -> Result<Vec<Annotation>> {
+        let mut annotations = sqlx::query_as!(
+            Annotation,
+            r#"
+            SELECT id, book_id, cfi_range, selected_text, note, color_code, created_at
+            FROM annotations
+            WHERE book_id = ?
+            ORDER BY created_at, id
+            "#,
+            book_id
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        // CFIs do not sort as text, so the reading order is settled here.
+        annotations.sort_by(|a, b| compare_cfi(&a.cfi_range, &b.cfi_range));
+        Ok(annotations)
+    }
+
+    async fn delete(&self, id: i64) -> Result<()> {
+        sqlx::query!(
+            r#"
+            DELETE FROM annotations
+            WHERE id = ?
+            "#,
+            id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
@@ -1,10 +1,44 @@
 use async_trait::async_trait;
-use sqlx::SqlitePool;
+use chrono::{NaiveDateTime, TimeDelta};
+use sqlx::{QueryBuilder, Sqlite, SqliteConnection, SqlitePool};
 
-use crate::domain::book::entity::{Book, BookWithState, ReadBook, ReadingState};
+use crate::container::entry_filter::ExclusionRule;
+use crate::domain::book::entity::{
+    Book, BookMetadata, BookQuery, BookQueryResult, BookSearchFilters, BookSortKey, BookWithState,
+    ReadBook, ReadStatus, ReadingState, TagMatch,
+};
 use crate::domain::book::repository::BookRepository;
 use crate::error::Result;
 use crate::infrastructure::database::models::BookWithStateRow;
+use crate::infrastructure::database::{search_index, NATURAL_COLLATION};
+use crate::search_text;
+
+/// The columns of the `book_search` full-text index, in the order of their `bm25`
+/// weights in [`SEARCH_RANKING`].
+const SEARCH_COLUMNS: [&str; 7] = [
+    "display_name",
+    "file_path",
+    "series_name",
+    "tag_names",
+    "authors",
+    "publisher",
+    "description",
+];
+
+/// Orders search results by relevance, weighting display name matches highest.
+const SEARCH_RANKING: &str = "bm25(book_search, 10.0, 1.0, 5.0, 3.0, 5.0, 2.0, 1.0)";
+
+/// The trigram tokenizer only indexes terms of at least this many characters; shorter
+/// terms are matched with `LIKE` instead.
+const MIN_INDEXED_TERM_CHARS: usize = 3;
+
+/// How long, in minutes, reading can pause before the next page turn starts a new
+/// reading session instead of continuing the current one.
+const SESSION_IDLE_MINUTES: i64 = 30;
+
+/// The most pages a single turn can advance: two, for a two-page spread. A bigger move
+/// is a jump (e.g. with the page slider) and is not counted as pages read.
+const MAX_PAGES_PER_TURN: i64 = 2;
 
 /// SQLite implementation of the `BookRepository`.
 pub struct SqliteBookRepository {
@@ -77,7 +111,8 @@ impl BookRepository for SqliteBookRepository {
             r#"
             SELECT
                 id, file_path, item_type, display_name, total_pages, series_id, series_order,
-                thumbnail_path, created_at, last_read_page_index, last_opened_at,
+                thumbnail_path, created_at, damage_code, missing_since, read_status as "read_status: _",
+                rating, is_favorite, last_read_page_index, last_read_cfi, last_opened_at,
                 tag_ids_str as "tag_ids_str?: String"
             FROM book_with_state_view
             WHERE id = ?
@@ -125,6 +160,7 @@ impl BookRepository for SqliteBookRepository {
         .await?
         .id;
 
+        search_index::refresh(&self.pool).await?;
         Ok(book_id)
     }
 
@@ -175,11 +211,240 @@ impl BookRepository for SqliteBookRepository {
         .execute(&mut *tx)
         .await?;
 
+        sqlx::query!(
+            r#"
+            UPDATE books
+            SET read_status = 'reading'
+            WHERE id = ? AND read_status IN ('unread', 'want_to_read')
+            "#,
+            book_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        let page = sqlx::query_scalar!(
+            r#"
+            SELECT last_read_page_index
+            FROM reading_state
+            WHERE book_id = ?
+            "#,
+            book_id
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+        track_reading_session(&mut tx, book_id, page, page, now).await?;
+
         tx.commit().await?;
+        search_index::refresh(&self.pool).await?;
         Ok(book_id)
     }
 
+    async fn set_damage_code(&self, book_id: i64, damage_code: Option<i64>) -> Result<()> {
+        sqlx::query!(
+            r#"
+            UPDATE books
+            SET damage_code = ?
+            WHERE id = ?
+            "#,
+            damage_code,
+            book_id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn set_metadata(&self, book_id: i64, metadata: &BookMetadata) -> Result<()> {
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query!(
+            r#"
+            INSERT INTO book_metadata (book_id, publisher, description)
+            VALUES (?, ?, ?)
+            ON CONFLICT(book_id) DO UPDATE SET
+                publisher = excluded.publisher,
+                description = excluded.description
+            "#,
+            book_id,
+            metadata.publisher,
+            metadata.description
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        let detached = sqlx::query_scalar!(
+            r#"
+            SELECT creator_id FROM book_creators WHERE book_id = ?
+            "#,
+            book_id
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+        sqlx::query!(
+            r#"
+            DELETE FROM book_creators WHERE book_id = ?
+            "#,
+            book_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        for (position, credit) in metadata.creators.iter().enumerate() {
+            // A renamed or merged creator is found through the alias of the name the
+            // file still uses.
+            let alias = sqlx::query_scalar!(
+                r#"
+                SELECT creator_id FROM creator_aliases WHERE name = ?
+                "#,
+                credit.name
+            )
+            .fetch_optional(&mut *tx)
+            .await?;
+            let creator_id = match alias {
+                Some(creator_id) => creator_id,
+                None => {
+                    sqlx::query!(
+                        r#"
+                        INSERT OR IGNORE INTO creators (name) VALUES (?)
+                        "#,
+                        credit.name
+                    )
+                    .execute(&mut *tx)
+                    .await?;
+                    sqlx::query_scalar!(
+                        r#"
+                        SELECT id FROM creators WHERE name = ?
+                        "#,
+                        credit.name
+                    )
+                    .fetch_one(&mut *tx)
+                    .await?
+                }
+            };
+
+            let position = position as i64;
+            sqlx::query!(
+                r#"
+                INSERT OR IGNORE INTO book_creators (book_id, creator_id, role, position)
+                VALUES (?, ?, ?, ?)
+                "#,
+                book_id,
+                creator_id,
+                credit.role,
+                position
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        // Drop the creators this book credited and no book credits anymore, after
+        // crediting the current ones so that creators still credited keep their ID.
+        for creator_id in detached {
+            sqlx::query!(
+                r#"
+                DELETE FROM creators
+                WHERE id = ?
+                    AND NOT EXISTS (SELECT 1 FROM book_creators bc WHERE bc.creator_id = creators.id)
+                "#,
+                creator_id
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+        search_index::refresh(&self.pool).await?;
+        Ok(())
+    }
+
+    async fn get_exclusion_rules(&self, book_id: i64) -> Result<Vec<ExclusionRule>> {
+        let row = sqlx::query!(
+            r#"
+            SELECT exclusion_rules
+            FROM books
+            WHERE id = ?
+            "#,
+            book_id
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        match row.and_then(|row| row.exclusion_rules) {
+            Some(json) => Ok(serde_json::from_str(&json)?),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    async fn set_exclusion_rules(&self, book_id: i64, rules: &[ExclusionRule]) -> Result<()> {
+        let rules = if rules.is_empty() {
+            None
+        } else {
+            Some(serde_json::to_string(rules)?)
+        };
+        let result = sqlx::query!(
+            r#"
+            UPDATE books
+            SET exclusion_rules = ?
+            WHERE id = ?
+            "#,
+            rules,
+            book_id
+        )
+        .execute(&self.pool)
+        .await?;
+        if result.rows_affected() == 0 {
+            return Err(sqlx::Error::RowNotFound.into());
+        }
+
+        Ok(())
+    }
+
+    async fn get_page_order(&self, book_id: i64) -> Result<Vec<String>> {
+        let row = sqlx::query!(
+            r#"
+            SELECT page_order
+            FROM books
+            WHERE id = ?
+            "#,
+            book_id
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        match row.and_then(|row| row.page_order) {
+            Some(json) => Ok(serde_json::from_str(&json)?),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    async fn set_page_order(&self, book_id: i64, page_order: &[String]) -> Result<()> {
+        let page_order = if page_order.is_empty() {
+            None
+        } else {
+            Some(serde_json::to_string(page_order)?)
+        };
+        let result = sqlx::query!(
+            r#"
+            UPDATE books
+            SET page_order = ?
+            WHERE id = ?
+            "#,
+            page_order,
+            book_id
+        )
+        .execute(&self.pool)
+        .await?;
+        if result.rows_affected() == 0 {
+            return Err(sqlx::Error::RowNotFound.into());
+        }
+
+        Ok(())
+    }
+
     async fn clear_reading_history(&self, book_id: i64) -> Result<()> {
+        let mut tx = self.pool.begin().await?;
+
         sqlx::query!(
             r#"
             DELETE FROM reading_state
@@ -187,37 +452,190 @@ impl BookRepository for SqliteBookRepository {
             "#,
             book_id
         )
-        .execute(&self.pool)
+        .execute(&mut *tx)
         .await?;
 
+        sqlx::query!(
+            r#"
+            UPDATE books
+            SET read_status = 'unread'
+            WHERE id = ? AND read_status = 'reading'
+            "#,
+            book_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
         Ok(())
     }
 
     async fn clear_all_reading_history(&self) -> Result<()> {
+        let mut tx = self.pool.begin().await?;
+
         sqlx::query!(
             r#"
             DELETE FROM reading_state
             "#
         )
-        .execute(&self.pool)
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            r#"
+            UPDATE books
+            SET read_status = 'unread'
+            WHERE read_status = 'reading'
+            "#
+        )
+        .execute(&mut *tx)
         .await?;
 
+        tx.commit().await?;
         Ok(())
     }
 
     async fn update_reading_progress(&self, state: &ReadingState) -> Result<()> {
+        let mut tx = self.pool.begin().await?;
+        // The session is timed by when pages are turned, not by `state.last_opened_at`,
+        // which is when the book was opened.
+        let now = chrono::Utc::now().naive_utc();
+        let utc_offset = local_utc_offset_seconds();
+
+        let previous_page = sqlx::query_scalar!(
+            r#"
+            SELECT last_read_page_index
+            FROM reading_state
+            WHERE book_id = ?
+            "#,
+            state.book_id
+        )
+        .fetch_optional(&mut *tx)
+        .await?
+        .unwrap_or(state.last_read_page_index);
+
         sqlx::query!(
             r#"
-            INSERT INTO reading_state (book_id, last_read_page_index, last_opened_at)
-            VALUES (?, ?, COALESCE(?, CURRENT_TIMESTAMP))
+            INSERT INTO reading_state (book_id, last_read_page_index, last_read_cfi, last_opened_at)
+            VALUES (?, ?, ?, COALESCE(?, CURRENT_TIMESTAMP))
             ON CONFLICT(book_id) DO UPDATE SET
                 last_read_page_index = excluded.last_read_page_index,
+                last_read_cfi = excluded.last_read_cfi,
                 last_opened_at = COALESCE(excluded.last_opened_at, reading_state.last_opened_at)
             "#,
             state.book_id,
             state.last_read_page_index,
+            state.last_read_cfi,
             state.last_opened_at
         )
+        .execute(&mut *tx)
+        .await?;
+
+        // Reaching the last page finishes the book, even one that was set aside; going
+        // back to an earlier page (e.g. to reread a chapter) keeps it finished. SET sees the
+        // row as it was, so `finished_at` is only stamped when the book becomes read.
+        sqlx::query!(
+            r#"
+            UPDATE books
+            SET read_status = CASE
+                    WHEN total_pages > 0 AND ? >= total_pages - 1 THEN 'read'
+                    WHEN read_status IN ('unread', 'want_to_read') THEN 'reading'
+                    ELSE read_status
+                END,
+                finished_at = CASE
+                    WHEN total_pages > 0 AND ? >= total_pages - 1 AND read_status <> 'read' THEN ?
+                    ELSE finished_at
+                END,
+                finished_utc_offset_seconds = CASE
+                    WHEN total_pages > 0 AND ? >= total_pages - 1 AND read_status <> 'read' THEN ?
+                    ELSE finished_utc_offset_seconds
+                END
+            WHERE id = ?
+            "#,
+            state.last_read_page_index,
+            state.last_read_page_index,
+            now,
+            state.last_read_page_index,
+            utc_offset,
+            state.book_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        track_reading_session(
+            &mut tx,
+            state.book_id,
+            previous_page,
+            state.last_read_page_index,
+            now,
+        )
+        .await?;
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn set_read_status(&self, book_ids: &[i64], status: ReadStatus) -> Result<()> {
+        let book_ids = serde_json::to_string(book_ids)?;
+        let now = chrono::Utc::now().naive_utc();
+        let utc_offset = local_utc_offset_seconds();
+        sqlx::query!(
+            r#"
+            UPDATE books
+            SET read_status = ?,
+                finished_at = CASE
+                    WHEN ? <> 'read' THEN NULL
+                    WHEN read_status = 'read' THEN finished_at
+                    ELSE ?
+                END,
+                finished_utc_offset_seconds = CASE
+                    WHEN ? <> 'read' THEN NULL
+                    WHEN read_status = 'read' THEN finished_utc_offset_seconds
+                    ELSE ?
+                END
+            WHERE id IN (SELECT value FROM json_each(?))
+            "#,
+            status,
+            status,
+            now,
+            status,
+            utc_offset,
+            book_ids
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn set_rating(&self, book_ids: &[i64], rating: i64) -> Result<()> {
+        let book_ids = serde_json::to_string(book_ids)?;
+        sqlx::query!(
+            r#"
+            UPDATE books
+            SET rating = ?
+            WHERE id IN (SELECT value FROM json_each(?))
+            "#,
+            rating,
+            book_ids
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn set_favorite(&self, book_ids: &[i64], is_favorite: bool) -> Result<()> {
+        let book_ids = serde_json::to_string(book_ids)?;
+        sqlx::query!(
+            r#"
+            UPDATE books
+            SET is_favorite = ?
+            WHERE id IN (SELECT value FROM json_each(?))
+            "#,
+            is_favorite,
+            book_ids
+        )
         .execute(&self.pool)
         .await?;
 
@@ -276,7 +694,8 @@ impl BookRepository for SqliteBookRepository {
             r#"
             SELECT
                 id, file_path, item_type, display_name, total_pages, series_id, series_order,
-                thumbnail_path, created_at, last_read_page_index, last_opened_at,
+                thumbnail_path, created_at, damage_code, missing_since, read_status as "read_status: _",
+                rating, is_favorite, last_read_page_index, last_read_cfi, last_opened_at,
                 tag_ids_str as "tag_ids_str?: String"
             FROM book_with_state_view
             ORDER BY id DESC
@@ -290,6 +709,141 @@ impl BookRepository for SqliteBookRepository {
         Ok(books)
     }
 
+    async fn search_books(
+        &self,
+        query: &str,
+        filters: &BookSearchFilters,
+        limit: u32,
+        offset: u32,
+    ) -> Result<Vec<BookWithState>> {
+        let (match_expression, short_terms) = parse_search_query(&search_text::normalize(query));
+
+        let mut builder = QueryBuilder::<Sqlite>::new(
+            r#"
+            SELECT
+                v.id, v.file_path, v.item_type, v.display_name, v.total_pages, v.series_id,
+                v.series_order, v.thumbnail_path, v.created_at, v.damage_code, v.missing_since,
+                v.read_status, v.rating, v.is_favorite, v.last_read_page_index, v.last_read_cfi,
+                v.last_opened_at, v.tag_ids_str
+            FROM book_with_state_view v
+            JOIN book_search ON book_search.rowid = v.id
+            WHERE 1 = 1
+            "#,
+        );
+        if let Some(expression) = &match_expression {
+            builder.push(" AND book_search MATCH ");
+            builder.push_bind(expression.clone());
+        }
+        for term in short_terms {
+            let pattern = format!("%{}%", escape_like(&term));
+            builder.push(" AND (");
+            for (i, column) in SEARCH_COLUMNS.iter().enumerate() {
+                if i > 0 {
+                    builder.push(" OR ");
+                }
+                builder.push(format!("book_search.{column} LIKE "));
+                builder.push_bind(pattern.clone());
+                builder.push(r" ESCAPE '\'");
+            }
+            builder.push(")");
+        }
+        for tag_id in &filters.tag_ids {
+            builder.push(
+                " AND EXISTS (SELECT 1 FROM book_tags bt WHERE bt.book_id = v.id AND bt.tag_id = ",
+            );
+            builder.push_bind(*tag_id);
+            builder.push(")");
+        }
+        if let Some(series_id) = filters.series_id {
+            builder.push(" AND v.series_id = ");
+            builder.push_bind(series_id);
+        }
+        if let Some(bookshelf_id) = filters.bookshelf_id {
+            builder.push(" AND EXISTS (SELECT 1 FROM bookshelf_items bi WHERE bi.book_id = v.id AND bi.bookshelf_id = ");
+            builder.push_bind(bookshelf_id);
+            builder.push(")");
+        }
+        if let Some(item_type) = &filters.item_type {
+            builder.push(" AND v.item_type = ");
+            builder.push_bind(item_type.clone());
+        }
+
+        // `bm25` is only available when the full-text index is queried with MATCH.
+        if match_expression.is_some() {
+            builder.push(format!(" ORDER BY {SEARCH_RANKING}, v.id"));
+        } else {
+            builder.push(" ORDER BY v.display_name, v.id");
+        }
+        builder.push(" LIMIT ");
+        builder.push_bind(i64::from(limit));
+        builder.push(" OFFSET ");
+        builder.push_bind(i64::from(offset));
+
+        let books = builder
+            .build_query_as::<BookWithStateRow>()
+            .fetch_all(&self.pool)
+            .await?
+            .into_iter()
+            .map(BookWithState::from)
+            .collect();
+        Ok(books)
+    }
+
+    async fn query_books(&self, query: &BookQuery) -> Result<BookQueryResult> {
+        let mut count_builder =
+            QueryBuilder::<Sqlite>::new("SELECT COUNT(*) FROM book_with_state_view v WHERE 1 = 1");
+        push_query_conditions(&mut count_builder, query);
+        let total_count: i64 = count_builder
+            .build_query_scalar()
+            .fetch_one(&self.pool)
+            .await?;
+
+        let mut builder = QueryBuilder::<Sqlite>::new(
+            r#"
+            SELECT
+                v.id, v.file_path, v.item_type, v.display_name, v.total_pages, v.series_id,
+                v.series_order, v.thumbnail_path, v.created_at, v.damage_code, v.missing_since,
+                v.read_status, v.rating, v.is_favorite, v.last_read_page_index, v.last_read_cfi,
+                v.last_opened_at, v.tag_ids_str
+            FROM book_with_state_view v
+            WHERE 1 = 1
+            "#,
+        );
+        push_query_conditions(&mut builder, query);
+
+        let direction = if query.descending { "DESC" } else { "ASC" };
+        let name_order = format!("v.display_name COLLATE {NATURAL_COLLATION}");
+        let key_order = match query.sort {
+            BookSortKey::Name => format!("{name_order} {direction}"),
+            BookSortKey::AddedAt => format!("v.created_at {direction} NULLS LAST"),
+            BookSortKey::LastOpenedAt => format!("v.last_opened_at {direction} NULLS LAST"),
+            BookSortKey::TotalPages => format!("v.total_pages {direction}"),
+            // Group the books by series before ordering them within it; series names
+            // are unique, so the books of two series never interleave.
+            BookSortKey::SeriesOrder => format!(
+                "v.series_id IS NULL, \
+                 (SELECT s.name FROM series s WHERE s.id = v.series_id) \
+                 COLLATE {NATURAL_COLLATION} {direction}, \
+                 v.series_order {direction} NULLS LAST"
+            ),
+        };
+        builder.push(format!(" ORDER BY {key_order}, {name_order}, v.id"));
+        // SQLite only accepts OFFSET after a LIMIT, where a negative LIMIT means none.
+        builder.push(" LIMIT ");
+        builder.push_bind(query.limit.map_or(-1, i64::from));
+        builder.push(" OFFSET ");
+        builder.push_bind(i64::from(query.offset));
+
+        let books = builder
+            .build_query_as::<BookWithStateRow>()
+            .fetch_all(&self.pool)
+            .await?
+            .into_iter()
+            .map(BookWithState::from)
+            .collect();
+        Ok(BookQueryResult { books, total_count })
+    }
+
     async fn delete_book(&self, id: i64) -> Result<()> {
         let mut tx = self.pool.begin().await?;
 
@@ -330,7 +884,250 @@ impl BookRepository for SqliteBookRepository {
         .await?;
 
         tx.commit().await?;
+        search_index::refresh(&self.pool).await?;
 
         Ok(())
     }
 }
+
+/// Appends the conditions of a [`BookQuery`] to a query over `book_with_state_view v`
+/// that already has a `WHERE` clause.
+pub(crate) fn push_query_conditions(builder: &mut QueryBuilder<Sqlite>, query: &BookQuery) {
+    if !query.include_tag_ids.is_empty() {
+        match query.tag_match {
+            TagMatch::All => {
+                for tag_id in &query.include_tag_ids {
+                    builder.push(
+                        " AND EXISTS (SELECT 1 FROM book_tags bt WHERE bt.book_id = v.id AND bt.tag_id = ",
+                    );
+                    builder.push_bind(*tag_id);
+                    builder.push(")");
+                }
+            }
+            TagMatch::Any => {
+                builder.push(
+                    " AND EXISTS (SELECT 1 FROM book_tags bt WHERE bt.book_id = v.id AND bt.tag_id IN (",
+                );
+                push_id_list(builder, &query.include_tag_ids);
+                builder.push("))");
+            }
+        }
+    }
+    if !query.exclude_tag_ids.is_empty() {
+        builder.push(
+            " AND NOT EXISTS (SELECT 1 FROM book_tags bt WHERE bt.book_id = v.id AND bt.tag_id IN (",
+        );
+        push_id_list(builder, &query.exclude_tag_ids);
+        builder.push("))");
+    }
+    if let Some(bookshelf_id) = query.bookshelf_id {
+        builder.push(" AND EXISTS (SELECT 1 FROM bookshelf_items bi WHERE bi.book_id = v.id AND bi.bookshelf_id = ");
+        builder.push_bind(bookshelf_id);
+        builder.push(")");
+    }
+    if let Some(series_id) = query.series_id {
+        builder.push(" AND v.series_id = ");
+        builder.push_bind(series_id);
+    }
+    if let Some(read_status) = query.read_status {
+        builder.push(" AND v.read_status = ");
+        builder.push_bind(read_status);
+    }
+    let ranges = [
+        ("v.created_at >= ", query.added_after),
+        ("v.created_at < ", query.added_before),
+        ("v.last_opened_at >= ", query.opened_after),
+        ("v.last_opened_at < ", query.opened_before),
+    ];
+    for (condition, time) in ranges {
+        if let Some(time) = time {
+            builder.push(format!(" AND {condition}"));
+            builder.push_bind(time);
+        }
+    }
+    if let Some(item_type) = &query.item_type {
+        builder.push(" AND v.item_type = ");
+        builder.push_bind(item_type.clone());
+    }
+    if let Some(prefix) = &query.path_prefix {
+        // Match the folder itself or anything below it, but not "/comics2" for "/comics".
+        let folder = prefix.trim_end_matches(['/', '\\']);
+        builder.push(" AND (v.file_path = ");
+        builder.push_bind(folder.to_string());
+        for separator in ['/', '\\'] {
+            let dir = format!("{folder}{separator}");
+            builder.push(" OR substr(v.file_path, 1, length(");
+            builder.push_bind(dir.clone());
+            builder.push(")) = ");
+            builder.push_bind(dir);
+        }
+        builder.push(")");
+    }
+    if !query.formats.is_empty() {
+        // LIKE ignores ASCII case, so `ZIP` and `zip` match alike.
+        builder.push(" AND v.item_type = 'file' AND (");
+        for (i, format) in query.formats.iter().enumerate() {
+            if i > 0 {
+                builder.push(" OR ");
+            }
+            builder.push("v.file_path LIKE ");
+            builder.push_bind(format!("%.{}", escape_like(format)));
+            builder.push(r" ESCAPE '\'");
+        }
+        builder.push(")");
+    }
+}
+
+/// Returns the current offset of the local time zone from UTC, in seconds.
+///
+/// It is stored with reading sessions and finish times, so statistics group them by the
+/// local day they happened on even after a daylight saving change.
+fn local_utc_offset_seconds() -> i32 {
+    chrono::Local::now().offset().local_minus_utc()
+}
+
+/// Adds a page turn (or the opening of a book, when both pages are the same) to the
+/// latest reading session of the book, or starts a new session if the book has not been
+/// read within [`SESSION_IDLE_MINUTES`].
+///
+/// Only turns forward of at most [`MAX_PAGES_PER_TURN`] pages are counted as pages
+/// read.
+///
+/// # Arguments
+///
+/// * `conn` - The connection of the surrounding transaction.
+/// * `book_id` - The unique identifier of the book.
+/// * `from_page` - The page index before the turn.
+/// * `to_page` - The page index after the turn.
+/// * `now` - The time of the turn, in UTC.
+async fn track_reading_session(
+    conn: &mut SqliteConnection,
+    book_id: i64,
+    from_page: i64,
+    to_page: i64,
+    now: NaiveDateTime,
+) -> Result<()> {
+    let pages_turned = match to_page - from_page {
+        delta @ 0..=MAX_PAGES_PER_TURN => delta,
+        _ => 0,
+    };
+    let latest = sqlx::query!(
+        r#"
+        SELECT id, ended_at
+        FROM reading_sessions
+        WHERE book_id = ?
+        ORDER BY ended_at DESC, id DESC
+        LIMIT 1
+        "#,
+        book_id
+    )
+    .fetch_optional(&mut *conn)
+    .await?;
+
+    match latest {
+        Some(session) if now - session.ended_at <= TimeDelta::minutes(SESSION_IDLE_MINUTES) => {
+            sqlx::query!(
+                r#"
+                UPDATE reading_sessions
+                SET ended_at = ?, end_page = ?, pages_turned = pages_turned + ?
+                WHERE id = ?
+                "#,
+                now,
+                to_page,
+                pages_turned,
+                session.id
+            )
+            .execute(&mut *conn)
+            .await?;
+        }
+        _ => {
+            let utc_offset = local_utc_offset_seconds();
+            sqlx::query!(
+                r#"
+                INSERT INTO reading_sessions (
+                    book_id, started_at, ended_at, start_page, end_page, pages_turned,
+                    utc_offset_seconds
+                )
+                VALUES (?, ?, ?, ?, ?, ?, ?)
+                "#,
+                book_id,
+                now,
+                now,
+                from_page,
+                to_page,
+                pages_turned,
+                utc_offset
+            )
+            .execute(&mut *conn)
+            .await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Appends a comma-separated list of bound IDs, for use inside `IN (...)`.
+fn push_id_list(builder: &mut QueryBuilder<Sqlite>, ids: &[i64]) {
+    let mut separated = builder.separated(", ");
+    for id in ids {
+        separated.push_bind(*id);
+    }
+}
+
+/// Splits a search query into an FTS5 expression matching the terms long enough for the
+/// trigram index, and the shorter terms.
+///
+/// Each indexed term is quoted as a phrase, so FTS5 operators and punctuation in the
+/// query are matched literally. Phrases separated by spaces must all match.
+fn parse_search_query(query: &str) -> (Option<String>, Vec<String>) {
+    let mut phrases = Vec::new();
+    let mut short_terms = Vec::new();
+    for term in query.split_whitespace() {
+        if term.chars().count() >= MIN_INDEXED_TERM_CHARS {
+            phrases.push(format!("\"{}\"", term.replace('"', "\"\"")));
+        } else {
+            short_terms.push(term.to_string());
+        }
+    }
+    (
+        (!phrases.is_empty()).then(|| phrases.join(" ")),
+        short_terms,
+    )
+}
+
+/// Escapes the `LIKE` wildcards in a term, using `\` as the escape character.
+fn escape_like(term: &str) -> String {
+    term.replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_search_query_splits_short_terms() {
+        let (expression, short_terms) = parse_search_query("  ワンピース  vol 第1巻 ");
+        assert_eq!(
+            expression.as_deref(),
+            Some("\"ワンピース\" \"vol\" \"第1巻\"")
+        );
+        assert!(short_terms.is_empty());
+
+        let (expression, short_terms) = parse_search_query("進撃 a");
+        assert_eq!(expression, None);
+        assert_eq!(short_terms, vec!["進撃", "a"]);
+    }
+
+    #[test]
+    fn test_parse_search_query_quotes_operators() {
+        let (expression, _) = parse_search_query(r#"say"hi" NOT"#);
+        assert_eq!(expression.as_deref(), Some(r#""say""hi""" "NOT""#));
+    }
+
+    #[test]
+    fn test_escape_like() {
+        assert_eq!(escape_like(r"50%_off\"), r"50\%\_off\\");
+    }
+}
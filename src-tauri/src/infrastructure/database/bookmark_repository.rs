@@ -0,0 +1,138 @@
+use std::cmp::Ordering;
+
+use async_trait::async_trait;
+use sqlx::SqlitePool;
+
+use crate::domain::annotation::entity::compare_cfi;
+use crate::domain::bookmark::entity::{Bookmark, BookmarkUpdate, BookmarkWithBook, NewBookmark};
+use crate::domain::bookmark::repository::BookmarkRepository;
+use crate::error::Result;
+
+/// SQLite implementation of the `BookmarkRepository`.
+pub struct SqliteBookmarkRepository {
+    /// The connection pool for the SQLite database.
+    pool: SqlitePool,
+}
+
+impl SqliteBookmarkRepository {
+    /// Creates a new `SqliteBookmarkRepository` instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` - The `SqlitePool` to use for database connections.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `SqliteBookmarkRepository`.
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl BookmarkRepository for SqliteBookmarkRepository {
+    async fn add(&self, bookmark: &NewBookmark) -> Result<Bookmark> {
+        let now = chrono::Utc::now().naive_utc();
+        let bookmark = sqlx::query_as!(
+            Bookmark,
+            r#"
+            INSERT INTO bookmarks (book_id, page_index, cfi, label, note, color_code, created_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?)
+            RETURNING id, book_id, page_index, cfi, label, note, color_code, created_at
+            "#,
+            bookmark.book_id,
+            bookmark.page_index,
+            bookmark.cfi,
+            bookmark.label,
+            bookmark.note,
+            bookmark.color_code,
+            now
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(bookmark)
+    }
+
+    async fn get_for_book(&self, book_id: i64) -> Result<Vec<Bookmark>> {
+        let mut bookmarks = sqlx::query_as!(
+            Bookmark,
+            r#"
+            SELECT id, book_id, page_index, cfi, label, note, color_code, created_at
+            FROM bookmarks
+            WHERE book_id = ?
+            ORDER BY page_index IS NULL, page_index, created_at, id
+            "#,
+            book_id
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        // CFIs do not sort as text, so novel bookmarks are put in reading order here.
+        bookmarks.sort_by(|a, b| match (&a.cfi, &b.cfi) {
+            (Some(a_cfi), Some(b_cfi)) => compare_cfi(a_cfi, b_cfi)
+                .then(a.created_at.cmp(&b.created_at))
+                .then(a.id.cmp(&b.id)),
+            (Some(_), None) => Ordering::Greater,
+            (None, Some(_)) => Ordering::Less,
+            (None, None) => Ordering::Equal,
+        });
+        Ok(bookmarks)
+    }
+
+    async fn get_all(&self) -> Result<Vec<BookmarkWithBook>> {
+        let bookmarks = sqlx::query_as!(
+            BookmarkWithBook,
+            r#"
+            SELECT
+                bm.id, bm.book_id, bm.page_index, bm.cfi, bm.label, bm.note, bm.color_code,
+                bm.created_at, b.display_name, b.file_path, b.item_type, b.thumbnail_path
+            FROM bookmarks bm
+            INNER JOIN books b ON b.id = bm.book_id
+            ORDER BY bm.created_at DESC, bm.id DESC
+            "#
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(bookmarks)
+    }
+
+    async fn update(&self, id: i64, update: &BookmarkUpdate) -> Result<()> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE bookmarks
+            SET label = ?, note = ?, color_code = ?
+            WHERE id = ?
+            "#,
+            update.label,
+            update.note,
+            update.color_code,
+            id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(sqlx::Error::RowNotFound.into());
+        }
+        Ok(())
+    }
+
+    async fn delete(&self, id: i64) -> Result<()> {
+        let result = sqlx::query!(
+            r#"
+            DELETE FROM bookmarks
+            WHERE id = ?
+            "#,
+            id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(sqlx::Error::RowNotFound.into());
+        }
+        Ok(())
+    }
+}
@@ -1,11 +1,13 @@
 use async_trait::async_trait;
-use sqlx::SqlitePool;
+use chrono::{Duration, Utc};
+use sqlx::{QueryBuilder, Sqlite, SqlitePool};
 
-use crate::domain::book::entity::BookWithState;
-use crate::domain::bookshelf::entity::Bookshelf;
+use crate::domain::book::entity::{BookQuery, BookWithState};
+use crate::domain::bookshelf::entity::{Bookshelf, BookshelfRule};
 use crate::domain::bookshelf::repository::BookshelfRepository;
 use crate::error::Result;
-use crate::infrastructure::database::models::BookWithStateRow;
+use crate::infrastructure::database::book_repository::push_query_conditions;
+use crate::infrastructure::database::models::{BookWithStateRow, BookshelfRow};
 
 /// SQLite implementation of the `BookshelfRepository`.
 pub struct SqliteBookshelfRepository {
@@ -26,17 +28,61 @@ impl SqliteBookshelfRepository {
     pub fn new(pool: SqlitePool) -> Self {
         Self { pool }
     }
+
+    /// Retrieves the books matching the rule of a smart bookshelf, most recently added
+    /// first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the database query fails.
+    async fn get_books_by_rule(&self, rule: &BookshelfRule) -> Result<Vec<BookWithState>> {
+        let query = BookQuery {
+            include_tag_ids: rule.tag_ids.clone(),
+            tag_match: rule.tag_match,
+            series_id: rule.series_id,
+            read_status: rule.read_status,
+            added_after: rule
+                .added_within_days
+                .map(|days| Utc::now().naive_utc() - Duration::days(i64::from(days))),
+            path_prefix: rule.path_prefix.clone(),
+            formats: rule.formats.clone(),
+            ..Default::default()
+        };
+
+        let mut builder = QueryBuilder::<Sqlite>::new(
+            r#"
+            SELECT
+                v.id, v.file_path, v.item_type, v.display_name, v.total_pages, v.series_id,
+                v.series_order, v.thumbnail_path, v.created_at, v.damage_code, v.missing_since,
+                v.read_status, v.rating, v.is_favorite, v.last_read_page_index, v.last_read_cfi,
+                v.last_opened_at, v.tag_ids_str
+            FROM book_with_state_view v
+            WHERE 1 = 1
+            "#,
+        );
+        push_query_conditions(&mut builder, &query);
+        builder.push(" ORDER BY v.created_at DESC, v.id DESC");
+
+        let books = builder
+            .build_query_as::<BookWithStateRow>()
+            .fetch_all(&self.pool)
+            .await?
+            .into_iter()
+            .map(BookWithState::from)
+            .collect();
+        Ok(books)
+    }
 }
 
 #[async_trait]
 impl BookshelfRepository for SqliteBookshelfRepository {
     async fn create(&self, name: &str, icon_id: &str) -> Result<Bookshelf> {
         let bookshelf = sqlx::query_as!(
-            Bookshelf,
+            BookshelfRow,
             r#"
             INSERT INTO bookshelves (name, icon_id)
             VALUES (?, ?)
-            RETURNING id, name, icon_id, created_at
+            RETURNING id, name, icon_id, created_at, rule
             "#,
             name,
             icon_id
@@ -44,32 +90,75 @@ impl BookshelfRepository for SqliteBookshelfRepository {
         .fetch_one(&self.pool)
         .await?;
 
-        Ok(bookshelf)
+        Bookshelf::try_from(bookshelf)
     }
 
     async fn get_all(&self) -> Result<Vec<Bookshelf>> {
         let bookshelves = sqlx::query_as!(
-            Bookshelf,
+            BookshelfRow,
             r#"
-            SELECT id, name, icon_id, created_at
+            SELECT id, name, icon_id, created_at, rule
             FROM bookshelves
             ORDER BY id ASC
             "#
         )
         .fetch_all(&self.pool)
+        .await?
+        .into_iter()
+        .filter_map(|row| {
+            let id = row.id;
+            // One broken rule must not hide every other bookshelf.
+            Bookshelf::try_from(row)
+                .inspect_err(|e| log::warn!("Skipping bookshelf {id} with invalid rule: {e}"))
+                .ok()
+        })
+        .collect();
+        Ok(bookshelves)
+    }
+
+    async fn set_rule(&self, id: i64, rule: Option<BookshelfRule>) -> Result<()> {
+        let rule = rule.as_ref().map(serde_json::to_string).transpose()?;
+        sqlx::query!(
+            r#"
+            UPDATE bookshelves
+            SET rule = ?
+            WHERE id = ?
+            "#,
+            rule,
+            id
+        )
+        .execute(&self.pool)
         .await?;
 
-        Ok(bookshelves)
+        Ok(())
     }
 
     async fn get_books_by_bookshelf(&self, bookshelf_id: i64) -> Result<Vec<BookWithState>> {
+        let rule = sqlx::query_scalar!(
+            r#"
+            SELECT rule
+            FROM bookshelves
+            WHERE id = ?
+            "#,
+            bookshelf_id
+        )
+        .fetch_optional(&self.pool)
+        .await?
+        .flatten();
+        if let Some(rule) = rule {
+            return self
+                .get_books_by_rule(&BookshelfRule::from_json(&rule)?)
+                .await;
+        }
+
         let books = sqlx::query_as!(
             BookWithStateRow,
             r#"
             SELECT
                 v.id, v.file_path, v.item_type, v.display_name, v.total_pages, v.series_id, v.series_order,
-                v.thumbnail_path, v.created_at, v.last_read_page_index, v.last_opened_at,
-                v.tag_ids_str as "tag_ids_str?: String"
+                v.thumbnail_path, v.created_at, v.damage_code, v.missing_since,
+                v.read_status as "read_status: _", v.rating, v.is_favorite, v.last_read_page_index,
+                v.last_read_cfi, v.last_opened_at, v.tag_ids_str as "tag_ids_str?: String"
             FROM book_with_state_view v
             INNER JOIN bookshelf_items bi ON v.id = bi.book_id
             WHERE bi.bookshelf_id = ?
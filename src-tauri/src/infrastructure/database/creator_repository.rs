@@ -0,0 +1,213 @@
+use async_trait::async_trait;
+use sqlx::SqlitePool;
+
+use crate::domain::book::entity::BookWithState;
+use crate::domain::creator::entity::{BookCreator, Creator};
+use crate::domain::creator::repository::CreatorRepository;
+use crate::error::{Error, Result};
+use crate::infrastructure::database::models::BookWithStateRow;
+use crate::infrastructure::database::search_index;
+use crate::natural_sort;
+
+/// SQLite implementation of the `CreatorRepository`.
+pub struct SqliteCreatorRepository {
+    /// The connection pool for the SQLite database.
+    pool: SqlitePool,
+}
+
+impl SqliteCreatorRepository {
+    /// Creates a new `SqliteCreatorRepository` instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` - The `SqlitePool` to use for database connections.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `SqliteCreatorRepository`.
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl CreatorRepository for SqliteCreatorRepository {
+    async fn get_all(&self) -> Result<Vec<Creator>> {
+        let mut creators = sqlx::query_as!(
+            Creator,
+            r#"
+            SELECT c.id, c.name, COUNT(DISTINCT bc.book_id) AS "book_count!: i64"
+            FROM creators c
+            INNER JOIN book_creators bc ON bc.creator_id = c.id
+            GROUP BY c.id
+            "#
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        creators.sort_by(|a, b| natural_sort::compare(&a.name, &b.name));
+        Ok(creators)
+    }
+
+    async fn get_creators_for_book(&self, book_id: i64) -> Result<Vec<BookCreator>> {
+        let creators = sqlx::query_as!(
+            BookCreator,
+            r#"
+            SELECT bc.creator_id, c.name, bc.role AS "role: _"
+            FROM book_creators bc
+            INNER JOIN creators c ON c.id = bc.creator_id
+            WHERE bc.book_id = ?
+            ORDER BY bc.position
+            "#,
+            book_id
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(creators)
+    }
+
+    async fn get_books_by_creator(&self, creator_id: i64) -> Result<Vec<BookWithState>> {
+        let books = sqlx::query_as!(
+            BookWithStateRow,
+            r#"
+            SELECT
+                v.id, v.file_path, v.item_type, v.display_name, v.total_pages, v.series_id, v.series_order,
+                v.thumbnail_path, v.created_at, v.damage_code, v.missing_since,
+                v.read_status as "read_status: _", v.rating, v.is_favorite, v.last_read_page_index,
+                v.last_read_cfi, v.last_opened_at, v.tag_ids_str as "tag_ids_str?: String"
+            FROM book_with_state_view v
+            WHERE v.id IN (SELECT book_id FROM book_creators WHERE creator_id = ?)
+            "#,
+            creator_id
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        // Sorted here rather than with the `natural` collation, which the query macros
+        // cannot check against.
+        let mut books: Vec<BookWithState> = books.into_iter().map(BookWithState::from).collect();
+        books.sort_by(|a, b| natural_sort::compare(&a.display_name, &b.display_name));
+        Ok(books)
+    }
+
+    async fn rename(&self, id: i64, name: &str) -> Result<()> {
+        let mut tx = self.pool.begin().await?;
+
+        let taken = sqlx::query_scalar!(
+            r#"
+            SELECT id FROM creators WHERE name = ? AND id <> ?
+            "#,
+            name,
+            id
+        )
+        .fetch_optional(&mut *tx)
+        .await?;
+        if taken.is_some() {
+            return Err(Error::InvalidArgument(format!(
+                "A creator named '{name}' already exists; merge the creators instead"
+            )));
+        }
+
+        sqlx::query!(
+            r#"
+            INSERT INTO creator_aliases (name, creator_id)
+            SELECT name, id FROM creators WHERE id = ?
+            ON CONFLICT(name) DO UPDATE SET creator_id = excluded.creator_id
+            "#,
+            id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        // The new name is the creator's own again, not an alias.
+        sqlx::query!(
+            r#"
+            DELETE FROM creator_aliases WHERE name = ?
+            "#,
+            name
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            r#"
+            UPDATE creators SET name = ? WHERE id = ?
+            "#,
+            name,
+            id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        search_index::refresh(&self.pool).await?;
+        Ok(())
+    }
+
+    async fn merge(&self, target_id: i64, source_ids: &[i64]) -> Result<()> {
+        let source_ids: Vec<i64> = source_ids
+            .iter()
+            .copied()
+            .filter(|&id| id != target_id)
+            .collect();
+        if source_ids.is_empty() {
+            return Ok(());
+        }
+        let source_ids = serde_json::to_string(&source_ids)?;
+        let mut tx = self.pool.begin().await?;
+
+        // Point the names and former aliases of the merged creators at the target.
+        sqlx::query!(
+            r#"
+            INSERT INTO creator_aliases (name, creator_id)
+            SELECT name, ? FROM creators WHERE id IN (SELECT value FROM json_each(?))
+            ON CONFLICT(name) DO UPDATE SET creator_id = excluded.creator_id
+            "#,
+            target_id,
+            source_ids
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            r#"
+            UPDATE creator_aliases
+            SET creator_id = ?
+            WHERE creator_id IN (SELECT value FROM json_each(?))
+            "#,
+            target_id,
+            source_ids
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        // OR IGNORE keeps the target's own credit where both are credited for a book in
+        // the same role.
+        sqlx::query!(
+            r#"
+            INSERT OR IGNORE INTO book_creators (book_id, creator_id, role, position)
+            SELECT book_id, ?, role, position
+            FROM book_creators
+            WHERE creator_id IN (SELECT value FROM json_each(?))
+            "#,
+            target_id,
+            source_ids
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query!(
+            r#"
+            DELETE FROM creators WHERE id IN (SELECT value FROM json_each(?))
+            "#,
+            source_ids
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        search_index::refresh(&self.pool).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,172 @@
+use async_trait::async_trait;
+use sqlx::SqlitePool;
+
+use crate::domain::library::entity::{IndexedBook, LibraryRoot};
+use crate::domain::library::repository::LibraryRepository;
+use crate::error::Result;
+
+/// SQLite implementation of the `LibraryRepository`.
+pub struct SqliteLibraryRepository {
+    /// The connection pool for the SQLite database.
+    pool: SqlitePool,
+}
+
+impl SqliteLibraryRepository {
+    /// Creates a new `SqliteLibraryRepository` instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` - The `SqlitePool` to use for database connections.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `SqliteLibraryRepository`.
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+/// Returns `path` with a trailing separator, so a prefix match only finds paths inside
+/// the folder (`/books/a` must not match `/books/ab.zip`).
+fn folder_prefix(path: &str) -> String {
+    if path.ends_with(['/', '\\']) {
+        path.to_string()
+    } else {
+        format!("{}{}", path, std::path::MAIN_SEPARATOR)
+    }
+}
+
+#[async_trait]
+impl LibraryRepository for SqliteLibraryRepository {
+    async fn add_root(&self, path: &str) -> Result<i64> {
+        let id = sqlx::query!(
+            r#"
+            INSERT INTO library_roots (path)
+            VALUES (?)
+            RETURNING id
+            "#,
+            path
+        )
+        .fetch_one(&self.pool)
+        .await?
+        .id;
+
+        Ok(id)
+    }
+
+    async fn get_roots(&self) -> Result<Vec<LibraryRoot>> {
+        let roots = sqlx::query_as!(
+            LibraryRoot,
+            r#"
+            SELECT id, path, created_at, last_scanned_at
+            FROM library_roots
+            ORDER BY id ASC
+            "#
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(roots)
+    }
+
+    async fn get_root(&self, id: i64) -> Result<Option<LibraryRoot>> {
+        let root = sqlx::query_as!(
+            LibraryRoot,
+            r#"
+            SELECT id, path, created_at, last_scanned_at
+            FROM library_roots
+            WHERE id = ?
+            "#,
+            id
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(root)
+    }
+
+    async fn delete_root(&self, id: i64) -> Result<()> {
+        sqlx::query!(
+            r#"
+            DELETE FROM library_roots
+            WHERE id = ?
+            "#,
+            id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn mark_root_scanned(&self, id: i64) -> Result<()> {
+        let now = chrono::Utc::now().naive_utc();
+        sqlx::query!(
+            r#"
+            UPDATE library_roots
+            SET last_scanned_at = ?
+            WHERE id = ?
+            "#,
+            now,
+            id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn get_indexed_books(&self, path: &str) -> Result<Vec<IndexedBook>> {
+        let prefix = folder_prefix(path);
+        let books = sqlx::query_as!(
+            IndexedBook,
+            r#"
+            SELECT
+                id, file_path, file_modified_at,
+                missing_since IS NOT NULL as "missing!: bool"
+            FROM books
+            WHERE file_path = ? OR substr(file_path, 1, length(?)) = ?
+            "#,
+            path,
+            prefix,
+            prefix
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(books)
+    }
+
+    async fn set_file_indexed(&self, book_id: i64, file_modified_at: Option<i64>) -> Result<()> {
+        sqlx::query!(
+            r#"
+            UPDATE books
+            SET file_modified_at = ?, missing_since = NULL
+            WHERE id = ?
+            "#,
+            file_modified_at,
+            book_id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn mark_missing(&self, book_id: i64) -> Result<()> {
+        let now = chrono::Utc::now().naive_utc();
+        sqlx::query!(
+            r#"
+            UPDATE books
+            SET missing_since = COALESCE(missing_since, ?)
+            WHERE id = ?
+            "#,
+            now,
+            book_id
+        )
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
@@ -1,5 +1,32 @@
+pub mod annotation_repository;
 pub mod book_repository;
+pub mod bookmark_repository;
 pub mod bookshelf_repository;
+pub mod creator_repository;
+pub mod library_repository;
 pub mod models;
+pub mod reading_session_repository;
+pub mod search_index;
 pub mod series_repository;
 pub mod tag_repository;
+
+use sqlx::sqlite::SqliteConnectOptions;
+
+use crate::natural_sort;
+
+/// The name of the collation ordering text in Japanese-aware natural order, as
+/// [`natural_sort::compare`] does.
+pub const NATURAL_COLLATION: &str = "natural";
+
+/// Registers the custom collations the repositories use on every connection.
+///
+/// # Arguments
+///
+/// * `options` - The options to open the database connections with.
+///
+/// # Returns
+///
+/// The options with the collations registered.
+pub fn with_collations(options: SqliteConnectOptions) -> SqliteConnectOptions {
+    options.collation(NATURAL_COLLATION, natural_sort::compare)
+}
@@ -1,7 +1,10 @@
 use chrono::NaiveDateTime;
 use sqlx::FromRow;
 
-use crate::domain::book::entity::BookWithState;
+use crate::domain::book::entity::{BookWithState, ReadStatus};
+use crate::domain::bookshelf::entity::{Bookshelf, BookshelfRule};
+use crate::error::{Error, Result};
+use crate::natural_sort;
 
 /// Represents a raw row from the `book_with_state_view`.
 #[derive(Debug, FromRow)]
@@ -24,8 +27,21 @@ pub struct BookWithStateRow {
     pub thumbnail_path: Option<String>,
     /// The timestamp when the book was created (registered).
     pub created_at: Option<NaiveDateTime>,
+    /// The error code explaining why the book could only be opened partially
+    /// (e.g. a truncated ZIP), or `None` if it opened cleanly.
+    pub damage_code: Option<i64>,
+    /// When the library scanner found the book file gone, or `None` while it exists.
+    pub missing_since: Option<NaiveDateTime>,
+    /// How far the book has been read.
+    pub read_status: ReadStatus,
+    /// The rating from 1 to 5, or 0 if the book is not rated.
+    pub rating: i64,
+    /// Whether the book is marked as a favorite.
+    pub is_favorite: bool,
     /// The last read page index, if the book has been opened.
     pub last_read_page_index: Option<i64>,
+    /// The EPUB CFI of the last read location of a novel, if any.
+    pub last_read_cfi: Option<String>,
     /// The timestamp when the book was last opened, if any.
     pub last_opened_at: Option<NaiveDateTime>,
     /// Comma-separated list of tag IDs associated with this book.
@@ -38,13 +54,20 @@ impl From<BookWithStateRow> for BookWithState {
             id: r.id,
             file_path: r.file_path,
             item_type: r.item_type,
+            sort_key: natural_sort::sort_key(&r.display_name),
             display_name: r.display_name,
             total_pages: r.total_pages,
             series_id: r.series_id,
             series_order: r.series_order,
             thumbnail_path: r.thumbnail_path,
             created_at: r.created_at,
+            damage_code: r.damage_code,
+            missing_since: r.missing_since,
+            read_status: r.read_status,
+            rating: r.rating,
+            is_favorite: r.is_favorite,
             last_read_page_index: r.last_read_page_index,
+            last_read_cfi: r.last_read_cfi,
             last_opened_at: r.last_opened_at,
             tag_ids_str: r.tag_ids_str,
             tag_ids: Vec::new(),
@@ -53,3 +76,36 @@ impl From<BookWithStateRow> for BookWithState {
         b
     }
 }
+
+/// Represents a raw row from the `bookshelves` table.
+#[derive(Debug, FromRow)]
+pub struct BookshelfRow {
+    /// The unique identifier for the bookshelf.
+    pub id: i64,
+    /// The display name of the bookshelf.
+    pub name: String,
+    /// The string identifier for the UI icon (e.g., "folder").
+    pub icon_id: String,
+    /// The timestamp when the bookshelf was created.
+    pub created_at: Option<NaiveDateTime>,
+    /// The rule of a smart bookshelf as JSON, or `None` for a manual bookshelf.
+    pub rule: Option<String>,
+}
+
+impl TryFrom<BookshelfRow> for Bookshelf {
+    type Error = Error;
+
+    fn try_from(r: BookshelfRow) -> Result<Self> {
+        Ok(Bookshelf {
+            id: r.id,
+            name: r.name,
+            icon_id: r.icon_id,
+            created_at: r.created_at,
+            rule: r
+                .rule
+                .as_deref()
+                .map(BookshelfRule::from_json)
+                .transpose()?,
+        })
+    }
+}
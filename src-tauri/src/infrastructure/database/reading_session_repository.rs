@@ -0,0 +1,193 @@
+use async_trait::async_trait;
+use chrono::{FixedOffset, NaiveDate, Utc};
+use sqlx::SqlitePool;
+
+use crate::domain::reading_session::entity::{
+    current_streak, longest_streak, DailyReading, MonthlyFinished, ReadingSession,
+    ReadingStatistics, SeriesReading,
+};
+use crate::domain::reading_session::repository::ReadingSessionRepository;
+use crate::error::Result;
+use crate::natural_sort;
+
+/// SQLite implementation of the `ReadingSessionRepository`.
+pub struct SqliteReadingSessionRepository {
+    /// The connection pool for the SQLite database.
+    pool: SqlitePool,
+}
+
+impl SqliteReadingSessionRepository {
+    /// Creates a new `SqliteReadingSessionRepository` instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` - The `SqlitePool` to use for database connections.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of `SqliteReadingSessionRepository`.
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ReadingSessionRepository for SqliteReadingSessionRepository {
+    async fn get_sessions_for_book(&self, book_id: i64) -> Result<Vec<ReadingSession>> {
+        let sessions = sqlx::query_as!(
+            ReadingSession,
+            r#"
+            SELECT id, book_id, started_at, ended_at, start_page, end_page, pages_turned
+            FROM reading_sessions
+            WHERE book_id = ?
+            ORDER BY started_at DESC, id DESC
+            "#,
+            book_id
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(sessions)
+    }
+
+    async fn get_statistics(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+        utc_offset: FixedOffset,
+    ) -> Result<ReadingStatistics> {
+        // Timestamps are stored in UTC along with the local offset they were recorded
+        // at; `utc_offset` stands in for the ones recorded without it.
+        let fallback_offset = utc_offset.local_minus_utc();
+
+        let daily = sqlx::query_as!(
+            DailyReading,
+            r#"
+            WITH local_sessions AS (
+                SELECT
+                    date(started_at, COALESCE(utc_offset_seconds, ?) || ' seconds') AS local_date,
+                    julianday(ended_at) - julianday(started_at) AS days_read,
+                    pages_turned
+                FROM reading_sessions
+            )
+            SELECT
+                local_date AS "date!: NaiveDate",
+                CAST(ROUND(SUM(days_read) * 86400) AS INTEGER) AS "seconds_read!: i64",
+                SUM(pages_turned) AS "pages_read!: i64"
+            FROM local_sessions
+            WHERE local_date BETWEEN ? AND ?
+            GROUP BY 1
+            ORDER BY 1
+            "#,
+            fallback_offset,
+            from,
+            to
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        let monthly_finished = sqlx::query_as!(
+            MonthlyFinished,
+            r#"
+            WITH local_finished AS (
+                SELECT
+                    datetime(finished_at, COALESCE(finished_utc_offset_seconds, ?) || ' seconds')
+                        AS local_time
+                FROM books
+                WHERE finished_at IS NOT NULL
+            )
+            SELECT
+                CAST(strftime('%Y', local_time) AS INTEGER) AS "year!: i32",
+                CAST(strftime('%m', local_time) AS INTEGER) AS "month!: u32",
+                COUNT(*) AS "books_finished!: i64"
+            FROM local_finished
+            WHERE date(local_time) BETWEEN ? AND ?
+            GROUP BY 1, 2
+            ORDER BY 1, 2
+            "#,
+            fallback_offset,
+            from,
+            to
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut series = sqlx::query_as!(
+            SeriesReading,
+            r#"
+            WITH period_sessions AS (
+                SELECT
+                    b.series_id,
+                    SUM(julianday(rs.ended_at) - julianday(rs.started_at)) AS days_read,
+                    SUM(rs.pages_turned) AS pages_read
+                FROM reading_sessions rs
+                INNER JOIN books b ON b.id = rs.book_id
+                WHERE date(rs.started_at, COALESCE(rs.utc_offset_seconds, ?) || ' seconds')
+                        BETWEEN ? AND ?
+                    AND b.series_id IS NOT NULL
+                GROUP BY b.series_id
+            ),
+            period_finished AS (
+                SELECT series_id, COUNT(*) AS books_finished
+                FROM books
+                WHERE date(finished_at, COALESCE(finished_utc_offset_seconds, ?) || ' seconds')
+                        BETWEEN ? AND ?
+                    AND series_id IS NOT NULL
+                GROUP BY series_id
+            )
+            SELECT
+                s.id AS "series_id!: i64",
+                s.name AS "series_name!: String",
+                CAST(ROUND(COALESCE(ps.days_read, 0) * 86400) AS INTEGER) AS "seconds_read!: i64",
+                COALESCE(ps.pages_read, 0) AS "pages_read!: i64",
+                COALESCE(pf.books_finished, 0) AS "books_finished!: i64"
+            FROM series s
+            LEFT JOIN period_sessions ps ON ps.series_id = s.id
+            LEFT JOIN period_finished pf ON pf.series_id = s.id
+            WHERE ps.series_id IS NOT NULL OR pf.series_id IS NOT NULL
+            "#,
+            fallback_offset,
+            from,
+            to,
+            fallback_offset,
+            from,
+            to
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        series.sort_by(|a, b| {
+            b.seconds_read
+                .cmp(&a.seconds_read)
+                .then_with(|| natural_sort::compare(&a.series_name, &b.series_name))
+        });
+
+        // Streaks may reach back before the period, so they are counted over every day
+        // with some reading.
+        let reading_days = sqlx::query_scalar!(
+            r#"
+            SELECT DISTINCT date(started_at, COALESCE(utc_offset_seconds, ?) || ' seconds')
+                AS "date!: NaiveDate"
+            FROM reading_sessions
+            ORDER BY 1
+            "#,
+            fallback_offset
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        let today = Utc::now().with_timezone(&utc_offset).date_naive();
+
+        Ok(ReadingStatistics {
+            total_seconds_read: daily.iter().map(|day| day.seconds_read).sum(),
+            total_pages_read: daily.iter().map(|day| day.pages_read).sum(),
+            total_books_finished: monthly_finished
+                .iter()
+                .map(|month| month.books_finished)
+                .sum(),
+            current_streak_days: current_streak(&reading_days, today),
+            longest_streak_days: longest_streak(&reading_days),
+            daily,
+            monthly_finished,
+            series,
+        })
+    }
+}
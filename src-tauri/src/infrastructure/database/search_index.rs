@@ -0,0 +1,97 @@
+//! Maintenance of the `book_search` full-text index.
+//!
+//! The index holds text normalized by [`search_text::normalize`], which only the
+//! application can compute, so the triggers only record which books are out of date.
+
+use sqlx::SqlitePool;
+
+use crate::error::Result;
+use crate::search_text;
+
+/// Re-indexes the books whose search text is out of date, as recorded in
+/// `book_search_dirty` by the triggers, storing their text normalized for search.
+///
+/// The repositories call this after every write that can change the indexed text, and
+/// the app once at startup for the books migrations marked, so searches only read.
+///
+/// # Arguments
+///
+/// * `pool` - The connection pool for the SQLite database.
+///
+/// # Errors
+///
+/// Returns an `Err` if a database operation fails.
+pub async fn refresh(pool: &SqlitePool) -> Result<()> {
+    let mut tx = pool.begin().await?;
+
+    // Claiming the dirty books is the first statement, so the transaction holds the
+    // write lock before it reads anything and no change can slip by unindexed.
+    let book_ids = sqlx::query_scalar!(
+        r#"
+        DELETE FROM book_search_dirty
+        RETURNING book_id
+        "#
+    )
+    .fetch_all(&mut *tx)
+    .await?;
+    if book_ids.is_empty() {
+        return Ok(());
+    }
+    let book_ids = serde_json::to_string(&book_ids)?;
+
+    sqlx::query!(
+        r#"
+        DELETE FROM book_search
+        WHERE rowid IN (SELECT value FROM json_each(?))
+        "#,
+        book_ids
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    let sources = sqlx::query!(
+        r#"
+        SELECT
+            book_id, display_name, file_path, series_name as "series_name?: String",
+            tag_names as "tag_names?: String", authors as "authors?: String",
+            publisher as "publisher?: String", description as "description?: String"
+        FROM book_search_source
+        WHERE book_id IN (SELECT value FROM json_each(?))
+        "#,
+        book_ids
+    )
+    .fetch_all(&mut *tx)
+    .await?;
+    for source in sources {
+        let normalize = |text: Option<String>| text.as_deref().map(search_text::normalize);
+        let display_name = search_text::normalize(&source.display_name);
+        let file_path = search_text::normalize(&source.file_path);
+        let series_name = normalize(source.series_name);
+        let tag_names = normalize(source.tag_names);
+        let authors = normalize(source.authors);
+        let publisher = normalize(source.publisher);
+        let description = normalize(source.description);
+        sqlx::query!(
+            r#"
+            INSERT INTO book_search (
+                rowid, display_name, file_path, series_name, tag_names, authors, publisher,
+                description
+            )
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+            source.book_id,
+            display_name,
+            file_path,
+            series_name,
+            tag_names,
+            authors,
+            publisher,
+            description
+        )
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    tx.commit().await?;
+    Ok(())
+}
@@ -6,6 +6,8 @@ use crate::domain::series::entity::Series;
 use crate::domain::series::repository::SeriesRepository;
 use crate::error::Result;
 use crate::infrastructure::database::models::BookWithStateRow;
+use crate::infrastructure::database::search_index;
+use crate::natural_sort;
 
 /// SQLite implementation of the `SeriesRepository`.
 pub struct SqliteSeriesRepository {
@@ -47,8 +49,7 @@ impl SeriesRepository for SqliteSeriesRepository {
     }
 
     async fn get_all(&self) -> Result<Vec<Series>> {
-        let series_list = sqlx::query_as!(
-            Series,
+        let rows = sqlx::query!(
             r#"
             SELECT id, name, created_at
             FROM series
@@ -58,20 +59,24 @@ impl SeriesRepository for SqliteSeriesRepository {
         .fetch_all(&self.pool)
         .await?;
 
-        Ok(series_list)
+        Ok(rows
+            .into_iter()
+            .map(|row| Series::new(row.id, row.name, row.created_at))
+            .collect())
     }
 
     async fn get_books_by_series(&self, series_id: i64) -> Result<Vec<BookWithState>> {
-        let books = sqlx::query_as!(
+        let mut books = sqlx::query_as!(
             BookWithStateRow,
             r#"
             SELECT
                 id, file_path, item_type, display_name, total_pages, series_id, series_order,
-                thumbnail_path, created_at, last_read_page_index, last_opened_at,
+                thumbnail_path, created_at, damage_code, missing_since, read_status as "read_status: _",
+                rating, is_favorite, last_read_page_index, last_read_cfi, last_opened_at,
                 tag_ids_str as "tag_ids_str?: String"
             FROM book_with_state_view
             WHERE series_id = ?
-            ORDER BY series_order ASC, display_name ASC
+            ORDER BY series_order ASC
             "#,
             series_id
         )
@@ -79,7 +84,14 @@ impl SeriesRepository for SqliteSeriesRepository {
         .await?
         .into_iter()
         .map(BookWithState::from)
-        .collect();
+        .collect::<Vec<_>>();
+        // Ties in the series order fall back to the display name, compared in natural
+        // order so `第十巻` follows `第二巻`. Books without an order come first, as in SQL.
+        books.sort_by(|a, b| {
+            a.series_order
+                .cmp(&b.series_order)
+                .then_with(|| natural_sort::compare(&a.display_name, &b.display_name))
+        });
         Ok(books)
     }
 
@@ -107,6 +119,7 @@ impl SeriesRepository for SqliteSeriesRepository {
         .execute(&self.pool)
         .await?;
 
+        search_index::refresh(&self.pool).await?;
         Ok(())
     }
 
@@ -146,6 +159,7 @@ impl SeriesRepository for SqliteSeriesRepository {
         .execute(&self.pool)
         .await?;
 
+        search_index::refresh(&self.pool).await?;
         Ok(())
     }
 }
@@ -173,6 +187,7 @@ mod tests {
         assert_eq!(all.len(), 1);
         assert_eq!(all[0].id, id);
         assert_eq!(all[0].name, "Test Series");
+        assert_eq!(all[0].sort_key, natural_sort::sort_key("Test Series"));
     }
 
     #[tokio::test]
@@ -6,6 +6,7 @@ use crate::domain::tag::entity::Tag;
 use crate::domain::tag::repository::TagRepository;
 use crate::error::Result;
 use crate::infrastructure::database::models::BookWithStateRow;
+use crate::infrastructure::database::search_index;
 
 /// SQLite implementation of the `TagRepository`.
 pub struct SqliteTagRepository {
@@ -92,6 +93,7 @@ impl TagRepository for SqliteTagRepository {
         .await?;
 
         tx.commit().await?;
+        search_index::refresh(&self.pool).await?;
         Ok(())
     }
 
@@ -115,8 +117,9 @@ impl TagRepository for SqliteTagRepository {
             r#"
             SELECT
                 v.id, v.file_path, v.item_type, v.display_name, v.total_pages, v.series_id, v.series_order,
-                v.thumbnail_path, v.created_at, v.last_read_page_index, v.last_opened_at,
-                v.tag_ids_str as "tag_ids_str?: String"
+                v.thumbnail_path, v.created_at, v.damage_code, v.missing_since,
+                v.read_status as "read_status: _", v.rating, v.is_favorite, v.last_read_page_index,
+                v.last_read_cfi, v.last_opened_at, v.tag_ids_str as "tag_ids_str?: String"
             FROM book_with_state_view v
             INNER JOIN book_tags bt ON v.id = bt.book_id
             WHERE bt.tag_id = ?
@@ -143,6 +146,7 @@ impl TagRepository for SqliteTagRepository {
         .execute(&self.pool)
         .await?;
 
+        search_index::refresh(&self.pool).await?;
         Ok(())
     }
 }
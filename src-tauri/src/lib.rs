@@ -9,31 +9,51 @@ pub mod domain;
 pub mod error;
 pub mod image;
 pub mod infrastructure;
+mod library;
+pub mod natural_sort;
+mod protocol;
+pub mod search_text;
 mod settings;
 mod setup;
 mod state;
 
 /// Builds the `tauri-specta` command registry used to export the TypeScript bindings.
 ///
-/// This intentionally excludes the three binary commands (`get_image`,
-/// `get_image_preview`, `get_entries_in_dir`) that return a raw
-/// `tauri::ipc::Response`: that type has no `specta::Type`, and the frontend keeps
-/// hand-written wrappers for them. At runtime [`run`] serves every command listed
-/// here through this builder's invoke handler and routes only those three binary
-/// commands to a small separate `tauri::generate_handler!`.
+/// This intentionally excludes the binary commands (`get_image`, `get_image_preview`,
+/// `get_image_progressive`, `get_image_tile`, `get_entries_in_dir`) that return a raw
+/// `tauri::ipc::Response` or stream raw frames over a `tauri::ipc::Channel`: neither
+/// has a `specta::Type`, and the frontend keeps hand-written wrappers for them. At
+/// runtime [`run`] serves every command listed here through this builder's invoke
+/// handler and routes only those binary commands to a small separate
+/// `tauri::generate_handler!`.
 ///
 /// # Returns
 ///
 /// A `tauri_specta::Builder` configured with every specta-compatible command.
 pub fn specta_builder() -> Builder<tauri::Wry> {
-    Builder::<tauri::Wry>::new().commands(collect_commands![
+    let builder = Builder::<tauri::Wry>::new().commands(collect_commands![
         commands::settings_commands::get_settings,
         commands::settings_commands::set_settings,
         commands::container_commands::request_preload_around,
         commands::container_commands::get_entries_in_container,
+        commands::container_commands::cancel_image_request,
+        commands::container_commands::get_image_tile_info,
+        commands::container_commands::get_strip_layout,
+        commands::container_commands::get_cache_stats,
+        commands::container_commands::get_performance_metrics,
+        commands::container_commands::purge_image_cache,
         commands::font_commands::get_fonts,
+        commands::search_commands::filter_by_search,
+        commands::annotation_commands::create_annotation::<tauri::Wry>,
+        commands::annotation_commands::get_annotations,
+        commands::annotation_commands::delete_annotation::<tauri::Wry>,
+        commands::annotation_commands::export_annotations,
         commands::book_commands::get_book_tags,
         commands::book_commands::update_book_tags::<tauri::Wry>,
+        commands::book_commands::get_book_exclusion_rules,
+        commands::book_commands::set_book_exclusion_rules,
+        commands::book_commands::get_book_page_order,
+        commands::book_commands::set_book_page_order,
         commands::book_commands::get_book,
         commands::book_commands::get_book_by_path,
         commands::book_commands::get_book_with_state_by_id,
@@ -44,25 +64,54 @@ pub fn specta_builder() -> Builder<tauri::Wry> {
         commands::book_commands::clear_all_reading_history::<tauri::Wry>,
         commands::book_commands::get_recently_read_books,
         commands::book_commands::update_reading_progress::<tauri::Wry>,
+        commands::book_commands::set_books_read_status::<tauri::Wry>,
+        commands::book_commands::set_books_rating::<tauri::Wry>,
+        commands::book_commands::set_books_favorite::<tauri::Wry>,
         commands::book_commands::get_all_books_with_state,
+        commands::book_commands::search_books,
+        commands::book_commands::query_books,
         commands::book_commands::get_books_with_state_by_bookshelf_id,
         commands::book_commands::get_books_with_state_by_tag_id,
         commands::book_commands::get_books_with_state_by_series_id,
         commands::book_commands::update_book_series::<tauri::Wry>,
         commands::book_commands::update_series_orders::<tauri::Wry>,
+        commands::bookmark_commands::add_bookmark::<tauri::Wry>,
+        commands::bookmark_commands::get_bookmarks,
+        commands::bookmark_commands::get_all_bookmarks,
+        commands::bookmark_commands::update_bookmark::<tauri::Wry>,
+        commands::bookmark_commands::delete_bookmark::<tauri::Wry>,
         commands::bookshelf_commands::create_bookshelf::<tauri::Wry>,
         commands::bookshelf_commands::get_all_bookshelves,
+        commands::bookshelf_commands::set_bookshelf_rule::<tauri::Wry>,
         commands::bookshelf_commands::add_book_to_bookshelf::<tauri::Wry>,
         commands::bookshelf_commands::remove_book_from_bookshelf::<tauri::Wry>,
         commands::bookshelf_commands::delete_bookshelf::<tauri::Wry>,
+        commands::creator_commands::get_all_creators,
+        commands::creator_commands::get_book_creators,
+        commands::creator_commands::get_books_with_state_by_creator_id,
+        commands::creator_commands::rename_creator::<tauri::Wry>,
+        commands::creator_commands::merge_creators::<tauri::Wry>,
         commands::series_commands::create_series::<tauri::Wry>,
         commands::series_commands::get_all_series,
         commands::series_commands::delete_series::<tauri::Wry>,
         commands::tag_commands::create_tag::<tauri::Wry>,
         commands::tag_commands::get_all_tags,
         commands::tag_commands::delete_tag::<tauri::Wry>,
+        commands::statistics_commands::get_reading_statistics,
+        commands::statistics_commands::get_reading_sessions,
         commands::updater_commands::is_updater_supported,
-    ])
+        commands::verification_commands::verify_book::<tauri::Wry>,
+        commands::verification_commands::verify_library::<tauri::Wry>,
+        commands::verification_commands::cancel_verification,
+        commands::library_commands::get_library_roots,
+        commands::library_commands::add_library_root::<tauri::Wry>,
+        commands::library_commands::remove_library_root::<tauri::Wry>,
+        commands::library_commands::rescan_library::<tauri::Wry>,
+    ]);
+    // Event payloads are not part of any command signature; export them explicitly.
+    builder
+        .typ::<commands::verification_commands::VerificationProgress>()
+        .typ::<library::scanner::LibraryScanProgress>()
 }
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
@@ -71,15 +120,23 @@ pub fn run() {
     // generated invoke handler serves every specta-compatible command.
     let specta_handler = specta_builder().invoke_handler();
 
-    // The binary commands return a raw `tauri::ipc::Response` (no `specta::Type`),
-    // so they can't be registered with the tauri-specta builder. They keep a tiny
+    // The binary commands return a raw `tauri::ipc::Response` or take a raw-frame
+    // `Channel` (no `specta::Type`), so they can't be registered with the tauri-specta builder. They keep a tiny
     // hand-written handler and are routed to it by command name; everything else
     // falls through to the generated handler above. Keep this list in sync with the
     // `generate_handler!` invocation below — both are the single, small binary set.
-    const BINARY_COMMANDS: [&str; 3] = ["get_image", "get_image_preview", "get_entries_in_dir"];
+    const BINARY_COMMANDS: [&str; 5] = [
+        "get_image",
+        "get_image_preview",
+        "get_image_progressive",
+        "get_image_tile",
+        "get_entries_in_dir",
+    ];
     let binary_handler = tauri::generate_handler![
         commands::container_commands::get_image,
         commands::container_commands::get_image_preview,
+        commands::container_commands::get_image_progressive,
+        commands::container_commands::get_image_tile,
         commands::directory_commands::get_entries_in_dir,
     ] as fn(tauri::ipc::Invoke<tauri::Wry>) -> bool;
 
@@ -93,6 +150,10 @@ pub fn run() {
         .plugin(tauri_plugin_updater::Builder::new().build())
         .manage(RwLock::new(state::app_state::AppState::default()))
         .manage(settings::SettingsFileLock::default())
+        .register_asynchronous_uri_scheme_protocol(
+            protocol::PAGE_SCHEME,
+            protocol::handle_page_request,
+        )
         .setup(|app| {
             if let Err(e) = setup::setup(app) {
                 app.dialog()
@@ -237,7 +298,7 @@ mod error_codes_export {
 
 #[cfg(test)]
 mod tests {
-    use crate::domain::book::entity::{Book, BookWithState};
+    use crate::domain::book::entity::{Book, BookWithState, ReadStatus};
     use crate::domain::series::entity::Series;
     use crate::domain::tag::entity::Tag;
     use crate::settings::AppSettings;
@@ -284,12 +345,19 @@ mod tests {
             file_path: "p".into(),
             item_type: "file".into(),
             display_name: "n".into(),
+            sort_key: "key".into(),
             total_pages: 3,
             series_id: None,
             series_order: None,
             thumbnail_path: None,
             created_at: None,
+            damage_code: None,
+            missing_since: None,
+            read_status: ReadStatus::Reading,
+            rating: 4,
+            is_favorite: true,
             last_read_page_index: Some(2),
+            last_read_cfi: None,
             last_opened_at: None,
             tag_ids_str: Some("1,2".into()),
             tag_ids: vec![1, 2],
@@ -323,6 +391,7 @@ mod tests {
         let series = Series {
             id: 9,
             name: "saga".into(),
+            sort_key: "key".into(),
             created_at,
         };
         let value = serde_json::to_value(&series).unwrap();
@@ -0,0 +1,156 @@
+//! Library roots: folders the app scans and watches for books on its own.
+
+pub mod scanner;
+pub mod watcher;
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use tauri::{AppHandle, Manager, Runtime};
+
+use crate::{
+    domain::library::{entity::LibraryRoot, repository::LibraryRepository},
+    error::Result,
+};
+
+/// Resolves a library root to its absolute path, with symbolic links and `..`
+/// resolved.
+///
+/// On Windows the usual `C:\` form is kept instead of the verbatim `\\?\C:\` one, so
+/// the paths of the books found match the paths of books opened by hand.
+///
+/// # Errors
+///
+/// Returns an `Err` if the path does not exist.
+pub fn canonicalize(path: &Path) -> std::io::Result<PathBuf> {
+    let path = fs::canonicalize(path)?;
+    #[cfg(windows)]
+    if let Some(plain) = path.to_str().and_then(|p| p.strip_prefix(r"\\?\")) {
+        if plain.as_bytes().get(1) == Some(&b':') {
+            return Ok(PathBuf::from(plain));
+        }
+    }
+    Ok(path)
+}
+
+/// Returns the path a root is compared by: its canonical path, or the stored path if
+/// the folder is not available.
+fn comparable_path(root: &LibraryRoot) -> PathBuf {
+    canonicalize(Path::new(&root.path)).unwrap_or_else(|_| PathBuf::from(&root.path))
+}
+
+/// Finds the root containing the given folder, if any. A folder contains itself.
+///
+/// # Arguments
+///
+/// * `roots` - The library roots.
+/// * `path` - The canonical path of the folder.
+pub fn containing_root<'a>(roots: &'a [LibraryRoot], path: &Path) -> Option<&'a LibraryRoot> {
+    roots
+        .iter()
+        .find(|root| path.starts_with(comparable_path(root)))
+}
+
+/// Splits the roots into the outermost ones and the ones inside (or equal to) another
+/// root, so nested or duplicate roots are scanned and watched only once.
+///
+/// Paths are compared by whole components: `/comics2` is not inside `/comics`.
+///
+/// # Returns
+///
+/// The outermost roots and the covered roots, each in the given order.
+pub fn partition_nested_roots(roots: &[LibraryRoot]) -> (Vec<&LibraryRoot>, Vec<&LibraryRoot>) {
+    let paths: Vec<PathBuf> = roots.iter().map(comparable_path).collect();
+    let covered = |i: usize| {
+        paths.iter().enumerate().any(|(j, other)| {
+            // Of two equal roots, the first one is kept.
+            j != i && paths[i].starts_with(other) && (paths[i] != *other || j < i)
+        })
+    };
+    let (outer, nested): (Vec<_>, Vec<_>) =
+        roots.iter().enumerate().partition(|(i, _)| !covered(*i));
+    (
+        outer.into_iter().map(|(_, root)| root).collect(),
+        nested.into_iter().map(|(_, root)| root).collect(),
+    )
+}
+
+/// Watches the library roots currently in the database, replacing the previous watch.
+///
+/// # Returns
+///
+/// The watched library roots.
+///
+/// # Errors
+///
+/// Returns an `Err` if the roots cannot be read or the watcher cannot be created.
+pub async fn watch_roots<R: Runtime>(app: &AppHandle<R>) -> Result<Vec<LibraryRoot>> {
+    let roots = app
+        .state::<Arc<dyn LibraryRepository>>()
+        .get_roots()
+        .await?;
+    app.state::<watcher::LibraryWatcher>().watch(app, &roots)?;
+    Ok(roots)
+}
+
+/// Starts watching the library roots and scans them in the background, picking up the
+/// changes made while the app was not running.
+///
+/// # Arguments
+///
+/// * `app` - The Tauri app handle.
+pub fn spawn_startup_scan<R: Runtime>(app: AppHandle<R>) {
+    tauri::async_runtime::spawn(async move {
+        let result = match watch_roots(&app).await {
+            Ok(roots) if roots.is_empty() => Ok(()),
+            Ok(roots) => scanner::scan_roots(&app, &roots).await.map(|_| ()),
+            Err(e) => Err(e),
+        };
+        if let Err(e) = result {
+            log::error!("Library startup scan failed: {e}");
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn root(id: i64, path: &Path) -> LibraryRoot {
+        LibraryRoot {
+            id,
+            path: path.to_string_lossy().into_owned(),
+            created_at: chrono::Utc::now().naive_utc(),
+            last_scanned_at: None,
+        }
+    }
+
+    #[test]
+    fn test_partition_nested_roots() {
+        let dir = tempfile::tempdir().unwrap();
+        let base = canonicalize(dir.path()).unwrap();
+        fs::create_dir_all(base.join("comics/manga")).unwrap();
+        fs::create_dir_all(base.join("comics2")).unwrap();
+
+        let roots = vec![
+            root(1, &base.join("comics/manga")),
+            root(2, &base.join("comics")),
+            root(3, &base.join("comics2")),
+            // The same folder as root 2, spelled differently.
+            root(4, &base.join("comics2/../comics")),
+        ];
+        let (outer, nested) = partition_nested_roots(&roots);
+        let ids = |roots: Vec<&LibraryRoot>| roots.iter().map(|r| r.id).collect::<Vec<_>>();
+        assert_eq!(ids(outer), vec![2, 3]);
+        assert_eq!(ids(nested), vec![1, 4]);
+
+        assert_eq!(
+            containing_root(&roots[1..], &base.join("comics/manga")).map(|r| r.id),
+            Some(2)
+        );
+        assert!(containing_root(&roots[1..2], &base.join("comics2")).is_none());
+    }
+}
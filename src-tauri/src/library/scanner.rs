@@ -0,0 +1,530 @@
+//! Finds the book files in the library roots and keeps the `books` table in sync with
+//! them: new files are registered, changed files are re-read and files that are gone
+//! are flagged as missing.
+
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::UNIX_EPOCH,
+};
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter, Manager, Runtime};
+use tokio::sync::{Mutex, RwLock};
+
+use crate::{
+    commands::book_commands,
+    container::{factory::create_container, rar_volume, traits::Container},
+    domain::{
+        book::repository::BookRepository,
+        library::{
+            entity::{IndexedBook, LibraryRoot},
+            repository::LibraryRepository,
+        },
+    },
+    error::{Error, ErrorCode, Result},
+    state::{app_state::AppState, container_settings::ContainerSettings},
+};
+
+/// Event emitted after each book file the scanner reads. It carries a
+/// `LibraryScanProgress`.
+const LIBRARY_SCAN_PROGRESS_EVENT: &str = "library-scan-progress";
+
+/// Event emitted when a scan of the library roots finishes. It carries the
+/// `LibraryScanReport`.
+const LIBRARY_SCAN_FINISHED_EVENT: &str = "library-scan-finished";
+
+/// Serializes scans, so a rescan and the updates from the file-system watcher never
+/// index the same file twice at once.
+static SCAN_LOCK: Mutex<()> = Mutex::const_new(());
+
+/// The progress of a library scan, emitted as a `library-scan-progress` event.
+#[derive(Debug, Clone, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "camelCase")]
+pub struct LibraryScanProgress {
+    /// The path of the book file just read.
+    pub path: String,
+    /// The number of new or changed files read so far in this folder.
+    pub files_read: u32,
+    /// The number of new or changed files found in this folder.
+    pub total_files: u32,
+}
+
+/// The result of a library scan.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "camelCase")]
+pub struct LibraryScanReport {
+    /// The number of book files found.
+    pub files_found: u32,
+    /// The number of books registered for the first time.
+    pub added: u32,
+    /// The number of known books re-read because their file changed or came back.
+    pub updated: u32,
+    /// The number of books newly flagged as missing.
+    pub missing: u32,
+    /// The paths of the files that could not be read. They are retried by the next scan.
+    pub failed: Vec<String>,
+}
+
+impl LibraryScanReport {
+    /// Returns `true` if the scan changed any book.
+    fn has_changes(&self) -> bool {
+        self.added > 0 || self.updated > 0 || self.missing > 0
+    }
+}
+
+/// A book file found on disk.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct FoundFile {
+    /// The path of the file.
+    path: String,
+    /// The modification time of the file, in seconds since the Unix epoch.
+    modified: Option<i64>,
+}
+
+/// What a scan has to do to bring the indexed books in line with the files on disk.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct ScanPlan {
+    /// The files to read, with the ID of the book they belong to if it is known.
+    to_read: Vec<(FoundFile, Option<i64>)>,
+    /// The IDs of the books whose file is gone.
+    to_flag_missing: Vec<i64>,
+}
+
+/// Returns `true` if the file is a book the library registers on its own.
+///
+/// Later volumes of a multi-volume RAR set are left out; the set is registered once,
+/// from its first volume, which is told apart by the names of the files next to it.
+fn is_book_file<'a>(file_name: &str, siblings: impl IntoIterator<Item = &'a str>) -> bool {
+    <dyn Container>::is_supported_format(file_name)
+        && !rar_volume::is_continuation_volume(file_name, siblings)
+}
+
+/// Returns the names of the files in `folder`, or none if it cannot be read.
+fn file_names(folder: &Path) -> Vec<String> {
+    fs::read_dir(folder)
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|entry| entry.file_name().to_string_lossy().to_string())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Returns the modification time of a file, in seconds since the Unix epoch.
+fn modified_secs(metadata: &fs::Metadata) -> Option<i64> {
+    let modified = metadata.modified().ok()?;
+    let secs = modified.duration_since(UNIX_EPOCH).ok()?.as_secs();
+    i64::try_from(secs).ok()
+}
+
+/// Finds the book files at or below `path`.
+///
+/// `path` may be a folder, which is walked recursively, or a single file. Hidden files
+/// and folders (starting with `.`) are skipped, and so are symbolic links, which could
+/// otherwise lead the walk in circles. Folders that cannot be read are logged and
+/// skipped.
+fn find_book_files(path: &Path) -> Vec<FoundFile> {
+    let mut found = Vec::new();
+    let Ok(metadata) = fs::symlink_metadata(path) else {
+        return found;
+    };
+    if metadata.is_file() {
+        let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+        let siblings = path.parent().map(file_names).unwrap_or_default();
+        if is_book_file(&file_name, siblings.iter().map(String::as_str)) {
+            found.push(FoundFile {
+                path: path.to_string_lossy().to_string(),
+                modified: modified_secs(&metadata),
+            });
+        }
+        return found;
+    }
+
+    let mut folders = vec![path.to_path_buf()];
+    while let Some(folder) = folders.pop() {
+        let entries = match fs::read_dir(&folder) {
+            Ok(entries) => entries,
+            Err(e) => {
+                log::warn!("Failed to read library folder {}: {}", folder.display(), e);
+                continue;
+            }
+        };
+        let entries: Vec<_> = entries.flatten().collect();
+        let siblings: Vec<String> = entries
+            .iter()
+            .map(|entry| entry.file_name().to_string_lossy().to_string())
+            .collect();
+        for entry in entries {
+            let file_name = entry.file_name().to_string_lossy().to_string();
+            let Ok(metadata) = entry.metadata() else {
+                continue;
+            };
+            if file_name.starts_with('.') {
+                continue;
+            }
+            if metadata.is_dir() {
+                folders.push(entry.path());
+            } else if metadata.is_file()
+                && is_book_file(&file_name, siblings.iter().map(String::as_str))
+            {
+                found.push(FoundFile {
+                    path: entry.path().to_string_lossy().to_string(),
+                    modified: modified_secs(&metadata),
+                });
+            }
+        }
+    }
+    found.sort_by(|a, b| a.path.cmp(&b.path));
+    found
+}
+
+/// Compares the files found on disk with the books indexed for the same path.
+///
+/// A file is read if it is new, changed since it was indexed, or back after going
+/// missing. A book is flagged missing if its file was not found and it is not flagged
+/// already.
+fn plan_scan(found: Vec<FoundFile>, indexed: &[IndexedBook]) -> ScanPlan {
+    let mut by_path: HashMap<&str, &IndexedBook> = indexed
+        .iter()
+        .map(|book| (book.file_path.as_str(), book))
+        .collect();
+    let mut plan = ScanPlan::default();
+    for file in found {
+        match by_path.remove(file.path.as_str()) {
+            None => plan.to_read.push((file, None)),
+            Some(book) if book.missing || book.file_modified_at != file.modified => {
+                plan.to_read.push((file, Some(book.id)))
+            }
+            Some(_) => {}
+        }
+    }
+    plan.to_flag_missing = by_path
+        .into_values()
+        .filter(|book| !book.missing)
+        .map(|book| book.id)
+        .collect();
+    plan.to_flag_missing.sort_unstable();
+    plan
+}
+
+/// The managed state a scan works with.
+struct Scanner<R: Runtime> {
+    app: AppHandle<R>,
+    book_repo: Arc<dyn BookRepository>,
+    library_repo: Arc<dyn LibraryRepository>,
+    settings: ContainerSettings,
+}
+
+impl<R: Runtime> Scanner<R> {
+    async fn new(app: &AppHandle<R>) -> Self {
+        let settings = app
+            .state::<RwLock<AppState>>()
+            .read()
+            .await
+            .container_state
+            .settings
+            .clone();
+        Self {
+            app: app.clone(),
+            book_repo: app.state::<Arc<dyn BookRepository>>().inner().clone(),
+            library_repo: app.state::<Arc<dyn LibraryRepository>>().inner().clone(),
+            settings,
+        }
+    }
+
+    /// Brings the books at or below `path` in line with the files on disk.
+    async fn sync(&self, path: &Path, report: &mut LibraryScanReport) -> Result<()> {
+        let walk_path = path.to_path_buf();
+        let found = tauri::async_runtime::spawn_blocking(move || find_book_files(&walk_path))
+            .await
+            .map_err(|e| Error::Other(format!("Spawn blocking failed: {e}")))?;
+        let indexed = self
+            .library_repo
+            .get_indexed_books(&path.to_string_lossy())
+            .await?;
+        report.files_found += found.len() as u32;
+
+        let plan = plan_scan(found, &indexed);
+        let total_files = plan.to_read.len() as u32;
+        for (files_read, (file, book_id)) in plan.to_read.into_iter().enumerate() {
+            match self.read_book(&file, book_id.is_some()).await {
+                Ok(()) if book_id.is_some() => report.updated += 1,
+                Ok(()) => report.added += 1,
+                Err(e) => {
+                    log::warn!("Failed to index {}: {}", file.path, e);
+                    report.failed.push(file.path.clone());
+                }
+            }
+            let progress = LibraryScanProgress {
+                path: file.path,
+                files_read: files_read as u32 + 1,
+                total_files,
+            };
+            // Progress is informational; a failed emit must not abort the scan.
+            if let Err(e) = self.app.emit(LIBRARY_SCAN_PROGRESS_EVENT, &progress) {
+                log::warn!("Failed to emit library scan progress: {e}");
+            }
+        }
+        for book_id in plan.to_flag_missing {
+            self.library_repo.mark_missing(book_id).await?;
+            report.missing += 1;
+        }
+        Ok(())
+    }
+
+    /// Reads a book file and registers it, replacing the thumbnail of a changed book.
+    async fn read_book(&self, file: &FoundFile, known: bool) -> Result<()> {
+        let path = file.path.clone();
+        let config = self.settings.container_config();
+        let container =
+            tauri::async_runtime::spawn_blocking(move || create_container(&path, config))
+                .await
+                .map_err(|e| Error::Other(format!("Spawn blocking failed: {e}")))??;
+
+        if known {
+            let thumbnail_path = book_commands::thumbnail_file_path(&self.app, &file.path)?;
+            if let Err(e) = fs::remove_file(&thumbnail_path) {
+                if e.kind() != std::io::ErrorKind::NotFound {
+                    return Err(e.into());
+                }
+            }
+        }
+        let thumbnail_path = book_commands::generate_and_save_thumbnail(
+            self.app.clone(),
+            file.path.clone(),
+            self.settings.clone(),
+            Some(container.clone()),
+        )
+        .await
+        .unwrap_or_else(|e| {
+            log::warn!("Thumbnail of {} generation failed: {}", file.path, e);
+            None
+        });
+
+        let display_name = Path::new(&file.path).file_name().map_or_else(
+            || file.path.clone(),
+            |name| name.to_string_lossy().to_string(),
+        );
+        let book_id = self
+            .book_repo
+            .register_book(
+                &file.path,
+                "file",
+                &display_name,
+                container.get_entries().len() as i64,
+                thumbnail_path,
+            )
+            .await?;
+        let damage_code = container
+            .damage()
+            .map(|damage| i64::from(ErrorCode::from(&damage).code()));
+        self.book_repo.set_damage_code(book_id, damage_code).await?;
+        if let Some(metadata) = book_commands::resolve_metadata(&file.path).await {
+            self.book_repo.set_metadata(book_id, &metadata).await?;
+        }
+        self.library_repo
+            .set_file_indexed(book_id, file.modified)
+            .await?;
+        Ok(())
+    }
+
+    /// Tells the frontend to refetch the books if the scan changed any.
+    fn notify_changes(&self, report: &LibraryScanReport) -> Result<()> {
+        if report.has_changes() {
+            self.app.emit("history-changed", ())?;
+        }
+        Ok(())
+    }
+}
+
+/// Scans library roots, registering new book files, re-reading changed ones and
+/// flagging the ones that are gone.
+///
+/// A root that is not available (e.g. on an unmounted drive) is skipped, so its books
+/// are not all flagged missing. A root inside another root is scanned only once. A `library-scan-progress` event is emitted after each
+/// file read and a `library-scan-finished` event at the end.
+///
+/// # Arguments
+///
+/// * `app` - The Tauri app handle providing the repositories and the container settings.
+/// * `roots` - The library roots to scan.
+///
+/// # Returns
+///
+/// A `LibraryScanReport` summarizing the changes.
+///
+/// # Errors
+///
+/// Returns an `Err` if a database operation fails. Files that cannot be read are
+/// reported in `failed` instead.
+pub async fn scan_roots<R: Runtime>(
+    app: &AppHandle<R>,
+    roots: &[LibraryRoot],
+) -> Result<LibraryScanReport> {
+    let _guard = SCAN_LOCK.lock().await;
+    let scanner = Scanner::new(app).await;
+    let mut report = LibraryScanReport::default();
+    let (roots, nested) = crate::library::partition_nested_roots(roots);
+    for root in roots {
+        let path = Path::new(&root.path);
+        if !path.is_dir() {
+            log::warn!("Library root {} is not available, skipping", root.path);
+            continue;
+        }
+        log::info!("Scanning library root {}", root.path);
+        scanner.sync(path, &mut report).await?;
+        scanner.library_repo.mark_root_scanned(root.id).await?;
+    }
+    // A root inside another one was scanned along with it.
+    for root in nested {
+        if Path::new(&root.path).is_dir() {
+            scanner.library_repo.mark_root_scanned(root.id).await?;
+        }
+    }
+
+    log::info!(
+        "Library scan found {} files: {} added, {} updated, {} missing, {} failed",
+        report.files_found,
+        report.added,
+        report.updated,
+        report.missing,
+        report.failed.len()
+    );
+    scanner.notify_changes(&report)?;
+    app.emit(LIBRARY_SCAN_FINISHED_EVENT, &report)?;
+    Ok(report)
+}
+
+/// Brings the books at or below the given paths in line with the files on disk.
+///
+/// Used for the incremental updates reported by the file-system watcher: a path may be
+/// a new, changed or removed file or folder.
+///
+/// # Arguments
+///
+/// * `app` - The Tauri app handle providing the repositories and the container settings.
+/// * `paths` - The changed paths.
+///
+/// # Errors
+///
+/// Returns an `Err` if a database operation fails.
+pub async fn sync_paths<R: Runtime>(app: &AppHandle<R>, paths: &[PathBuf]) -> Result<()> {
+    let _guard = SCAN_LOCK.lock().await;
+    let scanner = Scanner::new(app).await;
+    let mut report = LibraryScanReport::default();
+    for path in paths {
+        log::debug!("Syncing changed library path {}", path.display());
+        scanner.sync(path, &mut report).await?;
+    }
+    scanner.notify_changes(&report)
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn found(path: &str, modified: i64) -> FoundFile {
+        FoundFile {
+            path: path.to_string(),
+            modified: Some(modified),
+        }
+    }
+
+    fn indexed(id: i64, path: &str, modified: Option<i64>, missing: bool) -> IndexedBook {
+        IndexedBook {
+            id,
+            file_path: path.to_string(),
+            file_modified_at: modified,
+            missing,
+        }
+    }
+
+    #[rstest]
+    #[case("book.cbz", true)]
+    #[case("book.PDF", true)]
+    #[case("book.part1.rar", true)]
+    #[case("book.part02.rar", false)]
+    #[case("cover.jpg", false)]
+    fn test_is_book_file(#[case] file_name: &str, #[case] expected: bool) {
+        let siblings: [&str; 0] = [];
+        assert_eq!(is_book_file(file_name, siblings), expected, "{file_name}");
+    }
+
+    #[test]
+    fn test_plan_scan() {
+        let files = vec![
+            found("/lib/new.zip", 1),
+            found("/lib/same.zip", 2),
+            found("/lib/changed.zip", 5),
+            found("/lib/back.zip", 4),
+            found("/lib/opened.zip", 6),
+        ];
+        let books = vec![
+            indexed(1, "/lib/same.zip", Some(2), false),
+            indexed(2, "/lib/changed.zip", Some(3), false),
+            indexed(3, "/lib/back.zip", Some(4), true),
+            indexed(4, "/lib/opened.zip", None, false),
+            indexed(5, "/lib/gone.zip", Some(1), false),
+            indexed(6, "/lib/still_gone.zip", Some(1), true),
+        ];
+
+        let plan = plan_scan(files, &books);
+
+        assert_eq!(
+            plan.to_read,
+            vec![
+                (found("/lib/new.zip", 1), None),
+                (found("/lib/changed.zip", 5), Some(2)),
+                (found("/lib/back.zip", 4), Some(3)),
+                (found("/lib/opened.zip", 6), Some(4)),
+            ]
+        );
+        assert_eq!(plan.to_flag_missing, vec![5]);
+    }
+
+    #[test]
+    fn test_find_book_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        fs::create_dir_all(root.join("series/vol")).unwrap();
+        fs::create_dir_all(root.join(".hidden")).unwrap();
+        for file in [
+            "a.cbz",
+            "notes.txt",
+            "series/b.pdf",
+            "series/vol/c.part1.rar",
+            "series/vol/c.part2.rar",
+            "series/vol/f.part0.rar",
+            "series/vol/f.part1.rar",
+            ".hidden/d.zip",
+            ".e.zip",
+        ] {
+            fs::write(root.join(file), b"").unwrap();
+        }
+
+        let paths: Vec<PathBuf> = find_book_files(root)
+            .into_iter()
+            .map(|file| PathBuf::from(file.path))
+            .collect();
+        let mut expected = vec![
+            root.join("a.cbz"),
+            root.join("series/b.pdf"),
+            root.join("series/vol/c.part1.rar"),
+            root.join("series/vol/f.part0.rar"),
+        ];
+        expected.sort();
+        assert_eq!(paths, expected);
+
+        let single = find_book_files(&root.join("a.cbz"));
+        assert_eq!(single.len(), 1);
+        assert!(single[0].modified.is_some());
+        assert!(find_book_files(&root.join("series/vol/f.part1.rar")).is_empty());
+        assert!(find_book_files(&root.join("gone.zip")).is_empty());
+    }
+}
@@ -0,0 +1,126 @@
+//! Watches the library roots and syncs the paths that change, so books added, replaced
+//! or removed while the app runs show up without a rescan.
+
+use std::{
+    collections::BTreeSet,
+    path::{Path, PathBuf},
+    sync::Mutex,
+    time::Duration,
+};
+
+use notify_debouncer_full::{
+    new_debouncer,
+    notify::{RecommendedWatcher, RecursiveMode},
+    DebounceEventResult, DebouncedEvent, Debouncer, RecommendedCache,
+};
+use tauri::{AppHandle, Runtime};
+
+use super::scanner;
+use crate::{domain::library::entity::LibraryRoot, error::Result};
+
+/// How long the watcher waits for a path to settle before syncing it. Copying a large
+/// archive produces a burst of modify events; this lets it finish first.
+const DEBOUNCE_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// The file-system watcher of the library roots, kept as managed state.
+#[derive(Default)]
+pub struct LibraryWatcher {
+    /// The running watcher, or `None` if no root is watched. Dropping it stops the watch.
+    debouncer: Mutex<Option<Debouncer<RecommendedWatcher, RecommendedCache>>>,
+}
+
+impl LibraryWatcher {
+    /// Watches exactly the given roots, replacing the previous watch.
+    ///
+    /// A root that cannot be watched (e.g. on an unmounted drive) is logged and skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `Err` if the watcher cannot be created.
+    pub fn watch<R: Runtime>(&self, app: &AppHandle<R>, roots: &[LibraryRoot]) -> Result<()> {
+        let mut debouncer = self.debouncer.lock().unwrap_or_else(|e| e.into_inner());
+        // Stop the previous watch first, so no event is handled twice.
+        *debouncer = None;
+        if roots.is_empty() {
+            return Ok(());
+        }
+
+        let app = app.clone();
+        let mut watcher = new_debouncer(
+            DEBOUNCE_TIMEOUT,
+            None,
+            move |result: DebounceEventResult| match result {
+                Ok(events) => {
+                    let paths = changed_paths(&events);
+                    if paths.is_empty() {
+                        return;
+                    }
+                    let app = app.clone();
+                    tauri::async_runtime::spawn(async move {
+                        if let Err(e) = scanner::sync_paths(&app, &paths).await {
+                            log::warn!("Failed to sync changed library paths: {e}");
+                        }
+                    });
+                }
+                Err(errors) => {
+                    for e in errors {
+                        log::warn!("Library watch error: {e}");
+                    }
+                }
+            },
+        )?;
+        // A recursive watch of the outer root already covers the nested ones.
+        let (roots, _) = super::partition_nested_roots(roots);
+        for root in roots {
+            if let Err(e) = watcher.watch(Path::new(&root.path), RecursiveMode::Recursive) {
+                log::warn!("Failed to watch library root {}: {}", root.path, e);
+            }
+        }
+        *debouncer = Some(watcher);
+        Ok(())
+    }
+}
+
+/// Collects the distinct paths touched by the events, ignoring mere reads.
+fn changed_paths(events: &[DebouncedEvent]) -> Vec<PathBuf> {
+    events
+        .iter()
+        .filter(|event| !event.kind.is_access())
+        .flat_map(|event| event.paths.iter().cloned())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Instant;
+
+    use notify_debouncer_full::notify::{
+        event::{AccessKind, CreateKind, RemoveKind},
+        Event, EventKind,
+    };
+
+    use super::*;
+
+    fn event(kind: EventKind, path: &str) -> DebouncedEvent {
+        DebouncedEvent::new(
+            Event::new(kind).add_path(PathBuf::from(path)),
+            Instant::now(),
+        )
+    }
+
+    #[test]
+    fn test_changed_paths_are_distinct_and_skip_reads() {
+        let events = vec![
+            event(EventKind::Create(CreateKind::File), "/lib/b.zip"),
+            event(EventKind::Access(AccessKind::Any), "/lib/read.zip"),
+            event(EventKind::Remove(RemoveKind::File), "/lib/a.zip"),
+            event(EventKind::Create(CreateKind::File), "/lib/b.zip"),
+        ];
+        assert_eq!(
+            changed_paths(&events),
+            vec![PathBuf::from("/lib/a.zip"), PathBuf::from("/lib/b.zip")]
+        );
+    }
+}
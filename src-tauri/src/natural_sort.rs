@@ -0,0 +1,334 @@
+//! A natural sort order that understands Japanese titles and file names.
+//!
+//! On top of comparing digit runs by value and ignoring case, the comparison
+//! - folds full-width ASCII (`０`-`９`, `Ａ`-`Ｚ`, ...) to its half-width form,
+//! - reads kanji numerals (`一`-`九`, `〇`, `十`, `百`, `千`, `万`) as numbers, so `第十巻` sorts
+//!   after `第二巻`, unless they are part of a word such as `千葉`, and
+//! - orders the volume markers `上`/`中`/`下` and `前`/`中`/`後` (as in `前編`, `後編`)
+//!   by their meaning instead of their code point.
+//!
+//! The frontend sorts by [`sort_key`], so both sides share this one implementation.
+
+use std::cmp::Ordering;
+
+/// A unit two names are compared by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Token {
+    /// A run of ASCII digits or kanji numerals, by value.
+    Number(u64),
+    /// A volume marker, by its position in the set (`上` 0, `中` 1, `下` 2).
+    Marker(u8),
+    /// Any other character, folded and lowercased.
+    Char(char),
+}
+
+/// Folds a full-width ASCII character (U+FF01-U+FF5E) and the ideographic space to
+/// their half-width forms.
+fn fold_width(c: char) -> char {
+    match c {
+        '\u{FF01}'..='\u{FF5E}' => char::from_u32(c as u32 - 0xFEE0).unwrap_or(c),
+        '\u{3000}' => ' ',
+        _ => c,
+    }
+}
+
+/// Returns the value of a kanji digit.
+fn kanji_digit(c: char) -> Option<u64> {
+    match c {
+        '〇' | '零' => Some(0),
+        '一' => Some(1),
+        '二' => Some(2),
+        '三' => Some(3),
+        '四' => Some(4),
+        '五' => Some(5),
+        '六' => Some(6),
+        '七' => Some(7),
+        '八' => Some(8),
+        '九' => Some(9),
+        _ => None,
+    }
+}
+
+/// Returns the multiplier of a kanji numeral unit below ten thousand.
+fn kanji_unit(c: char) -> Option<u64> {
+    match c {
+        '十' => Some(10),
+        '百' => Some(100),
+        '千' => Some(1000),
+        _ => None,
+    }
+}
+
+/// Returns `true` if the character belongs to a kanji numeral.
+fn is_kanji_numeral(c: char) -> bool {
+    kanji_digit(c).is_some() || kanji_unit(c).is_some() || c == '万'
+}
+
+/// Returns `true` for kanji and kana, i.e. characters that continue a Japanese word.
+fn is_japanese_letter(c: char) -> bool {
+    matches!(c,
+        '\u{3041}'..='\u{309F}' // Hiragana
+        | '\u{30A0}'..='\u{30FF}' // Katakana
+        | '\u{3400}'..='\u{4DBF}' // CJK Extension A
+        | '\u{4E00}'..='\u{9FFF}' // CJK Unified Ideographs
+        | '\u{F900}'..='\u{FAFF}' // CJK Compatibility Ideographs
+    )
+}
+
+/// Returns `true` for hiragana, which mostly spells particles and endings around a word.
+fn is_hiragana(c: char) -> bool {
+    matches!(c, '\u{3041}'..='\u{309F}')
+}
+
+/// Returns `true` for the counters that follow a number in a title (`三巻`, `十二話`).
+fn is_counter(c: char) -> bool {
+    matches!(
+        c,
+        '巻' | '話'
+            | '章'
+            | '部'
+            | '回'
+            | '編'
+            | '篇'
+            | '集'
+            | '冊'
+            | '号'
+            | '幕'
+            | '夜'
+            | '期'
+            | '弾'
+            | '版'
+            | '節'
+            | '年'
+    )
+}
+
+/// Returns the position of a volume marker in its set.
+fn marker_rank(c: char) -> Option<u8> {
+    match c {
+        '上' | '前' => Some(0),
+        '中' => Some(1),
+        '下' | '後' => Some(2),
+        _ => None,
+    }
+}
+
+/// Parses a kanji numeral such as `二十三` or `千九百`. Digits without units are read
+/// positionally (`二〇二四` is 2024).
+fn parse_kanji_numeral(numeral: &[char]) -> u64 {
+    let mut total: u64 = 0;
+    let mut section: u64 = 0;
+    let mut current: u64 = 0;
+    for &c in numeral {
+        if let Some(digit) = kanji_digit(c) {
+            current = current.saturating_mul(10).saturating_add(digit);
+        } else if let Some(unit) = kanji_unit(c) {
+            section = section.saturating_add(current.max(1).saturating_mul(unit));
+            current = 0;
+        } else {
+            // 万
+            let value = section.saturating_add(current).max(1);
+            total = total.saturating_add(value.saturating_mul(10_000));
+            section = 0;
+            current = 0;
+        }
+    }
+    total.saturating_add(section).saturating_add(current)
+}
+
+/// Splits a name into comparison tokens.
+fn tokenize(name: &str) -> Vec<Token> {
+    let chars: Vec<char> = name
+        .chars()
+        .map(fold_width)
+        .flat_map(char::to_lowercase)
+        .collect();
+    let mut tokens = Vec::with_capacity(chars.len());
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c.is_ascii_digit() {
+            let run = &chars[i..run_end(&chars, i, |c| c.is_ascii_digit())];
+            let value = run.iter().fold(0u64, |value, c| {
+                value
+                    .saturating_mul(10)
+                    .saturating_add(u64::from(*c as u8 - b'0'))
+            });
+            tokens.push(Token::Number(value));
+            i += run.len();
+        } else if let Some(end) = kanji_numeral_end(&chars, i) {
+            tokens.push(Token::Number(parse_kanji_numeral(&chars[i..end])));
+            i = end;
+        } else if let Some(rank) = marker_rank(c).filter(|_| is_marker_at(&chars, i)) {
+            tokens.push(Token::Marker(rank));
+            i += 1;
+        } else {
+            tokens.push(Token::Char(c));
+            i += 1;
+        }
+    }
+    tokens
+}
+
+/// Returns the end of the run of characters matching `predicate` that starts at `start`.
+fn run_end(chars: &[char], start: usize, predicate: impl Fn(char) -> bool) -> usize {
+    start + chars[start..].iter().take_while(|c| predicate(**c)).count()
+}
+
+/// Returns the end of the kanji numeral that starts at `start`, or `None` if there is
+/// none or its kanji are part of a word (`千葉`, `九州`, `一緒`) rather than a number
+/// (`第十巻`, `二十三話`, `その二`).
+fn kanji_numeral_end(chars: &[char], start: usize) -> Option<usize> {
+    if !is_kanji_numeral(chars[start]) {
+        return None;
+    }
+    let end = run_end(chars, start, is_kanji_numeral);
+    let in_word = |c: char| is_japanese_letter(c) && !is_hiragana(c);
+    let before = start.checked_sub(1).map(|j| chars[j]);
+    let opens = before.is_none_or(|c| c == '第' || !in_word(c));
+    let closes = chars
+        .get(end)
+        .is_none_or(|c| is_counter(*c) || !in_word(*c));
+    (opens && closes).then_some(end)
+}
+
+/// Returns `true` if the marker character at `i` stands on its own (`上`, `(下)`,
+/// `上巻`, `前編`) rather than being part of a word (`上野`, `地下鉄`).
+fn is_marker_at(chars: &[char], i: usize) -> bool {
+    let after = chars.get(i + 1);
+    if matches!(after, Some('巻' | '編')) {
+        return true;
+    }
+    // A bare `前`/`後` is too common in titles; only `前編`/`後編` count.
+    if matches!(chars[i], '前' | '後') {
+        return false;
+    }
+    let standalone = |neighbor: Option<&char>| neighbor.is_none_or(|c| !is_japanese_letter(*c));
+    let before = i.checked_sub(1).and_then(|j| chars.get(j));
+    standalone(before) && standalone(after)
+}
+
+/// Compares two names in Japanese-aware natural order.
+///
+/// Names that only differ in ways the natural order ignores (case, width, leading
+/// zeros, kanji vs. ASCII digits) are ordered by their plain text, so the order is total.
+pub fn compare(a: &str, b: &str) -> Ordering {
+    tokenize(a).cmp(&tokenize(b)).then_with(|| a.cmp(b))
+}
+
+/// Returns an ASCII key that orders names like [`compare`] when keys are compared as
+/// plain strings, so the frontend can sort names exactly as the backend does.
+pub fn sort_key(name: &str) -> String {
+    let mut key = String::new();
+    // Each token starts with its kind, so numbers sort before markers and characters.
+    // Numbers are prefixed with their length to compare by value.
+    for token in tokenize(name) {
+        key.push_str(&match token {
+            Token::Number(value) => {
+                let digits = value.to_string();
+                format!("0{:02}{digits}", digits.len())
+            }
+            Token::Marker(rank) => format!("1{rank}"),
+            Token::Char(c) => format!("2{:06x}", u32::from(c)),
+        });
+    }
+    // The space sorts before every token, so a name sorts before the names it is a
+    // prefix of. The plain text follows to order equivalent names.
+    key.push(' ');
+    for c in name.chars() {
+        key.push_str(&format!("{:06x}", u32::from(c)));
+    }
+    key
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("2.jpg", "10.jpg")]
+    #[case("page2", "Page10")]
+    #[case("第二巻", "第十巻")]
+    #[case("第九巻", "第十一巻")]
+    #[case("第十九巻", "第二十巻")]
+    #[case("百二十話", "千話")]
+    #[case("第２巻", "第10巻")]
+    #[case("ｖｏｌ．３", "vol.12")]
+    #[case("物語 上", "物語 中")]
+    #[case("物語 中", "物語 下")]
+    #[case("物語(上)", "物語(下)")]
+    #[case("物語 上巻", "物語 下巻")]
+    #[case("物語 前編", "物語 中編")]
+    #[case("物語 中編", "物語 後編")]
+    #[case("001.jpg", "cover.jpg")]
+    #[case("第二話", "第十話")]
+    #[case("その二", "その十")]
+    #[case("千話", "千葉")]
+    #[case("b", "ba")]
+    #[case("01.jpg", "1.jpg")]
+    fn test_orders(#[case] first: &str, #[case] second: &str) {
+        assert_eq!(compare(first, second), Ordering::Less, "{first} < {second}");
+        assert_eq!(
+            compare(second, first),
+            Ordering::Greater,
+            "{second} > {first}"
+        );
+        assert!(sort_key(first) < sort_key(second), "key {first} < {second}");
+    }
+
+    #[rstest]
+    #[case("二十三", 23)]
+    #[case("十", 10)]
+    #[case("百五", 105)]
+    #[case("千九百八十四", 1984)]
+    #[case("二〇二四", 2024)]
+    #[case("三万五千", 35_000)]
+    fn test_parse_kanji_numeral(#[case] numeral: &str, #[case] value: u64) {
+        let chars: Vec<char> = numeral.chars().collect();
+        assert_eq!(parse_kanji_numeral(&chars), value);
+    }
+
+    #[test]
+    fn test_markers_inside_words_are_plain_characters() {
+        assert_eq!(tokenize("上野")[0], Token::Char('上'));
+        assert_eq!(tokenize("地下鉄")[1], Token::Char('下'));
+        assert_eq!(tokenize("前")[0], Token::Char('前'));
+        assert_eq!(tokenize("上")[0], Token::Marker(0));
+    }
+
+    #[test]
+    fn test_kanji_numerals_inside_words_are_plain_characters() {
+        assert_eq!(tokenize("千葉")[0], Token::Char('千'));
+        assert_eq!(tokenize("九州")[0], Token::Char('九'));
+        assert_eq!(tokenize("一緒")[0], Token::Char('一'));
+        assert_eq!(
+            tokenize("千話"),
+            vec![Token::Number(1000), Token::Char('話')]
+        );
+        assert_eq!(tokenize("その二")[2], Token::Number(2));
+        assert_eq!(tokenize("千")[0], Token::Number(1000));
+    }
+
+    #[test]
+    fn test_sort_key_format() {
+        // The frontend tests use this key.
+        assert_eq!(
+            sort_key("2.jpg"),
+            "0012200002e200006a20000702000067 00003200002e00006a000070000067"
+        );
+    }
+
+    #[test]
+    fn test_equivalent_names_are_ordered_deterministically() {
+        assert_ne!(compare("01.jpg", "1.jpg"), Ordering::Equal);
+        assert_eq!(compare("a.jpg", "a.jpg"), Ordering::Equal);
+
+        let mut names = vec!["B.jpg", "b.jpg", "a.jpg"];
+        names.sort_by(|a, b| compare(a, b));
+        assert_eq!(names, vec!["a.jpg", "B.jpg", "b.jpg"]);
+        assert_eq!(sort_key("a.jpg"), sort_key("a.jpg"));
+        assert!(sort_key("B.jpg") < sort_key("b.jpg"));
+    }
+}
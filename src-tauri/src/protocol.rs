@@ -0,0 +1,541 @@
+use std::{ops::Range, sync::Arc};
+
+use percent_encoding::percent_decode_str;
+use tauri::{
+    http::{header, Method, Request, Response, StatusCode, Uri},
+    Manager, Runtime, UriSchemeContext, UriSchemeResponder,
+};
+use tokio::sync::RwLock;
+
+use crate::{
+    error::{Error, Result},
+    image::{loader::ImageLoader, types::Image},
+    state::app_state::AppState,
+};
+
+/// The URI scheme pages are served under.
+pub const PAGE_SCHEME: &str = "rookreader";
+
+/// The response header carrying the width in pixels of the served image.
+const WIDTH_HEADER: &str = "x-image-width";
+
+/// The response header carrying the height in pixels of the served image.
+const HEIGHT_HEADER: &str = "x-image-height";
+
+/// The origins the app's pages are served from, which alone may read responses through
+/// `fetch`: the bundled frontend on each platform, and the dev server in debug builds.
+const ALLOWED_ORIGINS: &[&str] = &[
+    "tauri://localhost",
+    "http://tauri.localhost",
+    "https://tauri.localhost",
+    #[cfg(debug_assertions)]
+    "http://localhost:1420",
+];
+
+/// A page requested through the `rookreader` scheme.
+#[derive(Debug, PartialEq, Eq)]
+struct PageRequest {
+    /// The path of the book the page belongs to.
+    book: String,
+    /// The name of the image entry within the book.
+    entry: String,
+    /// The maximum height in pixels requested with `?h=`, if any.
+    height: Option<u32>,
+}
+
+impl PageRequest {
+    /// Parses a page URI.
+    ///
+    /// Accepts `rookreader://page/<book>/<entry>` as well as the origin-style forms the
+    /// webview uses for custom schemes (`rookreader://localhost/page/...` and, on
+    /// Windows and Android, `http://rookreader.localhost/page/...`). `<book>` must be
+    /// percent-encoded as a single segment; `<entry>` may contain raw `/`.
+    fn parse(uri: &Uri) -> Result<Self> {
+        let path = if uri.host() == Some("page") {
+            uri.path()
+        } else {
+            uri.path().strip_prefix("/page").unwrap_or_default()
+        };
+        let (book, entry) = path
+            .strip_prefix('/')
+            .and_then(|rest| rest.split_once('/'))
+            .filter(|(book, entry)| !book.is_empty() && !entry.is_empty())
+            .ok_or_else(|| Error::InvalidArgument(format!("Malformed page URI: {uri}")))?;
+
+        let height = uri
+            .query()
+            .into_iter()
+            .flat_map(|query| query.split('&'))
+            .find_map(|pair| pair.strip_prefix("h="))
+            .map(|value| {
+                value.parse().map_err(|_| {
+                    Error::InvalidArgument(format!("Invalid page height in {uri}: {value}"))
+                })
+            })
+            .transpose()?;
+
+        Ok(Self {
+            book: percent_decode(book)?,
+            entry: percent_decode(entry)?,
+            height,
+        })
+    }
+}
+
+/// Decodes a percent-encoded URI component as UTF-8.
+fn percent_decode(component: &str) -> Result<String> {
+    percent_decode_str(component)
+        .decode_utf8()
+        .map(|decoded| decoded.into_owned())
+        .map_err(|e| Error::InvalidArgument(format!("Invalid URI component {component}: {e}")))
+}
+
+/// The part of the body a `Range` request header asks for.
+#[derive(Debug, PartialEq, Eq)]
+enum ByteRange {
+    /// No usable range: the whole body is served.
+    Full,
+    /// A single satisfiable range of the body.
+    Partial(Range<usize>),
+    /// The range starts past the end of the body.
+    Unsatisfiable,
+}
+
+impl ByteRange {
+    /// Resolves a `Range` header value against a body of `len` bytes.
+    ///
+    /// Only a single `bytes` range is supported; malformed headers and multi-range
+    /// requests are ignored (the whole body is served), as RFC 9110 permits.
+    fn parse(value: &str, len: usize) -> Self {
+        let Some((start, end)) = value
+            .strip_prefix("bytes=")
+            .filter(|spec| !spec.contains(','))
+            .and_then(|spec| spec.trim().split_once('-'))
+        else {
+            return Self::Full;
+        };
+
+        if start.is_empty() {
+            // A suffix range: the last `end` bytes.
+            return match end.parse::<usize>() {
+                Ok(0) => Self::Unsatisfiable,
+                Ok(_) if len == 0 => Self::Unsatisfiable,
+                Ok(suffix) => Self::Partial(len.saturating_sub(suffix)..len),
+                Err(_) => Self::Full,
+            };
+        }
+
+        let Ok(start) = start.parse::<usize>() else {
+            return Self::Full;
+        };
+        let end = if end.is_empty() {
+            len
+        } else {
+            match end.parse::<usize>() {
+                Ok(last) if last >= start => (last + 1).min(len),
+                _ => return Self::Full,
+            }
+        };
+        if start >= len {
+            Self::Unsatisfiable
+        } else {
+            Self::Partial(start..end)
+        }
+    }
+}
+
+/// Handles a request to the `rookreader` scheme.
+///
+/// Serves pages of the currently open book straight to `<img>` elements, so the
+/// webview can decode them progressively, cache them, and pick renditions through
+/// `srcset`. The image is loaded on a blocking thread through the open `ImageLoader`,
+/// sharing its cache with the `get_image` command.
+///
+/// # Arguments
+///
+/// * `ctx` - The scheme context, used to reach the application state.
+/// * `request` - The HTTP request issued by the webview.
+/// * `responder` - Sends the response once the page is loaded.
+pub fn handle_page_request<R: Runtime>(
+    ctx: UriSchemeContext<'_, R>,
+    request: Request<Vec<u8>>,
+    responder: UriSchemeResponder,
+) {
+    let app = ctx.app_handle().clone();
+    let origin = request
+        .headers()
+        .get(header::ORIGIN)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string);
+    tauri::async_runtime::spawn(async move {
+        let image_loader = {
+            let state = app.state::<RwLock<AppState>>();
+            let state_lock = state.read().await;
+            state_lock.container_state.image_loader.clone()
+        };
+        let response =
+            tauri::async_runtime::spawn_blocking(move || serve_page(image_loader, &request))
+                .await
+                .unwrap_or_else(|e| {
+                    error_response(
+                        &Error::Other(format!("Spawn blocking failed: {e}")),
+                        origin.as_deref(),
+                    )
+                });
+        responder.respond(response);
+    });
+}
+
+/// Builds the response to a page request against the open `ImageLoader`.
+///
+/// # Arguments
+///
+/// * `image_loader` - The loader of the open book, if any.
+/// * `request` - The HTTP request issued by the webview.
+fn serve_page(
+    image_loader: Option<Arc<ImageLoader>>,
+    request: &Request<Vec<u8>>,
+) -> Response<Vec<u8>> {
+    let origin = request
+        .headers()
+        .get(header::ORIGIN)
+        .and_then(|value| value.to_str().ok());
+    if request.method() != Method::GET && request.method() != Method::HEAD {
+        return plain_response(StatusCode::METHOD_NOT_ALLOWED, origin)
+            .header(header::ALLOW, "GET, HEAD")
+            .body(Vec::new())
+            .unwrap_or_default();
+    }
+
+    let page = match PageRequest::parse(request.uri()) {
+        Ok(page) => page,
+        Err(e) => return error_response(&e, origin),
+    };
+    log::debug!("Serve {} in {} over {}", page.entry, page.book, PAGE_SCHEME);
+
+    let Some(image_loader) = image_loader.filter(|loader| loader.book_id() == page.book) else {
+        return error_response(
+            &Error::EntryNotFound(format!("{} is not the open book", page.book)),
+            origin,
+        );
+    };
+
+    // The tag is known without loading the page, so a revalidation costs no read.
+    let etag = image_loader.entity_tag(&page.entry, page.height);
+    let not_modified = request
+        .headers()
+        .get(header::IF_NONE_MATCH)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| {
+            value
+                .split(',')
+                .map(|tag| tag.trim().trim_start_matches("W/"))
+                .any(|tag| tag == "*" || tag == etag)
+        });
+    if not_modified {
+        return cacheable_response(StatusCode::NOT_MODIFIED, origin, &etag)
+            .body(Vec::new())
+            .unwrap_or_default();
+    }
+
+    let image = match page.height {
+        Some(height) => image_loader.get_scaled_image(&page.entry, height),
+        None => image_loader.get_image(&page.entry),
+    };
+    match image {
+        Ok(image) => image_response(request, origin, &etag, &image),
+        Err(e) => {
+            log::error!("Failed to serve {}: {}", page.entry, e);
+            error_response(&e, origin)
+        }
+    }
+}
+
+/// Builds the response carrying `image`, honoring range requests.
+fn image_response(
+    request: &Request<Vec<u8>>,
+    origin: Option<&str>,
+    etag: &str,
+    image: &Image,
+) -> Response<Vec<u8>> {
+    let len = image.data.len();
+    let builder = cacheable_response(StatusCode::OK, origin, etag)
+        .header(header::ACCEPT_RANGES, "bytes")
+        .header(WIDTH_HEADER, image.width)
+        .header(HEIGHT_HEADER, image.height);
+
+    let headers = request.headers();
+    let range = headers
+        .get(header::RANGE)
+        .and_then(|value| value.to_str().ok())
+        .map_or(ByteRange::Full, |value| ByteRange::parse(value, len));
+    let (builder, range) = match range {
+        ByteRange::Full => (builder, 0..len),
+        ByteRange::Partial(range) => (
+            builder.status(StatusCode::PARTIAL_CONTENT).header(
+                header::CONTENT_RANGE,
+                format!("bytes {}-{}/{len}", range.start, range.end - 1),
+            ),
+            range,
+        ),
+        ByteRange::Unsatisfiable => {
+            return builder
+                .status(StatusCode::RANGE_NOT_SATISFIABLE)
+                .header(header::CONTENT_RANGE, format!("bytes */{len}"))
+                .body(Vec::new())
+                .unwrap_or_default();
+        }
+    };
+
+    let content_type = image::guess_format(&image.data)
+        .map_or("application/octet-stream", |format| format.to_mime_type());
+    let body = if request.method() == Method::HEAD {
+        Vec::new()
+    } else {
+        image.data[range.clone()].to_vec()
+    };
+    builder
+        .header(header::CONTENT_TYPE, content_type)
+        .header(header::CONTENT_LENGTH, range.len())
+        .body(body)
+        .unwrap_or_default()
+}
+
+/// Builds the response of a failed request, carrying the serialized `Error` like the
+/// IPC commands do.
+fn error_response(error: &Error, origin: Option<&str>) -> Response<Vec<u8>> {
+    let status = match error {
+        Error::EntryNotFound(_) => StatusCode::NOT_FOUND,
+        Error::InvalidArgument(_) => StatusCode::BAD_REQUEST,
+        _ => StatusCode::INTERNAL_SERVER_ERROR,
+    };
+    plain_response(status, origin)
+        .header(header::CONTENT_TYPE, "application/json")
+        .body(serde_json::to_vec(error).unwrap_or_default())
+        .unwrap_or_default()
+}
+
+/// Starts a response carrying a page, tagged with `etag`.
+fn cacheable_response(
+    status: StatusCode,
+    origin: Option<&str>,
+    etag: &str,
+) -> tauri::http::response::Builder {
+    plain_response(status, origin)
+        .header(header::ETAG, etag)
+        // Revalidate on every use: the tag changes with the rendering settings and the
+        // book file, and a revalidation needs no read of the page.
+        .header(header::CACHE_CONTROL, "no-cache")
+}
+
+/// Starts a response with the headers every response of the scheme carries.
+///
+/// The app's pages are served from another origin than the scheme, so the headers are
+/// opened up for `fetch` through CORS, to the app's own origins only.
+fn plain_response(status: StatusCode, origin: Option<&str>) -> tauri::http::response::Builder {
+    let builder = Response::builder()
+        .status(status)
+        .header(header::VARY, "Origin");
+    match origin.filter(|origin| ALLOWED_ORIGINS.contains(origin)) {
+        Some(origin) => builder
+            .header(header::ACCESS_CONTROL_ALLOW_ORIGIN, origin)
+            .header(
+                header::ACCESS_CONTROL_EXPOSE_HEADERS,
+                format!("ETag, Content-Range, {WIDTH_HEADER}, {HEIGHT_HEADER}"),
+            ),
+        None => builder,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        container::traits::MockContainer,
+        image::{encoder::EncodeOptions, resizer::ResizeFilter, strip::StripConfig},
+    };
+
+    fn loader_with_page() -> Arc<ImageLoader> {
+        let img = image::RgbImage::from_pixel(40, 100, image::Rgb([10, 20, 30]));
+        let mut src = Vec::new();
+        image::DynamicImage::ImageRgb8(img)
+            .write_to(&mut std::io::Cursor::new(&mut src), image::ImageFormat::Png)
+            .unwrap();
+
+        let mut mock_container = MockContainer::new();
+        mock_container
+            .expect_is_single_threaded()
+            .return_const(false);
+        mock_container.expect_is_directory().return_const(false);
+        mock_container
+            .expect_get_entries()
+            .return_const(vec!["dir/01.png".to_string()]);
+        mock_container
+            .expect_get_image()
+            .returning(move |entry| match entry {
+                "dir/01.png" => Ok(Arc::new(Image::new(src.clone()).unwrap())),
+                _ => Err(Error::EntryNotFound(entry.to_string())),
+            });
+
+        Arc::new(
+            ImageLoader::new(
+                "/books/a b.zip".to_string(),
+                Arc::new(mock_container),
+                0,
+                ResizeFilter::Bilinear,
+                StripConfig::default(),
+                EncodeOptions::default(),
+                mini_moka::sync::Cache::new(100),
+            )
+            .unwrap(),
+        )
+    }
+
+    fn get(uri: &str) -> tauri::http::request::Builder {
+        Request::builder().method(Method::GET).uri(uri)
+    }
+
+    #[rstest]
+    #[case("rookreader://page/%2Fbooks%2Fa%20b.zip/dir/01.png", None)]
+    #[case("rookreader://localhost/page/%2Fbooks%2Fa%20b.zip/dir%2F01.png", None)]
+    #[case(
+        "http://rookreader.localhost/page/%2Fbooks%2Fa%20b.zip/dir/01.png?v=1&h=800",
+        Some(800)
+    )]
+    fn test_page_request_parse(#[case] uri: &str, #[case] height: Option<u32>) {
+        let page = PageRequest::parse(&uri.parse().unwrap()).unwrap();
+        assert_eq!(
+            page,
+            PageRequest {
+                book: "/books/a b.zip".to_string(),
+                entry: "dir/01.png".to_string(),
+                height,
+            }
+        );
+    }
+
+    #[rstest]
+    #[case("rookreader://page/only-book")]
+    #[case("rookreader://localhost/other/book/entry.png")]
+    #[case("rookreader://page/book/entry.png?h=tall")]
+    fn test_page_request_parse_malformed(#[case] uri: &str) {
+        assert!(matches!(
+            PageRequest::parse(&uri.parse().unwrap()),
+            Err(Error::InvalidArgument(_))
+        ));
+    }
+
+    #[rstest]
+    #[case("bytes=0-9", ByteRange::Partial(0..10))]
+    #[case("bytes=90-", ByteRange::Partial(90..100))]
+    #[case("bytes=95-200", ByteRange::Partial(95..100))]
+    #[case("bytes=-30", ByteRange::Partial(70..100))]
+    #[case("bytes=-300", ByteRange::Partial(0..100))]
+    #[case("bytes=100-", ByteRange::Unsatisfiable)]
+    #[case("bytes=-0", ByteRange::Unsatisfiable)]
+    #[case("bytes=9-0", ByteRange::Full)]
+    #[case("bytes=0-1,5-6", ByteRange::Full)]
+    #[case("items=0-9", ByteRange::Full)]
+    fn test_byte_range_parse(#[case] value: &str, #[case] expected: ByteRange) {
+        assert_eq!(ByteRange::parse(value, 100), expected);
+    }
+
+    #[test]
+    fn test_serve_page() {
+        let loader = loader_with_page();
+        let uri = "rookreader://page/%2Fbooks%2Fa%20b.zip/dir/01.png";
+
+        let response = serve_page(Some(loader.clone()), &get(uri).body(Vec::new()).unwrap());
+        assert_eq!(response.status(), StatusCode::OK);
+        let headers = response.headers();
+        assert_eq!(headers[header::CONTENT_TYPE], "image/png");
+        assert_eq!(headers[WIDTH_HEADER], "40");
+        assert_eq!(headers[HEIGHT_HEADER], "100");
+        assert_eq!(
+            headers[header::CONTENT_LENGTH],
+            response.body().len().to_string()
+        );
+        let etag = headers[header::ETAG].clone();
+
+        // Revalidation with the same tag skips the body.
+        let request = get(uri)
+            .header(header::IF_NONE_MATCH, etag)
+            .body(Vec::new())
+            .unwrap();
+        let response = serve_page(Some(loader.clone()), &request);
+        assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+        assert!(response.body().is_empty());
+
+        let request = get(uri)
+            .header(header::RANGE, "bytes=0-7")
+            .body(Vec::new())
+            .unwrap();
+        let response = serve_page(Some(loader.clone()), &request);
+        assert_eq!(response.status(), StatusCode::PARTIAL_CONTENT);
+        assert_eq!(response.body().as_slice(), b"\x89PNG\r\n\x1a\n");
+
+        let response = serve_page(
+            Some(loader),
+            &get(&format!("{uri}?h=50")).body(Vec::new()).unwrap(),
+        );
+        assert_eq!(response.headers()[HEIGHT_HEADER], "50");
+    }
+
+    #[test]
+    fn test_serve_page_etag_follows_rendition() {
+        let loader = loader_with_page();
+        let uri = "rookreader://page/%2Fbooks%2Fa%20b.zip/dir/01.png";
+
+        let etag = |uri: &str| {
+            serve_page(Some(loader.clone()), &get(uri).body(Vec::new()).unwrap()).headers()
+                [header::ETAG]
+                .clone()
+        };
+        assert_eq!(etag(uri), etag(uri));
+        assert_ne!(etag(uri), etag(&format!("{uri}?h=50")));
+        assert_eq!(etag(uri), loader.entity_tag("dir/01.png", None));
+    }
+
+    #[rstest]
+    #[case(Some("tauri://localhost"), Some("tauri://localhost"))]
+    #[case(Some("http://tauri.localhost"), Some("http://tauri.localhost"))]
+    #[case(Some("https://example.com"), None)]
+    #[case(None, None)]
+    fn test_serve_page_cors(#[case] origin: Option<&str>, #[case] allowed: Option<&str>) {
+        let mut request = get("rookreader://page/%2Fbooks%2Fa%20b.zip/dir/01.png");
+        if let Some(origin) = origin {
+            request = request.header(header::ORIGIN, origin);
+        }
+        let response = serve_page(Some(loader_with_page()), &request.body(Vec::new()).unwrap());
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response
+                .headers()
+                .get(header::ACCESS_CONTROL_ALLOW_ORIGIN)
+                .map(|value| value.to_str().unwrap()),
+            allowed
+        );
+    }
+
+    #[rstest]
+    #[case(
+        "rookreader://page/%2Fbooks%2Fother.zip/dir/01.png",
+        StatusCode::NOT_FOUND
+    )]
+    #[case(
+        "rookreader://page/%2Fbooks%2Fa%20b.zip/missing.png",
+        StatusCode::NOT_FOUND
+    )]
+    #[case("rookreader://page/%2Fbooks%2Fa%20b.zip", StatusCode::BAD_REQUEST)]
+    fn test_serve_page_errors(#[case] uri: &str, #[case] status: StatusCode) {
+        let response = serve_page(
+            Some(loader_with_page()),
+            &get(uri).body(Vec::new()).unwrap(),
+        );
+        assert_eq!(response.status(), status);
+        let error: serde_json::Value = serde_json::from_slice(response.body()).unwrap();
+        assert!(error["code"].is_number());
+    }
+}
@@ -0,0 +1,128 @@
+//! Normalization of text for search, so that differently written forms of the same
+//! Japanese or Latin text match each other.
+//!
+//! Both the indexed text and the query are normalized:
+//! - NFKC folds full-width ASCII (`ＶＯＬ１`) and half-width katakana (`ﾜﾝﾋﾟｰｽ`) to their
+//!   standard forms, composing voiced sound marks (`ﾋﾟ` becomes `ピ`),
+//! - case is folded,
+//! - katakana is folded to hiragana, so `ワンピース` matches `わんぴーす`,
+//! - small kana are folded to their full-size forms (`ぁ` to `あ`, `っ` to `つ`), and
+//! - the long vowel mark `ー` is dropped, so spellings with and without it match.
+
+use unicode_normalization::UnicodeNormalization;
+
+/// The offset between a katakana character and its hiragana counterpart.
+const KATAKANA_TO_HIRAGANA: u32 = 0x60;
+
+/// Normalizes text for search. See the module documentation for what is folded.
+///
+/// # Arguments
+///
+/// * `text` - The text to normalize.
+///
+/// # Returns
+///
+/// The normalized text.
+pub fn normalize(text: &str) -> String {
+    text.nfkc()
+        .flat_map(char::to_lowercase)
+        .filter_map(fold_kana)
+        .collect()
+}
+
+/// Returns the indices of the names that contain every whitespace-separated keyword of
+/// `query` (AND search), comparing the normalized forms of both.
+///
+/// # Arguments
+///
+/// * `query` - The user's input, with one or more keywords.
+/// * `names` - The names to match.
+///
+/// # Returns
+///
+/// The indices of the matching names in ascending order, or of every name if the query
+/// has no keywords.
+pub fn filter_names(query: &str, names: &[String]) -> Vec<u32> {
+    let query = normalize(query);
+    let keywords: Vec<&str> = query.split_whitespace().collect();
+    (0u32..)
+        .zip(names)
+        .filter(|(_, name)| {
+            let name = normalize(name);
+            keywords.iter().all(|keyword| name.contains(keyword))
+        })
+        .map(|(index, _)| index)
+        .collect()
+}
+
+/// Folds a katakana character to hiragana and a small kana to its full-size form, or
+/// drops the long vowel mark.
+fn fold_kana(c: char) -> Option<char> {
+    let c = match c {
+        'ー' => return None,
+        // ァ-ヶ map one to one onto ぁ-ゖ; ヽ and ヾ are the katakana iteration marks.
+        'ァ'..='ヶ' | 'ヽ' | 'ヾ' => {
+            char::from_u32(c as u32 - KATAKANA_TO_HIRAGANA).unwrap_or(c)
+        }
+        _ => c,
+    };
+    Some(match c {
+        'ぁ' => 'あ',
+        'ぃ' => 'い',
+        'ぅ' => 'う',
+        'ぇ' => 'え',
+        'ぉ' => 'お',
+        'っ' => 'つ',
+        'ゃ' => 'や',
+        'ゅ' => 'ゆ',
+        'ょ' => 'よ',
+        'ゎ' => 'わ',
+        'ゕ' => 'か',
+        'ゖ' => 'け',
+        _ => c,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("ワンピース", "わんぴす")]
+    #[case("ﾜﾝﾋﾟｰｽ", "わんぴす")]
+    #[case("わんぴーす", "わんぴす")]
+    #[case("ＶＯＬ１", "vol1")]
+    #[case("Vol.1", "vol.1")]
+    #[case("キャラクター", "きやらくた")]
+    #[case("ヴァイオレット", "ゔあいおれつと")]
+    #[case("いすゞ", "いすゞ")]
+    #[case("進撃の巨人　第１巻", "進撃の巨人 第1巻")]
+    #[case("ＳＰＹ×ＦＡＭＩＬＹ", "spy×family")]
+    fn test_normalize(#[case] text: &str, #[case] expected: &str) {
+        assert_eq!(normalize(text), expected);
+    }
+
+    #[test]
+    fn test_normalized_forms_match() {
+        assert_eq!(normalize("ﾜﾝﾋﾟｰｽ"), normalize("ワンピース"));
+        assert_eq!(normalize("わんぴーす"), normalize("ワンピース"));
+        assert_eq!(normalize("ＶＯＬ１"), normalize("vol1"));
+    }
+
+    #[test]
+    fn test_filter_names_matches_every_keyword() {
+        let names = [
+            "Test Dir".to_string(),
+            "test_file".to_string(),
+            "ﾜﾝﾋﾟｰｽ ＶＯＬ１".to_string(),
+            "another test file".to_string(),
+        ];
+        assert_eq!(filter_names("test file", &names), vec![1, 3]);
+        assert_eq!(filter_names("TEST", &names), vec![0, 1, 3]);
+        assert_eq!(filter_names("わんぴーす　vol1", &names), vec![2]);
+        assert_eq!(filter_names("test nothing", &names), Vec::<u32>::new());
+        assert_eq!(filter_names("  ", &names), vec![0, 1, 2, 3]);
+    }
+}
@@ -4,7 +4,9 @@ use std::path::PathBuf;
 use garde::Validate;
 use serde::{Deserialize, Serialize};
 
-use super::validation::finite_f64;
+use super::validation::{finite_f64, valid_exclusion_rules};
+use crate::container::entry_filter::{default_exclusion_rules, ExclusionRule};
+use crate::container::entry_order::EntrySortMode;
 
 /// General application settings.
 #[derive(Debug, Clone, Serialize, Deserialize, Validate, specta::Type)]
@@ -122,7 +124,7 @@ pub struct FileNavigatorSettings {
 }
 
 /// Settings for the reading experience.
-#[derive(Debug, Clone, Serialize, Deserialize, Default, Validate, specta::Type)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, specta::Type)]
 #[serde(rename_all = "camelCase", default)]
 pub struct ReaderSettings {
     /// Configuration specific to reading comics/manga (images).
@@ -137,6 +139,28 @@ pub struct ReaderSettings {
     /// Behavior when paging past the last/first page of a book (auto-open adjacent book).
     #[garde(skip)]
     pub auto_open_adjacent_book: AutoOpenAdjacentBookMode,
+    /// Rules hiding junk entries (e.g. `__MACOSX` folders, dotfiles) from every book.
+    /// Books can add rules of their own.
+    #[garde(custom(valid_exclusion_rules))]
+    #[serde(default = "default_exclusion_rules")]
+    pub exclusion_rules: Vec<ExclusionRule>,
+    /// How the pages of a book are ordered. Books can override it with a manual page
+    /// order.
+    #[garde(skip)]
+    pub entry_sort_mode: EntrySortMode,
+}
+
+impl Default for ReaderSettings {
+    fn default() -> Self {
+        Self {
+            comic: ComicSettings::default(),
+            novel: NovelSettings::default(),
+            rendering: RenderingSettings::default(),
+            auto_open_adjacent_book: AutoOpenAdjacentBookMode::default(),
+            exclusion_rules: default_exclusion_rules(),
+            entry_sort_mode: EntrySortMode::default(),
+        }
+    }
 }
 
 /// Configuration specific to reading comics (image-based content).
@@ -160,6 +184,9 @@ pub struct ComicSettings {
     /// Configuration for image caching and preloading.
     #[garde(dive)]
     pub cache: ComicCacheSettings,
+    /// Configuration for the long-strip (webtoon) reading mode.
+    #[garde(dive)]
+    pub long_strip: LongStripSettings,
 }
 
 impl Default for ComicSettings {
@@ -170,6 +197,7 @@ impl Default for ComicSettings {
             show_cover_as_single_page: default_true(),
             loupe: LoupeSettings::default(),
             cache: ComicCacheSettings::default(),
+            long_strip: LongStripSettings::default(),
         }
     }
 }
@@ -186,6 +214,14 @@ pub struct ComicCacheSettings {
     #[garde(range(min = 1, max = 65536))]
     #[serde(default = "default_image_cache_size_mib")]
     pub image_cache_size_mib: u64,
+    /// Whether to shrink the image cache when available system memory runs low.
+    /// Only supported on Linux, where available memory is read from `/proc/meminfo`.
+    #[garde(skip)]
+    pub shrink_on_memory_pressure: bool,
+    /// The available system memory in MiB below which the image cache is shrunk.
+    #[garde(range(min = 64, max = 65536))]
+    #[serde(default = "default_memory_pressure_threshold_mib")]
+    pub memory_pressure_threshold_mib: u64,
 }
 
 impl Default for ComicCacheSettings {
@@ -193,6 +229,8 @@ impl Default for ComicCacheSettings {
         Self {
             preload_page_count: default_preload_page_count(),
             image_cache_size_mib: default_image_cache_size_mib(),
+            shrink_on_memory_pressure: false,
+            memory_pressure_threshold_mib: default_memory_pressure_threshold_mib(),
         }
     }
 }
@@ -205,6 +243,49 @@ fn default_image_cache_size_mib() -> u64 {
     1024
 }
 
+fn default_memory_pressure_threshold_mib() -> u64 {
+    512
+}
+
+/// Configuration for the long-strip (webtoon) reading mode.
+///
+/// In this mode pages are scrolled as one continuous vertical strip; pages taller than
+/// `segment_height` are sliced by the backend so the webview never holds one huge image.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, specta::Type)]
+#[serde(rename_all = "camelCase", default)]
+pub struct LongStripSettings {
+    /// Whether comics are read as a continuous vertical strip.
+    #[garde(skip)]
+    pub enabled: bool,
+    /// The height in pixels of the segments tall pages are sliced into.
+    #[garde(range(min = 256, max = 16384))]
+    #[serde(default = "default_long_strip_segment_height")]
+    pub segment_height: i32,
+    /// The height in pixels repeated between consecutive segments to hide seams.
+    /// Capped below the minimum segment height so slicing always advances.
+    #[garde(range(min = 0, max = 255))]
+    #[serde(default = "default_long_strip_overlap")]
+    pub overlap: i32,
+}
+
+impl Default for LongStripSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            segment_height: default_long_strip_segment_height(),
+            overlap: default_long_strip_overlap(),
+        }
+    }
+}
+
+fn default_long_strip_segment_height() -> i32 {
+    2048
+}
+
+fn default_long_strip_overlap() -> i32 {
+    32
+}
+
 /// Configuration for the Loupe (Magnifier) feature.
 #[derive(Debug, Clone, Serialize, Deserialize, Validate, specta::Type)]
 #[serde(rename_all = "camelCase", default)]
@@ -290,10 +371,25 @@ pub struct RenderingSettings {
     /// The algorithm used for resampling (resizing) images.
     #[garde(skip)]
     pub image_resampling_method: ImageResamplingMethod,
+    /// The format resized images are re-encoded to. Images that need no resize are
+    /// always served as stored.
+    #[garde(skip)]
+    pub output_format: ImageOutputFormat,
+    /// The JPEG quality of re-encoded images.
+    #[garde(range(min = 1, max = 100))]
+    #[serde(default = "default_output_quality")]
+    pub output_quality: i32,
+    /// The chroma subsampling of re-encoded JPEG images.
+    #[garde(skip)]
+    pub chroma_subsampling: ChromaSubsampling,
     /// The vertical resolution used when rasterizing PDF pages to images.
     #[garde(range(min = 1, max = 20000))]
     #[serde(default = "default_pdf_render_resolution_height")]
     pub pdf_render_resolution_height: i32,
+    /// Pages taking longer than this many milliseconds to load are logged with a
+    /// timing breakdown (`0` = never).
+    #[garde(range(min = 0, max = 60000))]
+    pub slow_page_log_threshold_ms: i32,
 }
 
 impl Default for RenderingSettings {
@@ -302,11 +398,19 @@ impl Default for RenderingSettings {
             enable_thumbnail_preview: default_true(),
             max_image_height: i32::default(),
             image_resampling_method: ImageResamplingMethod::default(),
+            output_format: ImageOutputFormat::default(),
+            output_quality: default_output_quality(),
+            chroma_subsampling: ChromaSubsampling::default(),
             pdf_render_resolution_height: default_pdf_render_resolution_height(),
+            slow_page_log_threshold_ms: i32::default(),
         }
     }
 }
 
+fn default_output_quality() -> i32 {
+    80
+}
+
 fn default_pdf_render_resolution_height() -> i32 {
     2000
 }
@@ -448,6 +552,34 @@ pub enum ImageResamplingMethod {
     Lanczos3,
 }
 
+/// Represents the format resized images are re-encoded to.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, specta::Type)]
+#[serde(rename_all = "camelCase")]
+pub enum ImageOutputFormat {
+    /// JPEG (PNG for images with transparency)
+    #[default]
+    Jpeg,
+    /// Lossless WebP
+    WebpLossless,
+    /// PNG
+    Png,
+    /// The format of the original image
+    Passthrough,
+}
+
+/// Represents the chroma subsampling of re-encoded JPEG images.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, specta::Type)]
+#[serde(rename_all = "camelCase")]
+pub enum ChromaSubsampling {
+    /// 4:4:4 (full-resolution color)
+    Yuv444,
+    /// 4:2:2 (color halved horizontally)
+    Yuv422,
+    /// 4:2:0 (color halved in both directions)
+    #[default]
+    Yuv420,
+}
+
 /// A helper function to provide a default `true` value for Serde deserialization.
 ///
 /// This function is used in conjunction with the `#[serde(default = "...")]`
@@ -491,6 +623,28 @@ mod tests {
         assert_eq!(s.validate().is_ok(), valid, "preload_page_count={value}");
     }
 
+    #[rstest]
+    #[case(256, true)]
+    #[case(16384, true)]
+    #[case(255, false)]
+    #[case(16385, false)]
+    fn test_long_strip_segment_height_bounds(#[case] value: i32, #[case] valid: bool) {
+        let mut s = AppSettings::default();
+        s.reader.comic.long_strip.segment_height = value;
+        assert_eq!(s.validate().is_ok(), valid, "segment_height={value}");
+    }
+
+    #[rstest]
+    #[case(0, true)]
+    #[case(255, true)]
+    #[case(-1, false)]
+    #[case(256, false)]
+    fn test_long_strip_overlap_bounds(#[case] value: i32, #[case] valid: bool) {
+        let mut s = AppSettings::default();
+        s.reader.comic.long_strip.overlap = value;
+        assert_eq!(s.validate().is_ok(), valid, "overlap={value}");
+    }
+
     #[rstest]
     #[case(1, true)]
     #[case(65536, true)]
@@ -502,6 +656,58 @@ mod tests {
         assert_eq!(s.validate().is_ok(), valid, "image_cache_size_mib={value}");
     }
 
+    #[rstest]
+    #[case(64, true)]
+    #[case(65536, true)]
+    #[case(63, false)]
+    #[case(65537, false)]
+    fn test_memory_pressure_threshold_mib_bounds(#[case] value: u64, #[case] valid: bool) {
+        let mut s = AppSettings::default();
+        s.reader.comic.cache.memory_pressure_threshold_mib = value;
+        assert_eq!(
+            s.validate().is_ok(),
+            valid,
+            "memory_pressure_threshold_mib={value}"
+        );
+    }
+
+    #[test]
+    fn test_exclusion_rules_must_compile() {
+        use crate::container::entry_filter::{
+            default_exclusion_rules, ExclusionRule, PatternSyntax,
+        };
+
+        let mut s = AppSettings::default();
+        assert_eq!(s.reader.exclusion_rules, default_exclusion_rules());
+
+        s.reader.exclusion_rules.push(ExclusionRule {
+            pattern: "(unclosed".to_string(),
+            syntax: PatternSyntax::Regex,
+        });
+        assert!(s.validate().is_err());
+
+        s.reader.exclusion_rules = vec![ExclusionRule::glob("")];
+        assert!(s.validate().is_err());
+
+        s.reader.exclusion_rules = Vec::new();
+        assert!(s.validate().is_ok());
+    }
+
+    #[rstest]
+    #[case(0, true)]
+    #[case(60000, true)]
+    #[case(-1, false)]
+    #[case(60001, false)]
+    fn test_slow_page_log_threshold_ms_bounds(#[case] value: i32, #[case] valid: bool) {
+        let mut s = AppSettings::default();
+        s.reader.rendering.slow_page_log_threshold_ms = value;
+        assert_eq!(
+            s.validate().is_ok(),
+            valid,
+            "slow_page_log_threshold_ms={value}"
+        );
+    }
+
     #[rstest]
     #[case(0, true)]
     #[case(65535, true)]
@@ -513,6 +719,17 @@ mod tests {
         assert_eq!(s.validate().is_ok(), valid, "max_image_height={value}");
     }
 
+    #[rstest]
+    #[case(1, true)]
+    #[case(100, true)]
+    #[case(0, false)]
+    #[case(101, false)]
+    fn test_output_quality_bounds(#[case] value: i32, #[case] valid: bool) {
+        let mut s = AppSettings::default();
+        s.reader.rendering.output_quality = value;
+        assert_eq!(s.validate().is_ok(), valid, "output_quality={value}");
+    }
+
     #[rstest]
     #[case(1, true)]
     #[case(20000, true)]
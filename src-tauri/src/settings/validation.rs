@@ -1,6 +1,7 @@
 use serde_json::Value;
 
 use super::{SettingsValidationViolation, ViolationKind};
+use crate::container::entry_filter::ExclusionRule;
 
 /// The authoritative numeric bound for one settings leaf, used to produce structured
 /// validation violations.
@@ -40,6 +41,24 @@ const FIELD_BOUNDS: &[FieldBound] = &[
         min: 1.0,
         max: 65536.0,
     },
+    FieldBound {
+        path: "reader.comic.cache.memoryPressureThresholdMib",
+        integer: true,
+        min: 64.0,
+        max: 65536.0,
+    },
+    FieldBound {
+        path: "reader.comic.longStrip.segmentHeight",
+        integer: true,
+        min: 256.0,
+        max: 16384.0,
+    },
+    FieldBound {
+        path: "reader.comic.longStrip.overlap",
+        integer: true,
+        min: 0.0,
+        max: 255.0,
+    },
     FieldBound {
         path: "reader.comic.loupe.zoom",
         integer: false,
@@ -64,12 +83,24 @@ const FIELD_BOUNDS: &[FieldBound] = &[
         min: 0.0,
         max: 65535.0,
     },
+    FieldBound {
+        path: "reader.rendering.outputQuality",
+        integer: true,
+        min: 1.0,
+        max: 100.0,
+    },
     FieldBound {
         path: "reader.rendering.pdfRenderResolutionHeight",
         integer: true,
         min: 1.0,
         max: 20000.0,
     },
+    FieldBound {
+        path: "reader.rendering.slowPageLogThresholdMs",
+        integer: true,
+        min: 0.0,
+        max: 60000.0,
+    },
     FieldBound {
         path: "layout.sidePane.tabIndex",
         integer: true,
@@ -132,6 +163,16 @@ pub(super) fn finite_f64(value: &f64, _ctx: &()) -> garde::Result {
     }
 }
 
+/// `garde` custom validator rejecting exclusion rules that do not compile (an empty
+/// pattern or an invalid regular expression).
+#[allow(clippy::ptr_arg)] // garde passes the field itself, a `&Vec`.
+pub(super) fn valid_exclusion_rules(rules: &Vec<ExclusionRule>, _ctx: &()) -> garde::Result {
+    match rules.iter().find_map(|rule| rule.compile().err()) {
+        Some(e) => Err(garde::Error::new(e.to_string())),
+        None => Ok(()),
+    }
+}
+
 /// Converts a snake_case segment to camelCase.
 ///
 /// `garde` reports Rust field names (snake_case) while the serialized document uses
@@ -5,25 +5,35 @@ use sqlx::{
     sqlite::{SqliteConnectOptions, SqlitePoolOptions},
     SqlitePool,
 };
-use std::{fs, path::PathBuf, str::FromStr, sync::Arc};
+use std::{fs, path::PathBuf, str::FromStr, sync::Arc, time::Duration};
 use tauri::{App, Manager, Runtime, Theme};
 use tauri_plugin_log::{RotationStrategy, Target, TargetKind};
 use tokio::sync::RwLock;
 
 use crate::{
     domain::{
-        book::repository::BookRepository, bookshelf::repository::BookshelfRepository,
+        annotation::repository::AnnotationRepository, book::repository::BookRepository,
+        bookmark::repository::BookmarkRepository, bookshelf::repository::BookshelfRepository,
+        creator::repository::CreatorRepository, library::repository::LibraryRepository,
+        reading_session::repository::ReadingSessionRepository,
         series::repository::SeriesRepository, tag::repository::TagRepository,
     },
     error::{self, Error},
+    image::{encoder::EncodeOptions, metrics},
     infrastructure::database::{
-        book_repository::SqliteBookRepository, bookshelf_repository::SqliteBookshelfRepository,
+        annotation_repository::SqliteAnnotationRepository, book_repository::SqliteBookRepository,
+        bookmark_repository::SqliteBookmarkRepository,
+        bookshelf_repository::SqliteBookshelfRepository,
+        creator_repository::SqliteCreatorRepository, library_repository::SqliteLibraryRepository,
+        reading_session_repository::SqliteReadingSessionRepository, search_index,
         series_repository::SqliteSeriesRepository, tag_repository::SqliteTagRepository,
+        with_collations,
     },
+    library::{self, watcher::LibraryWatcher},
     settings::{
         AppSettings, AppTheme, LogLevel, LogSettings, SettingsFileProvider, SettingsStoreProvider,
     },
-    state::app_state::AppState,
+    state::{app_state::AppState, memory_pressure},
 };
 
 /// Reads a non-empty `ROOKREADER_DATA_DIR` override into a path, if present.
@@ -113,6 +123,9 @@ pub fn setup(app: &App) -> error::Result<()> {
     setup_database(app)?;
 
     setup_container_settings(app, &settings)?;
+    memory_pressure::spawn_monitor(app.handle().clone());
+    // Scanning needs the container settings; it runs in the background.
+    library::spawn_startup_scan(app.handle().clone());
 
     // Provide the settings file provider as managed state so `get_settings` /
     // `set_settings` reuse this single instance instead of reconstructing one (and
@@ -149,40 +162,65 @@ pub fn setup_container_settings(app: &App, settings: &AppSettings) -> error::Res
 
 /// Applies the reader/rendering settings to the container runtime state.
 ///
-/// This copies the persisted reader/rendering values into `ContainerState::settings`.
-/// Only the **image cache capacity** is applied to the currently-open container live:
-/// when it changes, the cache is rebuilt (which evicts every cached image) and handed to
-/// the open `ImageLoader`.
+/// This copies the persisted reader/rendering values into `ContainerSettings`. When the
+/// **image cache capacity** changes, the cache is rebuilt (which evicts every cached
+/// image) and handed to the open `ImageLoader`.
 ///
-/// The other values (`max_image_height`, `image_resampling_method`,
-/// `pdf_render_resolution_height`, `enable_preview`) are stored for the **next**
-/// `ContainerState::open_container` call: the already-open `ImageLoader` captured its
-/// resize height/method at construction, so changing them does not re-render the book
-/// currently on screen — it takes effect when a container is next opened.
+/// The open `ImageLoader` captured its resize height/method, encoding, PDF resolution
+/// and long-strip slicing at construction, so changing those is reported through the
+/// return value: the caller then rebuilds the open book with
+/// `container_commands::reload_open_container`, which needs I/O and is therefore not
+/// done here under the state lock. `enable_preview` is read per request and the slow
+/// page log threshold is global, so both apply immediately. The exclusion rules and the
+/// sort mode apply from the next book opened.
 ///
 /// # Arguments
 ///
 /// * `state` - The mutable application state to update.
 /// * `settings` - The settings whose reader values should be applied.
-pub fn apply_reader_settings_to_container(state: &mut AppState, settings: &AppSettings) {
+///
+/// # Returns
+///
+/// `true` if a book is open and its rendering parameters changed.
+pub fn apply_reader_settings_to_container(state: &mut AppState, settings: &AppSettings) -> bool {
     let new_cache_size_mib = settings.reader.comic.cache.image_cache_size_mib;
     let container_settings = &mut state.container_state.settings;
-    // Capture the previous capacity before overwriting it.
-    let cache_size_changed = container_settings.image_cache_size_mib != new_cache_size_mib;
+    // Capture the previous values before overwriting them.
+    let previous = container_settings.clone();
+    let cache_size_changed = previous.image_cache_size_mib != new_cache_size_mib;
 
     container_settings.enable_preview = settings.reader.rendering.enable_thumbnail_preview;
     container_settings.max_image_height = settings.reader.rendering.max_image_height;
     container_settings.image_cache_size_mib = new_cache_size_mib;
+    container_settings.shrink_on_memory_pressure =
+        settings.reader.comic.cache.shrink_on_memory_pressure;
+    container_settings.memory_pressure_threshold_mib =
+        settings.reader.comic.cache.memory_pressure_threshold_mib;
     container_settings.pdf_render_resolution_height =
         settings.reader.rendering.pdf_render_resolution_height;
     container_settings.image_resampling_method =
         settings.reader.rendering.image_resampling_method.into();
+    container_settings.encode_options = EncodeOptions {
+        format: settings.reader.rendering.output_format.into(),
+        quality: settings.reader.rendering.output_quality as u8,
+        subsampling: settings.reader.rendering.chroma_subsampling.into(),
+    };
+    container_settings.strip_segment_height = settings.reader.comic.long_strip.segment_height;
+    container_settings.strip_overlap = settings.reader.comic.long_strip.overlap;
+    container_settings.listing.exclusion_rules = settings.reader.exclusion_rules.clone();
+    container_settings.listing.sort_mode = settings.reader.entry_sort_mode;
+    metrics::set_slow_page_threshold(Duration::from_millis(
+        settings.reader.rendering.slow_page_log_threshold_ms as u64,
+    ));
+    let rendering_changed = previous.renders_differently(container_settings);
 
     if cache_size_changed {
         state
             .container_state
             .update_image_cache_size(new_cache_size_mib);
     }
+
+    rendering_changed && state.container_state.image_loader.is_some()
 }
 
 /// Initializes the application's logging system using `tauri-plugin-log`.
@@ -267,27 +305,45 @@ fn setup_database(app: &App) -> error::Result<()> {
     };
     let db_path = app_data_dir_path.join(db_filename);
     let db_url = format!("sqlite:{}", db_path.display());
-    let options = SqliteConnectOptions::from_str(&db_url)?.create_if_missing(true);
+    let options = with_collations(SqliteConnectOptions::from_str(&db_url)?.create_if_missing(true));
     log::debug!("Database file path: {:?}", options.get_filename());
 
     let pool = tauri::async_runtime::block_on(async {
         let pool = SqlitePoolOptions::new().connect_with(options).await?;
         migrate!("./migrations").run(&pool).await?;
+        // Index the books a migration marked out of date before the first search.
+        search_index::refresh(&pool).await?;
         Ok::<SqlitePool, Error>(pool)
     })?;
 
     let book_repository: Arc<dyn BookRepository> =
         Arc::new(SqliteBookRepository::new(pool.clone()));
+    let bookmark_repository: Arc<dyn BookmarkRepository> =
+        Arc::new(SqliteBookmarkRepository::new(pool.clone()));
     let bookshelf_repository: Arc<dyn BookshelfRepository> =
         Arc::new(SqliteBookshelfRepository::new(pool.clone()));
     let tag_repository: Arc<dyn TagRepository> = Arc::new(SqliteTagRepository::new(pool.clone()));
     let series_repository: Arc<dyn SeriesRepository> =
         Arc::new(SqliteSeriesRepository::new(pool.clone()));
+    let creator_repository: Arc<dyn CreatorRepository> =
+        Arc::new(SqliteCreatorRepository::new(pool.clone()));
+    let library_repository: Arc<dyn LibraryRepository> =
+        Arc::new(SqliteLibraryRepository::new(pool.clone()));
+    let reading_session_repository: Arc<dyn ReadingSessionRepository> =
+        Arc::new(SqliteReadingSessionRepository::new(pool.clone()));
+    let annotation_repository: Arc<dyn AnnotationRepository> =
+        Arc::new(SqliteAnnotationRepository::new(pool.clone()));
 
     app.manage(book_repository);
+    app.manage(bookmark_repository);
     app.manage(bookshelf_repository);
     app.manage(tag_repository);
     app.manage(series_repository);
+    app.manage(creator_repository);
+    app.manage(library_repository);
+    app.manage(reading_session_repository);
+    app.manage(annotation_repository);
+    app.manage(LibraryWatcher::default());
 
     Ok(())
 }
@@ -302,8 +358,11 @@ fn get_libs_dir(app: &App) -> error::Result<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::image::resizer::ResizeFilter;
-    use crate::settings::ImageResamplingMethod;
+    use crate::image::{
+        encoder::{OutputFormat, Subsampling},
+        resizer::ResizeFilter,
+    };
+    use crate::settings::{ChromaSubsampling, ImageOutputFormat, ImageResamplingMethod};
 
     #[test]
     fn test_apply_reader_settings_to_container() {
@@ -314,8 +373,16 @@ mod tests {
         settings.reader.rendering.pdf_render_resolution_height = 1500;
         settings.reader.rendering.image_resampling_method = ImageResamplingMethod::Lanczos3;
         settings.reader.comic.cache.image_cache_size_mib = 2048;
+        settings.reader.comic.cache.shrink_on_memory_pressure = true;
+        settings.reader.comic.cache.memory_pressure_threshold_mib = 256;
+        settings.reader.comic.long_strip.segment_height = 4096;
+        settings.reader.comic.long_strip.overlap = 64;
+        settings.reader.rendering.output_format = ImageOutputFormat::WebpLossless;
+        settings.reader.rendering.output_quality = 95;
+        settings.reader.rendering.chroma_subsampling = ChromaSubsampling::Yuv420;
 
-        apply_reader_settings_to_container(&mut state, &settings);
+        // No book is open, so there is nothing to rebuild.
+        assert!(!apply_reader_settings_to_container(&mut state, &settings));
 
         let container_settings = &state.container_state.settings;
         assert!(!container_settings.enable_preview);
@@ -326,6 +393,18 @@ mod tests {
             ResizeFilter::Lanczos3
         );
         assert_eq!(container_settings.image_cache_size_mib, 2048);
+        assert!(container_settings.shrink_on_memory_pressure);
+        assert_eq!(container_settings.memory_pressure_threshold_mib, 256);
+        assert_eq!(container_settings.strip_segment_height, 4096);
+        assert_eq!(container_settings.strip_overlap, 64);
+        assert_eq!(
+            container_settings.encode_options,
+            EncodeOptions {
+                format: OutputFormat::WebpLossless,
+                quality: 95,
+                subsampling: Subsampling::Yuv420,
+            }
+        );
     }
 
     #[cfg(any(debug_assertions, feature = "e2e-test"))]
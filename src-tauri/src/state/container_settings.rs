@@ -1,4 +1,45 @@
-use crate::image::resizer::ResizeFilter;
+use crate::{
+    container::{
+        entry_filter::{default_exclusion_rules, EntryFilter, ExclusionRule},
+        entry_order::EntrySortMode,
+        factory::ContainerConfig,
+        listing::ListingOptions,
+    },
+    image::{encoder::EncodeOptions, resizer::ResizeFilter},
+};
+
+/// The settings deciding which entries of a book are pages, and in which order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListingSettings {
+    /// The rules hiding junk entries: the global rules, followed by the book's own rules
+    /// once a book is opened.
+    pub exclusion_rules: Vec<ExclusionRule>,
+    /// How the pages are sorted.
+    pub sort_mode: EntrySortMode,
+    /// The manual page order of the book being opened. Empty in the global settings.
+    pub page_order: Vec<String>,
+}
+
+impl Default for ListingSettings {
+    fn default() -> Self {
+        Self {
+            exclusion_rules: default_exclusion_rules(),
+            sort_mode: EntrySortMode::default(),
+            page_order: Vec::new(),
+        }
+    }
+}
+
+impl ListingSettings {
+    /// Compiles these settings into the options containers list their entries with.
+    pub fn options(&self) -> ListingOptions {
+        ListingOptions {
+            filter: EntryFilter::new(&self.exclusion_rules),
+            sort_mode: self.sort_mode,
+            page_order: self.page_order.clone(),
+        }
+    }
+}
 
 /// Represents settings for handling content within containers.
 ///
@@ -13,13 +54,26 @@ pub struct ContainerSettings {
     pub max_image_height: i32,
     /// The algorithm to use when resampling images (e.g., `ResizeFilter::Bilinear`).
     pub image_resampling_method: ResizeFilter,
+    /// How resized pages, tiles and strip segments are re-encoded.
+    pub encode_options: EncodeOptions,
     /// The target height in pixels when rendering a page from a PDF document as an image.
     pub pdf_render_resolution_height: i32,
     /// The maximum size of the image memory cache in MiB.
     pub image_cache_size_mib: u64,
+    /// If `true`, the image cache is shrunk when available system memory drops below
+    /// `memory_pressure_threshold_mib`.
+    pub shrink_on_memory_pressure: bool,
+    /// The available system memory in MiB below which the image cache is shrunk.
+    pub memory_pressure_threshold_mib: u64,
+    /// The height in pixels of the segments tall pages are sliced into in long-strip mode.
+    pub strip_segment_height: i32,
+    /// The height in pixels repeated between consecutive long-strip segments.
+    pub strip_overlap: i32,
     /// An optional path to the PDFium library, required for rendering PDF files.
     /// If `None`, the application may not be able to open PDF files.
     pub pdfium_library_path: Option<String>,
+    /// Which entries are pages, and in which order.
+    pub listing: ListingSettings,
 }
 
 impl Default for ContainerSettings {
@@ -28,9 +82,42 @@ impl Default for ContainerSettings {
             enable_preview: true,
             max_image_height: 0,
             image_resampling_method: ResizeFilter::Bilinear,
+            encode_options: EncodeOptions::default(),
             pdf_render_resolution_height: 2000,
             image_cache_size_mib: 1024,
+            shrink_on_memory_pressure: false,
+            memory_pressure_threshold_mib: 512,
+            strip_segment_height: 2048,
+            strip_overlap: 32,
             pdfium_library_path: None,
+            listing: ListingSettings::default(),
+        }
+    }
+}
+
+impl ContainerSettings {
+    /// Returns the configuration containers are created with under these settings.
+    pub fn container_config(&self) -> ContainerConfig {
+        ContainerConfig {
+            pdf_render_height: Some(self.pdf_render_resolution_height),
+            encoding: self.encode_options,
+            pdfium_library_path: self.pdfium_library_path.clone(),
+            listing: self.listing.options(),
         }
     }
+
+    /// Returns whether an image loader built with `other` renders pages differently from
+    /// one built with these settings, i.e. whether an open book must be rebuilt.
+    ///
+    /// The preview flag, cache capacity and PDFium path do not affect how a page is
+    /// rendered and are ignored. Neither are the listing settings: they change which
+    /// pages a book has and their order, so they apply the next time a book is opened.
+    pub fn renders_differently(&self, other: &ContainerSettings) -> bool {
+        self.max_image_height != other.max_image_height
+            || self.image_resampling_method != other.image_resampling_method
+            || self.encode_options != other.encode_options
+            || self.pdf_render_resolution_height != other.pdf_render_resolution_height
+            || self.strip_segment_height != other.strip_segment_height
+            || self.strip_overlap != other.strip_overlap
+    }
 }
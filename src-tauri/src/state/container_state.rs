@@ -1,15 +1,14 @@
-use std::sync::Arc;
-
-use pdfium_render::prelude::PdfRenderConfig;
+use std::{cmp::Reverse, collections::HashMap, sync::Arc};
 
 use crate::{
-    container::{
-        factory::{create_container, ContainerConfig},
-        traits::Container,
-    },
+    container::{factory::create_container, traits::Container},
     error::Result,
-    image::loader::{Cache, ImageLoader},
-    state::container_settings::ContainerSettings,
+    image::{
+        cache_stats::CacheStats,
+        loader::{Cache, CacheGeneration, CacheKey, ImageLoader},
+        strip::StripConfig,
+    },
+    state::container_settings::{ContainerSettings, ListingSettings},
 };
 
 /// Builds an image cache whose total weight is capped at `size_mib` mebibytes.
@@ -46,6 +45,14 @@ pub struct ContainerState {
     pub image_loader: Option<Arc<ImageLoader>>,
     /// Global image cache shared across all containers.
     pub image_cache: Cache,
+    /// The invalidation count of `image_cache`, advanced before entries are invalidated
+    /// so loads in flight do not put them back.
+    pub cache_generation: CacheGeneration,
+    /// How many times the image cache was shrunk because available memory ran low.
+    pub memory_pressure_shrinks: u64,
+    /// The listing settings (global and the book's own) the open book was listed with,
+    /// reused when it is rebuilt so its page list stays the same.
+    pub open_listing: ListingSettings,
 }
 
 impl Default for ContainerState {
@@ -58,6 +65,9 @@ impl Default for ContainerState {
             settings,
             image_loader: None,
             image_cache,
+            cache_generation: CacheGeneration::default(),
+            memory_pressure_shrinks: 0,
+            open_listing: ListingSettings::default(),
         }
     }
 }
@@ -77,10 +87,122 @@ impl ContainerState {
         }
     }
 
+    /// Evicts every cached rendition of one book, leaving other books' entries intact.
+    ///
+    /// # Arguments
+    ///
+    /// * `book_id` - The identifier (path) of the book whose entries are evicted.
+    pub fn invalidate_book_cache(&self, book_id: &str) {
+        let count = self.invalidate_cache_where(|key| key.book_id == book_id);
+        log::debug!("Invalidated {} cached images of {}", count, book_id);
+    }
+
+    /// Evicts the cached images of one book, or of every book if `book_id` is `None`.
+    ///
+    /// # Arguments
+    ///
+    /// * `book_id` - The identifier (path) of the book to purge, or `None` for all.
+    pub fn purge_image_cache(&self, book_id: Option<&str>) {
+        match book_id {
+            Some(book_id) => self.invalidate_book_cache(book_id),
+            None => {
+                let count = self.invalidate_cache_where(|_| true);
+                log::debug!("Invalidated all {} cached images", count);
+            }
+        }
+    }
+
+    /// Frees cache memory because the system is running low on it.
+    ///
+    /// The decoded zoom and long-strip sources of the open book are released, and the
+    /// cache is cut down to half its size, so memory pressure that lasts shrinks it
+    /// step by step instead of emptying it at once. Images of books other than the open
+    /// one go first, as they are the least likely to be requested again, then the pages
+    /// of the open book farthest from the current one.
+    pub fn shrink_image_cache(&mut self) {
+        let mut center = 0;
+        let mut open_book = None;
+        if let Some(loader) = &self.image_loader {
+            loader.release_decoded_sources();
+            center = loader.last_preload().map_or(0, |(center, _)| center);
+            open_book = Some(loader.book_id().to_string());
+        }
+        let positions: HashMap<&str, usize> = self
+            .container
+            .iter()
+            .flat_map(|container| container.get_entries().iter().enumerate())
+            .map(|(i, entry)| (entry.as_str(), i))
+            .collect();
+
+        let mut cached: Vec<(CacheKey, u64)> = self
+            .image_cache
+            .iter()
+            .map(|entry| (entry.key().clone(), entry.value().data.len() as u64))
+            .collect();
+        // Entries the open book does not list (e.g. strip segments) sort as farthest.
+        cached.sort_by_key(|(key, _)| {
+            let distance = positions
+                .get(key.entry.as_str())
+                .map_or(usize::MAX, |&i| i.abs_diff(center));
+            (Some(&key.book_id) == open_book.as_ref(), Reverse(distance))
+        });
+
+        let resident: u64 = cached.iter().map(|(_, bytes)| bytes).sum();
+        let target = resident / 2;
+        let mut remaining = resident;
+        let mut count = 0;
+        for (key, bytes) in &cached {
+            if remaining <= target {
+                break;
+            }
+            self.image_cache.invalidate(key);
+            remaining -= bytes;
+            count += 1;
+        }
+        log::warn!(
+            "Low on memory: dropped {count} cached images, keeping {remaining} of {resident} bytes"
+        );
+        self.memory_pressure_shrinks += 1;
+    }
+
+    /// Evicts the cached images whose key matches `predicate`, returning how many were
+    /// evicted.
+    ///
+    /// Keys are invalidated one by one rather than with `invalidate_all`, which only
+    /// hides entries lazily and would leave them in `CacheStats` until the next sweep.
+    /// The cache generation is advanced first, so loads in flight drop their results
+    /// instead of refilling the evicted entries.
+    fn invalidate_cache_where(&self, predicate: impl Fn(&CacheKey) -> bool) -> usize {
+        self.cache_generation.advance();
+        let keys: Vec<CacheKey> = self
+            .image_cache
+            .iter()
+            .filter(|entry| predicate(entry.key()))
+            .map(|entry| entry.key().clone())
+            .collect();
+        for key in &keys {
+            self.image_cache.invalidate(key);
+        }
+        keys.len()
+    }
+
+    /// Returns a snapshot of the image cache and of how the open book uses it.
+    pub fn cache_stats(&self) -> CacheStats {
+        CacheStats::collect(
+            &self.image_cache,
+            self.settings.image_cache_size_mib * 1024 * 1024,
+            self.image_loader
+                .as_ref()
+                .map(|loader| loader.cache_usage()),
+            self.memory_pressure_shrinks,
+        )
+    }
+
     /// Clears any open container and its image loader.
     pub fn clear(&mut self) {
         self.container = None;
         self.image_loader = None;
+        self.open_listing = ListingSettings::default();
     }
 
     /// Builds the container and image loader from borrowed settings and a cache handle.
@@ -93,6 +215,7 @@ impl ContainerState {
     ///
     /// * `settings` - The container settings snapshot to build with.
     /// * `image_cache` - The shared image cache handle.
+    /// * `cache_generation` - The invalidation count of the shared image cache.
     /// * `path` - The file system path to the container to build.
     ///
     /// # Returns
@@ -106,15 +229,10 @@ impl ContainerState {
     pub fn build_with(
         settings: &ContainerSettings,
         image_cache: &Cache,
+        cache_generation: &CacheGeneration,
         path: &str,
     ) -> Result<(Arc<dyn Container>, ImageLoader)> {
-        let config = ContainerConfig {
-            pdf_render_config: PdfRenderConfig::default()
-                .set_target_height(settings.pdf_render_resolution_height),
-            pdfium_library_path: settings.pdfium_library_path.clone(),
-        };
-
-        let container = create_container(path, config)?;
+        let container = create_container(path, settings.container_config())?;
 
         // Containers that render at their own resolution (PDF) skip the generic resize,
         // asked of the container itself rather than sniffed from the path extension.
@@ -129,8 +247,14 @@ impl ContainerState {
             container.clone(),
             max_image_height,
             settings.image_resampling_method,
+            StripConfig {
+                segment_height: settings.strip_segment_height as u32,
+                overlap: settings.strip_overlap as u32,
+            },
+            settings.encode_options,
             image_cache.clone(),
-        )?;
+        )?
+        .with_cache_generation(cache_generation.clone());
 
         Ok((container, loader))
     }
@@ -185,6 +309,7 @@ mod tests {
         let result = ContainerState::build_with(
             &state.settings,
             &state.image_cache,
+            &state.cache_generation,
             "/path/to/file.unsupported",
         );
 
@@ -199,8 +324,12 @@ mod tests {
     #[test]
     fn test_build_without_extension() {
         let state = ContainerState::default();
-        let result =
-            ContainerState::build_with(&state.settings, &state.image_cache, "/path/to/noextension");
+        let result = ContainerState::build_with(
+            &state.settings,
+            &state.image_cache,
+            &state.cache_generation,
+            "/path/to/noextension",
+        );
 
         let Err(err) = result else {
             panic!("expected an error for a missing extension");
@@ -233,6 +362,7 @@ mod tests {
         let (container, loader) = ContainerState::build_with(
             &state.settings,
             &state.image_cache,
+            &state.cache_generation,
             dir.path().to_string_lossy().as_ref(),
         )
         .expect("building a valid directory container should succeed");
@@ -255,8 +385,12 @@ mod tests {
 
         // This would fail because the file doesn't exist, but it tests that
         // the height is being used in the PdfContainer::new call
-        let result =
-            ContainerState::build_with(&state.settings, &state.image_cache, "/path/to/file.pdf");
+        let result = ContainerState::build_with(
+            &state.settings,
+            &state.image_cache,
+            &state.cache_generation,
+            "/path/to/file.pdf",
+        );
 
         // The error is expected because the file doesn't exist
         assert!(result.is_err());
@@ -276,7 +410,12 @@ mod tests {
         ];
 
         for file_path in unsupported_files {
-            let result = ContainerState::build_with(&state.settings, &state.image_cache, file_path);
+            let result = ContainerState::build_with(
+                &state.settings,
+                &state.image_cache,
+                &state.cache_generation,
+                file_path,
+            );
             let Err(err) = result else {
                 panic!("File {} should be unsupported", file_path);
             };
@@ -295,7 +434,12 @@ mod tests {
         ];
 
         for (file_path, ext) in supported_files {
-            let result = ContainerState::build_with(&state.settings, &state.image_cache, file_path);
+            let result = ContainerState::build_with(
+                &state.settings,
+                &state.image_cache,
+                &state.cache_generation,
+                file_path,
+            );
 
             // These will fail because files don't exist, but we verify the
             // extension is recognized (different error message)
@@ -317,16 +461,24 @@ mod tests {
         state.settings.pdfium_library_path = Some(get_pdfium_lib_path());
 
         // Test uppercase extension
-        let result =
-            ContainerState::build_with(&state.settings, &state.image_cache, "/path/to/file.ZIP");
+        let result = ContainerState::build_with(
+            &state.settings,
+            &state.image_cache,
+            &state.cache_generation,
+            "/path/to/file.ZIP",
+        );
         if let Err(err) = result {
             // Should not be "Unsupported" error, meaning it recognized ZIP
             assert!(!err.to_string().contains("Unsupported Container Type"));
         }
 
         // Test mixed case
-        let result =
-            ContainerState::build_with(&state.settings, &state.image_cache, "/path/to/file.Pdf");
+        let result = ContainerState::build_with(
+            &state.settings,
+            &state.image_cache,
+            &state.cache_generation,
+            "/path/to/file.Pdf",
+        );
         if let Err(err) = result {
             assert!(!err.to_string().contains("Unsupported Container Type"));
         }
@@ -336,7 +488,12 @@ mod tests {
     fn test_container_error_fields() {
         let state = ContainerState::default();
         let test_path = "/test/path/file.unknown".to_string();
-        let result = ContainerState::build_with(&state.settings, &state.image_cache, &test_path);
+        let result = ContainerState::build_with(
+            &state.settings,
+            &state.image_cache,
+            &state.cache_generation,
+            &test_path,
+        );
 
         let Err(err) = result else {
             panic!("expected an error for an unknown extension");
@@ -350,10 +507,7 @@ mod tests {
         use crate::image::types::Image;
 
         let cache = build_image_cache(64);
-        let key = CacheKey {
-            book_id: "book".to_string(),
-            entry: "p1.png".to_string(),
-        };
+        let key = CacheKey::page("book", "p1.png");
         let image = Arc::new(Image {
             data: vec![1, 2, 3],
             width: 1,
@@ -365,6 +519,112 @@ mod tests {
         assert!(cache.get(&key).is_some());
     }
 
+    #[test]
+    fn test_invalidate_book_cache_keeps_other_books() {
+        use crate::image::loader::CacheKey;
+        use crate::image::types::Image;
+
+        let state = ContainerState::default();
+        let image = Arc::new(Image {
+            data: vec![1, 2, 3],
+            width: 1,
+            height: 1,
+        });
+        state
+            .image_cache
+            .insert(CacheKey::page("a.zip", "p1.png"), image.clone());
+        state
+            .image_cache
+            .insert(CacheKey::page("a.zip", "p2.png"), image.clone());
+        state
+            .image_cache
+            .insert(CacheKey::page("b.zip", "p1.png"), image);
+        let generation = state.cache_generation.current();
+
+        state.invalidate_book_cache("a.zip");
+
+        // Loads in flight must not put back what was just invalidated.
+        assert_ne!(state.cache_generation.current(), generation);
+
+        assert!(state
+            .image_cache
+            .get(&CacheKey::page("a.zip", "p1.png"))
+            .is_none());
+        assert!(state
+            .image_cache
+            .get(&CacheKey::page("a.zip", "p2.png"))
+            .is_none());
+        assert!(state
+            .image_cache
+            .get(&CacheKey::page("b.zip", "p1.png"))
+            .is_some());
+    }
+
+    #[test]
+    fn test_shrink_image_cache_drops_other_books_first() {
+        use crate::container::traits::MockContainer;
+        use crate::image::loader::CacheKey;
+        use crate::image::types::Image;
+
+        let pages: Vec<String> = (0..4).map(|i| format!("p{i}.png")).collect();
+        let mut container = MockContainer::new();
+        container.expect_get_entries().return_const(pages.clone());
+        container.expect_is_single_threaded().return_const(false);
+        let container: Arc<dyn Container> = Arc::new(container);
+
+        let mut state = ContainerState::default();
+        let image = Arc::new(Image {
+            data: vec![1, 2, 3],
+            width: 1,
+            height: 1,
+        });
+        for page in &pages {
+            for book in ["open.zip", "other.zip"] {
+                state
+                    .image_cache
+                    .insert(CacheKey::page(book, page), image.clone());
+            }
+        }
+        let loader = ImageLoader::new(
+            "open.zip".to_string(),
+            container.clone(),
+            0,
+            state.settings.image_resampling_method,
+            StripConfig::default(),
+            state.settings.encode_options,
+            state.image_cache.clone(),
+        )
+        .unwrap();
+        // Every page around the first one is cached, so this only records the position.
+        loader.request_preload_around(0, 0).unwrap();
+        state.container = Some(container);
+        state.image_loader = Some(Arc::new(loader));
+
+        let cached = |state: &ContainerState, book: &str| {
+            pages
+                .iter()
+                .filter(|page| state.image_cache.contains_key(&CacheKey::page(book, page)))
+                .count()
+        };
+        // Half of the cache goes, all of it from the other book.
+        state.shrink_image_cache();
+        assert_eq!(
+            (cached(&state, "open.zip"), cached(&state, "other.zip")),
+            (4, 0)
+        );
+        // Then the pages of the open book farthest from the current one.
+        state.shrink_image_cache();
+        assert!(state
+            .image_cache
+            .contains_key(&CacheKey::page("open.zip", "p0.png")));
+        assert!(state
+            .image_cache
+            .contains_key(&CacheKey::page("open.zip", "p1.png")));
+        assert_eq!(cached(&state, "open.zip"), 2);
+        assert_eq!(state.memory_pressure_shrinks, 2);
+        assert_eq!(state.cache_stats().memory_pressure_shrinks, 2);
+    }
+
     #[test]
     fn test_build_image_cache_accepts_different_sizes() {
         // Both a small and a large cache should build without panicking.
@@ -0,0 +1,96 @@
+use std::{thread, time::Duration};
+
+use tauri::{AppHandle, Manager, Runtime};
+use tokio::sync::RwLock;
+
+use crate::state::app_state::AppState;
+
+/// How often available system memory is checked.
+const POLL_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Starts a background thread that shrinks the image cache whenever available system
+/// memory drops below the configured threshold.
+///
+/// The check only runs while `ContainerSettings::shrink_on_memory_pressure` is enabled.
+/// On platforms where available memory cannot be read, no thread is started.
+///
+/// # Arguments
+///
+/// * `app` - The Tauri app handle used to reach the managed `AppState`.
+pub fn spawn_monitor<R: Runtime>(app: AppHandle<R>) {
+    if available_memory_mib().is_none() {
+        log::info!("Available memory is unknown on this platform; not monitoring it");
+        return;
+    }
+
+    let spawned = thread::Builder::new()
+        .name("memory-pressure".to_string())
+        .spawn(move || loop {
+            thread::sleep(POLL_INTERVAL);
+
+            let state = app.state::<RwLock<AppState>>();
+            let threshold_mib = {
+                let locked_state = state.blocking_read();
+                let settings = &locked_state.container_state.settings;
+                if !settings.shrink_on_memory_pressure {
+                    continue;
+                }
+                settings.memory_pressure_threshold_mib
+            };
+
+            if let Some(available_mib) = available_memory_mib() {
+                if available_mib < threshold_mib {
+                    log::warn!("Available memory {available_mib} MiB is below {threshold_mib} MiB");
+                    state.blocking_write().container_state.shrink_image_cache();
+                }
+            }
+        });
+    if let Err(e) = spawned {
+        log::error!("Failed to start the memory pressure monitor: {e}");
+    }
+}
+
+/// Returns the memory in MiB available to new allocations without swapping, or `None`
+/// if it cannot be determined on this platform.
+#[cfg(target_os = "linux")]
+pub fn available_memory_mib() -> Option<u64> {
+    let meminfo = std::fs::read_to_string("/proc/meminfo").ok()?;
+    parse_mem_available_kib(&meminfo).map(|kib| kib / 1024)
+}
+
+/// Returns the memory in MiB available to new allocations without swapping, or `None`
+/// if it cannot be determined on this platform.
+#[cfg(not(target_os = "linux"))]
+pub fn available_memory_mib() -> Option<u64> {
+    None
+}
+
+/// Extracts the `MemAvailable` value, in KiB, from the contents of `/proc/meminfo`.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_mem_available_kib(meminfo: &str) -> Option<u64> {
+    meminfo.lines().find_map(|line| {
+        let value = line.strip_prefix("MemAvailable:")?;
+        value.trim().strip_suffix("kB")?.trim().parse().ok()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_mem_available_kib() {
+        let meminfo = "MemTotal:       16303428 kB\n\
+                       MemFree:         1234567 kB\n\
+                       MemAvailable:    8388608 kB\n\
+                       Buffers:          123456 kB\n";
+        assert_eq!(parse_mem_available_kib(meminfo), Some(8_388_608));
+    }
+
+    #[test]
+    fn test_parse_mem_available_kib_missing() {
+        // Kernels older than 3.14 do not report MemAvailable.
+        assert_eq!(parse_mem_available_kib("MemTotal: 16303428 kB\n"), None);
+        assert_eq!(parse_mem_available_kib("MemAvailable: lots\n"), None);
+    }
+}
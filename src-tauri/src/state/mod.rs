@@ -1,3 +1,4 @@
 pub mod app_state;
 pub mod container_settings;
 pub mod container_state;
+pub mod memory_pressure;
@@ -1,8 +1,13 @@
-use sqlx::sqlite::SqlitePoolOptions;
+use std::str::FromStr;
+
+use rookreader_lib::infrastructure::database::with_collations;
+use sqlx::sqlite::{SqliteConnectOptions, SqlitePoolOptions};
 
 pub async fn setup_db() -> sqlx::SqlitePool {
+    let options = SqliteConnectOptions::from_str("sqlite::memory:")
+        .expect("Failed to parse the in-memory SQLite URL");
     let pool = SqlitePoolOptions::new()
-        .connect("sqlite::memory:")
+        .connect_with(with_collations(options))
         .await
         .expect("Failed to connect to in-memory SQLite");
 
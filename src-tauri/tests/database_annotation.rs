@@ -0,0 +1,136 @@
+use rookreader_lib::domain::annotation::entity::NewAnnotation;
+use rookreader_lib::domain::annotation::repository::AnnotationRepository;
+use rookreader_lib::domain::book::entity::ReadingState;
+use rookreader_lib::domain::book::repository::BookRepository;
+use rookreader_lib::infrastructure::database::annotation_repository::SqliteAnnotationRepository;
+use rookreader_lib::infrastructure::database::book_repository::SqliteBookRepository;
+
+mod common;
+use common::setup_db;
+
+fn highlight(book_id: i64, cfi_range: &str, selected_text: &str) -> NewAnnotation {
+    NewAnnotation {
+        book_id,
+        cfi_range: cfi_range.to_string(),
+        selected_text: selected_text.to_string(),
+        color_code: "#FFFF00".to_string(),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_annotations_are_listed_in_reading_order() {
+    let pool = setup_db().await;
+    let book_repo = SqliteBookRepository::new(pool.clone());
+    let repo = SqliteAnnotationRepository::new(pool);
+
+    let book_id = book_repo
+        .register_book("/books/novel.epub", "file", "Novel", 0, None)
+        .await
+        .unwrap();
+    // Added out of order; "/10" sorts before "/2" as text.
+    let third = repo
+        .add(&highlight(
+            book_id,
+            "epubcfi(/6/10!/4/2,/1:0,/1:5)",
+            "Third",
+        ))
+        .await
+        .unwrap();
+    let first = repo
+        .add(&NewAnnotation {
+            note: "Remember this".to_string(),
+            ..highlight(book_id, "epubcfi(/6/2!/4/2,/1:0,/1:5)", "First")
+        })
+        .await
+        .unwrap();
+    let second = repo
+        .add(&highlight(
+            book_id,
+            "epubcfi(/6/2!/4/8,/1:3,/1:9)",
+            "Second",
+        ))
+        .await
+        .unwrap();
+    assert_eq!(first.note, "Remember this");
+    assert_eq!(third.color_code, "#FFFF00");
+
+    let annotations = repo.get_for_book(book_id).await.unwrap();
+    assert_eq!(
+        annotations.iter().map(|a| a.id).collect::<Vec<_>>(),
+        vec![first.id, second.id, third.id]
+    );
+
+    repo.delete(second.id).await.unwrap();
+    let annotations = repo.get_for_book(book_id).await.unwrap();
+    assert_eq!(
+        annotations.iter().map(|a| a.id).collect::<Vec<_>>(),
+        vec![first.id, third.id]
+    );
+}
+
+#[tokio::test]
+async fn test_annotations_outlive_history_but_not_the_book() {
+    let pool = setup_db().await;
+    let book_repo = SqliteBookRepository::new(pool.clone());
+    let repo = SqliteAnnotationRepository::new(pool);
+
+    let book_id = book_repo
+        .register_book("/books/novel.epub", "file", "Novel", 0, None)
+        .await
+        .unwrap();
+    repo.add(&highlight(
+        book_id,
+        "epubcfi(/6/2!/4/2,/1:0,/1:5)",
+        "Passage",
+    ))
+    .await
+    .unwrap();
+
+    book_repo.clear_reading_history(book_id).await.unwrap();
+    assert_eq!(repo.get_for_book(book_id).await.unwrap().len(), 1);
+
+    book_repo.delete_book(book_id).await.unwrap();
+    assert!(repo.get_for_book(book_id).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_annotation_needs_existing_book() {
+    let pool = setup_db().await;
+    let repo = SqliteAnnotationRepository::new(pool);
+
+    assert!(repo
+        .add(&highlight(42, "epubcfi(/6/2!/4/2,/1:0,/1:5)", "Passage"))
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_reading_progress_keeps_the_cfi_position() {
+    let pool = setup_db().await;
+    let book_repo = SqliteBookRepository::new(pool);
+
+    let book_id = book_repo
+        .register_book("/books/novel.epub", "file", "Novel", 0, None)
+        .await
+        .unwrap();
+    book_repo
+        .update_reading_progress(&ReadingState {
+            book_id,
+            last_read_page_index: 0,
+            last_read_cfi: Some("epubcfi(/6/8!/4/2/1:120)".to_string()),
+            last_opened_at: Some(chrono::Utc::now().naive_utc()),
+        })
+        .await
+        .unwrap();
+
+    let book = book_repo
+        .get_book_with_state_by_id(book_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        book.last_read_cfi.as_deref(),
+        Some("epubcfi(/6/8!/4/2/1:120)")
+    );
+}
@@ -1,4 +1,6 @@
-use rookreader_lib::domain::book::entity::ReadingState;
+use rookreader_lib::domain::book::entity::{
+    ExclusionRule, PatternSyntax, ReadStatus, ReadingState,
+};
 use rookreader_lib::domain::book::repository::BookRepository;
 use rookreader_lib::domain::bookshelf::repository::BookshelfRepository;
 use rookreader_lib::domain::series::repository::SeriesRepository;
@@ -106,6 +108,7 @@ async fn test_reading_state() {
     let state = ReadingState {
         book_id,
         last_read_page_index: 50,
+        last_read_cfi: None,
         last_opened_at: Some(chrono::Utc::now().naive_utc()),
     };
     repository.update_reading_progress(&state).await.unwrap();
@@ -127,6 +130,130 @@ async fn test_reading_state() {
     assert!(book_with_state.last_read_page_index.is_none());
 }
 
+#[tokio::test]
+async fn test_set_damage_code() {
+    let pool = setup_db().await;
+    let repository = SqliteBookRepository::new(pool.clone());
+
+    let book_id = repository
+        .register_book("/path/to/book.zip", "file", "My Book", 100, None)
+        .await
+        .unwrap();
+    let book_with_state = repository
+        .get_book_with_state_by_id(book_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(book_with_state.damage_code, None);
+
+    repository
+        .set_damage_code(book_id, Some(10402))
+        .await
+        .unwrap();
+    let book_with_state = repository
+        .get_book_with_state_by_id(book_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(book_with_state.damage_code, Some(10402));
+
+    // Re-registering keeps the mark; only an explicit update clears it.
+    repository
+        .register_book("/path/to/book.zip", "file", "My Book", 100, None)
+        .await
+        .unwrap();
+    let books = repository.get_all_books_with_state().await.unwrap();
+    assert_eq!(books[0].damage_code, Some(10402));
+
+    repository.set_damage_code(book_id, None).await.unwrap();
+    let book_with_state = repository
+        .get_book_with_state_by_id(book_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(book_with_state.damage_code, None);
+}
+
+#[tokio::test]
+async fn test_exclusion_rules() {
+    let pool = setup_db().await;
+    let repository = SqliteBookRepository::new(pool.clone());
+
+    let book_id = repository
+        .register_book("/path/to/book.zip", "file", "My Book", 100, None)
+        .await
+        .unwrap();
+    assert!(repository
+        .get_exclusion_rules(book_id)
+        .await
+        .unwrap()
+        .is_empty());
+
+    let rules = vec![
+        ExclusionRule::glob("credits*.jpg"),
+        ExclusionRule {
+            pattern: r"^zz_".to_string(),
+            syntax: PatternSyntax::Regex,
+        },
+    ];
+    repository
+        .set_exclusion_rules(book_id, &rules)
+        .await
+        .unwrap();
+    assert_eq!(
+        repository.get_exclusion_rules(book_id).await.unwrap(),
+        rules
+    );
+
+    repository.set_exclusion_rules(book_id, &[]).await.unwrap();
+    assert!(repository
+        .get_exclusion_rules(book_id)
+        .await
+        .unwrap()
+        .is_empty());
+
+    // An unknown book has no rules, and cannot be given any.
+    assert!(repository
+        .get_exclusion_rules(9999)
+        .await
+        .unwrap()
+        .is_empty());
+    assert!(repository.set_exclusion_rules(9999, &rules).await.is_err());
+}
+
+#[tokio::test]
+async fn test_page_order() {
+    let pool = setup_db().await;
+    let repository = SqliteBookRepository::new(pool.clone());
+
+    let book_id = repository
+        .register_book("/path/to/book.zip", "file", "My Book", 3, None)
+        .await
+        .unwrap();
+    assert!(repository.get_page_order(book_id).await.unwrap().is_empty());
+
+    let page_order = vec![
+        "cover.jpg".to_string(),
+        "p1.jpg".to_string(),
+        "p1a.jpg".to_string(),
+    ];
+    repository
+        .set_page_order(book_id, &page_order)
+        .await
+        .unwrap();
+    assert_eq!(
+        repository.get_page_order(book_id).await.unwrap(),
+        page_order
+    );
+
+    repository.set_page_order(book_id, &[]).await.unwrap();
+    assert!(repository.get_page_order(book_id).await.unwrap().is_empty());
+
+    // An unknown book has no order, and cannot be given one.
+    assert!(repository.get_page_order(9999).await.unwrap().is_empty());
+    assert!(repository.set_page_order(9999, &page_order).await.is_err());
+}
+
 #[tokio::test]
 async fn test_record_book_opened() {
     let pool = setup_db().await;
@@ -191,6 +318,7 @@ async fn test_recently_read_books() {
         .update_reading_progress(&ReadingState {
             book_id: b1,
             last_read_page_index: 10,
+            last_read_cfi: None,
             last_opened_at: Some(now - chrono::Duration::minutes(10)),
         })
         .await
@@ -200,6 +328,7 @@ async fn test_recently_read_books() {
         .update_reading_progress(&ReadingState {
             book_id: b2,
             last_read_page_index: 20,
+            last_read_cfi: None,
             last_opened_at: Some(now),
         })
         .await
@@ -233,6 +362,7 @@ async fn test_update_reading_progress_defaults_null_last_opened_at() {
         .update_reading_progress(&ReadingState {
             book_id,
             last_read_page_index: 5,
+            last_read_cfi: None,
             last_opened_at: None,
         })
         .await
@@ -262,6 +392,7 @@ async fn test_recently_read_books_negative_limit_returns_empty() {
         .update_reading_progress(&ReadingState {
             book_id,
             last_read_page_index: 10,
+            last_read_cfi: None,
             last_opened_at: Some(chrono::Utc::now().naive_utc()),
         })
         .await
@@ -341,6 +472,143 @@ async fn test_filtering_by_bookshelf_tag_series() {
     assert_eq!(series_books[0].id, b1);
 }
 
+#[tokio::test]
+async fn test_series_books_with_equal_order_sort_by_natural_name() {
+    let pool = setup_db().await;
+    let repository = SqliteBookRepository::new(pool.clone());
+    let series_repo = SqliteSeriesRepository::new(pool.clone());
+
+    let series_id = series_repo.create("物語").await.unwrap();
+    for (path, name) in [("/path/10", "物語 第十巻"), ("/path/2", "物語 第二巻")] {
+        let id = repository
+            .register_book(path, "file", name, 100, None)
+            .await
+            .unwrap();
+        series_repo
+            .assign_book_to_series(id, Some(series_id))
+            .await
+            .unwrap();
+    }
+    // Imported series may carry duplicate orders; ties fall back to the name.
+    sqlx::query("UPDATE books SET series_order = 1 WHERE series_id = ?")
+        .bind(series_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let names: Vec<String> = series_repo
+        .get_books_by_series(series_id)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|book| book.display_name)
+        .collect();
+    assert_eq!(names, vec!["物語 第二巻", "物語 第十巻"]);
+}
+
+#[tokio::test]
+async fn test_read_status_follows_reading_progress() {
+    let pool = setup_db().await;
+    let repository = SqliteBookRepository::new(pool.clone());
+    let status = |book_id| {
+        let repository = &repository;
+        async move {
+            repository
+                .get_book_with_state_by_id(book_id)
+                .await
+                .unwrap()
+                .unwrap()
+                .read_status
+        }
+    };
+    let progress = |book_id, last_read_page_index| ReadingState {
+        book_id,
+        last_read_page_index,
+        last_read_cfi: None,
+        last_opened_at: None,
+    };
+
+    let book_id = repository
+        .register_book("/path/to/book.zip", "file", "Book", 10, None)
+        .await
+        .unwrap();
+    assert_eq!(status(book_id).await, ReadStatus::Unread);
+
+    repository
+        .set_read_status(&[book_id], ReadStatus::WantToRead)
+        .await
+        .unwrap();
+    repository
+        .update_reading_progress(&progress(book_id, 3))
+        .await
+        .unwrap();
+    assert_eq!(status(book_id).await, ReadStatus::Reading);
+
+    repository
+        .update_reading_progress(&progress(book_id, 9))
+        .await
+        .unwrap();
+    assert_eq!(status(book_id).await, ReadStatus::Read);
+    // Paging back keeps the book finished.
+    repository
+        .update_reading_progress(&progress(book_id, 2))
+        .await
+        .unwrap();
+    assert_eq!(status(book_id).await, ReadStatus::Read);
+
+    // An abandoned book stays abandoned until it is finished.
+    repository
+        .set_read_status(&[book_id], ReadStatus::Abandoned)
+        .await
+        .unwrap();
+    repository
+        .update_reading_progress(&progress(book_id, 4))
+        .await
+        .unwrap();
+    assert_eq!(status(book_id).await, ReadStatus::Abandoned);
+
+    // Clearing the history of a book being read makes it unread again.
+    repository
+        .set_read_status(&[book_id], ReadStatus::Reading)
+        .await
+        .unwrap();
+    repository.clear_reading_history(book_id).await.unwrap();
+    assert_eq!(status(book_id).await, ReadStatus::Unread);
+
+    repository
+        .record_book_opened("/path/to/book.zip", "file", "Book", 10, None)
+        .await
+        .unwrap();
+    assert_eq!(status(book_id).await, ReadStatus::Reading);
+}
+
+#[tokio::test]
+async fn test_set_rating_and_favorite() {
+    let pool = setup_db().await;
+    let repository = SqliteBookRepository::new(pool.clone());
+
+    let b1 = repository
+        .register_book("/path/1", "file", "B1", 100, None)
+        .await
+        .unwrap();
+    let b2 = repository
+        .register_book("/path/2", "file", "B2", 100, None)
+        .await
+        .unwrap();
+
+    repository.set_rating(&[b1, b2], 4).await.unwrap();
+    repository.set_favorite(&[b2], true).await.unwrap();
+
+    let books = repository.get_all_books_with_state().await.unwrap();
+    let b1_state = books.iter().find(|book| book.id == b1).unwrap();
+    let b2_state = books.iter().find(|book| book.id == b2).unwrap();
+    assert_eq!((b1_state.rating, b1_state.is_favorite), (4, false));
+    assert_eq!((b2_state.rating, b2_state.is_favorite), (4, true));
+
+    // The database rejects ratings outside 0 to 5.
+    assert!(repository.set_rating(&[b1], 6).await.is_err());
+}
+
 #[tokio::test]
 async fn test_clear_all_reading_history() {
     let pool = setup_db().await;
@@ -356,6 +624,7 @@ async fn test_clear_all_reading_history() {
         .update_reading_progress(&ReadingState {
             book_id: b1,
             last_read_page_index: 10,
+            last_read_cfi: None,
             last_opened_at: Some(now),
         })
         .await
@@ -437,6 +706,7 @@ async fn test_delete_book() {
         .update_reading_progress(&ReadingState {
             book_id,
             last_read_page_index: 10,
+            last_read_cfi: None,
             last_opened_at: Some(chrono::Utc::now().naive_utc()),
         })
         .await
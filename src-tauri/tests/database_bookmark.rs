@@ -0,0 +1,179 @@
+use rookreader_lib::domain::book::repository::BookRepository;
+use rookreader_lib::domain::bookmark::entity::{BookmarkUpdate, NewBookmark};
+use rookreader_lib::domain::bookmark::repository::BookmarkRepository;
+use rookreader_lib::error::Error;
+use rookreader_lib::infrastructure::database::book_repository::SqliteBookRepository;
+use rookreader_lib::infrastructure::database::bookmark_repository::SqliteBookmarkRepository;
+
+mod common;
+use common::setup_db;
+
+fn at_page(book_id: i64, page_index: i64, label: &str) -> NewBookmark {
+    NewBookmark {
+        book_id,
+        page_index: Some(page_index),
+        label: label.to_string(),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_add_update_and_delete_bookmarks() {
+    let pool = setup_db().await;
+    let book_repo = SqliteBookRepository::new(pool.clone());
+    let repo = SqliteBookmarkRepository::new(pool);
+
+    let book_id = book_repo
+        .register_book("/books/novel.epub", "file", "Novel", 0, None)
+        .await
+        .unwrap();
+    let later = repo.add(&at_page(book_id, 20, "Later")).await.unwrap();
+    let cfi = repo
+        .add(&NewBookmark {
+            book_id,
+            cfi: Some("epubcfi(/6/4!/4/2/1:0)".to_string()),
+            color_code: Some("#FF0000".to_string()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    let earlier = repo.add(&at_page(book_id, 5, "Earlier")).await.unwrap();
+    assert_eq!(later.page_index, Some(20));
+    assert_eq!(cfi.color_code.as_deref(), Some("#FF0000"));
+
+    // Page bookmarks come in page order, before the novel bookmarks.
+    let bookmarks = repo.get_for_book(book_id).await.unwrap();
+    assert_eq!(
+        bookmarks.iter().map(|b| b.id).collect::<Vec<_>>(),
+        vec![earlier.id, later.id, cfi.id]
+    );
+
+    let update = BookmarkUpdate {
+        label: "Renamed".to_string(),
+        note: "Reread this".to_string(),
+        color_code: Some("#00FF00".to_string()),
+    };
+    repo.update(later.id, &update).await.unwrap();
+    let updated = repo
+        .get_for_book(book_id)
+        .await
+        .unwrap()
+        .into_iter()
+        .find(|b| b.id == later.id)
+        .unwrap();
+    assert_eq!(updated.label, "Renamed");
+    assert_eq!(updated.note, "Reread this");
+    assert_eq!(updated.color_code.as_deref(), Some("#00FF00"));
+    assert_eq!(updated.page_index, Some(20));
+
+    repo.delete(earlier.id).await.unwrap();
+    assert_eq!(repo.get_for_book(book_id).await.unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_bookmarks_outlive_history_but_not_the_book() {
+    let pool = setup_db().await;
+    let book_repo = SqliteBookRepository::new(pool.clone());
+    let repo = SqliteBookmarkRepository::new(pool);
+
+    let first = book_repo
+        .register_book("/books/1.zip", "file", "Book 1", 30, None)
+        .await
+        .unwrap();
+    let second = book_repo
+        .register_book("/books/2.zip", "file", "Book 2", 30, None)
+        .await
+        .unwrap();
+    repo.add(&at_page(first, 3, "First")).await.unwrap();
+    repo.add(&at_page(second, 7, "Second")).await.unwrap();
+
+    book_repo.clear_reading_history(first).await.unwrap();
+    book_repo.clear_all_reading_history().await.unwrap();
+    let all = repo.get_all().await.unwrap();
+    assert_eq!(
+        all.iter()
+            .map(|b| (b.display_name.as_str(), b.label.as_str()))
+            .collect::<Vec<_>>(),
+        vec![("Book 2", "Second"), ("Book 1", "First")]
+    );
+
+    book_repo.delete_book(first).await.unwrap();
+    let all = repo.get_all().await.unwrap();
+    assert_eq!(all.len(), 1);
+    assert_eq!(all[0].book_id, second);
+}
+
+#[tokio::test]
+async fn test_bookmark_needs_exactly_one_position() {
+    let pool = setup_db().await;
+    let book_repo = SqliteBookRepository::new(pool.clone());
+    let repo = SqliteBookmarkRepository::new(pool);
+
+    let book_id = book_repo
+        .register_book("/books/1.zip", "file", "Book 1", 30, None)
+        .await
+        .unwrap();
+    let both = NewBookmark {
+        cfi: Some("epubcfi(/6/2)".to_string()),
+        ..at_page(book_id, 1, "")
+    };
+    assert!(repo.add(&both).await.is_err());
+    let neither = NewBookmark {
+        book_id,
+        ..Default::default()
+    };
+    assert!(repo.add(&neither).await.is_err());
+}
+
+#[tokio::test]
+async fn test_novel_bookmarks_are_in_reading_order() {
+    let pool = setup_db().await;
+    let book_repo = SqliteBookRepository::new(pool.clone());
+    let repo = SqliteBookmarkRepository::new(pool);
+
+    let book_id = book_repo
+        .register_book("/books/novel.epub", "file", "Novel", 0, None)
+        .await
+        .unwrap();
+    for cfi in [
+        "epubcfi(/6/12!/4/2/1:0)",
+        "epubcfi(/6/4!/4/10/3:5)",
+        "epubcfi(/6/4!/4/2/1:40)",
+    ] {
+        repo.add(&NewBookmark {
+            book_id,
+            cfi: Some(cfi.to_string()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    }
+
+    let bookmarks = repo.get_for_book(book_id).await.unwrap();
+    assert_eq!(
+        bookmarks
+            .iter()
+            .map(|b| b.cfi.as_deref().unwrap())
+            .collect::<Vec<_>>(),
+        vec![
+            "epubcfi(/6/4!/4/2/1:40)",
+            "epubcfi(/6/4!/4/10/3:5)",
+            "epubcfi(/6/12!/4/2/1:0)",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_update_and_delete_unknown_bookmark_fail() {
+    let pool = setup_db().await;
+    let repo = SqliteBookmarkRepository::new(pool);
+
+    assert!(matches!(
+        repo.update(42, &BookmarkUpdate::default()).await,
+        Err(Error::Database(sqlx::Error::RowNotFound))
+    ));
+    assert!(matches!(
+        repo.delete(42).await,
+        Err(Error::Database(sqlx::Error::RowNotFound))
+    ));
+}
@@ -1,5 +1,12 @@
+use rookreader_lib::domain::book::entity::{ReadStatus, ReadingState, TagMatch};
+use rookreader_lib::domain::book::repository::BookRepository;
+use rookreader_lib::domain::bookshelf::entity::BookshelfRule;
 use rookreader_lib::domain::bookshelf::repository::BookshelfRepository;
+use rookreader_lib::domain::tag::repository::TagRepository;
+use rookreader_lib::error::Error;
+use rookreader_lib::infrastructure::database::book_repository::SqliteBookRepository;
 use rookreader_lib::infrastructure::database::bookshelf_repository::SqliteBookshelfRepository;
+use rookreader_lib::infrastructure::database::tag_repository::SqliteTagRepository;
 use sqlx::Row;
 
 mod common;
@@ -97,3 +104,169 @@ async fn test_add_and_remove_book_from_bookshelf() {
     .unwrap();
     assert_eq!(count, 0);
 }
+
+#[tokio::test]
+async fn test_smart_bookshelf_books_follow_rule() {
+    let pool = setup_db().await;
+    let repository = SqliteBookshelfRepository::new(pool.clone());
+    let book_repo = SqliteBookRepository::new(pool.clone());
+    let tag_repo = SqliteTagRepository::new(pool.clone());
+
+    let register = |path: &'static str| {
+        let book_repo = &book_repo;
+        async move {
+            book_repo
+                .register_book(path, "file", path, 10, None)
+                .await
+                .unwrap()
+        }
+    };
+    let comic = register("/comics/a.cbz").await;
+    let old_comic = register("/comics/b.ZIP").await;
+    let novel = register("/novels/c.epub").await;
+    let neighbor = register("/comics2/d.txt").await;
+    sqlx::query("UPDATE books SET created_at = '2000-01-01 00:00:00' WHERE id IN (?, ?)")
+        .bind(old_comic)
+        .bind(neighbor)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let shelf = repository.create("Smart", "auto").await.unwrap();
+    // Books added by hand do not belong to a smart bookshelf.
+    repository
+        .add_book_to_bookshelf(shelf.id, novel)
+        .await
+        .unwrap();
+    let rule = BookshelfRule {
+        path_prefix: Some("/comics/".to_string()),
+        ..Default::default()
+    };
+    repository
+        .set_rule(shelf.id, Some(rule.clone()))
+        .await
+        .unwrap();
+    assert_eq!(repository.get_all().await.unwrap()[0].rule, Some(rule));
+
+    let book_ids = |rule: BookshelfRule| {
+        let repository = &repository;
+        async move {
+            repository.set_rule(shelf.id, Some(rule)).await.unwrap();
+            repository
+                .get_books_by_bookshelf(shelf.id)
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|book| book.id)
+                .collect::<Vec<_>>()
+        }
+    };
+    assert_eq!(
+        book_ids(BookshelfRule {
+            path_prefix: Some("/comics/".to_string()),
+            ..Default::default()
+        })
+        .await,
+        vec![comic, old_comic]
+    );
+    // The prefix is a folder, not a string prefix of the path.
+    assert_eq!(
+        book_ids(BookshelfRule {
+            path_prefix: Some("/comics".to_string()),
+            ..Default::default()
+        })
+        .await,
+        vec![comic, old_comic]
+    );
+    assert_eq!(
+        book_ids(BookshelfRule {
+            formats: vec!["zip".to_string(), "epub".to_string()],
+            ..Default::default()
+        })
+        .await,
+        vec![novel, old_comic]
+    );
+    assert_eq!(
+        book_ids(BookshelfRule {
+            added_within_days: Some(30),
+            ..Default::default()
+        })
+        .await,
+        vec![novel, comic]
+    );
+
+    let red = tag_repo.create("Red", "#ff0000").await.unwrap();
+    let blue = tag_repo.create("Blue", "#0000ff").await.unwrap();
+    tag_repo
+        .attach_tags_to_book(comic, &[red.id])
+        .await
+        .unwrap();
+    tag_repo
+        .attach_tags_to_book(novel, &[blue.id])
+        .await
+        .unwrap();
+    assert_eq!(
+        book_ids(BookshelfRule {
+            tag_ids: vec![red.id, blue.id],
+            tag_match: TagMatch::Any,
+            ..Default::default()
+        })
+        .await,
+        vec![novel, comic]
+    );
+
+    book_repo
+        .update_reading_progress(&ReadingState {
+            book_id: comic,
+            last_read_page_index: 9,
+            last_read_cfi: None,
+            last_opened_at: None,
+        })
+        .await
+        .unwrap();
+    assert_eq!(
+        book_ids(BookshelfRule {
+            read_status: Some(ReadStatus::Read),
+            ..Default::default()
+        })
+        .await,
+        vec![comic]
+    );
+
+    // Clearing the rule brings back the books added by hand.
+    repository.set_rule(shelf.id, None).await.unwrap();
+    let books = repository.get_books_by_bookshelf(shelf.id).await.unwrap();
+    assert_eq!(
+        books.iter().map(|book| book.id).collect::<Vec<_>>(),
+        vec![novel]
+    );
+}
+
+#[tokio::test]
+async fn test_smart_bookshelf_with_invalid_saved_rule_fails() {
+    let pool = setup_db().await;
+    let repository = SqliteBookshelfRepository::new(pool.clone());
+
+    let shelf = repository.create("Smart", "auto").await.unwrap();
+    let rule = r#"{"tag_ids": [], "tag_match": "all", "formats": ["*"]}"#;
+    sqlx::query("UPDATE bookshelves SET rule = ? WHERE id = ?")
+        .bind(rule)
+        .bind(shelf.id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let result = repository.get_books_by_bookshelf(shelf.id).await;
+    assert!(matches!(result, Err(Error::InvalidArgument(_))));
+
+    // The listing skips the broken bookshelf and keeps the others.
+    let other = repository.create("Manual", "folder").await.unwrap();
+    let ids = repository
+        .get_all()
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|bookshelf| bookshelf.id)
+        .collect::<Vec<_>>();
+    assert_eq!(ids, vec![other.id]);
+}
@@ -0,0 +1,194 @@
+use rookreader_lib::domain::book::entity::{BookMetadata, BookSearchFilters};
+use rookreader_lib::domain::book::repository::BookRepository;
+use rookreader_lib::domain::creator::entity::{CreatorCredit, CreatorRole};
+use rookreader_lib::domain::creator::repository::CreatorRepository;
+use rookreader_lib::error::Error;
+use rookreader_lib::infrastructure::database::book_repository::SqliteBookRepository;
+use rookreader_lib::infrastructure::database::creator_repository::SqliteCreatorRepository;
+
+mod common;
+use common::setup_db;
+
+async fn register(
+    repo: &SqliteBookRepository,
+    name: &str,
+    creators: &[(&str, CreatorRole)],
+) -> i64 {
+    let book_id = repo
+        .register_book(&format!("/books/{name}.zip"), "file", name, 10, None)
+        .await
+        .unwrap();
+    let metadata = BookMetadata {
+        creators: creators
+            .iter()
+            .map(|&(name, role)| CreatorCredit::new(name, role))
+            .collect(),
+        ..Default::default()
+    };
+    repo.set_metadata(book_id, &metadata).await.unwrap();
+    book_id
+}
+
+async fn names(repo: &SqliteCreatorRepository) -> Vec<(String, i64)> {
+    repo.get_all()
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|creator| (creator.name, creator.book_count))
+        .collect()
+}
+
+#[tokio::test]
+async fn test_creators_are_stored_from_metadata() {
+    let pool = setup_db().await;
+    let book_repo = SqliteBookRepository::new(pool.clone());
+    let repo = SqliteCreatorRepository::new(pool);
+
+    let first = register(
+        &book_repo,
+        "Book 1",
+        &[
+            ("Writer", CreatorRole::Author),
+            ("Artist", CreatorRole::Illustrator),
+            ("Writer", CreatorRole::Illustrator),
+        ],
+    )
+    .await;
+    let second = register(&book_repo, "Book 2", &[("Writer", CreatorRole::Author)]).await;
+
+    assert_eq!(
+        names(&repo).await,
+        vec![("Artist".to_string(), 1), ("Writer".to_string(), 2)]
+    );
+    let credits = repo.get_creators_for_book(first).await.unwrap();
+    assert_eq!(
+        credits
+            .iter()
+            .map(|credit| (credit.name.as_str(), credit.role))
+            .collect::<Vec<_>>(),
+        vec![
+            ("Writer", CreatorRole::Author),
+            ("Artist", CreatorRole::Illustrator),
+            ("Writer", CreatorRole::Illustrator),
+        ]
+    );
+
+    let writer = credits[0].creator_id;
+    let books = repo.get_books_by_creator(writer).await.unwrap();
+    assert_eq!(
+        books.iter().map(|book| book.id).collect::<Vec<_>>(),
+        vec![first, second]
+    );
+
+    // Creators no longer credited for any book are removed.
+    book_repo
+        .set_metadata(first, &BookMetadata::default())
+        .await
+        .unwrap();
+    assert_eq!(names(&repo).await, vec![("Writer".to_string(), 1)]);
+}
+
+#[tokio::test]
+async fn test_rename_keeps_the_former_name_as_alias() {
+    let pool = setup_db().await;
+    let book_repo = SqliteBookRepository::new(pool.clone());
+    let repo = SqliteCreatorRepository::new(pool);
+
+    let book_id = register(&book_repo, "Book", &[("Oda", CreatorRole::Author)]).await;
+    let oda = repo.get_all().await.unwrap()[0].id;
+    repo.rename(oda, "尾田栄一郎").await.unwrap();
+
+    // Storing the metadata again, as happens when the book is reopened, keeps the
+    // new name.
+    let metadata = BookMetadata {
+        creators: vec![CreatorCredit::new("Oda", CreatorRole::Author)],
+        ..Default::default()
+    };
+    book_repo.set_metadata(book_id, &metadata).await.unwrap();
+    let creators = repo.get_all().await.unwrap();
+    assert_eq!(creators.len(), 1);
+    assert_eq!(creators[0].id, oda);
+    assert_eq!(creators[0].name, "尾田栄一郎");
+
+    let search = book_repo
+        .search_books("尾田栄一郎", &BookSearchFilters::default(), 10, 0)
+        .await
+        .unwrap();
+    assert_eq!(search.len(), 1);
+
+    let other_id = register(&book_repo, "Other", &[("Someone", CreatorRole::Author)]).await;
+    let someone = repo.get_creators_for_book(other_id).await.unwrap()[0].creator_id;
+    let result = repo.rename(someone, "尾田栄一郎").await;
+    assert!(matches!(result, Err(Error::InvalidArgument(_))));
+}
+
+#[tokio::test]
+async fn test_merge_moves_books_and_names() {
+    let pool = setup_db().await;
+    let book_repo = SqliteBookRepository::new(pool.clone());
+    let repo = SqliteCreatorRepository::new(pool);
+
+    let both = register(
+        &book_repo,
+        "Book 1",
+        &[
+            ("J. Doe", CreatorRole::Author),
+            ("Jane Doe", CreatorRole::Author),
+        ],
+    )
+    .await;
+    let short_name = register(&book_repo, "Book 2", &[("J. Doe", CreatorRole::Translator)]).await;
+    let creators = repo.get_creators_for_book(both).await.unwrap();
+    let (abbreviated, full) = (creators[0].creator_id, creators[1].creator_id);
+
+    repo.merge(full, &[abbreviated]).await.unwrap();
+
+    assert_eq!(names(&repo).await, vec![("Jane Doe".to_string(), 2)]);
+    let credits = repo.get_creators_for_book(short_name).await.unwrap();
+    assert_eq!(credits.len(), 1);
+    assert_eq!(credits[0].creator_id, full);
+    assert_eq!(credits[0].role, CreatorRole::Translator);
+
+    // The merged name keeps resolving to the remaining creator.
+    let later = register(&book_repo, "Book 3", &[("J. Doe", CreatorRole::Author)]).await;
+    assert_eq!(
+        repo.get_creators_for_book(later).await.unwrap()[0].creator_id,
+        full
+    );
+}
+
+#[tokio::test]
+async fn test_books_by_creator_are_in_natural_order() {
+    let pool = setup_db().await;
+    let book_repo = SqliteBookRepository::new(pool.clone());
+    let repo = SqliteCreatorRepository::new(pool);
+
+    let tenth = register(&book_repo, "Volume 10", &[("Writer", CreatorRole::Author)]).await;
+    let second = register(&book_repo, "Volume 2", &[("Writer", CreatorRole::Author)]).await;
+
+    let writer = repo.get_creators_for_book(tenth).await.unwrap()[0].creator_id;
+    let books = repo.get_books_by_creator(writer).await.unwrap();
+    assert_eq!(
+        books.iter().map(|book| book.id).collect::<Vec<_>>(),
+        vec![second, tenth]
+    );
+}
+
+#[tokio::test]
+async fn test_set_metadata_only_removes_the_creators_it_detaches() {
+    let pool = setup_db().await;
+    let book_repo = SqliteBookRepository::new(pool.clone());
+    let repo = SqliteCreatorRepository::new(pool.clone());
+
+    sqlx::query("INSERT INTO creators (name) VALUES ('Uncredited')")
+        .execute(&pool)
+        .await
+        .unwrap();
+    let book = register(&book_repo, "Book", &[("Writer", CreatorRole::Author)]).await;
+    book_repo
+        .set_metadata(book, &BookMetadata::default())
+        .await
+        .unwrap();
+
+    assert_eq!(names(&repo).await, vec![("Uncredited".to_string(), 0)]);
+}
@@ -0,0 +1,98 @@
+use rookreader_lib::domain::book::repository::BookRepository;
+use rookreader_lib::domain::library::repository::LibraryRepository;
+use rookreader_lib::infrastructure::database::book_repository::SqliteBookRepository;
+use rookreader_lib::infrastructure::database::library_repository::SqliteLibraryRepository;
+
+mod common;
+use common::setup_db;
+
+#[tokio::test]
+async fn test_add_get_and_delete_roots() {
+    let pool = setup_db().await;
+    let repo = SqliteLibraryRepository::new(pool);
+
+    let id = repo.add_root("/books").await.unwrap();
+    assert!(repo.add_root("/books").await.is_err());
+
+    let roots = repo.get_roots().await.unwrap();
+    assert_eq!(roots.len(), 1);
+    assert_eq!(roots[0].path, "/books");
+    assert!(roots[0].last_scanned_at.is_none());
+
+    repo.mark_root_scanned(id).await.unwrap();
+    let root = repo.get_root(id).await.unwrap().unwrap();
+    assert!(root.last_scanned_at.is_some());
+
+    repo.delete_root(id).await.unwrap();
+    assert!(repo.get_root(id).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_indexed_books_are_matched_by_folder() {
+    let pool = setup_db().await;
+    let book_repo = SqliteBookRepository::new(pool.clone());
+    let repo = SqliteLibraryRepository::new(pool);
+
+    let sep = std::path::MAIN_SEPARATOR;
+    let inside = format!("{sep}books{sep}a.zip");
+    let nested = format!("{sep}books{sep}sub{sep}b.zip");
+    let sibling = format!("{sep}books2{sep}c.zip");
+    for path in [&inside, &nested, &sibling] {
+        book_repo
+            .register_book(path, "file", "Book", 10, None)
+            .await
+            .unwrap();
+    }
+
+    let mut paths: Vec<String> = repo
+        .get_indexed_books(&format!("{sep}books"))
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|book| book.file_path)
+        .collect();
+    paths.sort();
+    assert_eq!(paths, vec![inside.clone(), nested]);
+
+    let single = repo.get_indexed_books(&inside).await.unwrap();
+    assert_eq!(single.len(), 1);
+    assert_eq!(single[0].file_modified_at, None);
+    assert!(!single[0].missing);
+}
+
+#[tokio::test]
+async fn test_missing_flag_and_file_state() {
+    let pool = setup_db().await;
+    let book_repo = SqliteBookRepository::new(pool.clone());
+    let repo = SqliteLibraryRepository::new(pool);
+
+    let book_id = book_repo
+        .register_book("/books/a.zip", "file", "a.zip", 10, None)
+        .await
+        .unwrap();
+
+    repo.mark_missing(book_id).await.unwrap();
+    let book = book_repo
+        .get_book_with_state_by_id(book_id)
+        .await
+        .unwrap()
+        .unwrap();
+    let missing_since = book.missing_since.expect("book should be flagged missing");
+
+    // Flagging again keeps the original time.
+    repo.mark_missing(book_id).await.unwrap();
+    let book = book_repo
+        .get_book_with_state_by_id(book_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(book.missing_since, Some(missing_since));
+    assert!(repo.get_indexed_books("/books/a.zip").await.unwrap()[0].missing);
+
+    repo.set_file_indexed(book_id, Some(1_700_000_000))
+        .await
+        .unwrap();
+    let indexed = repo.get_indexed_books("/books/a.zip").await.unwrap();
+    assert_eq!(indexed[0].file_modified_at, Some(1_700_000_000));
+    assert!(!indexed[0].missing);
+}
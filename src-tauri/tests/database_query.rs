@@ -0,0 +1,290 @@
+use chrono::NaiveDate;
+use rookreader_lib::domain::book::entity::{
+    BookQuery, BookSortKey, ReadStatus, ReadingState, TagMatch,
+};
+use rookreader_lib::domain::book::repository::BookRepository;
+use rookreader_lib::domain::bookshelf::repository::BookshelfRepository;
+use rookreader_lib::domain::series::repository::SeriesRepository;
+use rookreader_lib::domain::tag::repository::TagRepository;
+use rookreader_lib::infrastructure::database::book_repository::SqliteBookRepository;
+use rookreader_lib::infrastructure::database::bookshelf_repository::SqliteBookshelfRepository;
+use rookreader_lib::infrastructure::database::series_repository::SqliteSeriesRepository;
+use rookreader_lib::infrastructure::database::tag_repository::SqliteTagRepository;
+
+mod common;
+use common::setup_db;
+
+async fn query(repo: &SqliteBookRepository, query: BookQuery) -> Vec<i64> {
+    repo.query_books(&query)
+        .await
+        .unwrap()
+        .books
+        .into_iter()
+        .map(|book| book.id)
+        .collect()
+}
+
+async fn register(repo: &SqliteBookRepository, name: &str, total_pages: i64) -> i64 {
+    repo.register_book(
+        &format!("/books/{name}.zip"),
+        "file",
+        name,
+        total_pages,
+        None,
+    )
+    .await
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_query_sorts_names_naturally_and_pages() {
+    let pool = setup_db().await;
+    let repo = SqliteBookRepository::new(pool);
+
+    let vol10 = register(&repo, "Vol 10", 10).await;
+    let vol2 = register(&repo, "vol 2", 30).await;
+    let vol1 = register(&repo, "Vol 1", 20).await;
+
+    assert_eq!(
+        query(&repo, BookQuery::default()).await,
+        vec![vol1, vol2, vol10]
+    );
+    assert_eq!(
+        query(
+            &repo,
+            BookQuery {
+                descending: true,
+                ..Default::default()
+            }
+        )
+        .await,
+        vec![vol10, vol2, vol1]
+    );
+    assert_eq!(
+        query(
+            &repo,
+            BookQuery {
+                sort: BookSortKey::TotalPages,
+                ..Default::default()
+            }
+        )
+        .await,
+        vec![vol10, vol1, vol2]
+    );
+
+    let page = repo
+        .query_books(&BookQuery {
+            limit: Some(1),
+            offset: 1,
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(page.total_count, 3);
+    assert_eq!(
+        page.books.iter().map(|book| book.id).collect::<Vec<_>>(),
+        vec![vol2]
+    );
+}
+
+#[tokio::test]
+async fn test_query_filters_by_tags() {
+    let pool = setup_db().await;
+    let repo = SqliteBookRepository::new(pool.clone());
+    let tag_repo = SqliteTagRepository::new(pool);
+
+    let both = register(&repo, "Book 1", 10).await;
+    let red_only = register(&repo, "Book 2", 10).await;
+    let untagged = register(&repo, "Book 3", 10).await;
+
+    let red = tag_repo.create("Red", "#ff0000").await.unwrap();
+    let blue = tag_repo.create("Blue", "#0000ff").await.unwrap();
+    tag_repo
+        .attach_tags_to_book(both, &[red.id, blue.id])
+        .await
+        .unwrap();
+    tag_repo
+        .attach_tags_to_book(red_only, &[red.id])
+        .await
+        .unwrap();
+
+    let all_tags = BookQuery {
+        include_tag_ids: vec![red.id, blue.id],
+        ..Default::default()
+    };
+    assert_eq!(query(&repo, all_tags.clone()).await, vec![both]);
+    let any_tag = BookQuery {
+        tag_match: TagMatch::Any,
+        ..all_tags
+    };
+    assert_eq!(query(&repo, any_tag).await, vec![both, red_only]);
+    let without_blue = BookQuery {
+        exclude_tag_ids: vec![blue.id],
+        ..Default::default()
+    };
+    assert_eq!(query(&repo, without_blue).await, vec![red_only, untagged]);
+}
+
+#[tokio::test]
+async fn test_query_filters_by_bookshelf_and_read_status() {
+    let pool = setup_db().await;
+    let repo = SqliteBookRepository::new(pool.clone());
+    let bookshelf_repo = SqliteBookshelfRepository::new(pool);
+
+    let unread = register(&repo, "Book 1", 10).await;
+    let reading = register(&repo, "Book 2", 10).await;
+    let read = register(&repo, "Book 3", 10).await;
+    for (book_id, page) in [(reading, 4), (read, 9)] {
+        repo.update_reading_progress(&ReadingState {
+            book_id,
+            last_read_page_index: page,
+            last_read_cfi: None,
+            last_opened_at: None,
+        })
+        .await
+        .unwrap();
+    }
+
+    for (status, expected) in [
+        (ReadStatus::Unread, unread),
+        (ReadStatus::Reading, reading),
+        (ReadStatus::Read, read),
+    ] {
+        let by_status = BookQuery {
+            read_status: Some(status),
+            ..Default::default()
+        };
+        assert_eq!(query(&repo, by_status).await, vec![expected]);
+    }
+
+    let shelf = bookshelf_repo.create("Shelf", "icon").await.unwrap();
+    bookshelf_repo
+        .add_book_to_bookshelf(shelf.id, read)
+        .await
+        .unwrap();
+    let on_shelf = BookQuery {
+        bookshelf_id: Some(shelf.id),
+        ..Default::default()
+    };
+    assert_eq!(query(&repo, on_shelf).await, vec![read]);
+}
+
+#[tokio::test]
+async fn test_query_filters_and_sorts_by_dates() {
+    let pool = setup_db().await;
+    let repo = SqliteBookRepository::new(pool.clone());
+
+    let old = register(&repo, "Book 1", 10).await;
+    let new = register(&repo, "Book 2", 10).await;
+    let never_opened = register(&repo, "Book 3", 10).await;
+    let date = |day| {
+        NaiveDate::from_ymd_opt(2026, 1, day)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+    };
+    for (book_id, day) in [(old, 1), (new, 20), (never_opened, 10)] {
+        sqlx::query("UPDATE books SET created_at = ? WHERE id = ?")
+            .bind(date(day))
+            .bind(book_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+    }
+    for (book_id, day) in [(old, 5), (new, 25)] {
+        repo.update_reading_progress(&ReadingState {
+            book_id,
+            last_read_page_index: 0,
+            last_read_cfi: None,
+            last_opened_at: Some(date(day)),
+        })
+        .await
+        .unwrap();
+    }
+
+    let added_early = BookQuery {
+        added_after: Some(date(1)),
+        added_before: Some(date(15)),
+        ..Default::default()
+    };
+    assert_eq!(query(&repo, added_early).await, vec![old, never_opened]);
+    let opened_late = BookQuery {
+        opened_after: Some(date(10)),
+        ..Default::default()
+    };
+    assert_eq!(query(&repo, opened_late).await, vec![new]);
+
+    let newest_first = BookQuery {
+        sort: BookSortKey::AddedAt,
+        descending: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        query(&repo, newest_first).await,
+        vec![new, never_opened, old]
+    );
+    // Books never opened come last in either direction.
+    let recently_opened = BookQuery {
+        sort: BookSortKey::LastOpenedAt,
+        descending: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        query(&repo, recently_opened).await,
+        vec![new, old, never_opened]
+    );
+    let least_recently_opened = BookQuery {
+        sort: BookSortKey::LastOpenedAt,
+        ..Default::default()
+    };
+    assert_eq!(
+        query(&repo, least_recently_opened).await,
+        vec![old, new, never_opened]
+    );
+}
+
+#[tokio::test]
+async fn test_query_sorts_by_series_then_series_order() {
+    let pool = setup_db().await;
+    let repo = SqliteBookRepository::new(pool.clone());
+    let series_repo = SqliteSeriesRepository::new(pool);
+
+    let series_b = series_repo.create("Series 10").await.unwrap();
+    let series_a = series_repo.create("Series 2").await.unwrap();
+    // Register and assign the books interleaved, so ids and orders alternate series.
+    let b1 = register(&repo, "B 1", 10).await;
+    let a1 = register(&repo, "A 1", 10).await;
+    let b2 = register(&repo, "B 2", 10).await;
+    let a2 = register(&repo, "A 2", 10).await;
+    let standalone = register(&repo, "Standalone", 10).await;
+    for (book_id, series_id) in [
+        (b1, series_b),
+        (a1, series_a),
+        (b2, series_b),
+        (a2, series_a),
+    ] {
+        series_repo
+            .assign_book_to_series(book_id, Some(series_id))
+            .await
+            .unwrap();
+    }
+
+    let by_series = BookQuery {
+        sort: BookSortKey::SeriesOrder,
+        ..Default::default()
+    };
+    assert_eq!(
+        query(&repo, by_series).await,
+        vec![a1, a2, b1, b2, standalone]
+    );
+    // Books outside a series come last in either direction.
+    let by_series_descending = BookQuery {
+        sort: BookSortKey::SeriesOrder,
+        descending: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        query(&repo, by_series_descending).await,
+        vec![b2, b1, a2, a1, standalone]
+    );
+}
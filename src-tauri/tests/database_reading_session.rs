@@ -0,0 +1,277 @@
+use chrono::{FixedOffset, NaiveDate, Utc};
+use rookreader_lib::domain::book::entity::{ReadStatus, ReadingState};
+use rookreader_lib::domain::book::repository::BookRepository;
+use rookreader_lib::domain::reading_session::entity::{DailyReading, MonthlyFinished};
+use rookreader_lib::domain::reading_session::repository::ReadingSessionRepository;
+use rookreader_lib::infrastructure::database::book_repository::SqliteBookRepository;
+use rookreader_lib::infrastructure::database::reading_session_repository::SqliteReadingSessionRepository;
+use sqlx::SqlitePool;
+
+mod common;
+use common::setup_db;
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+fn tokyo() -> FixedOffset {
+    FixedOffset::east_opt(9 * 3600).unwrap()
+}
+
+async fn add_session(
+    pool: &SqlitePool,
+    book_id: i64,
+    started_at: &str,
+    ended_at: &str,
+    pages_turned: i64,
+) {
+    sqlx::query(
+        "INSERT INTO reading_sessions (book_id, started_at, ended_at, start_page, end_page, pages_turned)
+         VALUES (?, ?, ?, 0, ?, ?)",
+    )
+    .bind(book_id)
+    .bind(started_at)
+    .bind(ended_at)
+    .bind(pages_turned)
+    .bind(pages_turned)
+    .execute(pool)
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn test_sessions_follow_reading_progress() {
+    let pool = setup_db().await;
+    let book_repo = SqliteBookRepository::new(pool.clone());
+    let repo = SqliteReadingSessionRepository::new(pool.clone());
+    let progress = |book_id, last_read_page_index| ReadingState {
+        book_id,
+        last_read_page_index,
+        last_read_cfi: None,
+        last_opened_at: None,
+    };
+
+    let book_id = book_repo
+        .record_book_opened("/path/to/book.zip", "file", "Book", 10, None)
+        .await
+        .unwrap();
+    for page in [1, 3, 2, 3, 6] {
+        book_repo
+            .update_reading_progress(&progress(book_id, page))
+            .await
+            .unwrap();
+    }
+
+    // Opening the book and turning pages right away make up one session; paging back
+    // and jumping ahead are not counted.
+    let sessions = repo.get_sessions_for_book(book_id).await.unwrap();
+    assert_eq!(sessions.len(), 1);
+    assert_eq!(
+        (
+            sessions[0].start_page,
+            sessions[0].end_page,
+            sessions[0].pages_turned
+        ),
+        (0, 6, 4)
+    );
+
+    // Turning a page after a break starts a new session.
+    sqlx::query("UPDATE reading_sessions SET ended_at = datetime(ended_at, '-1 hour')")
+        .execute(&pool)
+        .await
+        .unwrap();
+    for page in [7, 8, 9] {
+        book_repo
+            .update_reading_progress(&progress(book_id, page))
+            .await
+            .unwrap();
+    }
+    let sessions = repo.get_sessions_for_book(book_id).await.unwrap();
+    assert_eq!(sessions.len(), 2);
+    assert_eq!(
+        (
+            sessions[0].start_page,
+            sessions[0].end_page,
+            sessions[0].pages_turned
+        ),
+        (6, 9, 3)
+    );
+
+    // Reaching the last page counts the book as finished this month.
+    let today = Utc::now().with_timezone(&tokyo()).date_naive();
+    let statistics = repo.get_statistics(today, today, tokyo()).await.unwrap();
+    assert_eq!(statistics.total_pages_read, 7);
+    assert_eq!(statistics.total_books_finished, 1);
+    assert_eq!(statistics.current_streak_days, 1);
+
+    // A book no longer marked as read is not counted as finished.
+    book_repo
+        .set_read_status(&[book_id], ReadStatus::Reading)
+        .await
+        .unwrap();
+    let statistics = repo.get_statistics(today, today, tokyo()).await.unwrap();
+    assert_eq!(statistics.total_books_finished, 0);
+
+    // Clearing the reading history keeps the statistics.
+    book_repo.clear_reading_history(book_id).await.unwrap();
+    book_repo.clear_all_reading_history().await.unwrap();
+    assert_eq!(repo.get_sessions_for_book(book_id).await.unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn test_statistics_use_the_offset_each_session_was_recorded_at() {
+    let pool = setup_db().await;
+    let book_repo = SqliteBookRepository::new(pool.clone());
+    let repo = SqliteReadingSessionRepository::new(pool.clone());
+    let book_id = book_repo
+        .register_book("/books/1.zip", "file", "Book 1", 100, None)
+        .await
+        .unwrap();
+
+    // 23:30 UTC is 00:30 the next day in summer time (UTC+1) but still the same day in
+    // winter time (UTC+0), which is the offset the statistics are requested in.
+    add_session(
+        &pool,
+        book_id,
+        "2025-07-01 23:30:00",
+        "2025-07-01 23:40:00",
+        5,
+    )
+    .await;
+    sqlx::query("UPDATE reading_sessions SET utc_offset_seconds = 3600")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let utc = FixedOffset::east_opt(0).unwrap();
+    let statistics = repo
+        .get_statistics(date(2025, 1, 1), date(2025, 12, 31), utc)
+        .await
+        .unwrap();
+    assert_eq!(
+        statistics.daily,
+        vec![DailyReading {
+            date: date(2025, 7, 2),
+            seconds_read: 600,
+            pages_read: 5,
+        }]
+    );
+}
+
+#[tokio::test]
+async fn test_statistics_are_grouped_by_local_date() {
+    let pool = setup_db().await;
+    let book_repo = SqliteBookRepository::new(pool.clone());
+    let repo = SqliteReadingSessionRepository::new(pool.clone());
+
+    let series_id =
+        sqlx::query_scalar::<_, i64>("INSERT INTO series (name) VALUES ('Series') RETURNING id")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+    let in_series = book_repo
+        .register_book("/books/1.zip", "file", "Book 1", 100, None)
+        .await
+        .unwrap();
+    let standalone = book_repo
+        .register_book("/books/2.zip", "file", "Book 2", 100, None)
+        .await
+        .unwrap();
+    sqlx::query("UPDATE books SET series_id = ? WHERE id = ?")
+        .bind(series_id)
+        .bind(in_series)
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("UPDATE books SET read_status = 'read', finished_at = ? WHERE id = ?")
+        .bind("2025-03-31 16:00:00")
+        .bind(in_series)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    // Started at 23:30 on March 1st, and at 08:00 on March 2nd and 3rd, in Tokyo.
+    add_session(
+        &pool,
+        in_series,
+        "2025-03-01 14:30:00",
+        "2025-03-01 15:00:00",
+        20,
+    )
+    .await;
+    add_session(
+        &pool,
+        standalone,
+        "2025-03-01 23:00:00",
+        "2025-03-01 23:10:00",
+        5,
+    )
+    .await;
+    add_session(
+        &pool,
+        in_series,
+        "2025-03-02 23:00:00",
+        "2025-03-02 23:20:00",
+        10,
+    )
+    .await;
+    // Outside the period.
+    add_session(
+        &pool,
+        standalone,
+        "2024-12-31 00:00:00",
+        "2024-12-31 01:00:00",
+        50,
+    )
+    .await;
+
+    let statistics = repo
+        .get_statistics(date(2025, 1, 1), date(2025, 12, 31), tokyo())
+        .await
+        .unwrap();
+    assert_eq!(
+        statistics.daily,
+        vec![
+            DailyReading {
+                date: date(2025, 3, 1),
+                seconds_read: 1800,
+                pages_read: 20,
+            },
+            DailyReading {
+                date: date(2025, 3, 2),
+                seconds_read: 600,
+                pages_read: 5,
+            },
+            DailyReading {
+                date: date(2025, 3, 3),
+                seconds_read: 1200,
+                pages_read: 10,
+            },
+        ]
+    );
+    assert_eq!(statistics.total_seconds_read, 3600);
+    assert_eq!(statistics.total_pages_read, 35);
+    // Finished at 01:00 on April 1st in Tokyo.
+    assert_eq!(
+        statistics.monthly_finished,
+        vec![MonthlyFinished {
+            year: 2025,
+            month: 4,
+            books_finished: 1,
+        }]
+    );
+    assert_eq!(statistics.longest_streak_days, 3);
+    assert_eq!(statistics.current_streak_days, 0);
+
+    assert_eq!(statistics.series.len(), 1);
+    let series = &statistics.series[0];
+    assert_eq!(series.series_name, "Series");
+    assert_eq!(
+        (
+            series.seconds_read,
+            series.pages_read,
+            series.books_finished
+        ),
+        (3000, 30, 1)
+    );
+}
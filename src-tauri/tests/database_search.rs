@@ -0,0 +1,270 @@
+use rookreader_lib::domain::book::entity::{BookMetadata, BookSearchFilters};
+use rookreader_lib::domain::book::repository::BookRepository;
+use rookreader_lib::domain::bookshelf::repository::BookshelfRepository;
+use rookreader_lib::domain::creator::entity::{CreatorCredit, CreatorRole};
+use rookreader_lib::domain::series::repository::SeriesRepository;
+use rookreader_lib::domain::tag::repository::TagRepository;
+use rookreader_lib::infrastructure::database::book_repository::SqliteBookRepository;
+use rookreader_lib::infrastructure::database::bookshelf_repository::SqliteBookshelfRepository;
+use rookreader_lib::infrastructure::database::search_index;
+use rookreader_lib::infrastructure::database::series_repository::SqliteSeriesRepository;
+use rookreader_lib::infrastructure::database::tag_repository::SqliteTagRepository;
+
+mod common;
+use common::setup_db;
+
+async fn search(repo: &SqliteBookRepository, query: &str) -> Vec<i64> {
+    repo.search_books(query, &BookSearchFilters::default(), 100, 0)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|book| book.id)
+        .collect()
+}
+
+#[tokio::test]
+async fn test_search_by_name_and_path() {
+    let pool = setup_db().await;
+    let repo = SqliteBookRepository::new(pool);
+
+    let pirates = repo
+        .register_book(
+            "/comics/pirates/vol1.zip",
+            "file",
+            "ワンピース 第1巻",
+            10,
+            None,
+        )
+        .await
+        .unwrap();
+    let ninjas = repo
+        .register_book("/comics/ninjas.zip", "file", "Ninja Story", 10, None)
+        .await
+        .unwrap();
+
+    assert_eq!(search(&repo, "ワンピース").await, vec![pirates]);
+    assert_eq!(search(&repo, "ninja").await, vec![ninjas]);
+    assert_eq!(search(&repo, "pirates").await, vec![pirates]);
+    // Every term must match.
+    assert_eq!(search(&repo, "comics story").await, vec![ninjas]);
+    assert!(search(&repo, "ninja 第1巻").await.is_empty());
+    // Terms shorter than a trigram are matched too.
+    assert_eq!(search(&repo, "1巻").await, vec![pirates]);
+    // An empty query matches every book, ordered by display name.
+    assert_eq!(search(&repo, "  ").await, vec![ninjas, pirates]);
+}
+
+#[tokio::test]
+async fn test_search_ranks_display_name_matches_first() {
+    let pool = setup_db().await;
+    let repo = SqliteBookRepository::new(pool);
+
+    let in_path = repo
+        .register_book("/dragon/book.zip", "file", "Book", 10, None)
+        .await
+        .unwrap();
+    let in_name = repo
+        .register_book("/other/book.zip", "file", "Dragon", 10, None)
+        .await
+        .unwrap();
+
+    assert_eq!(search(&repo, "dragon").await, vec![in_name, in_path]);
+}
+
+#[tokio::test]
+async fn test_search_follows_series_tags_and_metadata() {
+    let pool = setup_db().await;
+    let repo = SqliteBookRepository::new(pool.clone());
+    let series_repo = SqliteSeriesRepository::new(pool.clone());
+    let tag_repo = SqliteTagRepository::new(pool);
+
+    let book_id = repo
+        .register_book("/books/a.epub", "file", "a.epub", 10, None)
+        .await
+        .unwrap();
+
+    let series_id = series_repo.create("Galaxy Saga").await.unwrap();
+    series_repo
+        .assign_book_to_series(book_id, Some(series_id))
+        .await
+        .unwrap();
+    assert_eq!(search(&repo, "galaxy").await, vec![book_id]);
+    series_repo
+        .assign_book_to_series(book_id, None)
+        .await
+        .unwrap();
+    assert!(search(&repo, "galaxy").await.is_empty());
+
+    let tag = tag_repo.create("Favourite", "#ff0000").await.unwrap();
+    tag_repo
+        .attach_tags_to_book(book_id, &[tag.id])
+        .await
+        .unwrap();
+    assert_eq!(search(&repo, "favourite").await, vec![book_id]);
+    tag_repo.delete(tag.id).await.unwrap();
+    assert!(search(&repo, "favourite").await.is_empty());
+
+    let metadata = BookMetadata {
+        creators: vec![CreatorCredit::new("尾田栄一郎", CreatorRole::Author)],
+        publisher: Some("Shueisha".to_string()),
+        description: Some("A pirate adventure.".to_string()),
+    };
+    repo.set_metadata(book_id, &metadata).await.unwrap();
+    assert_eq!(search(&repo, "尾田栄一郎").await, vec![book_id]);
+    assert_eq!(search(&repo, "shueisha adventure").await, vec![book_id]);
+
+    repo.set_metadata(book_id, &BookMetadata::default())
+        .await
+        .unwrap();
+    assert!(search(&repo, "shueisha").await.is_empty());
+
+    repo.delete_book(book_id).await.unwrap();
+    assert!(search(&repo, "epub").await.is_empty());
+}
+
+#[tokio::test]
+async fn test_writes_keep_the_index_up_to_date() {
+    let pool = setup_db().await;
+    let repo = SqliteBookRepository::new(pool.clone());
+
+    let book_id = repo
+        .register_book("/books/dune.epub", "file", "Dune", 10, None)
+        .await
+        .unwrap();
+    // The write indexed the book, so a search has nothing left to index.
+    let dirty: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM book_search_dirty")
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(dirty, 0);
+    assert_eq!(search(&repo, "dune").await, vec![book_id]);
+
+    // Changes made outside the repositories, such as by a migration, wait for a refresh.
+    sqlx::query("UPDATE books SET display_name = 'Arrakis' WHERE id = ?")
+        .bind(book_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+    assert!(search(&repo, "arrakis").await.is_empty());
+    search_index::refresh(&pool).await.unwrap();
+    assert_eq!(search(&repo, "arrakis").await, vec![book_id]);
+}
+
+#[tokio::test]
+async fn test_search_filters_and_paging() {
+    let pool = setup_db().await;
+    let repo = SqliteBookRepository::new(pool.clone());
+    let bookshelf_repo = SqliteBookshelfRepository::new(pool.clone());
+    let tag_repo = SqliteTagRepository::new(pool);
+
+    let mut ids = Vec::new();
+    for i in 1..=4 {
+        let id = repo
+            .register_book(
+                &format!("/books/book{i}.zip"),
+                "file",
+                &format!("Book {i}"),
+                10,
+                None,
+            )
+            .await
+            .unwrap();
+        ids.push(id);
+    }
+    let folder = repo
+        .register_book("/books/folder", "directory", "Book 5", 10, None)
+        .await
+        .unwrap();
+
+    let red = tag_repo.create("Red", "#ff0000").await.unwrap();
+    let blue = tag_repo.create("Blue", "#0000ff").await.unwrap();
+    tag_repo
+        .attach_tags_to_book(ids[0], &[red.id, blue.id])
+        .await
+        .unwrap();
+    tag_repo
+        .attach_tags_to_book(ids[1], &[red.id])
+        .await
+        .unwrap();
+
+    let shelf = bookshelf_repo.create("Shelf", "icon").await.unwrap();
+    bookshelf_repo
+        .add_book_to_bookshelf(shelf.id, ids[2])
+        .await
+        .unwrap();
+
+    let search_with = |filters: BookSearchFilters| {
+        let repo = &repo;
+        async move {
+            repo.search_books("book", &filters, 100, 0)
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|book| book.id)
+                .collect::<Vec<_>>()
+        }
+    };
+
+    let mut tagged = search_with(BookSearchFilters {
+        tag_ids: vec![red.id],
+        ..Default::default()
+    })
+    .await;
+    tagged.sort();
+    assert_eq!(tagged, vec![ids[0], ids[1]]);
+    let both_tags = search_with(BookSearchFilters {
+        tag_ids: vec![red.id, blue.id],
+        ..Default::default()
+    })
+    .await;
+    assert_eq!(both_tags, vec![ids[0]]);
+    let on_shelf = search_with(BookSearchFilters {
+        bookshelf_id: Some(shelf.id),
+        ..Default::default()
+    })
+    .await;
+    assert_eq!(on_shelf, vec![ids[2]]);
+    let folders = search_with(BookSearchFilters {
+        item_type: Some("directory".to_string()),
+        ..Default::default()
+    })
+    .await;
+    assert_eq!(folders, vec![folder]);
+
+    let all = search(&repo, "").await;
+    let page = repo
+        .search_books("", &BookSearchFilters::default(), 2, 1)
+        .await
+        .unwrap();
+    assert_eq!(
+        page.iter().map(|book| book.id).collect::<Vec<_>>(),
+        all[1..3].to_vec()
+    );
+}
+
+#[tokio::test]
+async fn test_search_ignores_kana_width_and_case() {
+    let pool = setup_db().await;
+    let repo = SqliteBookRepository::new(pool.clone());
+    let tag_repo = SqliteTagRepository::new(pool);
+
+    let half_width = repo
+        .register_book("/comics/a.zip", "file", "ﾜﾝﾋﾟｰｽ", 10, None)
+        .await
+        .unwrap();
+    let full_width = repo
+        .register_book("/comics/ＶＯＬ１.zip", "file", "Other", 10, None)
+        .await
+        .unwrap();
+    let tag = tag_repo.create("キャラクター", "#ff0000").await.unwrap();
+    tag_repo
+        .attach_tags_to_book(full_width, &[tag.id])
+        .await
+        .unwrap();
+
+    assert_eq!(search(&repo, "ワンピース").await, vec![half_width]);
+    assert_eq!(search(&repo, "わんぴーす").await, vec![half_width]);
+    assert_eq!(search(&repo, "vol1").await, vec![full_width]);
+    assert_eq!(search(&repo, "ＶＯＬ１").await, vec![full_width]);
+    assert_eq!(search(&repo, "きゃら").await, vec![full_width]);
+}
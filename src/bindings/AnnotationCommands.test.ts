@@ -0,0 +1,51 @@
+import { invoke } from "@tauri-apps/api/core";
+import { beforeEach, describe, expect, it, vi } from "vitest";
+import { CommandError } from "../types/Error";
+import * as AnnotationCommands from "./AnnotationCommands";
+
+vi.unmock("./AnnotationCommands");
+
+describe("AnnotationCommands", () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+  });
+
+  it("createAnnotation should call invoke", async () => {
+    const annotation = {
+      book_id: 1,
+      cfi_range: "epubcfi(/6/4!/4/2,/1:0,/1:8)",
+      selected_text: "Passage",
+      note: "",
+      color_code: "#FFFF00",
+    };
+    const mockAnnotation = { ...annotation, id: 5, created_at: "2026-03-01T10:00:00" };
+    vi.mocked(invoke).mockResolvedValue(mockAnnotation);
+    const result = await AnnotationCommands.createAnnotation(annotation);
+    expect(invoke).toHaveBeenCalledWith("create_annotation", { annotation });
+    expect(result).toEqual(mockAnnotation);
+  });
+
+  it("getAnnotations should call invoke", async () => {
+    vi.mocked(invoke).mockResolvedValue([]);
+    await AnnotationCommands.getAnnotations(1);
+    expect(invoke).toHaveBeenCalledWith("get_annotations", { bookId: 1 });
+  });
+
+  it("deleteAnnotation should call invoke", async () => {
+    vi.mocked(invoke).mockResolvedValue(null);
+    await AnnotationCommands.deleteAnnotation(5);
+    expect(invoke).toHaveBeenCalledWith("delete_annotation", { id: 5 });
+  });
+
+  it("exportAnnotations should call invoke", async () => {
+    vi.mocked(invoke).mockResolvedValue("# Novel\n");
+    const result = await AnnotationCommands.exportAnnotations(1, "markdown");
+    expect(invoke).toHaveBeenCalledWith("export_annotations", { bookId: 1, format: "markdown" });
+    expect(result).toBe("# Novel\n");
+  });
+
+  it("exportAnnotations should throw CommandError on failure", async () => {
+    vi.mocked(invoke).mockRejectedValue(new Error("fail"));
+    await expect(AnnotationCommands.exportAnnotations(9, "json")).rejects.toThrow(CommandError);
+  });
+});
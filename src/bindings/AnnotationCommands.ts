@@ -0,0 +1,47 @@
+import { type AnnotationExportFormat, commands, type NewAnnotation } from "./bindings";
+import { runCommand } from "./result";
+
+/**
+ * Highlights a passage of a novel.
+ *
+ * @param annotation - The annotation to add, with the CFI range of the passage.
+ * @returns A promise that resolves to the created Annotation object.
+ * @throws {CommandError} If the CFI range is empty, or the command fails.
+ */
+export async function createAnnotation(annotation: NewAnnotation) {
+  return await runCommand(commands.createAnnotation(annotation));
+}
+
+/**
+ * Retrieves the annotations of a book, in reading order.
+ *
+ * @param bookId - The unique identifier of the book.
+ * @returns A promise that resolves to an array of Annotation objects.
+ * @throws {CommandError} If the Tauri command fails.
+ */
+export async function getAnnotations(bookId: number) {
+  return await runCommand(commands.getAnnotations(bookId));
+}
+
+/**
+ * Deletes an annotation.
+ *
+ * @param id - The unique identifier of the annotation.
+ * @returns A promise that resolves when the annotation is deleted.
+ * @throws {CommandError} If the Tauri command fails.
+ */
+export async function deleteAnnotation(id: number): Promise<void> {
+  await runCommand(commands.deleteAnnotation(id));
+}
+
+/**
+ * Exports the annotations of a book as a Markdown or JSON document.
+ *
+ * @param bookId - The unique identifier of the book.
+ * @param format - The format of the document.
+ * @returns A promise that resolves to the document text.
+ * @throws {CommandError} If the book does not exist, or the command fails.
+ */
+export async function exportAnnotations(bookId: number, format: AnnotationExportFormat) {
+  return await runCommand(commands.exportAnnotations(bookId, format));
+}
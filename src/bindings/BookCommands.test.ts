@@ -70,6 +70,93 @@ describe("BookCommands", () => {
     await expect(BookCommands.getAllBooksWithState()).rejects.toThrow(CommandError);
   });
 
+  it("searchBooks should call invoke with default filters and paging", async () => {
+    vi.mocked(invoke).mockResolvedValue([]);
+    await BookCommands.searchBooks({ query: "ワンピース" });
+    expect(invoke).toHaveBeenCalledWith("search_books", {
+      query: "ワンピース",
+      filters: { tag_ids: [], series_id: null, bookshelf_id: null, item_type: null },
+      limit: 100,
+      offset: 0,
+    });
+  });
+
+  it("searchBooks should pass filters and paging", async () => {
+    vi.mocked(invoke).mockResolvedValue([]);
+    await BookCommands.searchBooks({
+      query: "vol",
+      filters: { tag_ids: [1, 2], series_id: 3 },
+      limit: 20,
+      offset: 40,
+    });
+    expect(invoke).toHaveBeenCalledWith("search_books", {
+      query: "vol",
+      filters: { tag_ids: [1, 2], series_id: 3, bookshelf_id: null, item_type: null },
+      limit: 20,
+      offset: 40,
+    });
+  });
+
+  it("queryBooks should call invoke with defaults", async () => {
+    vi.mocked(invoke).mockResolvedValue({ books: [], total_count: 0 });
+    const result = await BookCommands.queryBooks();
+    expect(invoke).toHaveBeenCalledWith("query_books", {
+      query: {
+        include_tag_ids: [],
+        tag_match: "all",
+        exclude_tag_ids: [],
+        bookshelf_id: null,
+        series_id: null,
+        read_status: null,
+        added_after: null,
+        added_before: null,
+        opened_after: null,
+        opened_before: null,
+        item_type: null,
+        path_prefix: null,
+        formats: [],
+        sort: "name",
+        descending: false,
+        limit: null,
+        offset: 0,
+      },
+    });
+    expect(result).toEqual({ books: [], total_count: 0 });
+  });
+
+  it("queryBooks should pass the given conditions and paging", async () => {
+    vi.mocked(invoke).mockResolvedValue({ books: [], total_count: 0 });
+    await BookCommands.queryBooks({
+      include_tag_ids: [1, 2],
+      tag_match: "any",
+      read_status: "reading",
+      sort: "last_opened_at",
+      descending: true,
+      limit: 50,
+      offset: 100,
+    });
+    expect(invoke).toHaveBeenCalledWith(
+      "query_books",
+      expect.objectContaining({
+        query: expect.objectContaining({
+          include_tag_ids: [1, 2],
+          tag_match: "any",
+          exclude_tag_ids: [],
+          read_status: "reading",
+          sort: "last_opened_at",
+          descending: true,
+          limit: 50,
+          offset: 100,
+        }),
+      }),
+    );
+  });
+
+  it("queryBooks should throw CommandError on failure", async () => {
+    vi.mocked(invoke).mockRejectedValue(mockError);
+    await expect(BookCommands.queryBooks()).rejects.toThrow(CommandError);
+  });
+
   it("registerBook should call invoke", async () => {
     vi.mocked(invoke).mockResolvedValue(10);
     const params = { filePath: "p", itemType: "file" as const, displayName: "d", totalPages: 100 };
@@ -141,6 +228,47 @@ describe("BookCommands", () => {
     await expect(BookCommands.updateBookTags(1, [1])).rejects.toThrow(CommandError);
   });
 
+  it("getBookExclusionRules should call invoke", async () => {
+    const rules = [{ pattern: "credits*.jpg", syntax: "glob" }];
+    vi.mocked(invoke).mockResolvedValue(rules);
+    const result = await BookCommands.getBookExclusionRules(1);
+    expect(invoke).toHaveBeenCalledWith("get_book_exclusion_rules", { bookId: 1 });
+    expect(result).toEqual(rules);
+  });
+
+  it("setBookExclusionRules should call invoke", async () => {
+    vi.mocked(invoke).mockResolvedValue(undefined);
+    const rules = [{ pattern: "^zz_", syntax: "regex" as const }];
+    await BookCommands.setBookExclusionRules(1, rules);
+    expect(invoke).toHaveBeenCalledWith("set_book_exclusion_rules", { bookId: 1, rules });
+  });
+
+  it("setBookExclusionRules should throw CommandError on failure", async () => {
+    vi.mocked(invoke).mockRejectedValue(mockError);
+    await expect(BookCommands.setBookExclusionRules(1, [])).rejects.toThrow(CommandError);
+  });
+
+  it("getBookPageOrder should call invoke", async () => {
+    vi.mocked(invoke).mockResolvedValue(["2.jpg", "1.jpg"]);
+    const result = await BookCommands.getBookPageOrder(1);
+    expect(invoke).toHaveBeenCalledWith("get_book_page_order", { bookId: 1 });
+    expect(result).toEqual(["2.jpg", "1.jpg"]);
+  });
+
+  it("setBookPageOrder should call invoke", async () => {
+    vi.mocked(invoke).mockResolvedValue(undefined);
+    await BookCommands.setBookPageOrder(1, ["2.jpg", "1.jpg"]);
+    expect(invoke).toHaveBeenCalledWith("set_book_page_order", {
+      bookId: 1,
+      pageOrder: ["2.jpg", "1.jpg"],
+    });
+  });
+
+  it("setBookPageOrder should throw CommandError on failure", async () => {
+    vi.mocked(invoke).mockRejectedValue(mockError);
+    await expect(BookCommands.setBookPageOrder(1, [])).rejects.toThrow(CommandError);
+  });
+
   it("updateReadingProgress should call invoke", async () => {
     vi.mocked(invoke).mockResolvedValue(undefined);
     const state = { book_id: 1, last_read_page_index: 5, last_opened_at: "now" };
@@ -154,6 +282,32 @@ describe("BookCommands", () => {
     await expect(BookCommands.updateReadingProgress(state)).rejects.toThrow(CommandError);
   });
 
+  it("setBooksReadStatus should call invoke", async () => {
+    vi.mocked(invoke).mockResolvedValue(null);
+    await BookCommands.setBooksReadStatus([1, 2], "want_to_read");
+    expect(invoke).toHaveBeenCalledWith("set_books_read_status", {
+      bookIds: [1, 2],
+      status: "want_to_read",
+    });
+  });
+
+  it("setBooksRating should call invoke", async () => {
+    vi.mocked(invoke).mockResolvedValue(null);
+    await BookCommands.setBooksRating([1], 4);
+    expect(invoke).toHaveBeenCalledWith("set_books_rating", { bookIds: [1], rating: 4 });
+  });
+
+  it("setBooksFavorite should call invoke", async () => {
+    vi.mocked(invoke).mockResolvedValue(null);
+    await BookCommands.setBooksFavorite([1], true);
+    expect(invoke).toHaveBeenCalledWith("set_books_favorite", { bookIds: [1], isFavorite: true });
+  });
+
+  it("setBooksRating should throw CommandError on failure", async () => {
+    vi.mocked(invoke).mockRejectedValue(mockError);
+    await expect(BookCommands.setBooksRating([1], 6)).rejects.toThrow(CommandError);
+  });
+
   it("clearReadingHistory should call invoke", async () => {
     vi.mocked(invoke).mockResolvedValue(undefined);
     await BookCommands.clearReadingHistory(1);
@@ -1,5 +1,11 @@
-import type { Book, BookWithState, ReadBook, ReadingState } from "../domain/book/schema";
-import { commands } from "./bindings";
+import type {
+  Book,
+  BookWithState,
+  ReadBook,
+  ReadingState,
+  ReadStatus,
+} from "../domain/book/schema";
+import { type BookQuery, type BookSearchFilters, commands, type ExclusionRule } from "./bindings";
 import { runCommand } from "./result";
 
 // The hand-written domain types (e.g. `Book.item_type` narrowed to "file" | "directory") are kept as
@@ -60,6 +66,74 @@ export async function getAllBooksWithState(): Promise<BookWithState[]> {
   return (await runCommand(commands.getAllBooksWithState())) as BookWithState[];
 }
 
+/**
+ * Searches the library by display name, file path, series name, tag names and the metadata read
+ * from the book files (authors, publisher, description).
+ *
+ * @param params - The search parameters.
+ * @param params.query - The search terms. Every whitespace-separated term must match; an empty
+ * query matches every book that passes the filters.
+ * @param params.filters - Further conditions the books must meet. Omitted filters match every book.
+ * @param params.limit - The maximum number of books to return. Defaults to 100.
+ * @param params.offset - The number of matching books to skip, for paging. Defaults to 0.
+ * @returns A promise that resolves to the matching books, best match first.
+ * @throws {CommandError} If the Tauri command fails.
+ */
+export async function searchBooks(params: {
+  query: string;
+  filters?: Partial<BookSearchFilters>;
+  limit?: number;
+  offset?: number;
+}): Promise<BookWithState[]> {
+  const filters: BookSearchFilters = {
+    tag_ids: [],
+    series_id: null,
+    bookshelf_id: null,
+    item_type: null,
+    ...params.filters,
+  };
+  return (await runCommand(
+    commands.searchBooks(params.query, filters, params.limit ?? 100, params.offset ?? 0),
+  )) as BookWithState[];
+}
+
+/**
+ * Retrieves one page of the books matching a query. Filtering, sorting and paging happen in the
+ * database, so only the requested page is transferred.
+ *
+ * @param query - The conditions, sort order and page of the books. Omitted conditions match every
+ * book; the books are sorted by name in ascending order and all of them are returned by default.
+ * @returns A promise that resolves to the requested page of books and the total number of matching
+ * books.
+ * @throws {CommandError} If the Tauri command fails.
+ */
+export async function queryBooks(
+  query: Partial<BookQuery> = {},
+): Promise<{ books: BookWithState[]; total_count: number }> {
+  return (await runCommand(
+    commands.queryBooks({
+      include_tag_ids: [],
+      tag_match: "all",
+      exclude_tag_ids: [],
+      bookshelf_id: null,
+      series_id: null,
+      read_status: null,
+      added_after: null,
+      added_before: null,
+      opened_after: null,
+      opened_before: null,
+      item_type: null,
+      path_prefix: null,
+      formats: [],
+      sort: "name",
+      descending: false,
+      limit: null,
+      offset: 0,
+      ...query,
+    }),
+  )) as { books: BookWithState[]; total_count: number };
+}
+
 /**
  * Registers a book or returns its ID if it already exists.
  *
@@ -132,6 +206,58 @@ export async function updateBookTags(bookId: number, tagIds: number[]): Promise<
   await runCommand(commands.updateBookTags(bookId, tagIds));
 }
 
+/**
+ * Retrieves the exclusion rules of a specific book, applied on top of the global rules.
+ *
+ * @param bookId - The unique identifier of the book.
+ * @returns A promise that resolves to the book's own exclusion rules.
+ * @throws {CommandError} If the Tauri command fails.
+ */
+export async function getBookExclusionRules(bookId: number): Promise<ExclusionRule[]> {
+  return await runCommand(commands.getBookExclusionRules(bookId));
+}
+
+/**
+ * Replaces the exclusion rules of a specific book. They take effect the next time the book is
+ * opened.
+ *
+ * @param bookId - The unique identifier of the book.
+ * @param rules - The new rules. An empty array removes the book's rules.
+ * @returns A promise that resolves when the update is successful.
+ * @throws {CommandError} If a rule is invalid or the Tauri command fails.
+ */
+export async function setBookExclusionRules(
+  bookId: number,
+  rules: ExclusionRule[],
+): Promise<void> {
+  await runCommand(commands.setBookExclusionRules(bookId, rules));
+}
+
+/**
+ * Retrieves the manual page order of a specific book.
+ *
+ * @param bookId - The unique identifier of the book.
+ * @returns A promise that resolves to the entry names in page order, or an empty array if the
+ * book follows the sort mode from the settings.
+ * @throws {CommandError} If the Tauri command fails.
+ */
+export async function getBookPageOrder(bookId: number): Promise<string[]> {
+  return await runCommand(commands.getBookPageOrder(bookId));
+}
+
+/**
+ * Replaces the manual page order of a specific book. It takes effect the next time the book is
+ * opened; pages missing from the order follow in their sorted order.
+ *
+ * @param bookId - The unique identifier of the book.
+ * @param pageOrder - The entry names in page order. An empty array removes the manual order.
+ * @returns A promise that resolves when the update is successful.
+ * @throws {CommandError} If an entry name is repeated or the Tauri command fails.
+ */
+export async function setBookPageOrder(bookId: number, pageOrder: string[]): Promise<void> {
+  await runCommand(commands.setBookPageOrder(bookId, pageOrder));
+}
+
 /**
  * Updates the series associated with a specific book.
  *
@@ -166,6 +292,42 @@ export async function updateReadingProgress(stateData: ReadingState): Promise<vo
   await runCommand(commands.updateReadingProgress(stateData));
 }
 
+/**
+ * Sets the read status of several books at once.
+ *
+ * @param bookIds - The unique identifiers of the books.
+ * @param status - The new read status.
+ * @returns A promise that resolves when the books are updated.
+ * @throws {CommandError} If the Tauri command fails.
+ */
+export async function setBooksReadStatus(bookIds: number[], status: ReadStatus): Promise<void> {
+  await runCommand(commands.setBooksReadStatus(bookIds, status));
+}
+
+/**
+ * Sets the rating of several books at once.
+ *
+ * @param bookIds - The unique identifiers of the books.
+ * @param rating - The rating from 1 to 5, or 0 to remove the rating.
+ * @returns A promise that resolves when the books are updated.
+ * @throws {CommandError} If the rating is outside 0 to 5, or the Tauri command fails.
+ */
+export async function setBooksRating(bookIds: number[], rating: number): Promise<void> {
+  await runCommand(commands.setBooksRating(bookIds, rating));
+}
+
+/**
+ * Marks several books as favorites at once, or unmarks them.
+ *
+ * @param bookIds - The unique identifiers of the books.
+ * @param isFavorite - Whether the books are favorites.
+ * @returns A promise that resolves when the books are updated.
+ * @throws {CommandError} If the Tauri command fails.
+ */
+export async function setBooksFavorite(bookIds: number[], isFavorite: boolean): Promise<void> {
+  await runCommand(commands.setBooksFavorite(bookIds, isFavorite));
+}
+
 /**
  * Clears the reading history for a specific book.
  *
@@ -0,0 +1,67 @@
+import { invoke } from "@tauri-apps/api/core";
+import { beforeEach, describe, expect, it, vi } from "vitest";
+import { CommandError } from "../types/Error";
+import * as BookmarkCommands from "./BookmarkCommands";
+
+vi.unmock("./BookmarkCommands");
+
+describe("BookmarkCommands", () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+  });
+
+  it("addBookmark should call invoke", async () => {
+    const bookmark = {
+      book_id: 1,
+      page_index: 12,
+      cfi: null,
+      label: "Chapter 2",
+      note: "",
+      color_code: null,
+    };
+    const mockBookmark = { ...bookmark, id: 5, created_at: "2026-03-01T10:00:00" };
+    vi.mocked(invoke).mockResolvedValue(mockBookmark);
+    const result = await BookmarkCommands.addBookmark(bookmark);
+    expect(invoke).toHaveBeenCalledWith("add_bookmark", { bookmark });
+    expect(result).toEqual(mockBookmark);
+  });
+
+  it("getBookmarks should call invoke", async () => {
+    vi.mocked(invoke).mockResolvedValue([]);
+    await BookmarkCommands.getBookmarks(1);
+    expect(invoke).toHaveBeenCalledWith("get_bookmarks", { bookId: 1 });
+  });
+
+  it("getAllBookmarks should call invoke", async () => {
+    vi.mocked(invoke).mockResolvedValue([]);
+    await BookmarkCommands.getAllBookmarks();
+    expect(invoke).toHaveBeenCalledWith("get_all_bookmarks");
+  });
+
+  it("updateBookmark should call invoke", async () => {
+    const update = { label: "Renamed", note: "Reread this", color_code: "#00FF00" };
+    vi.mocked(invoke).mockResolvedValue(null);
+    await BookmarkCommands.updateBookmark(5, update);
+    expect(invoke).toHaveBeenCalledWith("update_bookmark", { id: 5, update });
+  });
+
+  it("deleteBookmark should call invoke", async () => {
+    vi.mocked(invoke).mockResolvedValue(null);
+    await BookmarkCommands.deleteBookmark(5);
+    expect(invoke).toHaveBeenCalledWith("delete_bookmark", { id: 5 });
+  });
+
+  it("addBookmark should throw CommandError on failure", async () => {
+    vi.mocked(invoke).mockRejectedValue(new Error("fail"));
+    await expect(
+      BookmarkCommands.addBookmark({
+        book_id: 1,
+        page_index: null,
+        cfi: null,
+        label: "",
+        note: "",
+        color_code: null,
+      }),
+    ).rejects.toThrow(CommandError);
+  });
+});
@@ -0,0 +1,57 @@
+import { type BookmarkUpdate, commands, type NewBookmark } from "./bindings";
+import { runCommand } from "./result";
+
+/**
+ * Adds a bookmark to a book.
+ *
+ * @param bookmark - The bookmark to add, pointing at either a page index or a CFI.
+ * @returns A promise that resolves to the created Bookmark object.
+ * @throws {CommandError} If the bookmark has no valid position, or the command fails.
+ */
+export async function addBookmark(bookmark: NewBookmark) {
+  return await runCommand(commands.addBookmark(bookmark));
+}
+
+/**
+ * Retrieves the bookmarks of a book, in reading order.
+ *
+ * @param bookId - The unique identifier of the book.
+ * @returns A promise that resolves to an array of Bookmark objects.
+ * @throws {CommandError} If the Tauri command fails.
+ */
+export async function getBookmarks(bookId: number) {
+  return await runCommand(commands.getBookmarks(bookId));
+}
+
+/**
+ * Retrieves the bookmarks of every book in the library, most recently added first.
+ *
+ * @returns A promise that resolves to an array of BookmarkWithBook objects.
+ * @throws {CommandError} If the Tauri command fails.
+ */
+export async function getAllBookmarks() {
+  return await runCommand(commands.getAllBookmarks());
+}
+
+/**
+ * Changes the label, note and color of a bookmark.
+ *
+ * @param id - The unique identifier of the bookmark.
+ * @param update - The new label, note and color.
+ * @returns A promise that resolves when the bookmark is updated.
+ * @throws {CommandError} If the Tauri command fails.
+ */
+export async function updateBookmark(id: number, update: BookmarkUpdate): Promise<void> {
+  await runCommand(commands.updateBookmark(id, update));
+}
+
+/**
+ * Deletes a bookmark.
+ *
+ * @param id - The unique identifier of the bookmark.
+ * @returns A promise that resolves when the bookmark is deleted.
+ * @throws {CommandError} If the Tauri command fails.
+ */
+export async function deleteBookmark(id: number): Promise<void> {
+  await runCommand(commands.deleteBookmark(id));
+}
@@ -34,6 +34,28 @@ describe("BookshelfCommand", () => {
     await expect(BookshelfCommand.createBookshelf("S", "i")).rejects.toThrow(CommandError);
   });
 
+  it("setBookshelfRule should call invoke", async () => {
+    vi.mocked(invoke).mockResolvedValue(null);
+    const rule = {
+      tag_ids: [1],
+      tag_match: "any" as const,
+      series_id: null,
+      read_status: "unread" as const,
+      added_within_days: 30,
+      path_prefix: null,
+      formats: ["cbz"],
+    };
+    await BookshelfCommand.setBookshelfRule(1, rule);
+    expect(invoke).toHaveBeenCalledWith("set_bookshelf_rule", { id: 1, rule });
+    await BookshelfCommand.setBookshelfRule(1, null);
+    expect(invoke).toHaveBeenCalledWith("set_bookshelf_rule", { id: 1, rule: null });
+  });
+
+  it("setBookshelfRule should throw CommandError on failure", async () => {
+    vi.mocked(invoke).mockRejectedValue(mockError);
+    await expect(BookshelfCommand.setBookshelfRule(1, null)).rejects.toThrow(CommandError);
+  });
+
   it("addBookToBookshelf should call invoke", async () => {
     vi.mocked(invoke).mockResolvedValue(undefined);
     await BookshelfCommand.addBookToBookshelf(1, 2);
@@ -1,5 +1,5 @@
 import type { Bookshelf } from "../domain/bookshelf/schema";
-import { commands } from "./bindings";
+import { type BookshelfRule, commands } from "./bindings";
 import { runCommand } from "./result";
 
 /**
@@ -24,6 +24,20 @@ export async function getAllBookshelves(): Promise<Bookshelf[]> {
   return (await runCommand(commands.getAllBookshelves())) as Bookshelf[];
 }
 
+/**
+ * Turns a bookshelf into a smart bookshelf, whose books are the ones matching a rule, or back into
+ * a bookshelf whose books are added by hand.
+ *
+ * @param id - The unique identifier of the bookshelf.
+ * @param rule - The rule selecting the books, or null to use the books added by hand.
+ * @returns A promise that resolves when the rule is saved.
+ * @throws {CommandError} If the rule has a condition that cannot match a book, or the Tauri command
+ * fails.
+ */
+export async function setBookshelfRule(id: number, rule: BookshelfRule | null): Promise<void> {
+  await runCommand(commands.setBookshelfRule(id, rule));
+}
+
 /**
  * Adds a book to a specific bookshelf.
  *
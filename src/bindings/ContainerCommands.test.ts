@@ -15,6 +15,8 @@ describe("ContainerCommands", () => {
       entries: ["1.jpg"],
       is_directory: false,
       is_novel: false,
+      damage_code: null,
+      excluded_entries: [],
     });
     await ContainerCommands.getEntriesInContainer("path");
     expect(invoke).toHaveBeenCalledWith("get_entries_in_container", {
@@ -1,4 +1,4 @@
-import { invoke } from "@tauri-apps/api/core";
+import { Channel, convertFileSrc, invoke } from "@tauri-apps/api/core";
 import { createCommandError } from "../types/Error";
 import { commands } from "./bindings";
 import { runCommand } from "./result";
@@ -27,9 +27,93 @@ export const requestPreloadAround = async (
   await runCommand(commands.requestPreloadAround(index, bufferSize ?? null));
 };
 
-// NOTE: `getImage` / `getImagePreview` return a raw binary `tauri::ipc::Response` from the backend,
-// which has no `specta::Type` and is not part of the generated `commands`. They keep a hand-written
-// `invoke` wrapper that receives the custom `[width][height][data]` binary payload.
+/**
+ * Cancels an in-flight progressive image request in the backend.
+ *
+ * @param requestId The ID passed to `getImageProgressive`.
+ * @returns A promise that resolves when the cancellation is submitted.
+ */
+export const cancelImageRequest = async (requestId: number): Promise<void> => {
+  await runCommand(commands.cancelImageRequest(requestId));
+};
+
+/**
+ * Fetches the deep-zoom tile pyramid description of an image from the backend.
+ *
+ * @param path The path of the container file.
+ * @param entryName The name of the image entry.
+ * @returns A promise that resolves to the source dimensions, tile size, and number of levels.
+ */
+export const getImageTileInfo = async (path: string, entryName: string) => {
+  return await runCommand(commands.getImageTileInfo(path, entryName));
+};
+
+/**
+ * Fetches the long-strip (webtoon) layout of a container from the backend.
+ *
+ * Tall pages are sliced into segments whose `entry` is fetched with `getImage` like any page.
+ *
+ * @param path The path of the container file.
+ * @returns A promise that resolves to the strip width, total height, and segments in reading order.
+ */
+export const getStripLayout = async (path: string) => {
+  return await runCommand(commands.getStripLayout(path));
+};
+
+/**
+ * Fetches statistics about the backend image cache.
+ *
+ * @returns A promise that resolves to the resident bytes and entries per book, plus the hit rate
+ * and preload usage of the open book.
+ */
+export const getCacheStats = async () => {
+  return await runCommand(commands.getCacheStats());
+};
+
+/**
+ * Fetches the recent timings of the backend image pipeline.
+ *
+ * @returns A promise that resolves to the recorded samples, oldest first, and their summaries per
+ * step and source format.
+ */
+export const getPerformanceMetrics = async () => {
+  return await runCommand(commands.getPerformanceMetrics());
+};
+
+/**
+ * Evicts images from the backend image cache.
+ *
+ * @param bookId The path of the book to purge, or `null` to purge every book.
+ * @returns A promise that resolves when the images have been evicted.
+ */
+export const purgeImageCache = async (bookId: string | null = null) => {
+  return await runCommand(commands.purgeImageCache(bookId));
+};
+
+// NOTE: `getImage` / `getImagePreview` / `getImageProgressive` / `getImageTile` return (or stream)
+// raw binary data from the backend, which has no `specta::Type` and is not part of the generated
+// `commands`. They keep a hand-written `invoke` wrapper that receives the custom
+// `[width][height][data]` binary payload.
+
+/**
+ * Builds the `rookreader://` URL serving a page of the open container, for use as an `<img>`
+ * `src` or in a `srcset`.
+ *
+ * Unlike `getImage`, the webview decodes such pages progressively and revalidates them through
+ * ETags. The image dimensions are exposed in the `X-Image-Width` / `X-Image-Height` headers.
+ *
+ * @param path The path of the container file.
+ * @param entryName The name of the image entry.
+ * @param height The maximum height of the served image, or `undefined` for the displayed page.
+ * @returns The URL of the page.
+ */
+export const getPageUrl = (path: string, entryName: string, height?: number): string => {
+  // `convertFileSrc` yields the scheme's origin for the current platform
+  // (`rookreader://localhost/` or `http://rookreader.localhost/`).
+  const origin = convertFileSrc("", "rookreader");
+  const url = `${origin}page/${encodeURIComponent(path)}/${encodeURIComponent(entryName)}`;
+  return height === undefined ? url : `${url}?h=${height}`;
+};
 
 /**
  * Fetches an image from a container in the backend.
@@ -60,3 +144,65 @@ export const getImagePreview = async (path: string, entryName: string): Promise<
     throw createCommandError(error);
   }
 };
+
+/**
+ * Fetches a deep-zoom tile of an image from the backend.
+ *
+ * @param path The path of the container file.
+ * @param entryName The name of the image entry.
+ * @param level The pyramid level (`0` is the full resolution).
+ * @param x The tile column.
+ * @param y The tile row.
+ * @returns A promise that resolves to the tile data as an ArrayBuffer.
+ */
+export const getImageTile = async (
+  path: string,
+  entryName: string,
+  level: number,
+  x: number,
+  y: number,
+): Promise<ArrayBuffer> => {
+  try {
+    return await invoke("get_image_tile", { path, entryName, level, x, y });
+  } catch (error) {
+    throw createCommandError(error);
+  }
+};
+
+/** The stage of an image delivered by `getImageProgressive`. */
+export enum ImageStage {
+  /** A fast, low-resolution preview. */
+  Preview = 0,
+  /** The full-resolution image. */
+  Full = 1,
+}
+
+/**
+ * Fetches an image progressively: a low-resolution preview first (when available), then the
+ * full-resolution image once it is ready.
+ *
+ * @param path The path of the container file.
+ * @param entryName The name of the image entry.
+ * @param requestId An ID identifying this request, used to cancel it with `cancelImageRequest`.
+ * @param onImage Called for every delivered image with its stage and its
+ *   `[width][height][data]` payload.
+ * @returns A promise that resolves when the request has finished or was cancelled.
+ */
+export const getImageProgressive = async (
+  path: string,
+  entryName: string,
+  requestId: number,
+  onImage: (stage: ImageStage, image: ArrayBuffer) => void,
+): Promise<void> => {
+  const onImageChannel = new Channel<ArrayBuffer>();
+  onImageChannel.onmessage = (frame) => {
+    // Frame format: [Stage (1 byte)][Width (4 bytes)][Height (4 bytes)][Image Data...]
+    const stage = new Uint8Array(frame, 0, 1)[0] as ImageStage;
+    onImage(stage, frame.slice(1));
+  };
+  try {
+    await invoke("get_image_progressive", { path, entryName, requestId, onImage: onImageChannel });
+  } catch (error) {
+    throw createCommandError(error);
+  }
+};
@@ -0,0 +1,49 @@
+import { invoke } from "@tauri-apps/api/core";
+import { beforeEach, describe, expect, it, vi } from "vitest";
+import { CommandError } from "../types/Error";
+import * as CreatorCommands from "./CreatorCommands";
+
+vi.unmock("./CreatorCommands");
+
+describe("CreatorCommands", () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+  });
+
+  it("getAllCreators should call invoke", async () => {
+    const mockCreators = [{ id: 1, name: "Author", book_count: 2 }];
+    vi.mocked(invoke).mockResolvedValue(mockCreators);
+    const result = await CreatorCommands.getAllCreators();
+    expect(invoke).toHaveBeenCalledWith("get_all_creators");
+    expect(result).toEqual(mockCreators);
+  });
+
+  it("getBookCreators should call invoke", async () => {
+    vi.mocked(invoke).mockResolvedValue([]);
+    await CreatorCommands.getBookCreators(3);
+    expect(invoke).toHaveBeenCalledWith("get_book_creators", { bookId: 3 });
+  });
+
+  it("getBooksWithStateByCreatorId should call invoke", async () => {
+    vi.mocked(invoke).mockResolvedValue([]);
+    await CreatorCommands.getBooksWithStateByCreatorId(1);
+    expect(invoke).toHaveBeenCalledWith("get_books_with_state_by_creator_id", { creatorId: 1 });
+  });
+
+  it("renameCreator should call invoke", async () => {
+    vi.mocked(invoke).mockResolvedValue(null);
+    await CreatorCommands.renameCreator(1, "New Name");
+    expect(invoke).toHaveBeenCalledWith("rename_creator", { id: 1, name: "New Name" });
+  });
+
+  it("mergeCreators should call invoke", async () => {
+    vi.mocked(invoke).mockResolvedValue(null);
+    await CreatorCommands.mergeCreators(1, [2, 3]);
+    expect(invoke).toHaveBeenCalledWith("merge_creators", { targetId: 1, sourceIds: [2, 3] });
+  });
+
+  it("renameCreator should throw CommandError on failure", async () => {
+    vi.mocked(invoke).mockRejectedValue(new Error("fail"));
+    await expect(CreatorCommands.renameCreator(1, "")).rejects.toThrow(CommandError);
+  });
+});
@@ -0,0 +1,59 @@
+import type { BookWithState } from "../domain/book/schema";
+import { commands } from "./bindings";
+import { runCommand } from "./result";
+
+/**
+ * Retrieves every creator credited for at least one book, ordered by name.
+ *
+ * @returns A promise that resolves to an array of Creator objects.
+ * @throws {CommandError} If the Tauri command fails.
+ */
+export async function getAllCreators() {
+  return await runCommand(commands.getAllCreators());
+}
+
+/**
+ * Retrieves the creators of a book and their roles on it.
+ *
+ * @param bookId - The unique identifier of the book.
+ * @returns A promise that resolves to the creators, in the order the book's metadata lists them.
+ * @throws {CommandError} If the Tauri command fails.
+ */
+export async function getBookCreators(bookId: number) {
+  return await runCommand(commands.getBookCreators(bookId));
+}
+
+/**
+ * Retrieves all books along with their reading states that a specific creator is credited for.
+ *
+ * @param creatorId - The unique identifier of the creator.
+ * @returns A promise that resolves to an array of BookWithState objects.
+ * @throws {CommandError} If the Tauri command fails.
+ */
+export async function getBooksWithStateByCreatorId(creatorId: number): Promise<BookWithState[]> {
+  return (await runCommand(commands.getBooksWithStateByCreatorId(creatorId))) as BookWithState[];
+}
+
+/**
+ * Renames a creator. Book files still using the former name keep being credited to the creator.
+ *
+ * @param id - The unique identifier of the creator to rename.
+ * @param name - The new name.
+ * @returns A promise that resolves when the creator is renamed.
+ * @throws {CommandError} If the name is blank or taken by another creator, or the command fails.
+ */
+export async function renameCreator(id: number, name: string): Promise<void> {
+  await runCommand(commands.renameCreator(id, name));
+}
+
+/**
+ * Merges creators into another one, which takes over their books.
+ *
+ * @param targetId - The unique identifier of the creator to keep.
+ * @param sourceIds - The unique identifiers of the creators to merge into it.
+ * @returns A promise that resolves when the creators are merged.
+ * @throws {CommandError} If the Tauri command fails.
+ */
+export async function mergeCreators(targetId: number, sourceIds: number[]): Promise<void> {
+  await runCommand(commands.mergeCreators(targetId, sourceIds));
+}
@@ -0,0 +1,60 @@
+import { invoke } from "@tauri-apps/api/core";
+import { beforeEach, describe, expect, it, vi } from "vitest";
+import { CommandError } from "../types/Error";
+import * as LibraryCommands from "./LibraryCommands";
+
+vi.unmock("./LibraryCommands");
+
+describe("LibraryCommands", () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+  });
+
+  it("getLibraryRoots should call invoke", async () => {
+    const mockRoots = [
+      { id: 1, path: "/books", created_at: "2026-10-01T00:00:00", last_scanned_at: null },
+    ];
+    vi.mocked(invoke).mockResolvedValue(mockRoots);
+    const result = await LibraryCommands.getLibraryRoots();
+    expect(invoke).toHaveBeenCalledWith("get_library_roots");
+    expect(result).toEqual(mockRoots);
+  });
+
+  it("addLibraryRoot should call invoke", async () => {
+    vi.mocked(invoke).mockResolvedValue(1);
+    const result = await LibraryCommands.addLibraryRoot("/books");
+    expect(invoke).toHaveBeenCalledWith("add_library_root", { path: "/books" });
+    expect(result).toBe(1);
+  });
+
+  it("removeLibraryRoot should call invoke", async () => {
+    vi.mocked(invoke).mockResolvedValue(null);
+    await LibraryCommands.removeLibraryRoot(1);
+    expect(invoke).toHaveBeenCalledWith("remove_library_root", { id: 1 });
+  });
+
+  it("rescanLibrary should rescan all roots by default", async () => {
+    const mockReport = { filesFound: 2, added: 1, updated: 0, missing: 1, failed: [] };
+    vi.mocked(invoke).mockResolvedValue(mockReport);
+    const result = await LibraryCommands.rescanLibrary();
+    expect(invoke).toHaveBeenCalledWith("rescan_library", { rootId: null });
+    expect(result).toEqual(mockReport);
+  });
+
+  it("rescanLibrary should rescan a single root", async () => {
+    vi.mocked(invoke).mockResolvedValue({
+      filesFound: 0,
+      added: 0,
+      updated: 0,
+      missing: 0,
+      failed: [],
+    });
+    await LibraryCommands.rescanLibrary(3);
+    expect(invoke).toHaveBeenCalledWith("rescan_library", { rootId: 3 });
+  });
+
+  it("addLibraryRoot should throw CommandError on failure", async () => {
+    vi.mocked(invoke).mockRejectedValue(new Error("fail"));
+    await expect(LibraryCommands.addLibraryRoot("/missing")).rejects.toThrow(CommandError);
+  });
+});
@@ -0,0 +1,49 @@
+import { commands } from "./bindings";
+import { runCommand } from "./result";
+
+/**
+ * Retrieves the folders watched for books.
+ *
+ * @returns A promise that resolves to the library roots, in the order they were added.
+ * @throws {CommandError} If the Tauri command fails.
+ */
+export async function getLibraryRoots() {
+  return await runCommand(commands.getLibraryRoots());
+}
+
+/**
+ * Adds a folder to the library, starts watching it and scans it in the background.
+ *
+ * Progress is reported through `library-scan-progress` events and the result through a
+ * `library-scan-finished` event.
+ *
+ * @param path - The path of the folder.
+ * @returns A promise that resolves to the ID of the new library root.
+ * @throws {CommandError} If the path is not a folder, is already in the library, or the command fails.
+ */
+export async function addLibraryRoot(path: string) {
+  return await runCommand(commands.addLibraryRoot(path));
+}
+
+/**
+ * Removes a folder from the library and stops watching it. Its books stay in the library.
+ *
+ * @param id - The ID of the library root to remove.
+ * @returns A promise that resolves when the folder is removed.
+ * @throws {CommandError} If the Tauri command fails.
+ */
+export async function removeLibraryRoot(id: number) {
+  await runCommand(commands.removeLibraryRoot(id));
+}
+
+/**
+ * Rescans the library roots, registering new book files, re-reading changed ones and flagging
+ * the ones that are gone as missing.
+ *
+ * @param rootId - The ID of the library root to rescan, or `null` to rescan all of them.
+ * @returns A promise that resolves to a summary of the changes.
+ * @throws {CommandError} If the library root does not exist or the command fails.
+ */
+export async function rescanLibrary(rootId: number | null = null) {
+  return await runCommand(commands.rescanLibrary(rootId));
+}
@@ -0,0 +1,28 @@
+import { invoke } from "@tauri-apps/api/core";
+import { beforeEach, describe, expect, it, vi } from "vitest";
+import { CommandError } from "../types/Error";
+import * as SearchCommands from "./SearchCommands";
+
+// Explicitly unmock the module being tested to avoid conflict with global mocks in setup.ts
+vi.unmock("./SearchCommands");
+
+describe("SearchCommands", () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+  });
+
+  it("filterBySearch should call invoke", async () => {
+    vi.mocked(invoke).mockResolvedValue([1]);
+    const result = await SearchCommands.filterBySearch("test", ["Apple", "test"]);
+    expect(invoke).toHaveBeenCalledWith("filter_by_search", {
+      query: "test",
+      names: ["Apple", "test"],
+    });
+    expect(result).toEqual([1]);
+  });
+
+  it("should throw CommandError on failure", async () => {
+    vi.mocked(invoke).mockRejectedValue(new Error("fail"));
+    await expect(SearchCommands.filterBySearch("test", [])).rejects.toThrow(CommandError);
+  });
+});
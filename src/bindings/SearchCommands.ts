@@ -0,0 +1,18 @@
+import { createCommandError } from "../types/Error";
+import { commands } from "./bindings";
+
+/**
+ * Filters names by an AND search in the backend, which normalizes names and keywords the
+ * same way as the book search.
+ *
+ * @param query - The user's input, with one or more whitespace-separated keywords.
+ * @param names - The names to match.
+ * @returns A promise that resolves to the indices of the names containing every keyword.
+ */
+export const filterBySearch = async (query: string, names: string[]): Promise<number[]> => {
+  try {
+    return await commands.filterBySearch(query, names);
+  } catch (error) {
+    throw createCommandError(error);
+  }
+};
@@ -0,0 +1,45 @@
+import { invoke } from "@tauri-apps/api/core";
+import { beforeEach, describe, expect, it, vi } from "vitest";
+import { CommandError } from "../types/Error";
+import * as StatisticsCommands from "./StatisticsCommands";
+
+vi.unmock("./StatisticsCommands");
+
+describe("StatisticsCommands", () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+  });
+
+  it("getReadingStatistics should call invoke", async () => {
+    const mockStatistics = {
+      total_seconds_read: 3600,
+      total_pages_read: 120,
+      total_books_finished: 1,
+      current_streak_days: 2,
+      longest_streak_days: 5,
+      daily: [{ date: "2026-03-01", seconds_read: 3600, pages_read: 120 }],
+      monthly_finished: [{ year: 2026, month: 3, books_finished: 1 }],
+      series: [],
+    };
+    vi.mocked(invoke).mockResolvedValue(mockStatistics);
+    const result = await StatisticsCommands.getReadingStatistics("2026-01-01", "2026-12-31");
+    expect(invoke).toHaveBeenCalledWith("get_reading_statistics", {
+      from: "2026-01-01",
+      to: "2026-12-31",
+    });
+    expect(result).toEqual(mockStatistics);
+  });
+
+  it("getReadingSessions should call invoke", async () => {
+    vi.mocked(invoke).mockResolvedValue([]);
+    await StatisticsCommands.getReadingSessions(4);
+    expect(invoke).toHaveBeenCalledWith("get_reading_sessions", { bookId: 4 });
+  });
+
+  it("getReadingStatistics should throw CommandError on failure", async () => {
+    vi.mocked(invoke).mockRejectedValue(new Error("fail"));
+    await expect(
+      StatisticsCommands.getReadingStatistics("2026-02-01", "2026-01-31"),
+    ).rejects.toThrow(CommandError);
+  });
+});
@@ -0,0 +1,27 @@
+import { commands } from "./bindings";
+import { runCommand } from "./result";
+
+/**
+ * Summarizes the reading done between two dates of the local time zone, e.g. for a yearly
+ * reading report.
+ *
+ * @param from - The first date of the period, as `YYYY-MM-DD`.
+ * @param to - The last date of the period, inclusive, as `YYYY-MM-DD`.
+ * @returns A promise that resolves to the time and pages read per day, the books finished per
+ * month, the reading streaks and the totals per series.
+ * @throws {CommandError} If `from` is after `to`, or the command fails.
+ */
+export async function getReadingStatistics(from: string, to: string) {
+  return await runCommand(commands.getReadingStatistics(from, to));
+}
+
+/**
+ * Retrieves the reading sessions of a book, most recent first.
+ *
+ * @param bookId - The unique identifier of the book.
+ * @returns A promise that resolves to an array of ReadingSession objects.
+ * @throws {CommandError} If the Tauri command fails.
+ */
+export async function getReadingSessions(bookId: number) {
+  return await runCommand(commands.getReadingSessions(bookId));
+}
@@ -0,0 +1,47 @@
+import { invoke } from "@tauri-apps/api/core";
+import { beforeEach, describe, expect, it, vi } from "vitest";
+import { CommandError } from "../types/Error";
+import * as VerificationCommands from "./VerificationCommands";
+
+vi.unmock("./VerificationCommands");
+
+describe("VerificationCommands", () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+  });
+
+  it("verifyBook should call invoke", async () => {
+    const mockReport = {
+      path: "/book.zip",
+      totalPages: 2,
+      pagesChecked: 2,
+      problems: [{ entry: "02.png", kind: "truncated", message: "unexpected end of file" }],
+      damageCode: null,
+      openError: null,
+      cancelled: false,
+    };
+    vi.mocked(invoke).mockResolvedValue(mockReport);
+    const result = await VerificationCommands.verifyBook("/book.zip", 7);
+    expect(invoke).toHaveBeenCalledWith("verify_book", { path: "/book.zip", jobId: 7 });
+    expect(result).toEqual(mockReport);
+  });
+
+  it("verifyLibrary should call invoke", async () => {
+    const mockReport = { totalBooks: 3, booksChecked: 3, reports: [], cancelled: false };
+    vi.mocked(invoke).mockResolvedValue(mockReport);
+    const result = await VerificationCommands.verifyLibrary(8);
+    expect(invoke).toHaveBeenCalledWith("verify_library", { jobId: 8 });
+    expect(result).toEqual(mockReport);
+  });
+
+  it("cancelVerification should call invoke", async () => {
+    vi.mocked(invoke).mockResolvedValue(null);
+    await VerificationCommands.cancelVerification(7);
+    expect(invoke).toHaveBeenCalledWith("cancel_verification", { jobId: 7 });
+  });
+
+  it("verifyBook should throw CommandError on failure", async () => {
+    vi.mocked(invoke).mockRejectedValue(new Error("fail"));
+    await expect(VerificationCommands.verifyBook("/book.zip", 1)).rejects.toThrow(CommandError);
+  });
+});
@@ -0,0 +1,39 @@
+import { commands } from "./bindings";
+import { runCommand } from "./result";
+
+/**
+ * Verifies the integrity of a book as a cancellable background job.
+ *
+ * Every page is read to the end (checking ZIP/RAR entry CRCs) and fully decoded. Progress is
+ * reported through `verification-progress` events.
+ *
+ * @param path - The path of the book to verify.
+ * @param jobId - An ID identifying this job, used to cancel it with `cancelVerification`.
+ * @returns A promise that resolves to the report of unreadable, truncated and undecodable pages.
+ * @throws {CommandError} If the Tauri command fails.
+ */
+export async function verifyBook(path: string, jobId: number) {
+  return await runCommand(commands.verifyBook(path, jobId));
+}
+
+/**
+ * Verifies the integrity of every book in the library as a cancellable background job.
+ *
+ * @param jobId - An ID identifying this job, used to cancel it with `cancelVerification`.
+ * @returns A promise that resolves to the reports of the books that have problems.
+ * @throws {CommandError} If the Tauri command fails.
+ */
+export async function verifyLibrary(jobId: number) {
+  return await runCommand(commands.verifyLibrary(jobId));
+}
+
+/**
+ * Cancels a running verification job. Cancelling an unknown or finished job is a no-op.
+ *
+ * @param jobId - The ID passed to `verifyBook` or `verifyLibrary`.
+ * @returns A promise that resolves when the cancellation is submitted.
+ * @throws {CommandError} If the Tauri command fails.
+ */
+export async function cancelVerification(jobId: number) {
+  await runCommand(commands.cancelVerification(jobId));
+}
@@ -31,7 +31,8 @@ export const commands = {
 	 * 
 	 *  The `patch` carries only the changed leaves of a single category. The backend
 	 *  deep-merges it into the current settings, validates the merged whole, persists it,
-	 *  applies the reader/rendering values to the live container runtime, returns the
+	 *  applies the reader/rendering values to the live container runtime (rebuilding the
+	 *  open book when its rendering parameters changed), returns the
 	 *  full merged settings to the caller, and broadcasts a `settings-changed` event to
 	 *  every window **except the caller** so other windows re-hydrate.
 	 * 
@@ -77,17 +78,22 @@ export const commands = {
 	 *  Opens a container file (e.g., ZIP, RAR) and retrieves a list of its contents.
 	 * 
 	 *  This function opens the container specified by the `path` and reads the list of file entries
-	 *  within it.
+	 *  within it. Entries matching the global exclusion rules or the book's own rules are left
+	 *  out, and the rest are sorted by the sort mode from the settings, then by the book's
+	 *  manual page order.
 	 * 
 	 *  # Arguments
 	 * 
 	 *  * `path` - The file path to the container to open.
+	 *  * `repo` - The managed book repository state, used to look up the book's own rules and
+	 *    page order.
 	 *  * `state` - A `tauri::State` holding the application's global `AppState`.
 	 * 
 	 *  # Returns
 	 * 
 	 *  A `Result` which is `Ok` with an `EntriesResult` struct containing the list of entry
-	 *  names, a boolean indicating if the path is a directory, and a boolean indicating if it's a novel.
+	 *  names, a boolean indicating if the path is a directory, a boolean indicating if it's a novel,
+	 *  the error code of any damage found while opening it, and the excluded entry names.
 	 * 
 	 *  # Errors
 	 * 
@@ -96,6 +102,101 @@ export const commands = {
 	 *  * The `container` within the application state is unexpectedly missing.
 	 */
 	getEntriesInContainer: (path: string) => typedError<EntriesResult, CommandError>(__TAURI_INVOKE("get_entries_in_container", { path })),
+	/**
+	 *  Cancels an in-flight progressive image request.
+	 * 
+	 *  Cancelling an unknown or already finished request is a no-op.
+	 * 
+	 *  # Arguments
+	 * 
+	 *  * `request_id` - The ID passed to `get_image_progressive`.
+	 */
+	cancelImageRequest: (requestId: number) => typedError<null, CommandError>(__TAURI_INVOKE("cancel_image_request", { requestId })),
+	/**
+	 *  Describes the deep-zoom tile pyramid of an image in the currently open container.
+	 * 
+	 *  The pyramid is built over the image's full-resolution source (for PDF, the page
+	 *  re-rendered at a higher DPI), not the downscaled page returned by `get_image`.
+	 * 
+	 *  # Arguments
+	 * 
+	 *  * `path` - The path of the container, used to reject requests that raced a book switch.
+	 *  * `entry_name` - The name of the image entry.
+	 *  * `state` - A `tauri::State` holding the application's global `AppState`.
+	 * 
+	 *  # Returns
+	 * 
+	 *  A `Result` which is `Ok` with the `TileInfo` (source dimensions, tile size and
+	 *  number of levels) of the image.
+	 * 
+	 *  # Errors
+	 * 
+	 *  This function will return an `Err` if:
+	 *  * The `image_loader` within the application state is unexpectedly missing.
+	 *  * The full-resolution source cannot be loaded or decoded.
+	 */
+	getImageTileInfo: (path: string, entryName: string) => typedError<TileInfo, CommandError>(__TAURI_INVOKE("get_image_tile_info", { path, entryName })),
+	/**
+	 *  Lays the currently open container out as one continuous vertical strip.
+	 * 
+	 *  Used by the long-strip (webtoon) reading mode: tall pages are sliced into
+	 *  overlapping segments whose entries are fetched through `get_image` like any other
+	 *  page, and consecutive short pages are stitched directly below each other.
+	 * 
+	 *  # Arguments
+	 * 
+	 *  * `path` - The path of the container, used to reject requests that raced a book switch.
+	 *  * `state` - A `tauri::State` holding the application's global `AppState`.
+	 * 
+	 *  # Returns
+	 * 
+	 *  A `Result` which is `Ok` with the `StripLayout` of the container.
+	 * 
+	 *  # Errors
+	 * 
+	 *  This function will return an `Err` if:
+	 *  * The `image_loader` within the application state is unexpectedly missing.
+	 *  * The dimensions of a page cannot be read.
+	 */
+	getStripLayout: (path: string) => typedError<StripLayout, CommandError>(__TAURI_INVOKE("get_strip_layout", { path })),
+	/**
+	 *  Reports the contents of the image cache and how the open book has used it.
+	 * 
+	 *  # Arguments
+	 * 
+	 *  * `state` - A `tauri::State` holding the application's global `AppState`.
+	 * 
+	 *  # Returns
+	 * 
+	 *  A `Result` which is `Ok` with the `CacheStats`: resident bytes and entries per book,
+	 *  plus the hit rate and preload usage of the open book.
+	 */
+	getCacheStats: () => typedError<CacheStats, CommandError>(__TAURI_INVOKE("get_cache_stats")),
+	/**
+	 *  Reports the recent timings of the image pipeline.
+	 * 
+	 *  Container reads, PDF renders, decodes, resizes and encodes are recorded in a ring
+	 *  buffer of the most recent samples, so a slow book can be attributed to a step and
+	 *  `max_image_height` and the cache size tuned per source format.
+	 * 
+	 *  # Returns
+	 * 
+	 *  A `Result` which is `Ok` with the recorded samples, oldest first, and their
+	 *  summaries per step and source format.
+	 */
+	getPerformanceMetrics: () => typedError<PerformanceMetrics, CommandError>(__TAURI_INVOKE("get_performance_metrics")),
+	/**
+	 *  Evicts cached images, either of one book or of every book.
+	 * 
+	 *  Evicted pages are reloaded from their container the next time they are requested.
+	 * 
+	 *  # Arguments
+	 * 
+	 *  * `book_id` - Optional. The identifier (path) of the book to purge; every book is
+	 *    purged if `None`.
+	 *  * `state` - A `tauri::State` holding the application's global `AppState`.
+	 */
+	purgeImageCache: (bookId: string | null) => typedError<null, CommandError>(__TAURI_INVOKE("purge_image_cache", { bookId })),
 	/**
 	 *  Retrieves a list of all font families installed on the system.
 	 * 
@@ -109,6 +210,24 @@ export const commands = {
 	 *  it returns an empty vector.
 	 */
 	getFonts: () => __TAURI_INVOKE<string[]>("get_fonts"),
+	/**
+	 *  Filters names by an AND search, so the frontend matches names the way the book search
+	 *  does.
+	 * 
+	 *  Names and keywords are compared after [`search_text::normalize`], so differences in
+	 *  case, character width and kana are ignored.
+	 * 
+	 *  # Arguments
+	 * 
+	 *  * `query` - The user's input, with one or more whitespace-separated keywords.
+	 *  * `names` - The names to match.
+	 * 
+	 *  # Returns
+	 * 
+	 *  The indices of the names that contain every keyword, in ascending order. Every index
+	 *  is returned if the query has no keywords.
+	 */
+	filterBySearch: (query: string, names: string[]) => __TAURI_INVOKE<number[]>("filter_by_search", { query, names }),
 	/**
 	 *  Retrieves the IDs of all tags associated with a specific book.
 	 * 
@@ -142,6 +261,80 @@ export const commands = {
 	 *  (e.g., due to a database error, connection issue, or query execution failure).
 	 */
 	updateBookTags: (bookId: number, tagIds: number[]) => typedError<null, CommandError>(__TAURI_INVOKE("update_book_tags", { bookId, tagIds })),
+	/**
+	 *  Retrieves the exclusion rules of a book.
+	 * 
+	 *  # Arguments
+	 * 
+	 *  * `book_id` - The unique identifier of the book.
+	 *  * `repo` - The managed book repository state.
+	 * 
+	 *  # Returns
+	 * 
+	 *  A `Result` which is `Ok` containing the book's own rules, which apply on top of the
+	 *  global rules from the settings.
+	 * 
+	 *  # Errors
+	 * 
+	 *  This function will return an `Err` if the underlying repository operation fails.
+	 */
+	getBookExclusionRules: (bookId: number) => typedError<ExclusionRule_Serialize[], CommandError>(__TAURI_INVOKE("get_book_exclusion_rules", { bookId })),
+	/**
+	 *  Replaces the exclusion rules of a book.
+	 * 
+	 *  The rules take effect the next time the book is opened.
+	 * 
+	 *  # Arguments
+	 * 
+	 *  * `book_id` - The unique identifier of the book.
+	 *  * `rules` - The new rules. An empty list removes the book's rules.
+	 *  * `repo` - The managed book repository state.
+	 * 
+	 *  # Errors
+	 * 
+	 *  This function will return an `Err` if:
+	 *  * A rule has an empty or invalid pattern (`Error::InvalidArgument`).
+	 *  * The underlying repository operation fails.
+	 */
+	setBookExclusionRules: (bookId: number, rules: ExclusionRule_Deserialize[]) => typedError<null, CommandError>(__TAURI_INVOKE("set_book_exclusion_rules", { bookId, rules })),
+	/**
+	 *  Retrieves the manual page order of a book.
+	 * 
+	 *  # Arguments
+	 * 
+	 *  * `book_id` - The unique identifier of the book.
+	 *  * `repo` - The managed book repository state.
+	 * 
+	 *  # Returns
+	 * 
+	 *  A `Result` which is `Ok` containing the entry names in page order, or an empty list
+	 *  if the book follows the sort mode from the settings.
+	 * 
+	 *  # Errors
+	 * 
+	 *  This function will return an `Err` if the underlying repository operation fails.
+	 */
+	getBookPageOrder: (bookId: number) => typedError<string[], CommandError>(__TAURI_INVOKE("get_book_page_order", { bookId })),
+	/**
+	 *  Replaces the manual page order of a book.
+	 * 
+	 *  The order is applied after the pages are sorted and filtered, the next time the book
+	 *  is opened: the named pages come first, in this order, and any other page follows in
+	 *  its sorted order.
+	 * 
+	 *  # Arguments
+	 * 
+	 *  * `book_id` - The unique identifier of the book.
+	 *  * `page_order` - The entry names in page order. An empty list removes the manual order.
+	 *  * `repo` - The managed book repository state.
+	 * 
+	 *  # Errors
+	 * 
+	 *  This function will return an `Err` if:
+	 *  * An entry name appears more than once (`Error::InvalidArgument`).
+	 *  * The underlying repository operation fails.
+	 */
+	setBookPageOrder: (bookId: number, pageOrder: string[]) => typedError<null, CommandError>(__TAURI_INVOKE("set_book_page_order", { bookId, pageOrder })),
 	/**
 	 *  Retrieves a book by its unique ID.
 	 * 
@@ -252,8 +445,23 @@ export const commands = {
 	thumbnail_path: string | null,
 	/**  The timestamp when the book was created (registered). */
 	created_at: string | null,
+	/**
+	 *  The error code explaining why the book could only be opened partially
+	 *  (e.g. a truncated ZIP), or `None` if it opened cleanly.
+	 */
+	damage_code: number | null,
+	/**  When the library scanner found the book file gone, or `None` while it exists. */
+	missing_since: string | null,
+	/**  How far the book has been read. */
+	read_status: ReadStatus,
+	/**  The rating from 1 to 5, or 0 if the book is not rated. */
+	rating: number,
+	/**  Whether the book is marked as a favorite. */
+	is_favorite: boolean,
 	/**  The last read page index, if the book has been opened. */
 	last_read_page_index: number | null,
+	/**  The EPUB CFI of the last read location of a novel, if any. */
+	last_read_cfi: string | null,
 	/**  The timestamp when the book was last opened, if any. */
 	last_opened_at: string | null,
 	/**  List of tag IDs associated with this book. */
@@ -381,6 +589,53 @@ export const commands = {
 	 *  (e.g., due to a database error, connection issue, or query execution failure).
 	 */
 	updateReadingProgress: (stateData: ReadingState) => typedError<null, CommandError>(__TAURI_INVOKE("update_reading_progress", { stateData })),
+	/**
+	 *  Sets the read status of several books at once.
+	 * 
+	 *  # Arguments
+	 * 
+	 *  * `book_ids` - The IDs of the books.
+	 *  * `status` - The new read status.
+	 *  * `repo` - The managed book repository state.
+	 *  * `app` - The Tauri AppHandle.
+	 * 
+	 *  # Errors
+	 * 
+	 *  This function will return an `Err` if the underlying repository operation fails.
+	 */
+	setBooksReadStatus: (bookIds: number[], status: ReadStatus) => typedError<null, CommandError>(__TAURI_INVOKE("set_books_read_status", { bookIds, status })),
+	/**
+	 *  Sets the rating of several books at once.
+	 * 
+	 *  # Arguments
+	 * 
+	 *  * `book_ids` - The IDs of the books.
+	 *  * `rating` - The rating from 1 to 5, or 0 to remove the rating.
+	 *  * `repo` - The managed book repository state.
+	 *  * `app` - The Tauri AppHandle.
+	 * 
+	 *  # Errors
+	 * 
+	 *  This function will return an `Err` if:
+	 *  * The rating is outside 0 to 5 (`Error::InvalidArgument`).
+	 *  * The underlying repository operation fails.
+	 */
+	setBooksRating: (bookIds: number[], rating: number) => typedError<null, CommandError>(__TAURI_INVOKE("set_books_rating", { bookIds, rating })),
+	/**
+	 *  Marks several books as favorites at once, or unmarks them.
+	 * 
+	 *  # Arguments
+	 * 
+	 *  * `book_ids` - The IDs of the books.
+	 *  * `is_favorite` - Whether the books are favorites.
+	 *  * `repo` - The managed book repository state.
+	 *  * `app` - The Tauri AppHandle.
+	 * 
+	 *  # Errors
+	 * 
+	 *  This function will return an `Err` if the underlying repository operation fails.
+	 */
+	setBooksFavorite: (bookIds: number[], isFavorite: boolean) => typedError<null, CommandError>(__TAURI_INVOKE("set_books_favorite", { bookIds, isFavorite })),
 	/**
 	 *  Retrieves all books, including their reading states.
 	 * 
@@ -398,9 +653,56 @@ export const commands = {
 	 *  (e.g., due to a database error, connection issue, or query execution failure).
 	 */
 	getAllBooksWithState: () => typedError<BookWithState[], CommandError>(__TAURI_INVOKE("get_all_books_with_state")),
+	/**
+	 *  Searches the library by display name, file path, series name, tag names and the
+	 *  metadata read from the book files (creator names, publisher, description).
+	 * 
+	 *  # Arguments
+	 * 
+	 *  * `query` - The search terms. Every whitespace-separated term must match; an empty
+	 *    query matches every book that passes the filters.
+	 *  * `filters` - Further conditions the books must meet.
+	 *  * `limit` - The maximum number of books to return.
+	 *  * `offset` - The number of matching books to skip, for paging.
+	 *  * `repo` - The managed book repository state.
+	 * 
+	 *  # Returns
+	 * 
+	 *  A `Result` containing the matching books ranked by relevance, best match first.
+	 * 
+	 *  # Errors
+	 * 
+	 *  This function will return an `Err` if the underlying repository operation fails
+	 *  (e.g., due to a database error, connection issue, or query execution failure).
+	 */
+	searchBooks: (query: string, filters: BookSearchFilters, limit: number, offset: number) => typedError<BookWithState[], CommandError>(__TAURI_INVOKE("search_books", { query, filters, limit, offset })),
+	/**
+	 *  Retrieves one page of the books matching a query, with their reading states.
+	 * 
+	 *  Unlike the listing commands, the filtering, sorting and paging happen in the
+	 *  database, so only the requested page crosses the IPC boundary.
+	 * 
+	 *  # Arguments
+	 * 
+	 *  * `query` - The conditions, sort order and page of the books to return.
+	 *  * `repo` - The managed book repository state.
+	 * 
+	 *  # Returns
+	 * 
+	 *  A `Result` containing the requested page of books and the total number of matching
+	 *  books.
+	 * 
+	 *  # Errors
+	 * 
+	 *  This function will return an `Err` if the underlying repository operation fails
+	 *  (e.g., due to a database error, connection issue, or query execution failure).
+	 */
+	queryBooks: (query: BookQuery) => typedError<BookQueryResult, CommandError>(__TAURI_INVOKE("query_books", { query })),
 	/**
 	 *  Retrieves all books contained within a specific bookshelf, including their reading states.
 	 * 
+	 *  The books of a smart bookshelf are the books matching its rule.
+	 * 
 	 *  # Arguments
 	 * 
 	 *  * `bookshelf_id` - The ID of the bookshelf.
@@ -479,6 +781,155 @@ export const commands = {
 	 *  This function will return an `Err` if the underlying repository operation fails.
 	 */
 	updateSeriesOrders: (bookIds: number[]) => typedError<null, CommandError>(__TAURI_INVOKE("update_series_orders", { bookIds })),
+	/**
+	 *  Adds a bookmark to a book and returns its complete entity.
+	 * 
+	 *  # Arguments
+	 * 
+	 *  * `bookmark` - The bookmark to add, pointing at either a page index or a CFI.
+	 *  * `repo` - The managed bookmark repository state.
+	 * 
+	 *  # Returns
+	 * 
+	 *  A `Result` containing the newly created `Bookmark` entity.
+	 * 
+	 *  # Errors
+	 * 
+	 *  This function will return an `Err` if:
+	 *  * The bookmark does not point at exactly one valid position (`Error::InvalidArgument`).
+	 *  * The underlying repository operation fails.
+	 */
+	addBookmark: (bookmark: NewBookmark) => typedError<Bookmark, CommandError>(__TAURI_INVOKE("add_bookmark", { bookmark })),
+	/**
+	 *  Retrieves the bookmarks of a book, in reading order.
+	 * 
+	 *  # Arguments
+	 * 
+	 *  * `book_id` - The ID of the book.
+	 *  * `repo` - The managed bookmark repository state.
+	 * 
+	 *  # Returns
+	 * 
+	 *  A `Result` containing a vector of `Bookmark` entities.
+	 * 
+	 *  # Errors
+	 * 
+	 *  This function will return an `Err` if the underlying repository operation fails.
+	 */
+	getBookmarks: (bookId: number) => typedError<Bookmark[], CommandError>(__TAURI_INVOKE("get_bookmarks", { bookId })),
+	/**
+	 *  Retrieves the bookmarks of every book in the library, most recently added first.
+	 * 
+	 *  # Arguments
+	 * 
+	 *  * `repo` - The managed bookmark repository state.
+	 * 
+	 *  # Returns
+	 * 
+	 *  A `Result` containing a vector of `BookmarkWithBook` entities.
+	 * 
+	 *  # Errors
+	 * 
+	 *  This function will return an `Err` if the underlying repository operation fails.
+	 */
+	getAllBookmarks: () => typedError<BookmarkWithBook[], CommandError>(__TAURI_INVOKE("get_all_bookmarks")),
+	/**
+	 *  Changes the label, note and color of a bookmark.
+	 * 
+	 *  # Arguments
+	 * 
+	 *  * `id` - The ID of the bookmark to update.
+	 *  * `update` - The new label, note and color.
+	 *  * `repo` - The managed bookmark repository state.
+	 * 
+	 *  # Errors
+	 * 
+	 *  This function will return an `Err` if the underlying repository operation fails.
+	 */
+	updateBookmark: (id: number, update: BookmarkUpdate) => typedError<null, CommandError>(__TAURI_INVOKE("update_bookmark", { id, update })),
+	/**
+	 *  Deletes a bookmark.
+	 * 
+	 *  # Arguments
+	 * 
+	 *  * `id` - The ID of the bookmark to delete.
+	 *  * `repo` - The managed bookmark repository state.
+	 * 
+	 *  # Errors
+	 * 
+	 *  This function will return an `Err` if the underlying repository operation fails.
+	 */
+	deleteBookmark: (id: number) => typedError<null, CommandError>(__TAURI_INVOKE("delete_bookmark", { id })),
+	/**
+	 *  Highlights a passage of a novel and returns the complete annotation.
+	 * 
+	 *  # Arguments
+	 * 
+	 *  * `annotation` - The annotation to add.
+	 *  * `repo` - The managed annotation repository state.
+	 * 
+	 *  # Returns
+	 * 
+	 *  A `Result` containing the newly created `Annotation` entity.
+	 * 
+	 *  # Errors
+	 * 
+	 *  This function will return an `Err` if:
+	 *  * The CFI range is empty (`Error::InvalidArgument`).
+	 *  * The underlying repository operation fails.
+	 */
+	createAnnotation: (annotation: NewAnnotation) => typedError<Annotation, CommandError>(__TAURI_INVOKE("create_annotation", { annotation })),
+	/**
+	 *  Retrieves the annotations of a book, in reading order.
+	 * 
+	 *  # Arguments
+	 * 
+	 *  * `book_id` - The ID of the book.
+	 *  * `repo` - The managed annotation repository state.
+	 * 
+	 *  # Returns
+	 * 
+	 *  A `Result` containing a vector of `Annotation` entities.
+	 * 
+	 *  # Errors
+	 * 
+	 *  This function will return an `Err` if the underlying repository operation fails.
+	 */
+	getAnnotations: (bookId: number) => typedError<Annotation[], CommandError>(__TAURI_INVOKE("get_annotations", { bookId })),
+	/**
+	 *  Deletes an annotation.
+	 * 
+	 *  # Arguments
+	 * 
+	 *  * `id` - The ID of the annotation to delete.
+	 *  * `repo` - The managed annotation repository state.
+	 * 
+	 *  # Errors
+	 * 
+	 *  This function will return an `Err` if the underlying repository operation fails.
+	 */
+	deleteAnnotation: (id: number) => typedError<null, CommandError>(__TAURI_INVOKE("delete_annotation", { id })),
+	/**
+	 *  Exports the annotations of a book, in reading order, as a Markdown or JSON document.
+	 * 
+	 *  # Arguments
+	 * 
+	 *  * `book_id` - The ID of the book.
+	 *  * `format` - The format of the document.
+	 *  * `repo` - The managed annotation repository state.
+	 *  * `book_repo` - The managed book repository state.
+	 * 
+	 *  # Returns
+	 * 
+	 *  A `Result` containing the document, ready to be saved to a file.
+	 * 
+	 *  # Errors
+	 * 
+	 *  This function will return an `Err` if:
+	 *  * The book does not exist (`Error::InvalidArgument`).
+	 *  * The underlying repository operation fails.
+	 */
+	exportAnnotations: (bookId: number, format: AnnotationExportFormat) => typedError<string, CommandError>(__TAURI_INVOKE("export_annotations", { bookId, format })),
 	/**
 	 *  Creates a new bookshelf and returns its complete entity.
 	 * 
@@ -515,6 +966,23 @@ export const commands = {
 	 *  (e.g., due to a database error, connection issue, or query execution failure).
 	 */
 	getAllBookshelves: () => typedError<Bookshelf[], CommandError>(__TAURI_INVOKE("get_all_bookshelves")),
+	/**
+	 *  Turns a bookshelf into a smart bookshelf, whose books are the ones matching a rule,
+	 *  or back into a bookshelf whose books are added by hand.
+	 * 
+	 *  # Arguments
+	 * 
+	 *  * `id` - The ID of the bookshelf.
+	 *  * `rule` - The rule selecting the books, or `None` to use the books added by hand.
+	 *  * `repo` - The managed bookshelf repository state.
+	 * 
+	 *  # Errors
+	 * 
+	 *  This function will return an `Err` if:
+	 *  * The rule has a condition that cannot match a book (`Error::InvalidArgument`).
+	 *  * The underlying repository operation fails.
+	 */
+	setBookshelfRule: (id: number, rule: BookshelfRule | null) => typedError<null, CommandError>(__TAURI_INVOKE("set_bookshelf_rule", { id, rule })),
 	/**
 	 *  Adds a book to a specific bookshelf.
 	 * 
@@ -557,6 +1025,89 @@ export const commands = {
 	 *  This function will return an `Err` if the underlying repository operation fails.
 	 */
 	deleteBookshelf: (id: number) => typedError<null, CommandError>(__TAURI_INVOKE("delete_bookshelf", { id })),
+	/**
+	 *  Retrieves every creator credited for at least one book, ordered by name.
+	 * 
+	 *  # Arguments
+	 * 
+	 *  * `repo` - The managed creator repository state.
+	 * 
+	 *  # Returns
+	 * 
+	 *  A `Result` containing a vector of `Creator` entities.
+	 * 
+	 *  # Errors
+	 * 
+	 *  This function will return an `Err` if the underlying repository operation fails
+	 *  (e.g., due to a database error, connection issue, or query execution failure).
+	 */
+	getAllCreators: () => typedError<Creator[], CommandError>(__TAURI_INVOKE("get_all_creators")),
+	/**
+	 *  Retrieves the creators of a book and their roles on it.
+	 * 
+	 *  # Arguments
+	 * 
+	 *  * `book_id` - The ID of the book.
+	 *  * `repo` - The managed creator repository state.
+	 * 
+	 *  # Returns
+	 * 
+	 *  A `Result` containing the creators, in the order the book's metadata lists them.
+	 * 
+	 *  # Errors
+	 * 
+	 *  This function will return an `Err` if the underlying repository operation fails.
+	 */
+	getBookCreators: (bookId: number) => typedError<BookCreator[], CommandError>(__TAURI_INVOKE("get_book_creators", { bookId })),
+	/**
+	 *  Retrieves all books a specific creator is credited for, with their reading state.
+	 * 
+	 *  # Arguments
+	 * 
+	 *  * `creator_id` - The ID of the creator.
+	 *  * `repo` - The managed creator repository state.
+	 * 
+	 *  # Returns
+	 * 
+	 *  A `Result` containing a vector of `BookWithState` entities.
+	 * 
+	 *  # Errors
+	 * 
+	 *  This function will return an `Err` if the underlying repository operation fails.
+	 */
+	getBooksWithStateByCreatorId: (creatorId: number) => typedError<BookWithState[], CommandError>(__TAURI_INVOKE("get_books_with_state_by_creator_id", { creatorId })),
+	/**
+	 *  Renames a creator. Book files still using the former name keep being credited to
+	 *  the creator.
+	 * 
+	 *  # Arguments
+	 * 
+	 *  * `id` - The ID of the creator to rename.
+	 *  * `name` - The new name. Surrounding whitespace is removed.
+	 *  * `repo` - The managed creator repository state.
+	 * 
+	 *  # Errors
+	 * 
+	 *  This function will return an `Err` if:
+	 *  * The name is blank or another creator already has it (`Error::InvalidArgument`).
+	 *  * The underlying repository operation fails.
+	 */
+	renameCreator: (id: number, name: string) => typedError<null, CommandError>(__TAURI_INVOKE("rename_creator", { id, name })),
+	/**
+	 *  Merges creators into another one, which takes over their books and keeps their
+	 *  names as aliases.
+	 * 
+	 *  # Arguments
+	 * 
+	 *  * `target_id` - The ID of the creator to keep.
+	 *  * `source_ids` - The IDs of the creators to merge into it.
+	 *  * `repo` - The managed creator repository state.
+	 * 
+	 *  # Errors
+	 * 
+	 *  This function will return an `Err` if the underlying repository operation fails.
+	 */
+	mergeCreators: (targetId: number, sourceIds: number[]) => typedError<null, CommandError>(__TAURI_INVOKE("merge_creators", { targetId, sourceIds })),
 	/**
 	 *  Creates a new series.
 	 * 
@@ -574,97 +1125,298 @@ export const commands = {
 	 *  This function will return an `Err` if the underlying repository operation fails
 	 *  (e.g., due to a database error, connection issue, or query execution failure).
 	 */
-	createSeries: (name: string) => typedError<number, CommandError>(__TAURI_INVOKE("create_series", { name })),
+	createSeries: (name: string) => typedError<number, CommandError>(__TAURI_INVOKE("create_series", { name })),
+	/**
+	 *  Retrieves all series from the database.
+	 * 
+	 *  # Arguments
+	 * 
+	 *  * `repo` - The managed series repository state.
+	 * 
+	 *  # Returns
+	 * 
+	 *  A `Result` containing a vector of `Series` entities.
+	 * 
+	 *  # Errors
+	 * 
+	 *  This function will return an `Err` if the underlying repository operation fails
+	 *  (e.g., due to a database error, connection issue, or query execution failure).
+	 */
+	getAllSeries: () => typedError<Series[], CommandError>(__TAURI_INVOKE("get_all_series")),
+	/**
+	 *  Deletes a series by its ID.
+	 * 
+	 *  # Arguments
+	 * 
+	 *  * `id` - The ID of the series to delete.
+	 *  * `repo` - The managed series repository state.
+	 * 
+	 *  # Errors
+	 * 
+	 *  This function will return an `Err` if the underlying repository operation fails.
+	 */
+	deleteSeries: (id: number) => typedError<null, CommandError>(__TAURI_INVOKE("delete_series", { id })),
+	/**
+	 *  Creates a new tag and returns its complete entity.
+	 * 
+	 *  # Arguments
+	 * 
+	 *  * `name` - The name of the new tag.
+	 *  * `color_code` - The color code for the tag.
+	 *  * `repo` - The managed tag repository state.
+	 * 
+	 *  # Returns
+	 * 
+	 *  A `Result` containing the newly created `Tag` entity.
+	 * 
+	 *  # Errors
+	 * 
+	 *  This function will return an `Err` if the underlying repository operation fails
+	 *  (e.g., due to a database error, connection issue, or query execution failure).
+	 */
+	createTag: (name: string, colorCode: string) => typedError<Tag, CommandError>(__TAURI_INVOKE("create_tag", { name, colorCode })),
+	/**
+	 *  Retrieves all tags from the database.
+	 * 
+	 *  # Arguments
+	 * 
+	 *  * `repo` - The managed tag repository state.
+	 * 
+	 *  # Returns
+	 * 
+	 *  A `Result` containing a vector of `Tag` entities.
+	 * 
+	 *  # Errors
+	 * 
+	 *  This function will return an `Err` if the underlying repository operation fails
+	 *  (e.g., due to a database error, connection issue, or query execution failure).
+	 */
+	getAllTags: () => typedError<Tag[], CommandError>(__TAURI_INVOKE("get_all_tags")),
+	/**
+	 *  Deletes a tag from the database.
+	 * 
+	 *  # Arguments
+	 * 
+	 *  * `id` - The ID of the tag to delete.
+	 *  * `repo` - The managed tag repository state.
+	 * 
+	 *  # Errors
+	 * 
+	 *  This function will return an `Err` if the underlying repository operation fails.
+	 */
+	deleteTag: (id: number) => typedError<null, CommandError>(__TAURI_INVOKE("delete_tag", { id })),
+	/**
+	 *  Summarizes the reading done between two dates of the local time zone, e.g. for a
+	 *  yearly reading report.
+	 * 
+	 *  The current offset of the local time zone is used for the whole period.
+	 * 
+	 *  # Arguments
+	 * 
+	 *  * `from` - The first date of the period.
+	 *  * `to` - The last date of the period, inclusive.
+	 *  * `repo` - The managed reading session repository state.
+	 * 
+	 *  # Returns
+	 * 
+	 *  A `Result` containing the time read and pages read per day, the books finished per
+	 *  month, the reading streaks and the totals per series.
+	 * 
+	 *  # Errors
+	 * 
+	 *  This function will return an `Err` if:
+	 *  * `from` is after `to` (`Error::InvalidArgument`).
+	 *  * The underlying repository operation fails.
+	 */
+	getReadingStatistics: (from: string, to: string) => typedError<ReadingStatistics, CommandError>(__TAURI_INVOKE("get_reading_statistics", { from, to })),
+	/**
+	 *  Retrieves the reading sessions of a book, most recent first.
+	 * 
+	 *  # Arguments
+	 * 
+	 *  * `book_id` - The ID of the book.
+	 *  * `repo` - The managed reading session repository state.
+	 * 
+	 *  # Returns
+	 * 
+	 *  A `Result` containing a vector of `ReadingSession` entities.
+	 * 
+	 *  # Errors
+	 * 
+	 *  This function will return an `Err` if the underlying repository operation fails.
+	 */
+	getReadingSessions: (bookId: number) => typedError<ReadingSession[], CommandError>(__TAURI_INVOKE("get_reading_sessions", { bookId })),
+	/**
+	 *  Checks if the auto-updater is supported on the current platform/environment.
+	 * 
+	 *  Returns `true` if the updater is supported, `false` otherwise.
+	 *  - Windows: Always supported.
+	 *  - macOS: Always supported.
+	 *  - Linux: Supported only if running as an AppImage (checked via `APPIMAGE` environment variable).
+	 */
+	isUpdaterSupported: () => __TAURI_INVOKE<boolean>("is_updater_supported"),
+	/**
+	 *  Verifies the integrity of a book as a cancellable background job.
+	 * 
+	 *  Every page is read to the end (checking the entry CRCs of ZIP and RAR archives)
+	 *  and fully decoded. The book is opened separately from the one shown in the reader,
+	 *  which stays untouched. A `verification-progress` event is emitted after each page.
+	 * 
+	 *  # Arguments
+	 * 
+	 *  * `path` - The path of the book to verify.
+	 *  * `job_id` - A frontend-issued ID, used to cancel the job with `cancel_verification`.
+	 *  * `app` - The Tauri app handle used to emit progress events.
+	 *  * `state` - The application's runtime state.
+	 * 
+	 *  # Returns
+	 * 
+	 *  A `VerificationReport` listing the unreadable, truncated and undecodable pages. A
+	 *  book that cannot be opened at all is reported through its `openError`.
+	 * 
+	 *  # Errors
+	 * 
+	 *  Returns an `Err` only if the background task cannot be run.
+	 */
+	verifyBook: (path: string, jobId: number) => typedError<VerificationReport, CommandError>(__TAURI_INVOKE("verify_book", { path, jobId })),
 	/**
-	 *  Retrieves all series from the database.
+	 *  Verifies the integrity of every book in the library as a cancellable background job.
+	 * 
+	 *  Books are verified one after another, the same way `verify_book` verifies a single
+	 *  book. A `verification-progress` event is emitted after each page.
 	 * 
 	 *  # Arguments
 	 * 
-	 *  * `repo` - The managed series repository state.
+	 *  * `job_id` - A frontend-issued ID, used to cancel the job with `cancel_verification`.
+	 *  * `app` - The Tauri app handle used to emit progress events.
+	 *  * `repo` - The managed book repository state.
+	 *  * `state` - The application's runtime state.
 	 * 
 	 *  # Returns
 	 * 
-	 *  A `Result` containing a vector of `Series` entities.
+	 *  A `LibraryVerificationReport` with the reports of the books that have problems.
 	 * 
 	 *  # Errors
 	 * 
-	 *  This function will return an `Err` if the underlying repository operation fails
-	 *  (e.g., due to a database error, connection issue, or query execution failure).
+	 *  Returns an `Err` if the books cannot be listed or the background task cannot be run.
 	 */
-	getAllSeries: () => typedError<Series[], CommandError>(__TAURI_INVOKE("get_all_series")),
+	verifyLibrary: (jobId: number) => typedError<LibraryVerificationReport, CommandError>(__TAURI_INVOKE("verify_library", { jobId })),
 	/**
-	 *  Deletes a series by its ID.
+	 *  Cancels a running verification job.
 	 * 
-	 *  # Arguments
-	 * 
-	 *  * `id` - The ID of the series to delete.
-	 *  * `repo` - The managed series repository state.
+	 *  The job stops after the page being checked and returns a report marked as
+	 *  cancelled. Cancelling an unknown or already finished job is a no-op.
 	 * 
-	 *  # Errors
+	 *  # Arguments
 	 * 
-	 *  This function will return an `Err` if the underlying repository operation fails.
+	 *  * `job_id` - The ID passed to `verify_book` or `verify_library`.
 	 */
-	deleteSeries: (id: number) => typedError<null, CommandError>(__TAURI_INVOKE("delete_series", { id })),
+	cancelVerification: (jobId: number) => typedError<null, CommandError>(__TAURI_INVOKE("cancel_verification", { jobId })),
 	/**
-	 *  Creates a new tag and returns its complete entity.
+	 *  Retrieves all library roots.
 	 * 
 	 *  # Arguments
 	 * 
-	 *  * `name` - The name of the new tag.
-	 *  * `color_code` - The color code for the tag.
-	 *  * `repo` - The managed tag repository state.
+	 *  * `repo` - The managed library repository state.
 	 * 
 	 *  # Returns
 	 * 
-	 *  A `Result` containing the newly created `Tag` entity.
+	 *  A `Result` containing the library roots, in the order they were added.
 	 * 
 	 *  # Errors
 	 * 
 	 *  This function will return an `Err` if the underlying repository operation fails
 	 *  (e.g., due to a database error, connection issue, or query execution failure).
 	 */
-	createTag: (name: string, colorCode: string) => typedError<Tag, CommandError>(__TAURI_INVOKE("create_tag", { name, colorCode })),
+	getLibraryRoots: () => typedError<LibraryRoot[], CommandError>(__TAURI_INVOKE("get_library_roots")),
 	/**
-	 *  Retrieves all tags from the database.
+	 *  Adds a folder to the library, starts watching it and scans it in the background.
+	 * 
+	 *  Progress of the scan is reported through `library-scan-progress` events and its
+	 *  result through a `library-scan-finished` event.
 	 * 
 	 *  # Arguments
 	 * 
-	 *  * `repo` - The managed tag repository state.
+	 *  * `path` - The path of the folder.
+	 *  * `repo` - The managed library repository state.
+	 *  * `app` - The Tauri AppHandle.
 	 * 
 	 *  # Returns
 	 * 
-	 *  A `Result` containing a vector of `Tag` entities.
+	 *  A `Result` containing the ID of the new library root.
 	 * 
 	 *  # Errors
 	 * 
-	 *  This function will return an `Err` if the underlying repository operation fails
-	 *  (e.g., due to a database error, connection issue, or query execution failure).
+	 *  Returns [`Error::InvalidArgument`] if `path` is not a folder, or an `Err` if the
+	 *  folder is already in the library or the watcher cannot be restarted.
 	 */
-	getAllTags: () => typedError<Tag[], CommandError>(__TAURI_INVOKE("get_all_tags")),
+	addLibraryRoot: (path: string) => typedError<number, CommandError>(__TAURI_INVOKE("add_library_root", { path })),
 	/**
-	 *  Deletes a tag from the database.
+	 *  Removes a folder from the library and stops watching it.
+	 * 
+	 *  The books found in the folder stay in the library.
 	 * 
 	 *  # Arguments
 	 * 
-	 *  * `id` - The ID of the tag to delete.
-	 *  * `repo` - The managed tag repository state.
+	 *  * `id` - The ID of the library root to remove.
+	 *  * `repo` - The managed library repository state.
+	 *  * `app` - The Tauri AppHandle.
 	 * 
 	 *  # Errors
 	 * 
-	 *  This function will return an `Err` if the underlying repository operation fails.
+	 *  This function will return an `Err` if the underlying repository operation fails or
+	 *  the watcher cannot be restarted.
 	 */
-	deleteTag: (id: number) => typedError<null, CommandError>(__TAURI_INVOKE("delete_tag", { id })),
+	removeLibraryRoot: (id: number) => typedError<null, CommandError>(__TAURI_INVOKE("remove_library_root", { id })),
 	/**
-	 *  Checks if the auto-updater is supported on the current platform/environment.
+	 *  Rescans the library roots, registering new book files, re-reading changed ones and
+	 *  flagging the ones that are gone as missing.
 	 * 
-	 *  Returns `true` if the updater is supported, `false` otherwise.
-	 *  - Windows: Always supported.
-	 *  - macOS: Always supported.
-	 *  - Linux: Supported only if running as an AppImage (checked via `APPIMAGE` environment variable).
+	 *  Progress is reported through `library-scan-progress` events.
+	 * 
+	 *  # Arguments
+	 * 
+	 *  * `root_id` - The ID of the library root to rescan, or `None` to rescan all of them.
+	 *  * `repo` - The managed library repository state.
+	 *  * `app` - The Tauri AppHandle.
+	 * 
+	 *  # Returns
+	 * 
+	 *  A `LibraryScanReport` summarizing the changes.
+	 * 
+	 *  # Errors
+	 * 
+	 *  Returns [`Error::InvalidArgument`] if `root_id` does not exist, or an `Err` if a
+	 *  database operation fails.
 	 */
-	isUpdaterSupported: () => __TAURI_INVOKE<boolean>("is_updater_supported"),
+	rescanLibrary: (rootId: number | null) => typedError<LibraryScanReport, CommandError>(__TAURI_INVOKE("rescan_library", { rootId })),
+};
+
+/**  A highlighted passage of a novel, with an optional note. */
+export type Annotation = {
+	/**  The unique identifier for the annotation. */
+	id: number,
+	/**  The identifier of the annotated book. */
+	book_id: number,
+	/**  The EPUB CFI range of the highlighted passage. */
+	cfi_range: string,
+	/**  The highlighted text, as it was selected. */
+	selected_text: string,
+	/**  A free-form note. May be empty. */
+	note: string,
+	/**  The color code of the highlight (e.g., "#FFFF00"). */
+	color_code: string,
+	/**  The timestamp when the annotation was created. */
+	created_at: string,
 };
 
+/**  The file format annotations are exported in. */
+export type AnnotationExportFormat = 
+/**  A Markdown document quoting each passage, followed by its note. */
+"markdown" | 
+/**  A JSON document with the book name and every annotation. */
+"json";
+
 /* Types */
 /**  Represents the root configuration of the application settings. */
 export type AppSettings = AppSettings_Serialize | AppSettings_Deserialize;
@@ -743,6 +1495,108 @@ export type Book = {
 	thumbnail_path: string | null,
 };
 
+/**  The cached images of one book. */
+export type BookCacheStats = {
+	/**  The identifier (path) of the book. */
+	bookId: string,
+	/**  The number of cached images of the book. */
+	entryCount: number,
+	/**  The total size in bytes of the cached images of the book. */
+	bytes: number,
+};
+
+/**  A creator credited for a specific book, with their role on it. */
+export type BookCreator = {
+	/**  The unique identifier of the creator. */
+	creator_id: number,
+	/**  The name of the creator. */
+	name: string,
+	/**  The part the creator had in making the book. */
+	role: CreatorRole,
+};
+
+/**
+ *  Selects, sorts and pages the books of the library.
+ * 
+ *  Conditions that are not set match every book; the conditions that are set must all
+ *  hold.
+ */
+export type BookQuery = {
+	/**  Only match books with these tags, as selected by `tag_match`. */
+	include_tag_ids: number[],
+	/**  Whether a book needs all of `include_tag_ids` or any one of them. */
+	tag_match: TagMatch,
+	/**  Exclude books with any of these tags. */
+	exclude_tag_ids: number[],
+	/**  Only match books on this bookshelf. */
+	bookshelf_id: number | null,
+	/**  Only match books in this series. */
+	series_id: number | null,
+	/**  Only match books with this read status. */
+	read_status: ReadStatus | null,
+	/**  Only match books added at or after this time. */
+	added_after: string | null,
+	/**  Only match books added before this time. */
+	added_before: string | null,
+	/**  Only match books last opened at or after this time. */
+	opened_after: string | null,
+	/**  Only match books last opened before this time. */
+	opened_before: string | null,
+	/**  Only match books of this type ('file' or 'directory'). */
+	item_type: string | null,
+	/**  Only match books whose path starts with this prefix (e.g. a folder). */
+	path_prefix: string | null,
+	/**
+	 *  Only match files with one of these extensions (e.g. "zip", "epub"), compared
+	 *  case-insensitively.
+	 */
+	formats: string[],
+	/**  The key the books are sorted by. Ties are broken by display name. */
+	sort: BookSortKey,
+	/**  Whether to sort in descending instead of ascending order. */
+	descending: boolean,
+	/**  The maximum number of books to return, or `None` for all of them. */
+	limit: number | null,
+	/**  The number of matching books to skip, for paging. */
+	offset: number,
+};
+
+/**  One page of the books matching a [`BookQuery`]. */
+export type BookQueryResult = {
+	/**  The books of the requested page, in sort order. */
+	books: BookWithState[],
+	/**  The number of books matching the query across all pages. */
+	total_count: number,
+};
+
+/**  Narrows the results of a library search. Filters that are not set match every book. */
+export type BookSearchFilters = {
+	/**  Only match books that have all of these tags. */
+	tag_ids: number[],
+	/**  Only match books in this series. */
+	series_id: number | null,
+	/**  Only match books on this bookshelf. */
+	bookshelf_id: number | null,
+	/**  Only match books of this type ('file' or 'directory'). */
+	item_type: string | null,
+};
+
+/**  The key books are sorted by in a [`BookQuery`]. */
+export type BookSortKey = 
+/**  The display name, in Japanese-aware natural order. */
+"name" | 
+/**  The time the book was added to the library. */
+"added_at" | 
+/**  The time the book was last opened. Books never opened come last. */
+"last_opened_at" | 
+/**  The number of pages. */
+"total_pages" | 
+/**
+ *  The series name in Japanese-aware natural order, then the order within the
+ *  series. Books outside a series come last.
+ */
+"series_order";
+
 /**
  *  Represents a book along with its optional reading state.
  *  Useful for displaying book details whether it has been read or not.
@@ -756,6 +1610,8 @@ export type BookWithState = {
 	item_type: string,
 	/**  The display name of the book. */
 	display_name: string,
+	/**  The key the display name sorts by in natural order (see `natural_sort::sort_key`). */
+	sort_key?: string,
 	/**  The total number of pages in the book. */
 	total_pages: number,
 	/**  The optional identifier of the series this book belongs to. */
@@ -766,14 +1622,91 @@ export type BookWithState = {
 	thumbnail_path: string | null,
 	/**  The timestamp when the book was created (registered). */
 	created_at: string | null,
+	/**
+	 *  The error code explaining why the book could only be opened partially
+	 *  (e.g. a truncated ZIP), or `None` if it opened cleanly.
+	 */
+	damage_code: number | null,
+	/**  When the library scanner found the book file gone, or `None` while it exists. */
+	missing_since: string | null,
+	/**  How far the book has been read. */
+	read_status: ReadStatus,
+	/**  The rating from 1 to 5, or 0 if the book is not rated. */
+	rating: number,
+	/**  Whether the book is marked as a favorite. */
+	is_favorite: boolean,
 	/**  The last read page index, if the book has been opened. */
 	last_read_page_index: number | null,
+	/**  The EPUB CFI of the last read location of a novel, if any. */
+	last_read_cfi: string | null,
 	/**  The timestamp when the book was last opened, if any. */
 	last_opened_at: string | null,
 	/**  List of tag IDs associated with this book. */
 	tag_ids?: number[],
 };
 
+/**
+ *  A position in a book the reader wants to come back to.
+ * 
+ *  A bookmark has either a `page_index` (image books) or a `cfi` (novels), never both.
+ */
+export type Bookmark = {
+	/**  The unique identifier for the bookmark. */
+	id: number,
+	/**  The identifier of the bookmarked book. */
+	book_id: number,
+	/**  The bookmarked page index, for image books. */
+	page_index: number | null,
+	/**  The EPUB CFI of the bookmarked location, for novels. */
+	cfi: string | null,
+	/**  The short label shown in bookmark lists. May be empty. */
+	label: string,
+	/**  A free-form note. May be empty. */
+	note: string,
+	/**  The color code of the bookmark (e.g., "#FF0000"), or `None` for no color. */
+	color_code: string | null,
+	/**  The timestamp when the bookmark was created. */
+	created_at: string,
+};
+
+/**  The fields of a bookmark the reader can change after adding it. */
+export type BookmarkUpdate = {
+	/**  The short label shown in bookmark lists. May be empty. */
+	label: string,
+	/**  A free-form note. May be empty. */
+	note: string,
+	/**  The color code of the bookmark (e.g., "#FF0000"), or `None` for no color. */
+	color_code: string | null,
+};
+
+/**  A bookmark along with the book it belongs to, for the library-wide bookmark list. */
+export type BookmarkWithBook = {
+	/**  The unique identifier for the bookmark. */
+	id: number,
+	/**  The identifier of the bookmarked book. */
+	book_id: number,
+	/**  The bookmarked page index, for image books. */
+	page_index: number | null,
+	/**  The EPUB CFI of the bookmarked location, for novels. */
+	cfi: string | null,
+	/**  The short label shown in bookmark lists. May be empty. */
+	label: string,
+	/**  A free-form note. May be empty. */
+	note: string,
+	/**  The color code of the bookmark (e.g., "#FF0000"), or `None` for no color. */
+	color_code: string | null,
+	/**  The timestamp when the bookmark was created. */
+	created_at: string,
+	/**  The display name of the book. */
+	display_name: string,
+	/**  The unique file path or directory path of the book. */
+	file_path: string,
+	/**  The type of the book ('file' or 'directory'). */
+	item_type: string,
+	/**  The optional file path to the thumbnail image of the book. */
+	thumbnail_path: string | null,
+};
+
 /**  Represents a bookshelf entity used to organize books. */
 export type Bookshelf = {
 	/**  The unique identifier for the bookshelf. */
@@ -784,6 +1717,35 @@ export type Bookshelf = {
 	icon_id: string,
 	/**  The timestamp when the bookshelf was created. */
 	created_at: string | null,
+	/**
+	 *  The rule selecting the books of a smart bookshelf, or `None` for a bookshelf
+	 *  whose books are added by hand.
+	 */
+	rule: BookshelfRule | null,
+};
+
+/**
+ *  The saved rule of a smart bookshelf. The bookshelf holds every book meeting all of
+ *  the conditions that are set.
+ */
+export type BookshelfRule = {
+	/**  Only include books with these tags, as selected by `tag_match`. */
+	tag_ids: number[],
+	/**  Whether a book needs all of `tag_ids` or any one of them. */
+	tag_match: TagMatch,
+	/**  Only include books in this series. */
+	series_id: number | null,
+	/**  Only include books with this read status. */
+	read_status: ReadStatus | null,
+	/**  Only include books added to the library within this many days. */
+	added_within_days: number | null,
+	/**  Only include books whose path starts with this prefix (e.g. a folder). */
+	path_prefix: string | null,
+	/**
+	 *  Only include files with one of these extensions (e.g. "zip", "epub"), compared
+	 *  case-insensitively.
+	 */
+	formats: string[],
 };
 
 /**  Settings specific to the bookshelf view. */
@@ -796,12 +1758,62 @@ export type BookshelfSettings = {
 	enableAutoScroll?: boolean,
 };
 
+/**  A snapshot of the global image cache. */
+export type CacheStats = {
+	/**  The configured capacity of the cache in bytes. */
+	capacityBytes: number,
+	/**  The total size in bytes of the cached images. */
+	residentBytes: number,
+	/**  The number of cached images. */
+	entryCount: number,
+	/**  The cached images per book, largest first. */
+	books: BookCacheStats[],
+	/**  How the open book has used the cache, or `None` if no book is open. */
+	openBook: CacheUsage | null,
+	/**  How many times the cache was shrunk because available memory ran low. */
+	memoryPressureShrinks: number,
+};
+
+/**  How one book has used the image cache since it was opened. */
+export type CacheUsage = {
+	/**  The identifier (path) of the book. */
+	bookId: string,
+	/**  The number of page requests served from the cache. */
+	hits: number,
+	/**  The number of page requests that had to load the page. */
+	misses: number,
+	/**  The share of page requests served from the cache (`0.0` when none were made). */
+	hitRate: number,
+	/**  The number of pages loaded ahead by preloading. */
+	preloadedPages: number,
+	/**  The number of preloaded pages that were requested afterwards. */
+	preloadedPagesUsed: number,
+	/**  The number of preloaded pages evicted before they were ever requested. */
+	preloadedPagesWasted: number,
+};
+
+/**  Represents the chroma subsampling of re-encoded JPEG images. */
+export type ChromaSubsampling = 
+/**  4:4:4 (full-resolution color) */
+"yuv444" | 
+/**  4:2:2 (color halved horizontally) */
+"yuv422" | 
+/**  4:2:0 (color halved in both directions) */
+"yuv420";
+
 /**  Configuration for image caching and preloading. */
 export type ComicCacheSettings = {
 	/**  The number of pages to preload in each direction (forward and backward). */
 	preloadPageCount?: number,
 	/**  The maximum size of the image memory cache in MiB. */
 	imageCacheSizeMib?: number,
+	/**
+	 *  Whether to shrink the image cache when available system memory runs low.
+	 *  Only supported on Linux, where available memory is read from `/proc/meminfo`.
+	 */
+	shrinkOnMemoryPressure?: boolean,
+	/**  The available system memory in MiB below which the image cache is shrunk. */
+	memoryPressureThresholdMib?: number,
 };
 
 /**  Configuration specific to reading comics (image-based content). */
@@ -816,6 +1828,8 @@ export type ComicSettings = {
 	loupe?: LoupeSettings,
 	/**  Configuration for image caching and preloading. */
 	cache?: ComicCacheSettings,
+	/**  Configuration for the long-strip (webtoon) reading mode. */
+	longStrip?: LongStripSettings,
 };
 
 /**
@@ -835,6 +1849,35 @@ export type CommandError = {
 	details: SettingsValidationViolation[] | null,
 };
 
+/**  Represents a person credited for books (an author, illustrator or translator). */
+export type Creator = {
+	/**  The unique identifier for the creator. */
+	id: number,
+	/**  The unique name of the creator. */
+	name: string,
+	/**  The number of books the creator is credited for. */
+	book_count: number,
+};
+
+/**  The part a creator had in making a book. */
+export type CreatorRole = 
+/**  Wrote the book. */
+"author" | 
+/**  Drew the art or illustrations. */
+"illustrator" | 
+/**  Translated the book. */
+"translator";
+
+/**  The reading done on one day. */
+export type DailyReading = {
+	/**  The local date. */
+	date: string,
+	/**  The time spent reading, in seconds. */
+	seconds_read: number,
+	/**  The number of pages read. */
+	pages_read: number,
+};
+
 /**  Represents the direction in which content should be read. */
 export type Direction = 
 /**  Right-to-Left (e.g., traditional Japanese manga). */
@@ -850,6 +1893,55 @@ export type EntriesResult = {
 	is_directory: boolean,
 	/**  Whether the container is an EPUB novel. */
 	is_novel: boolean,
+	/**
+	 *  The error code explaining why the container could only be opened partially (e.g.
+	 *  a truncated ZIP whose pages were salvaged), or `None` if it opened cleanly.
+	 */
+	damage_code: number | null,
+	/**
+	 *  The entry names hidden by the exclusion rules, so the user can see what was left
+	 *  out.
+	 */
+	excluded_entries: string[],
+};
+
+/**  How the pages of a container are ordered. */
+export type EntrySortMode = 
+/**
+ *  Natural order of the entry paths, ignoring case and width and reading kanji
+ *  numerals (`2.jpg` before `10.jpg`, `第二巻` before `第十巻`).
+ */
+"natural" | 
+/**  The order the entries are stored in the archive, or listed by the file system. */
+"archiveOrder" | 
+/**
+ *  Oldest modification time first. Entries without a time (e.g. salvaged from a
+ *  damaged archive) follow in natural order.
+ */
+"modifiedTime" | 
+/**
+ *  Natural order, with pages named like a cover (`cover`, `front`, `表紙`) moved to
+ *  the front.
+ */
+"coverFirst";
+
+/**  A rule hiding the entries whose path matches its pattern. */
+export type ExclusionRule = ExclusionRule_Serialize | ExclusionRule_Deserialize;
+
+/**  A rule hiding the entries whose path matches its pattern. */
+export type ExclusionRule_Deserialize = {
+	/**  The pattern matched against the entry path. */
+	pattern: string,
+	/**  How the pattern is interpreted. */
+	syntax?: PatternSyntax,
+};
+
+/**  A rule hiding the entries whose path matches its pattern. */
+export type ExclusionRule_Serialize = {
+	/**  The pattern matched against the entry path. */
+	pattern: string,
+	/**  How the pattern is interpreted. */
+	syntax: PatternSyntax,
 };
 
 /**  Settings for the file navigator. */
@@ -885,6 +1977,17 @@ export type HistorySettings = {
 	recordReadingHistory?: boolean,
 };
 
+/**  Represents the format resized images are re-encoded to. */
+export type ImageOutputFormat = 
+/**  JPEG (PNG for images with transparency) */
+"jpeg" | 
+/**  Lossless WebP */
+"webpLossless" | 
+/**  PNG */
+"png" | 
+/**  The format of the original image */
+"passthrough";
+
 /**  Represents the algorithm used for resampling images. */
 export type ImageResamplingMethod = ImageResamplingMethod_Serialize | ImageResamplingMethod_Deserialize;
 
@@ -939,6 +2042,57 @@ export type LayoutSettings = {
 	sidePane?: SidePaneSettings,
 };
 
+/**  Represents a folder watched for books. */
+export type LibraryRoot = {
+	/**  The unique identifier for the library root. */
+	id: number,
+	/**  The unique path of the watched folder. */
+	path: string,
+	/**  The timestamp when the folder was added to the library. */
+	created_at: string,
+	/**  The timestamp when the folder was last scanned completely, if ever. */
+	last_scanned_at: string | null,
+};
+
+/**  The progress of a library scan, emitted as a `library-scan-progress` event. */
+export type LibraryScanProgress = {
+	/**  The path of the book file just read. */
+	path: string,
+	/**  The number of new or changed files read so far in this folder. */
+	filesRead: number,
+	/**  The number of new or changed files found in this folder. */
+	totalFiles: number,
+};
+
+/**  The result of a library scan. */
+export type LibraryScanReport = {
+	/**  The number of book files found. */
+	filesFound: number,
+	/**  The number of books registered for the first time. */
+	added: number,
+	/**  The number of known books re-read because their file changed or came back. */
+	updated: number,
+	/**  The number of books newly flagged as missing. */
+	missing: number,
+	/**  The paths of the files that could not be read. They are retried by the next scan. */
+	failed: string[],
+};
+
+/**  The result of verifying every book in the library. */
+export type LibraryVerificationReport = {
+	/**  The number of books in the library. */
+	totalBooks: number,
+	/**  The number of books fully verified before the job finished or was cancelled. */
+	booksChecked: number,
+	/**
+	 *  The reports of the books that failed to open, were opened partially, or have
+	 *  failing pages. Healthy books are left out.
+	 */
+	reports: VerificationReport[],
+	/**  Whether the job was cancelled before every book was checked. */
+	cancelled: boolean,
+};
+
 /**  Represents the severity level for application logs. */
 export type LogLevel = "trace" | "debug" | "info" | "warn" | "error";
 
@@ -948,6 +2102,24 @@ export type LogSettings = {
 	level?: LogLevel,
 };
 
+/**
+ *  Configuration for the long-strip (webtoon) reading mode.
+ * 
+ *  In this mode pages are scrolled as one continuous vertical strip; pages taller than
+ *  `segment_height` are sliced by the backend so the webview never holds one huge image.
+ */
+export type LongStripSettings = {
+	/**  Whether comics are read as a continuous vertical strip. */
+	enabled?: boolean,
+	/**  The height in pixels of the segments tall pages are sliced into. */
+	segmentHeight?: number,
+	/**
+	 *  The height in pixels repeated between consecutive segments to hide seams.
+	 *  Capped below the minimum segment height so slicing always advances.
+	 */
+	overlap?: number,
+};
+
 /**  Configuration for the Loupe (Magnifier) feature. */
 export type LoupeSettings = {
 	/**  The magnification zoom level of the loupe (`1.0` = no magnification). */
@@ -958,6 +2130,46 @@ export type LoupeSettings = {
 	toggleKey?: string,
 };
 
+/**  The number of books finished in one month. */
+export type MonthlyFinished = {
+	/**  The year, e.g. 2026. */
+	year: number,
+	/**  The month, from 1 to 12. */
+	month: number,
+	/**  The number of books finished in the month. */
+	books_finished: number,
+};
+
+/**  An annotation to add to a novel. */
+export type NewAnnotation = {
+	/**  The identifier of the book to annotate. */
+	book_id: number,
+	/**  The EPUB CFI range of the highlighted passage. */
+	cfi_range: string,
+	/**  The highlighted text, as it was selected. */
+	selected_text: string,
+	/**  A free-form note. May be empty. */
+	note: string,
+	/**  The color code of the highlight (e.g., "#FFFF00"). */
+	color_code: string,
+};
+
+/**  A bookmark to add to a book. */
+export type NewBookmark = {
+	/**  The identifier of the book to bookmark. */
+	book_id: number,
+	/**  The page index to bookmark, for image books. */
+	page_index: number | null,
+	/**  The EPUB CFI of the location to bookmark, for novels. */
+	cfi: string | null,
+	/**  The short label shown in bookmark lists. May be empty. */
+	label: string,
+	/**  A free-form note. May be empty. */
+	note: string,
+	/**  The color code of the bookmark (e.g., "#FF0000"), or `None` for no color. */
+	color_code: string | null,
+};
+
 /**  Configuration specific to reading novels (text-based content). */
 export type NovelSettings = {
 	/**  The font family used for rendering the text. */
@@ -966,6 +2178,48 @@ export type NovelSettings = {
 	fontSize?: number | null,
 };
 
+/**  A page that failed verification. */
+export type PageProblem = {
+	/**  The entry name of the page. */
+	entry: string,
+	/**  Why the page failed. */
+	kind: PageProblemKind,
+	/**  The underlying error message. */
+	message: string,
+};
+
+/**  Why a page failed verification. */
+export type PageProblemKind = 
+/**
+ *  The entry could not be read from the container (e.g. a CRC mismatch in a ZIP or
+ *  RAR archive, or a PDF page that failed to render).
+ */
+"unreadable" | 
+/**  The entry or its image data ends prematurely. */
+"truncated" | 
+/**  The entry was read, but its image data could not be decoded. */
+"undecodable";
+
+/**  How the pattern of an [`ExclusionRule`] is interpreted. */
+export type PatternSyntax = 
+/**
+ *  A case-insensitive glob: `*` matches within one path component, `**` across
+ *  components and `?` one character. A glob without a `/` is matched against every
+ *  component of the entry path, so `__MACOSX` hides the whole folder; one with a `/`
+ *  is matched against the whole path.
+ */
+"glob" | 
+/**  A regular expression searched for anywhere in the entry path. */
+"regex";
+
+/**  The recent timings of the image pipeline. */
+export type PerformanceMetrics = {
+	/**  The most recent samples, oldest first. */
+	samples: TimingSample[],
+	/**  The samples aggregated per step and source format. */
+	summaries: StageSummary[],
+};
+
 /**  Represents a book along with its reading state, specifically for books that have been opened. */
 export type ReadBook = {
 	/**  The unique identifier for the book. */
@@ -993,6 +2247,25 @@ export type ReadBook = {
 	last_opened_at: string,
 };
 
+/**
+ *  How far a book has been read.
+ * 
+ *  The status is set by the reader, and advances by itself as the book is read: opening
+ *  an unread or wanted book makes it `Reading`, and reaching the last page makes it
+ *  `Read`.
+ */
+export type ReadStatus = 
+/**  The book has not been read yet. */
+"unread" | 
+/**  The reader wants to read the book. */
+"want_to_read" | 
+/**  The book is being read. */
+"reading" | 
+/**  The book has been finished. */
+"read" | 
+/**  The reader stopped reading the book without finishing it. */
+"abandoned";
+
 /**  Settings for the reading experience. */
 export type ReaderSettings = ReaderSettings_Serialize | ReaderSettings_Deserialize;
 
@@ -1006,6 +2279,16 @@ export type ReaderSettings_Deserialize = {
 	rendering?: RenderingSettings_Deserialize,
 	/**  Behavior when paging past the last/first page of a book (auto-open adjacent book). */
 	autoOpenAdjacentBook?: AutoOpenAdjacentBookMode,
+	/**
+	 *  Rules hiding junk entries (e.g. `__MACOSX` folders, dotfiles) from every book.
+	 *  Books can add rules of their own.
+	 */
+	exclusionRules?: ExclusionRule_Deserialize[],
+	/**
+	 *  How the pages of a book are ordered. Books can override it with a manual page
+	 *  order.
+	 */
+	entrySortMode?: EntrySortMode,
 };
 
 /**  Settings for the reading experience. */
@@ -1018,6 +2301,39 @@ export type ReaderSettings_Serialize = {
 	rendering: RenderingSettings_Serialize,
 	/**  Behavior when paging past the last/first page of a book (auto-open adjacent book). */
 	autoOpenAdjacentBook: AutoOpenAdjacentBookMode,
+	/**
+	 *  Rules hiding junk entries (e.g. `__MACOSX` folders, dotfiles) from every book.
+	 *  Books can add rules of their own.
+	 */
+	exclusionRules: ExclusionRule_Serialize[],
+	/**
+	 *  How the pages of a book are ordered. Books can override it with a manual page
+	 *  order.
+	 */
+	entrySortMode: EntrySortMode,
+};
+
+/**
+ *  A stretch of time a book was read without a long break.
+ * 
+ *  A session starts when the book is opened or a page is turned after a break, and
+ *  grows as pages keep being turned.
+ */
+export type ReadingSession = {
+	/**  The unique identifier for the session. */
+	id: number,
+	/**  The identifier of the book that was read. */
+	book_id: number,
+	/**  When the session started. */
+	started_at: string,
+	/**  When a page was last turned in the session. */
+	ended_at: string,
+	/**  The page index the session started on. */
+	start_page: number,
+	/**  The page index the session ended on. */
+	end_page: number,
+	/**  The number of pages read forward in the session. Paging back is not counted. */
+	pages_turned: number,
 };
 
 /**  Represents the reading state of a specific book. */
@@ -1026,10 +2342,41 @@ export type ReadingState = {
 	book_id: number,
 	/**  The last read page index. */
 	last_read_page_index: number,
+	/**
+	 *  The EPUB CFI of the last read location of a novel, which stays valid when the
+	 *  pagination changes. `None` for image books.
+	 */
+	last_read_cfi?: string | null,
 	/**  The timestamp when the book was last opened. */
 	last_opened_at: string | null,
 };
 
+/**  A summary of the reading done over a period, e.g. for a yearly reading report. */
+export type ReadingStatistics = {
+	/**  The time spent reading in the period, in seconds. */
+	total_seconds_read: number,
+	/**  The number of pages read in the period. */
+	total_pages_read: number,
+	/**  The number of books finished in the period. */
+	total_books_finished: number,
+	/**
+	 *  The number of days in a row, up to today, with some reading. Reading yesterday
+	 *  keeps the streak going until the end of today.
+	 */
+	current_streak_days: number,
+	/**  The most days in a row with some reading, at any time. */
+	longest_streak_days: number,
+	/**  The reading done on each day of the period with some reading, in date order. */
+	daily: DailyReading[],
+	/**
+	 *  The books finished in each month of the period with a finished book, in date
+	 *  order.
+	 */
+	monthly_finished: MonthlyFinished[],
+	/**  The reading done in each series during the period, most time read first. */
+	series: SeriesReading[],
+};
+
 /**  Configuration for image and document rendering. */
 export type RenderingSettings = RenderingSettings_Serialize | RenderingSettings_Deserialize;
 
@@ -1041,8 +2388,22 @@ export type RenderingSettings_Deserialize = {
 	maxImageHeight?: number,
 	/**  The algorithm used for resampling (resizing) images. */
 	imageResamplingMethod?: ImageResamplingMethod_Deserialize,
+	/**
+	 *  The format resized images are re-encoded to. Images that need no resize are
+	 *  always served as stored.
+	 */
+	outputFormat?: ImageOutputFormat,
+	/**  The JPEG quality of re-encoded images. */
+	outputQuality?: number,
+	/**  The chroma subsampling of re-encoded JPEG images. */
+	chromaSubsampling?: ChromaSubsampling,
 	/**  The vertical resolution used when rasterizing PDF pages to images. */
 	pdfRenderResolutionHeight?: number,
+	/**
+	 *  Pages taking longer than this many milliseconds to load are logged with a
+	 *  timing breakdown (`0` = never).
+	 */
+	slowPageLogThresholdMs?: number,
 };
 
 /**  Configuration for image and document rendering. */
@@ -1053,8 +2414,22 @@ export type RenderingSettings_Serialize = {
 	maxImageHeight: number,
 	/**  The algorithm used for resampling (resizing) images. */
 	imageResamplingMethod: ImageResamplingMethod_Serialize,
+	/**
+	 *  The format resized images are re-encoded to. Images that need no resize are
+	 *  always served as stored.
+	 */
+	outputFormat: ImageOutputFormat,
+	/**  The JPEG quality of re-encoded images. */
+	outputQuality: number,
+	/**  The chroma subsampling of re-encoded JPEG images. */
+	chromaSubsampling: ChromaSubsampling,
 	/**  The vertical resolution used when rasterizing PDF pages to images. */
 	pdfRenderResolutionHeight: number,
+	/**
+	 *  Pages taking longer than this many milliseconds to load are logged with a
+	 *  timing breakdown (`0` = never).
+	 */
+	slowPageLogThresholdMs: number,
 };
 
 /**  Represents a series entity that groups multiple books together. */
@@ -1063,10 +2438,26 @@ export type Series = {
 	id: number,
 	/**  The unique name of the series. */
 	name: string,
+	/**  The key the name sorts by in natural order (see `natural_sort::sort_key`). */
+	sort_key?: string,
 	/**  The timestamp when the series was created. */
 	created_at: string,
 };
 
+/**  The reading done in the books of one series. */
+export type SeriesReading = {
+	/**  The identifier of the series. */
+	series_id: number,
+	/**  The name of the series. */
+	series_name: string,
+	/**  The time spent reading the series, in seconds. */
+	seconds_read: number,
+	/**  The number of pages read in the series. */
+	pages_read: number,
+	/**  The number of books of the series finished. */
+	books_finished: number,
+};
+
 /**
  *  A single-category settings change.
  * 
@@ -1137,6 +2528,37 @@ export type SortOrder =
 /**  Sort by date, descending (Newest first). */
 "date_desc";
 
+/**  A step of the image pipeline that is timed. */
+export type Stage = 
+/**  Reading a page out of its container (for PDF, this includes the render). */
+"read" | 
+/**  Rasterizing a PDF page. */
+"pdfRender" | 
+/**  Decoding a page before it is resized. */
+"decode" | 
+/**  Resizing a decoded page. */
+"resize" | 
+/**  Re-encoding a resized page. */
+"encode" | 
+/**  The whole load of a page, from the read to the final encode. */
+"load";
+
+/**  The timings of one step for one source format. */
+export type StageSummary = {
+	/**  The timed step. */
+	stage: Stage,
+	/**  The format of the source images, if known. */
+	format: string | null,
+	/**  The number of samples. */
+	count: number,
+	/**  The mean duration in milliseconds. */
+	meanMs: number,
+	/**  The 95th percentile duration in milliseconds. */
+	p95Ms: number,
+	/**  The longest duration in milliseconds. */
+	maxMs: number,
+};
+
 /**  Settings related to the application's startup behavior. */
 export type StartupSettings = {
 	/**  The initial view presented to the user upon launch. */
@@ -1147,6 +2569,48 @@ export type StartupSettings = {
 	checkUpdateOnStartup?: boolean,
 };
 
+/**
+ *  The layout of a book read as one continuous vertical strip.
+ * 
+ *  Consecutive pages are stitched top to bottom. Pages narrower or wider than the
+ *  strip are scaled to its width, so `strip_top`/`strip_height` are expressed in
+ *  strip pixels rather than source pixels.
+ */
+export type StripLayout = {
+	/**  The width of the strip in pixels (the widest page). */
+	width: number,
+	/**  The total height of the strip in strip pixels. */
+	height: number,
+	/**  The segments in reading order. */
+	segments: StripSegment[],
+};
+
+/**
+ *  One piece of the continuous long strip.
+ * 
+ *  Pages no taller than the segment height are a single segment whose `entry` is the
+ *  page itself; taller pages are sliced into virtual sub-entries that `ImageLoader`
+ *  cuts from the page on request.
+ */
+export type StripSegment = {
+	/**  The entry to request through the image commands. */
+	entry: string,
+	/**  The page entry the segment is cut from. */
+	sourceEntry: string,
+	/**  The offset in pixels of the segment within its page. */
+	sourceTop: number,
+	/**  The width of the segment in pixels. */
+	width: number,
+	/**  The height of the segment in pixels. */
+	height: number,
+	/**  The height in pixels shared with the previous segment of the same page. */
+	overlap: number,
+	/**  The offset of the segment within the whole strip, in strip pixels. */
+	stripTop: number,
+	/**  The height of the segment within the whole strip, in strip pixels. */
+	stripHeight: number,
+};
+
 /**  Represents a tag entity used to categorize books. */
 export type Tag = {
 	/**  The unique identifier for the tag. */
@@ -1157,6 +2621,81 @@ export type Tag = {
 	color_code: string,
 };
 
+/**  Whether a book has to have all or any of the tags it is filtered by. */
+export type TagMatch = 
+/**  The book has every one of the tags. */
+"all" | 
+/**  The book has at least one of the tags. */
+"any";
+
+/**
+ *  Describes the deep-zoom tile pyramid of a page.
+ * 
+ *  Level `0` is the full-resolution source; each further level halves both dimensions,
+ *  up to `max_level`, at which the whole page fits in a single tile.
+ */
+export type TileInfo = {
+	/**  The width of the full-resolution source in pixels. */
+	width: number,
+	/**  The height of the full-resolution source in pixels. */
+	height: number,
+	/**  The edge length of a tile in pixels. */
+	tile_size: number,
+	/**  The coarsest level of the pyramid. */
+	max_level: number,
+};
+
+/**  One timed step of loading a page. */
+export type TimingSample = {
+	/**  The timed step. */
+	stage: Stage,
+	/**  The entry the step worked on. */
+	entry: string,
+	/**  The format of the source image (e.g. `jpg`, `png`, `pdf`), if known. */
+	format: string | null,
+	/**  How long the step took, in milliseconds. */
+	durationMs: number,
+	/**  When the step finished, in milliseconds since the Unix epoch. */
+	recordedAt: number,
+};
+
+/**  The progress of a verification job, emitted as a `verification-progress` event. */
+export type VerificationProgress = {
+	/**  The ID of the job. */
+	jobId: number,
+	/**  The path of the book being verified. */
+	path: string,
+	/**  The number of pages of the book checked so far. */
+	pagesChecked: number,
+	/**  The number of pages in the book. */
+	totalPages: number,
+	/**  The number of books finished before this one. */
+	booksChecked: number,
+	/**  The number of books the job verifies (1 for `verify_book`). */
+	totalBooks: number,
+};
+
+/**  The result of verifying one book. */
+export type VerificationReport = {
+	/**  The path of the verified book. */
+	path: string,
+	/**  The number of pages in the book. */
+	totalPages: number,
+	/**  The number of pages checked before the verification finished or was cancelled. */
+	pagesChecked: number,
+	/**  The pages that failed verification, in entry order. */
+	problems: PageProblem[],
+	/**
+	 *  The error code of damage found while opening the book (e.g. a truncated ZIP
+	 *  whose pages were salvaged), or `None` if it opened cleanly.
+	 */
+	damageCode: number | null,
+	/**  The error that kept the book from opening at all, or `None` if it opened. */
+	openError: string | null,
+	/**  Whether the verification was cancelled before every page was checked. */
+	cancelled: boolean,
+};
+
 /**  The category of a [`SettingsValidationViolation`]. */
 export type ViolationKind = 
 /**  The value is below `min` or above `max`. */
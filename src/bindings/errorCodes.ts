@@ -11,11 +11,14 @@ export const ErrorCode = {
   image: 80001,
   imageResize: 80101,
   unrar: 10301,
+  missingVolume: 10302,
   zip: 10401,
+  damagedArchive: 10402,
   epub: 10501,
   epubArchive: 10502,
   io: 20001,
   path: 20101,
+  watch: 20201,
   tauri: 30001,
   rayonThreadPool: 30201,
   serdeJson: 40001,
@@ -23,6 +26,7 @@ export const ErrorCode = {
   parseInt: 40201,
   settings: 50001,
   settingsValidation: 50002,
+  invalidArgument: 60001,
   database: 70001,
   migration: 70101,
   other: 90001,
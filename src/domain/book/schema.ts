@@ -30,6 +30,11 @@ export const ReadingStateSchema = z.object({
   book_id: z.number(),
   /** The last read page index. */
   last_read_page_index: z.number(),
+  /**
+   * The EPUB CFI of the last read location of a novel, which stays valid when the pagination
+   * changes. Absent or null for image books.
+   */
+  last_read_cfi: z.string().nullable().optional(),
   /**
    * The timestamp when the book was last opened.
    * Represented as an ISO 8601 string (e.g., "2026-03-01T15:30:00").
@@ -50,17 +55,46 @@ export const ReadBookSchema = BookSchema.extend({
   last_opened_at: z.string(),
 });
 
+/**
+ * How far a book has been read. It is set by the reader, and advances by itself as the book is
+ * read: opening an unread or wanted book makes it "reading", and reaching the last page "read".
+ */
+export const ReadStatusSchema = z.enum(["unread", "want_to_read", "reading", "read", "abandoned"]);
+
 /**
  * Represents a book along with its optional reading state.
  */
 export const BookWithStateSchema = BookSchema.extend({
+  /**
+   * The key the display name sorts by in natural order, computed by the backend.
+   * Compare keys as plain strings (see `compareSortKeys`).
+   */
+  sort_key: z.string(),
   /**
    * The timestamp when the book was created (registered).
    * Represented as an ISO 8601 string (e.g., "2026-03-01T15:30:00"), or null.
    */
   created_at: z.string().nullable(),
+  /**
+   * The error code explaining why the book could only be opened partially
+   * (e.g. a truncated ZIP), or null if it opened cleanly.
+   */
+  damage_code: z.number().nullable(),
+  /**
+   * When the library scanner found the book file gone, or null while it exists.
+   * Represented as an ISO 8601 string (e.g., "2026-03-01T15:30:00").
+   */
+  missing_since: z.string().nullable(),
+  /** How far the book has been read. */
+  read_status: ReadStatusSchema,
+  /** The rating from 1 to 5, or 0 if the book is not rated. */
+  rating: z.number().int().min(0).max(5),
+  /** Whether the book is marked as a favorite. */
+  is_favorite: z.boolean(),
   /** The last read page index, if the book has been opened. */
   last_read_page_index: z.number().nullable(),
+  /** The EPUB CFI of the last read location of a novel, if any. */
+  last_read_cfi: z.string().nullable(),
   /**
    * The timestamp when the book was last opened.
    * Represented as an ISO 8601 string (e.g., "2026-03-01T15:30:00").
@@ -85,6 +119,11 @@ export type ReadingState = z.infer<typeof ReadingStateSchema>;
  */
 export type ReadBook = z.infer<typeof ReadBookSchema>;
 
+/**
+ * How far a book has been read.
+ */
+export type ReadStatus = z.infer<typeof ReadStatusSchema>;
+
 /**
  * Represents a book along with its optional reading state.
  */
@@ -1,4 +1,31 @@
 import { z } from "zod";
+import { ReadStatusSchema } from "../book/schema";
+
+/**
+ * The saved rule of a smart bookshelf. The bookshelf holds every book meeting all of the
+ * conditions that are set.
+ */
+export const BookshelfRuleSchema = z.object({
+  /** Only include books with these tags, as selected by `tag_match`. */
+  tag_ids: z.array(z.number()),
+  /** Whether a book needs all of `tag_ids` or any one of them. */
+  tag_match: z.enum(["all", "any"]),
+  /** Only include books in this series, or null. */
+  series_id: z.number().nullable(),
+  /** Only include books with this read status, or null. */
+  read_status: ReadStatusSchema.nullable(),
+  /** Only include books added to the library within this many days, or null. */
+  added_within_days: z.number().nullable(),
+  /** Only include books whose path starts with this prefix (e.g. a folder), or null. */
+  path_prefix: z.string().nullable(),
+  /** Only include files with one of these extensions (e.g. "zip"), compared case-insensitively. */
+  formats: z.array(z.string()),
+});
+
+/**
+ * The saved rule of a smart bookshelf.
+ */
+export type BookshelfRule = z.infer<typeof BookshelfRuleSchema>;
 
 /**
  * Represents a bookshelf entity used to organize books.
@@ -15,6 +42,11 @@ export const BookshelfSchema = z.object({
    * Represented as an ISO 8601 string (e.g., "2026-03-01T15:30:00"), or null.
    */
   created_at: z.string().nullable(),
+  /**
+   * The rule selecting the books of a smart bookshelf, or null for a bookshelf whose books are
+   * added by hand.
+   */
+  rule: BookshelfRuleSchema.nullable(),
 });
 
 /**
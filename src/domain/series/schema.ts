@@ -8,6 +8,11 @@ export const SeriesSchema = z.object({
   id: z.number(),
   /** The unique name of the series. */
   name: z.string(),
+  /**
+   * The key the name sorts by in natural order, computed by the backend.
+   * Compare keys as plain strings (see `compareSortKeys`).
+   */
+  sort_key: z.string(),
   /**
    * The timestamp when the series was created.
    * Represented as an ISO 8601 string (e.g., "2026-03-01T15:30:00").
@@ -72,15 +72,17 @@ describe("FileNavigator", () => {
     const preloadedState = createBasePreloadedState();
     preloadedState.read.explorer.searchText = "Banana";
     preloadedState.read.explorer.entries = [
-      { name: "Apple", is_directory: false, last_modified: 0 },
+      { name: "Apple", is_directory: false, last_modified: 0, sort_key: "Apple" },
     ];
 
     renderWithProviders(<FileNavigator />, { preloadedState });
-    expect(screen.getByText(/No results for "Banana"/i)).toBeInTheDocument();
+    expect(await screen.findByText(/No results for "Banana"/i)).toBeInTheDocument();
   });
 
   it("should dispatch setContainerFilePath when a file is clicked", async () => {
-    const entries: DirEntry[] = [{ name: "book.zip", is_directory: false, last_modified: 0 }];
+    const entries: DirEntry[] = [
+      { name: "book.zip", is_directory: false, last_modified: 0, sort_key: "book.zip" },
+    ];
     const preloadedState = createBasePreloadedState();
     preloadedState.read.explorer.entries = entries;
 
@@ -96,7 +98,9 @@ describe("FileNavigator", () => {
   });
 
   it("should dispatch updateExploreBasePath when a directory is double-clicked", async () => {
-    const entries: DirEntry[] = [{ name: "folder", is_directory: true, last_modified: 0 }];
+    const entries: DirEntry[] = [
+      { name: "folder", is_directory: true, last_modified: 0, sort_key: "folder" },
+    ];
     const preloadedState = createBasePreloadedState();
     preloadedState.read.explorer.entries = entries;
 
@@ -114,8 +118,8 @@ describe("FileNavigator", () => {
     // Single-clicking row A then row B within the double-click interval must not be
     // misread as a double-click on B; B is selected and no directory navigation occurs.
     const entries: DirEntry[] = [
-      { name: "folderA", is_directory: true, last_modified: 0 },
-      { name: "folderB", is_directory: true, last_modified: 0 },
+      { name: "folderA", is_directory: true, last_modified: 0, sort_key: "folderA" },
+      { name: "folderB", is_directory: true, last_modified: 0, sort_key: "folderB" },
     ];
     const preloadedState = createBasePreloadedState();
     preloadedState.read.explorer.entries = entries;
@@ -140,7 +144,9 @@ describe("FileNavigator", () => {
   });
 
   it("should open the file when a file is double-clicked", async () => {
-    const entries: DirEntry[] = [{ name: "book.zip", is_directory: false, last_modified: 0 }];
+    const entries: DirEntry[] = [
+      { name: "book.zip", is_directory: false, last_modified: 0, sort_key: "book.zip" },
+    ];
     const preloadedState = createBasePreloadedState();
     preloadedState.read.explorer.entries = entries;
 
@@ -157,7 +163,9 @@ describe("FileNavigator", () => {
   });
 
   it("should scroll to row when selectedIndex is set", async () => {
-    const entries: DirEntry[] = [{ name: "book.zip", is_directory: false, last_modified: 0 }];
+    const entries: DirEntry[] = [
+      { name: "book.zip", is_directory: false, last_modified: 0, sort_key: "book.zip" },
+    ];
     vi.mocked(useFileSelection).mockImplementationOnce(
       (_fileHistory, _fileHistoryIndex, _entries, callback) => {
         callback(0);
@@ -181,7 +189,9 @@ describe("FileNavigator", () => {
   });
 
   it("should log error if scrollToRow fails", async () => {
-    const entries: DirEntry[] = [{ name: "book.zip", is_directory: false, last_modified: 0 }];
+    const entries: DirEntry[] = [
+      { name: "book.zip", is_directory: false, last_modified: 0, sort_key: "book.zip" },
+    ];
     vi.mocked(useFileSelection).mockImplementationOnce(
       (_fileHistory, _fileHistoryIndex, _entries, callback) => {
         callback(0);
@@ -5,6 +5,7 @@ import { debug, error } from "@tauri-apps/plugin-log";
 import { useCallback, useEffect, useMemo, useRef, useState } from "react";
 import { useTranslation } from "react-i18next";
 import { List, type RowComponentProps, useListCallbackRef } from "react-window";
+import { useAndSearch } from "../../../../hooks/useAndSearch";
 import { type RootState, useAppDispatch, useAppSelector } from "../../../../store/store";
 import type { DirEntry } from "../../../../types/DirEntry";
 import SidePanelHeader from "../../../SidePane/components/SidePanelHeader";
@@ -91,11 +92,10 @@ export default function FileListViewer() {
   const pendingIndex = useRef<number | null>(null);
   const doubleClickIntervalMs = 200;
 
+  const searchedEntries = useAndSearch(entries, searchText, andSearch);
   const filteredSortedEntries = useMemo(() => {
-    return andSearch(entries, searchText)
-      .slice()
-      .sort((a, b) => sortBy(a, b, fileNavigatorSettings.sortOrder));
-  }, [entries, fileNavigatorSettings.sortOrder, searchText]);
+    return searchedEntries.slice().sort((a, b) => sortBy(a, b, fileNavigatorSettings.sortOrder));
+  }, [searchedEntries, fileNavigatorSettings.sortOrder]);
 
   const updateEntriesCallback = useCallback(() => {
     if (history[historyIndex]) {
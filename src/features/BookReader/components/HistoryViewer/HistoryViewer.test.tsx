@@ -92,8 +92,10 @@ describe("HistoryViewer", () => {
     const searchInput = screen.getByRole("searchbox");
     await user.type(searchInput, "Apple");
 
+    await waitFor(() => {
+      expect(screen.queryByText("Banana")).not.toBeInTheDocument();
+    });
     expect(screen.getByText("Apple")).toBeInTheDocument();
-    expect(screen.queryByText("Banana")).not.toBeInTheDocument();
   });
 
   it("should show 'no search results' when search does not match any entry", async () => {
@@ -107,7 +109,7 @@ describe("HistoryViewer", () => {
     const searchInput = screen.getByRole("searchbox");
     await user.type(searchInput, "Banana");
 
-    expect(screen.getByText(/No results for "Banana"/i)).toBeInTheDocument();
+    expect(await screen.findByText(/No results for "Banana"/i)).toBeInTheDocument();
   });
 
   it("should scroll to row when selectedIndex is set", async () => {
@@ -5,6 +5,7 @@ import { useCallback, useEffect, useMemo, useState } from "react";
 import { useTranslation } from "react-i18next";
 import { List, type RowComponentProps, useListCallbackRef } from "react-window";
 import type { ReadBook } from "../../../../domain/book/schema";
+import { useAndSearch } from "../../../../hooks/useAndSearch";
 import { useAppDispatch, useAppSelector } from "../../../../store/store";
 import SidePanelHeader from "../../../SidePane/components/SidePanelHeader";
 import { useHistorySelection } from "../../hooks/useHistorySelection";
@@ -55,9 +56,7 @@ export default function HistoryViewer() {
   const [list, setList] = useListCallbackRef(null);
   const dispatch = useAppDispatch();
 
-  const filteredEntries = useMemo(() => {
-    return andSearch(recentlyReadBooks, searchText);
-  }, [recentlyReadBooks, searchText]);
+  const filteredEntries = useAndSearch(recentlyReadBooks, searchText, andSearch);
 
   useHistorySelection(history[historyIndex], filteredEntries, setSelectedIndex);
 
@@ -1,3 +1,4 @@
+import { type Event, type EventCallback, listen } from "@tauri-apps/api/event";
 import { act, renderHook, waitFor } from "@testing-library/react";
 import { beforeEach, describe, expect, it, vi } from "vitest";
 import type { Image } from "../../../types/Image";
@@ -144,6 +145,34 @@ describe("useViewerController", () => {
     expect(global.URL.revokeObjectURL).toHaveBeenCalledWith("blob:0");
   });
 
+  // Verify that a backend re-render of the open book drops the cached pages and
+  // re-fetches the visible one, revoking the replaced URLs once it has loaded
+  it("should re-fetch the visible page when the open book is re-rendered", async () => {
+    let onRendered: EventCallback<string> | undefined;
+    vi.mocked(listen).mockImplementationOnce(async (_event, handler) => {
+      onRendered = handler as EventCallback<string>;
+      return () => {};
+    });
+    mockedFetchImageBlob.mockResolvedValue({} as Image);
+    let urlCounter = 0;
+    mockedCreateImageCacheItem.mockImplementation(
+      () => ({ fullUrl: `blob:${urlCounter++}` }) as ImageUtils.ImageCacheItem,
+    );
+
+    renderHook(() => useViewerController("path", mockEntries, 0, mockSettings, mockDispatch));
+    await waitFor(() => expect(mockedFetchImageBlob).toHaveBeenCalledTimes(1));
+    await waitFor(() => expect(onRendered).toBeDefined());
+
+    // Another book was re-rendered: nothing to do.
+    act(() => onRendered?.({ payload: "other" } as Event<string>));
+    expect(mockedFetchImageBlob).toHaveBeenCalledTimes(1);
+
+    act(() => onRendered?.({ payload: "path" } as Event<string>));
+    await waitFor(() => expect(mockedFetchImageBlob).toHaveBeenCalledTimes(2));
+    await waitFor(() => expect(global.URL.revokeObjectURL).toHaveBeenCalledWith("blob:0"));
+    expect(global.URL.revokeObjectURL).not.toHaveBeenCalledWith("blob:1");
+  });
+
   // Verify that displayedLayout is set on successful image loading
   it("should load image and set displayedLayout when successful", async () => {
     const mockLayout: ImageUtils.ViewLayout = {
@@ -1,6 +1,7 @@
 import { warn } from "@tauri-apps/plugin-log";
 import { useCallback, useEffect, useRef, useState } from "react";
 import { requestPreloadAround } from "../../../bindings/ContainerCommands";
+import { useTauriEvent } from "../../../hooks/useTauriEvent";
 import type { AppDispatch } from "../../../store/store";
 import type { Image } from "../../../types/Image";
 import { setImageIndex } from "../slice";
@@ -65,6 +66,26 @@ export const useViewerController = (
   const [isImageLoading, setIsImageLoading] = useState(false);
   const [layoutState, setLayoutState] = useState<{ layout: ViewLayout; path: string } | null>(null);
   const abortControllerRef = useRef<AbortController | null>(null);
+  // Bumped when the backend re-rendered the open book, to re-fetch the visible pages.
+  const [renderGeneration, setRenderGeneration] = useState(0);
+  // Items dropped by a re-render, still shown until their replacements have loaded.
+  const staleItemsRef = useRef<ImageCacheItem[]>([]);
+
+  const revokeStaleItems = useCallback(() => {
+    staleItemsRef.current.forEach(revokeCacheItemUrls);
+    staleItemsRef.current = [];
+  }, []);
+
+  // The backend rebuilt the open book with new rendering settings: drop the pages
+  // rendered with the old ones and re-fetch the visible pages.
+  useTauriEvent<string>("rendering-settings-applied", (event) => {
+    if (event.payload !== containerPath) {
+      return;
+    }
+    staleItemsRef.current.push(...cacheRef.current.values());
+    cacheRef.current.clear();
+    setRenderGeneration((generation) => generation + 1);
+  });
 
   // biome-ignore lint/correctness/useExhaustiveDependencies: update the cache whenever containerPath changes.
   useEffect(() => {
@@ -79,10 +100,12 @@ export const useViewerController = (
     return () => {
       revokeCacheUrls(cache);
       cache.clear();
+      revokeStaleItems();
     };
-  }, []);
+  }, [revokeStaleItems]);
 
   // Loads the missing images and updates the layout.
+  // biome-ignore lint/correctness/useExhaustiveDependencies: reload whenever renderGeneration changes.
   useEffect(() => {
     const updateLayout = async () => {
       // Cancels previous request.
@@ -162,6 +185,8 @@ export const useViewerController = (
       await Promise.all(fullPromises);
 
       if (!controller.signal.aborted) {
+        // The re-rendered pages are on screen now, so the replaced ones can go.
+        revokeStaleItems();
         // Loading has settled. If no full layout resolved (e.g. a spread's second
         // page failed to load), degrade to a single-page layout for the first image
         // instead of leaving the viewer blank/stale.
@@ -183,7 +208,7 @@ export const useViewerController = (
       abortControllerRef.current?.abort();
       abortControllerRef.current = null;
     };
-  }, [containerPath, index, entries, settings]);
+  }, [containerPath, index, entries, settings, renderGeneration, revokeStaleItems]);
 
   // Request preloading around the current index in the backend.
   useEffect(() => {
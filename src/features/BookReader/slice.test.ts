@@ -262,6 +262,8 @@ describe("ReadReducer", () => {
           is_directory: false,
           entries: ["p1", "p2"],
           is_novel: false,
+          damage_code: null,
+          excluded_entries: [],
         });
         vi.mocked(BookCommands.recordBookOpened).mockResolvedValue(1);
         vi.mocked(BookCommands.getBookWithStateById).mockResolvedValue(mockBook);
@@ -276,6 +278,33 @@ describe("ReadReducer", () => {
         expect(state.containerFile.entries).toEqual(["p1", "p2"]);
       });
 
+      // Verify that a novel resumes at the CFI saved with its reading state
+      it("should restore the last read CFI of a novel", async () => {
+        const mockBook = createMockBookWithState({
+          id: 1,
+          file_path: "path/to/novel.epub",
+          last_read_page_index: 1,
+          last_read_cfi: "epubcfi(/6/4!/4/2/1:0)",
+        });
+
+        vi.mocked(ContainerCommands.getEntriesInContainer).mockResolvedValue({
+          is_directory: false,
+          entries: ["p1", "p2"],
+          is_novel: true,
+          damage_code: null,
+          excluded_entries: [],
+        });
+        vi.mocked(BookCommands.recordBookOpened).mockResolvedValue(1);
+        vi.mocked(BookCommands.getBookWithStateById).mockResolvedValue(mockBook);
+
+        store.dispatch(setContainerFilePath("path/to/novel.epub"));
+        await store.dispatch(openContainerFile("path/to/novel.epub"));
+
+        const state = store.getState().read;
+        expect(state.containerFile.index).toBe(1);
+        expect(state.containerFile.cfi).toBe("epubcfi(/6/4!/4/2/1:0)");
+      });
+
       // Verify that the container opens on its last page when pendingInitialPosition is "last"
       it("should open on the last page when pendingInitialPosition is 'last'", async () => {
         const mockBook = createMockBookWithState({ id: 1, last_read_page_index: 5 });
@@ -284,6 +313,8 @@ describe("ReadReducer", () => {
           is_directory: false,
           entries: ["p1", "p2", "p3"],
           is_novel: false,
+          damage_code: null,
+          excluded_entries: [],
         });
         vi.mocked(BookCommands.recordBookOpened).mockResolvedValue(1);
         vi.mocked(BookCommands.getBookWithStateById).mockResolvedValue(mockBook);
@@ -306,6 +337,8 @@ describe("ReadReducer", () => {
           is_directory: false,
           entries: ["p1", "p2", "p3"],
           is_novel: false,
+          damage_code: null,
+          excluded_entries: [],
         });
         vi.mocked(BookCommands.recordBookOpened).mockResolvedValue(1);
         vi.mocked(BookCommands.getBookWithStateById).mockResolvedValue(mockBook);
@@ -328,6 +361,8 @@ describe("ReadReducer", () => {
           is_directory: false,
           entries: ["p1", "p2", "p3"],
           is_novel: false,
+          damage_code: null,
+          excluded_entries: [],
         });
         vi.mocked(BookCommands.recordBookOpened).mockResolvedValue(1);
         vi.mocked(BookCommands.getBookWithStateById).mockResolvedValue(mockBook);
@@ -347,6 +382,8 @@ describe("ReadReducer", () => {
           is_directory: false,
           entries: ["p1", "p2"],
           is_novel: true,
+          damage_code: null,
+          excluded_entries: [],
         });
         vi.mocked(BookCommands.recordBookOpened).mockResolvedValue(1);
         vi.mocked(BookCommands.getBookWithStateById).mockResolvedValue(mockBook);
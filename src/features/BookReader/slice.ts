@@ -346,6 +346,7 @@ export const readSlice = createSlice({
         state.containerFile.isDirectory = action.payload.isDirectory;
         state.containerFile.isLoading = false;
         state.containerFile.book = action.payload.book;
+        state.containerFile.cfi = null;
         if (state.containerFile.pendingInitialPosition === "last") {
           const total = action.payload.entries?.length ?? 0;
           state.containerFile.index = Math.max(0, total - 1);
@@ -357,9 +358,10 @@ export const readSlice = createSlice({
           const total = state.containerFile.entries.length;
           const restored = action.payload.book?.last_read_page_index ?? 0;
           state.containerFile.index = total > 0 ? Math.min(Math.max(0, restored), total - 1) : 0;
+          // Novels resume at the exact CFI, which survives font and layout changes.
+          state.containerFile.cfi = action.payload.book?.last_read_cfi ?? null;
         }
         state.containerFile.pendingInitialPosition = null;
-        state.containerFile.cfi = null;
         state.containerFile.error = null;
         if (action.payload.isNovel !== undefined) {
           state.containerFile.isNovel = action.payload.isNovel;
@@ -16,6 +16,7 @@ const dirEntry = (name: string, isDir = false): DirEntry => ({
   name,
   is_directory: isDir,
   last_modified: 0,
+  sort_key: name,
 });
 
 describe("resolveAdjacentBook", () => {
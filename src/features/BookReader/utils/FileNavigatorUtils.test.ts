@@ -4,41 +4,56 @@ import { andSearch, sortBy } from "./FileNavigatorUtils";
 
 describe("FileNavigatorUtils", () => {
   const mockEntries: DirEntry[] = [
-    { name: "Documents", is_directory: true, last_modified: Date.parse("2023-01-01") },
-    { name: "image.png", is_directory: false, last_modified: Date.parse("2023-01-02") },
-    { name: "data.txt", is_directory: false, last_modified: Date.parse("2023-01-01") },
+    {
+      name: "Documents",
+      is_directory: true,
+      last_modified: Date.parse("2023-01-01"),
+      sort_key: "documents",
+    },
+    {
+      name: "image.png",
+      is_directory: false,
+      last_modified: Date.parse("2023-01-02"),
+      sort_key: "image.png",
+    },
+    {
+      name: "data.txt",
+      is_directory: false,
+      last_modified: Date.parse("2023-01-01"),
+      sort_key: "data.txt",
+    },
   ];
 
   describe("andSearch", () => {
     // Verify that filtering by keyword is performed correctly
-    it("should filter by keyword", () => {
-      const result = andSearch(mockEntries, "doc");
+    it("should filter by keyword", async () => {
+      const result = await andSearch(mockEntries, "doc");
       expect(result).toHaveLength(1);
       expect(result[0].name).toBe("Documents");
     });
 
     // Verify that filtering by multiple words (AND search) is performed correctly
-    it("should handle multi-word search", () => {
-      const result = andSearch(mockEntries, "image png");
+    it("should handle multi-word search", async () => {
+      const result = await andSearch(mockEntries, "image png");
       expect(result).toHaveLength(1);
       expect(result[0].name).toBe("image.png");
     });
 
     // Verify that all entries are returned if the query is empty
-    it("should return all entries for an empty query", () => {
-      const result = andSearch(mockEntries, "");
+    it("should return all entries for an empty query", async () => {
+      const result = await andSearch(mockEntries, "");
       expect(result).toHaveLength(mockEntries.length);
     });
 
     // Verify that all entries are returned if the query consists only of whitespace
-    it("should return all entries for a whitespace query", () => {
-      const result = andSearch(mockEntries, "   ");
+    it("should return all entries for a whitespace query", async () => {
+      const result = await andSearch(mockEntries, "   ");
       expect(result).toHaveLength(mockEntries.length);
     });
 
     // Verify that searching (filtering) is case-insensitive
-    it("should be case-insensitive", () => {
-      const result = andSearch(mockEntries, "DOCUMENTS");
+    it("should be case-insensitive", async () => {
+      const result = await andSearch(mockEntries, "DOCUMENTS");
       expect(result).toHaveLength(1);
       expect(result[0].name).toBe("Documents");
     });
@@ -59,6 +74,16 @@ describe("FileNavigatorUtils", () => {
       expect(sorted[2].name).toBe("data.txt");
     });
 
+    // Verify that names are ordered by the backend's sort keys rather than their text
+    it("should sort names by their sort keys", () => {
+      const entries: DirEntry[] = [
+        { name: "10.jpg", is_directory: false, last_modified: 0, sort_key: "0210" },
+        { name: "2.jpg", is_directory: false, last_modified: 0, sort_key: "012" },
+      ];
+      const sorted = [...entries].sort((a, b) => sortBy(a, b, "name_asc"));
+      expect(sorted.map((e) => e.name)).toEqual(["2.jpg", "10.jpg"]);
+    });
+
     // Verify correct sorting by date (ascending)
     it("should sort by date ascending", () => {
       const sorted = [...mockEntries].sort((a, b) => sortBy(a, b, "date_asc"));
@@ -76,9 +101,9 @@ describe("FileNavigatorUtils", () => {
     // Regression: raw numeric timestamps must be compared numerically, not parsed as date strings.
     it("should order raw numeric timestamps numerically", () => {
       const numericEntries: DirEntry[] = [
-        { name: "c", is_directory: false, last_modified: 3000 },
-        { name: "a", is_directory: false, last_modified: 1000 },
-        { name: "b", is_directory: false, last_modified: 2000 },
+        { name: "c", is_directory: false, last_modified: 3000, sort_key: "c" },
+        { name: "a", is_directory: false, last_modified: 1000, sort_key: "a" },
+        { name: "b", is_directory: false, last_modified: 2000, sort_key: "b" },
       ];
       const asc = [...numericEntries].sort((a, b) => sortBy(a, b, "date_asc"));
       expect(asc.map((e) => e.last_modified)).toEqual([1000, 2000, 3000]);
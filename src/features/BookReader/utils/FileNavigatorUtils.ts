@@ -1,22 +1,23 @@
 import type { SortOrder } from "../../../types/AppSettings";
 import type { DirEntry } from "../../../types/DirEntry";
+import { compareSortKeys } from "../../../utils/NaturalSortUtils";
 import { andSearchBy } from "../../../utils/SearchUtils";
 
 /**
  * Filters an array of DirEntry objects to find entries whose 'name' property contains ALL specified keywords (AND search).
  * Keywords are derived from the user's input string, separated by whitespace (including full-width spaces).
  *
- * The search is case-insensitive.
+ * The matching is done by the backend, which ignores case, character width and kana differences.
  *
  * @param entries - The array of DirEntry objects to be searched.
  * @param query - The user's input string containing one or more space-separated keywords.
- * @returns A new array containing only the DirEntry objects whose 'name' property matches all provided keywords.
- * Returns the original array if the query is empty or contains only whitespace.
+ * @returns A promise that resolves to the DirEntry objects whose 'name' property matches all provided keywords.
+ * Resolves to the original array if the query is empty or contains only whitespace.
  *
  * @example
  * const entries = [{ name: "test_dir", ... }, { name: "test_file", ... }];
- * andSearch(entries, "test file"); // Returns [{ name: "test_file", ... }]
- * andSearch(entries, "example"); // Returns []
+ * await andSearch(entries, "test file"); // Returns [{ name: "test_file", ... }]
+ * await andSearch(entries, "example"); // Returns []
  */
 export const andSearch = (entries: DirEntry[], query: string) =>
   andSearchBy(entries, query, (entry) => entry.name);
@@ -41,9 +42,9 @@ export const andSearch = (entries: DirEntry[], query: string) =>
 export const sortBy = (a: DirEntry, b: DirEntry, sortOrder: SortOrder) => {
   switch (sortOrder) {
     case "name_asc":
-      return a.name.localeCompare(b.name);
+      return compareSortKeys(a.sort_key, b.sort_key);
     case "name_desc":
-      return b.name.localeCompare(a.name);
+      return compareSortKeys(b.sort_key, a.sort_key);
     case "date_asc":
       return a.last_modified - b.last_modified;
     case "date_desc":
@@ -3,6 +3,7 @@ import userEvent from "@testing-library/user-event";
 import { describe, expect, it, vi } from "vitest";
 import { createMockBookWithState, createMockTag } from "../../../test/factories";
 import { renderWithProviders } from "../../../test/utils";
+import { ErrorCode } from "../../../types/Error";
 import { useBookSelection } from "../hooks/useBookSelection";
 import BookCard from "./BookCard";
 import { BookSelectionProvider } from "./BookSelectionContext";
@@ -172,4 +173,16 @@ describe("BookCard", () => {
     expect(screen.getByText("Reading")).toBeInTheDocument();
     expect(screen.getByTestId("MenuBookIcon")).toBeInTheDocument();
   });
+
+  it("should render damaged chip only for damaged books", () => {
+    renderBookCard();
+    expect(screen.queryByText("Damaged")).not.toBeInTheDocument();
+
+    renderBookCard({
+      ...defaultProps,
+      book: createMockBookWithState({ ...mockBook, damage_code: ErrorCode.damagedArchive }),
+    });
+    expect(screen.getByText("Damaged")).toBeInTheDocument();
+    expect(screen.getByTestId("BrokenImageIcon")).toBeInTheDocument();
+  });
 });
@@ -1,4 +1,4 @@
-import { CheckCircle, MenuBook } from "@mui/icons-material";
+import { BrokenImage, CheckCircle, MenuBook } from "@mui/icons-material";
 import {
   alpha,
   Box,
@@ -129,6 +129,20 @@ export default function BookCard({
               }}
             />
           )}
+          {book.damage_code !== null && (
+            <Chip
+              icon={<BrokenImage fontSize="small" />}
+              label={t("bookshelf.damaged-chip-label")}
+              color="warning"
+              size="small"
+              sx={{
+                position: "absolute",
+                top: 4,
+                left: 4,
+                zIndex: 1,
+              }}
+            />
+          )}
           {isSelected && (
             <CheckCircle
               color="primary"
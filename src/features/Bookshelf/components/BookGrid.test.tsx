@@ -1,4 +1,4 @@
-import { fireEvent, render, screen } from "@testing-library/react";
+import { fireEvent, render, screen, waitFor } from "@testing-library/react";
 import { beforeEach, describe, expect, it, vi } from "vitest";
 import { useResizeObserver } from "../../../hooks/useResizeObserver";
 import { type RootState, useAppDispatch, useAppSelector } from "../../../store/store";
@@ -317,19 +317,21 @@ describe("BookGrid", () => {
     expect(screen.getByText("Series Name")).toBeInTheDocument();
   });
 
-  it("filters books by search text", () => {
+  it("filters books by search text", async () => {
     const book1 = createMockBookWithState({ id: 1, display_name: "Apple" });
     const book2 = createMockBookWithState({ id: 2, display_name: "Banana" });
+    // Keep the books stable across renders, as the store does, so the search settles.
+    const state = {
+      ...defaultState,
+      bookCollection: {
+        ...defaultState.bookCollection,
+        searchText: "app",
+        books: [book1, book2],
+      },
+    };
 
     vi.mocked(useAppSelector).mockImplementation(<T,>(selector: (state: RootState) => T): T => {
-      return selector({
-        ...defaultState,
-        bookCollection: {
-          ...defaultState.bookCollection,
-          searchText: "app",
-          books: [book1, book2],
-        },
-      } as unknown as RootState);
+      return selector(state as unknown as RootState);
     });
 
     render(
@@ -338,20 +340,24 @@ describe("BookGrid", () => {
       </BookSelectionContext.Provider>,
     );
 
+    await waitFor(() => {
+      expect(screen.queryByText("Banana")).not.toBeInTheDocument();
+    });
     expect(screen.getByText("Apple")).toBeInTheDocument();
-    expect(screen.queryByText("Banana")).not.toBeInTheDocument();
   });
 
   it("shows no results message when search matches nothing", () => {
+    const state = {
+      ...defaultState,
+      bookCollection: {
+        ...defaultState.bookCollection,
+        searchText: "nothing",
+        books: [],
+      },
+    };
+
     vi.mocked(useAppSelector).mockImplementation(<T,>(selector: (state: RootState) => T): T => {
-      return selector({
-        ...defaultState,
-        bookCollection: {
-          ...defaultState.bookCollection,
-          searchText: "nothing",
-          books: [],
-        },
-      } as unknown as RootState);
+      return selector(state as unknown as RootState);
     });
 
     render(
@@ -975,7 +981,7 @@ describe("BookGrid", () => {
     expect(screen.getByText("Series")).toBeInTheDocument();
   });
 
-  it("does not activate a stale focus index after the list shrinks", () => {
+  it("does not activate a stale focus index after the list shrinks", async () => {
     const book1 = createMockBookWithState({ id: 1, display_name: "Apple" });
     const book2 = createMockBookWithState({ id: 2, display_name: "Banana" });
 
@@ -1018,6 +1024,9 @@ describe("BookGrid", () => {
       </BookSelectionContext.Provider>,
     );
 
+    await waitFor(() => {
+      expect(screen.queryByText("Banana")).not.toBeInTheDocument();
+    });
     mockHandleSelectionClick.mockClear();
 
     // Pressing Enter must neither throw nor activate the stale index.
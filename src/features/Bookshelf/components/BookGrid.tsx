@@ -5,6 +5,7 @@ import { useCallback, useEffect, useMemo, useRef, useState } from "react";
 import { useTranslation } from "react-i18next";
 import { Grid, useGridCallbackRef } from "react-window";
 import type { Book, BookWithState } from "../../../domain/book/schema";
+import { useAndSearch } from "../../../hooks/useAndSearch";
 import { useAppTheme } from "../../../hooks/useAppTheme";
 import { useResizeObserver } from "../../../hooks/useResizeObserver";
 import { type RootState, useAppDispatch, useAppSelector } from "../../../store/store";
@@ -15,12 +16,7 @@ import { useReadingBookSelection } from "../hooks/useReadingBookSelection";
 import { fetchSeries, setEditSeriesOrderDialogState, setSelectedSeriesId } from "../seriesSlice";
 import { setSearchText } from "../slice";
 import { fetchTags } from "../tagSlice";
-import {
-  andSearch,
-  andSearchGridItems,
-  sortByGridItem,
-  sortBySeriesOrder,
-} from "../utils/BookshelfUtils";
+import { andSearchGridItems, sortByGridItem, sortBySeriesOrder } from "../utils/BookshelfUtils";
 import BookGridCell, { type BookGridCellProps, type GridItem } from "./BookGridCell";
 import { BookshelfActionsContext } from "./BookshelfActionsContext";
 import AddBooksToBookshelvesDialog from "./Dialog/AddBooksToBookshelvesDialog";
@@ -144,7 +140,7 @@ export default function BookGrid({ onBookSelect }: BookGridProps) {
     [bookshelfSettings.gridSize],
   );
 
-  const filteredSortedItems = useMemo(() => {
+  const candidateItems = useMemo((): GridItem[] => {
     // Filter books based on selected tag
     const taggedBooks =
       tagId === null
@@ -153,9 +149,8 @@ export default function BookGrid({ onBookSelect }: BookGridProps) {
 
     // Drill-down mode logic: if a series is selected, show only books in that series
     if (selectedSeriesId !== null) {
-      return andSearch(taggedBooks, searchText)
+      return taggedBooks
         .filter((book) => book.series_id === selectedSeriesId)
-        .sort(sortBySeriesOrder)
         .map((book) => ({ type: "book" as const, data: book }));
     }
 
@@ -197,19 +192,20 @@ export default function BookGrid({ onBookSelect }: BookGridProps) {
       groupedItems.push({ type: "book", data: book });
     });
 
-    // Perform search on the grouped items (Search by Series name or Standalone Book name)
-    const searchedItems = andSearchGridItems(groupedItems, searchText);
+    return groupedItems;
+  }, [booksInSelectedBookshelf, tagId, selectedSeriesId, allSeries]);
 
-    // Sort the final list
-    return searchedItems.sort((a, b) => sortByGridItem(a, b, bookshelfSettings.sortOrder));
-  }, [
-    booksInSelectedBookshelf,
-    tagId,
-    searchText,
-    selectedSeriesId,
-    allSeries,
-    bookshelfSettings.sortOrder,
-  ]);
+  // Search by Series name or Standalone Book name (by Book name in drill-down mode)
+  const searchedItems = useAndSearch(candidateItems, searchText, andSearchGridItems);
+
+  const filteredSortedItems = useMemo((): GridItem[] => {
+    if (selectedSeriesId !== null) {
+      return searchedItems
+        .filter((item): item is { type: "book"; data: BookWithState } => item.type === "book")
+        .sort((a, b) => sortBySeriesOrder(a.data, b.data));
+    }
+    return searchedItems.slice().sort((a, b) => sortByGridItem(a, b, bookshelfSettings.sortOrder));
+  }, [searchedItems, selectedSeriesId, bookshelfSettings.sortOrder]);
 
   const allBooks = useMemo(() => {
     return filteredSortedItems
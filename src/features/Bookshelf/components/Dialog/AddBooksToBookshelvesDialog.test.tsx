@@ -11,8 +11,20 @@ describe("AddBooksToBookshelvesDialog", () => {
   const user = userEvent.setup();
 
   const mockBookshelves: Bookshelf[] = [
-    { id: 1, name: "Shelf 1", icon_id: "library_books", created_at: "2026-03-01T10:00:00" },
-    { id: 2, name: "Shelf 2", icon_id: "library_books", created_at: "2026-03-01T11:00:00" },
+    {
+      id: 1,
+      name: "Shelf 1",
+      icon_id: "library_books",
+      created_at: "2026-03-01T10:00:00",
+      rule: null,
+    },
+    {
+      id: 2,
+      name: "Shelf 2",
+      icon_id: "library_books",
+      created_at: "2026-03-01T11:00:00",
+      rule: null,
+    },
   ];
 
   beforeEach(() => {
@@ -12,8 +12,8 @@ describe("SetSeriesDialog", () => {
   const user = userEvent.setup();
 
   const mockSeries: Series[] = [
-    { id: 1, name: "Series A", created_at: "2026-03-01T15:30:00" },
-    { id: 2, name: "Series B", created_at: "2026-03-01T15:30:00" },
+    { id: 1, name: "Series A", sort_key: "Series A", created_at: "2026-03-01T15:30:00" },
+    { id: 2, name: "Series B", sort_key: "Series B", created_at: "2026-03-01T15:30:00" },
   ];
 
   const defaultProps: SetSeriesDialogProps = {
@@ -36,7 +36,9 @@ vi.mock("../../MainView/slice", async () => {
 describe("MenuList", () => {
   const user = userEvent.setup();
 
-  const mockBookshelves = [{ id: 1, name: "Bookshelf 1", icon_id: "folder", created_at: "" }];
+  const mockBookshelves = [
+    { id: 1, name: "Bookshelf 1", icon_id: "folder", created_at: "", rule: null },
+  ];
   const mockTags = [{ id: 10, name: "Tag 1", color_code: "#ff0000" }];
 
   const preloadedState = createBasePreloadedState();
@@ -70,7 +72,7 @@ describe("MenuList", () => {
   it("should show fallback icon for unknown icon_id", () => {
     const stateWithUnknownIcon = createBasePreloadedState();
     stateWithUnknownIcon.bookCollection.bookshelves = [
-      { id: 2, name: "Unknown Icon", icon_id: "non-existent", created_at: "" },
+      { id: 2, name: "Unknown Icon", icon_id: "non-existent", created_at: "", rule: null },
     ];
     renderWithProviders(<MenuList {...defaultProps} />, { preloadedState: stateWithUnknownIcon });
     expect(screen.getByTestId("QuestionMarkIcon")).toBeInTheDocument();
@@ -91,7 +91,14 @@ describe("NavigationBar", () => {
   it("should render breadcrumbs when a series is selected", () => {
     const preloadedState = createBasePreloadedState();
     preloadedState.series = {
-      series: [{ id: 1, name: "Selected Series", created_at: "2026-03-01T15:30:00" }],
+      series: [
+        {
+          id: 1,
+          name: "Selected Series",
+          sort_key: "Selected Series",
+          created_at: "2026-03-01T15:30:00",
+        },
+      ],
       selectedId: 1,
       books: [],
       isEditSeriesOrderDialogOpen: false,
@@ -109,7 +116,14 @@ describe("NavigationBar", () => {
   it("should clear selected series when 'Bookshelf' link is clicked", async () => {
     const preloadedState = createBasePreloadedState();
     preloadedState.series = {
-      series: [{ id: 1, name: "Selected Series", created_at: "2026-03-01T15:30:00" }],
+      series: [
+        {
+          id: 1,
+          name: "Selected Series",
+          sort_key: "Selected Series",
+          created_at: "2026-03-01T15:30:00",
+        },
+      ],
       selectedId: 1,
       books: [],
       isEditSeriesOrderDialogOpen: false,
@@ -129,7 +143,14 @@ describe("NavigationBar", () => {
   it("should hide sort controls and add button when a series is selected", () => {
     const preloadedState = createBasePreloadedState();
     preloadedState.series = {
-      series: [{ id: 1, name: "Selected Series", created_at: "2026-03-01T15:30:00" }],
+      series: [
+        {
+          id: 1,
+          name: "Selected Series",
+          sort_key: "Selected Series",
+          created_at: "2026-03-01T15:30:00",
+        },
+      ],
       selectedId: 1,
       books: [],
       isEditSeriesOrderDialogOpen: false,
@@ -148,7 +169,14 @@ describe("NavigationBar", () => {
   it("should open edit series order dialog when 'Edit Order' button is clicked", async () => {
     const preloadedState = createBasePreloadedState();
     preloadedState.series = {
-      series: [{ id: 1, name: "Selected Series", created_at: "2026-03-01T15:30:00" }],
+      series: [
+        {
+          id: 1,
+          name: "Selected Series",
+          sort_key: "Selected Series",
+          created_at: "2026-03-01T15:30:00",
+        },
+      ],
       selectedId: 1,
       books: [],
       isEditSeriesOrderDialogOpen: false,
@@ -10,7 +10,12 @@ import SeriesContextMenu, { type SeriesContextMenuProps } from "./SeriesContextM
 
 describe("SeriesContextMenu", () => {
   const user = userEvent.setup();
-  const mockSeries: Series = { id: 1, name: "Test Series", created_at: "2026-03-01T15:30:00" };
+  const mockSeries: Series = {
+    id: 1,
+    name: "Test Series",
+    sort_key: "Test Series",
+    created_at: "2026-03-01T15:30:00",
+  };
 
   const mockActions = {
     openDialog: vi.fn(),
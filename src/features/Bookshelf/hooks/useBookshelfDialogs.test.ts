@@ -8,6 +8,7 @@ describe("useBookshelfDialogs", () => {
     {
       id: 1,
       display_name: "Book 1",
+      sort_key: "Book 1",
       file_path: "/path/1",
       item_type: "file",
       total_pages: 100,
@@ -15,13 +16,20 @@ describe("useBookshelfDialogs", () => {
       series_order: null,
       thumbnail_path: null,
       created_at: null,
+      damage_code: null,
+      missing_since: null,
+      read_status: "unread",
+      rating: 0,
+      is_favorite: false,
       last_read_page_index: null,
+      last_read_cfi: null,
       last_opened_at: null,
       tag_ids: [],
     },
     {
       id: 2,
       display_name: "Book 2",
+      sort_key: "Book 2",
       file_path: "/path/2",
       item_type: "file",
       total_pages: 200,
@@ -29,7 +37,13 @@ describe("useBookshelfDialogs", () => {
       series_order: null,
       thumbnail_path: null,
       created_at: null,
+      damage_code: null,
+      missing_since: null,
+      read_status: "unread",
+      rating: 0,
+      is_favorite: false,
       last_read_page_index: null,
+      last_read_cfi: null,
       last_opened_at: null,
       tag_ids: [],
     },
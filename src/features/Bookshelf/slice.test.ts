@@ -101,11 +101,13 @@ describe("BookCollectionReducer", () => {
       readingProgressChanged({
         book_id: 2,
         last_read_page_index: 9,
+        last_read_cfi: "epubcfi(/6/4!/4/2/1:0)",
         last_opened_at: "2026-07-04T10:00:00",
       }),
     );
     expect(nextState.books[0].last_read_page_index).toBe(0);
     expect(nextState.books[1].last_read_page_index).toBe(9);
+    expect(nextState.books[1].last_read_cfi).toBe("epubcfi(/6/4!/4/2/1:0)");
     expect(nextState.books[1].last_opened_at).toBe("2026-07-04T10:00:00");
   });
 
@@ -325,6 +327,7 @@ describe("BookCollectionReducer", () => {
           is_directory: false,
           entries: ["1.jpg"],
           is_novel: false,
+          damage_code: null,
         });
         vi.mocked(BookCommands.registerBook).mockResolvedValue(10);
 
@@ -341,6 +344,7 @@ describe("BookCollectionReducer", () => {
           is_directory: false,
           entries: ["1.jpg"],
           is_novel: false,
+          damage_code: null,
         });
         vi.mocked(BookCommands.registerBook).mockResolvedValue(10);
 
@@ -357,6 +361,7 @@ describe("BookCollectionReducer", () => {
           is_directory: false,
           entries: [],
           is_novel: true,
+          damage_code: null,
         });
         vi.mocked(BookCommands.registerBook).mockResolvedValue(10);
         vi.mocked(BookCommands.getAllBooksWithState).mockResolvedValue([]);
@@ -375,6 +380,7 @@ describe("BookCollectionReducer", () => {
           is_directory: true,
           entries: ["1.jpg"],
           is_novel: false,
+          damage_code: null,
         });
         vi.mocked(BookCommands.registerBook).mockResolvedValue(10);
         vi.mocked(BookCommands.getAllBooksWithState).mockResolvedValue([]);
@@ -753,7 +759,9 @@ describe("SeriesReducer", () => {
 
     // Verify state update on successful series fetching
     it("fetchSeries should update state with fetched series on success", async () => {
-      const mockSeries: Series[] = [{ id: 1, name: "Series 1", created_at: "2026-03-01T15:30:00" }];
+      const mockSeries: Series[] = [
+        { id: 1, name: "Series 1", sort_key: "Series 1", created_at: "2026-03-01T15:30:00" },
+      ];
       vi.mocked(SeriesCommand.getAllSeries).mockResolvedValue(mockSeries);
 
       await store.dispatch(fetchSeries());
@@ -771,7 +779,7 @@ describe("SeriesReducer", () => {
       const preloadedState = {
         series: {
           ...seriesInitialState,
-          series: [{ id: 1, name: "S1", created_at: "" }],
+          series: [{ id: 1, name: "S1", sort_key: "S1", created_at: "" }],
         },
       };
       store = createTestStore(preloadedState);
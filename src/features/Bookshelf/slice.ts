@@ -313,6 +313,7 @@ const bookCollectionSlice = createSlice({
         const book = state.books.find((b) => b.id === action.payload.book_id);
         if (book) {
           book.last_read_page_index = action.payload.last_read_page_index;
+          book.last_read_cfi = action.payload.last_read_cfi ?? null;
           book.last_opened_at = action.payload.last_opened_at;
         }
       });
@@ -1,7 +1,7 @@
 import { describe, expect, it } from "vitest";
 import { createMockBookWithState, createMockSeries } from "../../../test/factories";
 import type { GridItem } from "../components/BookGridCell";
-import { andSearch, sortBy, sortByGridItem, sortBySeriesOrder } from "./BookshelfUtils";
+import { andSearchGridItems, sortBy, sortByGridItem, sortBySeriesOrder } from "./BookshelfUtils";
 
 describe("BookshelfUtils", () => {
   const mockBooks = [
@@ -10,36 +10,34 @@ describe("BookshelfUtils", () => {
     createMockBookWithState({ id: 3, display_name: "Apple Tart" }),
   ];
 
-  describe("andSearch", () => {
-    it("should return all entries if query is empty", () => {
-      expect(andSearch(mockBooks, "")).toEqual(mockBooks);
-    });
+  describe("andSearchGridItems", () => {
+    const bookItems: GridItem[] = mockBooks.map((book) => ({ type: "book", data: book }));
+    const seriesItem: GridItem = {
+      type: "series",
+      data: createMockSeries({ id: 1, name: "Apple Series" }),
+      books: [],
+    };
 
-    it("should return all entries if query is just whitespace", () => {
-      expect(andSearch(mockBooks, "   ")).toEqual(mockBooks);
+    it("should return all items if query is empty", async () => {
+      expect(await andSearchGridItems(bookItems, "")).toEqual(bookItems);
     });
 
-    it("should filter by single keyword (case-insensitive)", () => {
-      const result = andSearch(mockBooks, "apple");
-      expect(result).toHaveLength(2);
-      expect(result.map((b) => b.display_name)).toContain("Apple Pie");
-      expect(result.map((b) => b.display_name)).toContain("Apple Tart");
+    it("should return all items if query is just whitespace", async () => {
+      expect(await andSearchGridItems(bookItems, "   ")).toEqual(bookItems);
     });
 
-    it("should filter by multiple keywords (AND search)", () => {
-      const result = andSearch(mockBooks, "apple pie");
-      expect(result).toHaveLength(1);
-      expect(result[0].display_name).toBe("Apple Pie");
+    it("should filter by multiple keywords (AND search)", async () => {
+      const result = await andSearchGridItems(bookItems, "apple   pie");
+      expect(result).toEqual([bookItems[0]]);
     });
 
-    it("should filter by multiple keywords with multiple spaces", () => {
-      const result = andSearch(mockBooks, "apple   pie");
-      expect(result).toHaveLength(1);
-      expect(result[0].display_name).toBe("Apple Pie");
+    it("should match series by their name", async () => {
+      const result = await andSearchGridItems([...bookItems, seriesItem], "series");
+      expect(result).toEqual([seriesItem]);
     });
 
-    it("should return empty if no match", () => {
-      expect(andSearch(mockBooks, "cherry")).toHaveLength(0);
+    it("should return empty if no match", async () => {
+      expect(await andSearchGridItems(bookItems, "cherry")).toHaveLength(0);
     });
   });
 
@@ -56,6 +54,14 @@ describe("BookshelfUtils", () => {
       expect(sortBy(a, b, "name_desc")).toBeGreaterThan(0);
     });
 
+    it("should sort names by the sort keys from the backend", () => {
+      // The backend's natural order puts 第二巻 before 第十巻, unlike their code points.
+      const vol2 = createMockBookWithState({ id: 3, display_name: "物語 第二巻", sort_key: "1" });
+      const vol10 = createMockBookWithState({ id: 4, display_name: "物語 第十巻", sort_key: "2" });
+      expect(sortBy(vol2, vol10, "name_asc")).toBeLessThan(0);
+      expect(sortBy(vol2, vol10, "name_desc")).toBeGreaterThan(0);
+    });
+
     it("should sort by date_asc", () => {
       expect(sortBy(a, b, "date_asc")).toBeLessThan(0);
     });
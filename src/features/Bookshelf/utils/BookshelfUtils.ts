@@ -1,35 +1,19 @@
 import type { BookWithState } from "../../../domain/book/schema";
 import type { SortOrder } from "../../../types/AppSettings";
+import { compareSortKeys } from "../../../utils/NaturalSortUtils";
 import { andSearchBy } from "../../../utils/SearchUtils";
 import type { GridItem } from "../components/BookGridCell";
 
-/**
- * Filters an array of BookWithState objects to find entries whose 'display_name' property contains ALL specified keywords (AND search).
- * Keywords are derived from the user's input string, separated by whitespace (including full-width spaces).
- *
- * The search is case-insensitive.
- *
- * @param entries - The array of BookWithState objects to be searched.
- * @param query - The user's input string containing one or more space-separated keywords.
- * @returns A new array containing only the BookWithState objects whose 'display_name' property matches all provided keywords.
- * Returns the original array if the query is empty or contains only whitespace.
- *
- * @example
- * const entries = [{ display_name: "test_dir", ... }, { display_name: "test_file", ... }];
- * andSearch(entries, "test file"); // Returns [{ display_name: "test_file", ... }]
- * andSearch(entries, "example"); // Returns []
- */
-export const andSearch = (entries: BookWithState[], query: string) =>
-  andSearchBy(entries, query, (entry) => entry.display_name);
-
 /**
  * Filters an array of GridItem objects based on their names.
  * For 'book' items, it checks 'display_name'.
  * For 'series' items, it checks the series 'name'.
  *
+ * The matching is done by the backend, which ignores case, character width and kana differences.
+ *
  * @param items - The array of GridItem objects to be searched.
  * @param query - The space-separated keywords for AND search.
- * @returns A filtered array of GridItem objects.
+ * @returns A promise that resolves to the matching GridItem objects.
  */
 export const andSearchGridItems = (items: GridItem[], query: string) =>
   andSearchBy(items, query, (item) =>
@@ -56,9 +40,9 @@ export const andSearchGridItems = (items: GridItem[], query: string) =>
 export const sortBy = (a: BookWithState, b: BookWithState, sortOrder: SortOrder) => {
   switch (sortOrder) {
     case "name_asc":
-      return a.display_name.localeCompare(b.display_name);
+      return compareSortKeys(a.sort_key, b.sort_key);
     case "name_desc":
-      return b.display_name.localeCompare(a.display_name);
+      return compareSortKeys(b.sort_key, a.sort_key);
     case "date_asc":
       // Sort by the real creation timestamp (ISO string), falling back to id so the
       // order stays consistent with the bookshelf grid and adjacent-book navigation.
@@ -71,7 +55,7 @@ export const sortBy = (a: BookWithState, b: BookWithState, sortOrder: SortOrder)
 /**
  * Comparison function for sorting an array of BookWithState objects by their series_order.
  * Books with null series_order are placed at the end.
- * Fallback to display_name (locale-aware) if both have null or the same series_order.
+ * Fallback to display_name (natural order) if both have null or the same series_order.
  *
  * @param a - The first BookWithState object for comparison.
  * @param b - The second BookWithState object for comparison.
@@ -89,7 +73,7 @@ export const sortBySeriesOrder = (a: BookWithState, b: BookWithState) => {
   }
 
   // Fallback: name_asc
-  return a.display_name.localeCompare(b.display_name);
+  return compareSortKeys(a.sort_key, b.sort_key);
 };
 
 /**
@@ -104,13 +88,13 @@ export const sortByGridItem = (a: GridItem, b: GridItem, sortOrder: SortOrder) =
   const getComparisonValues = (item: GridItem) => {
     if (item.type === "series") {
       return {
-        name: item.data.name,
+        sortKey: item.data.sort_key,
         date: item.data.created_at,
         id: item.data.id,
       };
     }
     return {
-      name: item.data.display_name,
+      sortKey: item.data.sort_key,
       // Both series and standalone books now carry a real ISO `created_at`, so they
       // interleave by actual creation date. Fall back to an empty string when absent.
       date: item.data.created_at ?? "",
@@ -123,9 +107,9 @@ export const sortByGridItem = (a: GridItem, b: GridItem, sortOrder: SortOrder) =
 
   switch (sortOrder) {
     case "name_asc":
-      return valA.name.localeCompare(valB.name);
+      return compareSortKeys(valA.sortKey, valB.sortKey);
     case "name_desc":
-      return valB.name.localeCompare(valA.name);
+      return compareSortKeys(valB.sortKey, valA.sortKey);
     case "date_asc":
       return valA.date.localeCompare(valB.date) || valA.id - valB.id;
     case "date_desc":
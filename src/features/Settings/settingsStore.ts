@@ -46,6 +46,13 @@ export const defaultSettings: AppSettings = {
       cache: {
         preloadPageCount: 10,
         imageCacheSizeMib: 1024,
+        shrinkOnMemoryPressure: false,
+        memoryPressureThresholdMib: 512,
+      },
+      longStrip: {
+        enabled: false,
+        segmentHeight: 2048,
+        overlap: 32,
       },
     },
     novel: {
@@ -56,9 +63,18 @@ export const defaultSettings: AppSettings = {
       enableThumbnailPreview: true,
       maxImageHeight: 0,
       imageResamplingMethod: "bilinear",
+      outputFormat: "jpeg",
+      outputQuality: 80,
+      chromaSubsampling: "yuv420",
       pdfRenderResolutionHeight: 2000,
+      slowPageLogThresholdMs: 0,
     },
     autoOpenAdjacentBook: "ask",
+    exclusionRules: [
+      { pattern: "__MACOSX", syntax: "glob" },
+      { pattern: ".*", syntax: "glob" },
+    ],
+    entrySortMode: "natural",
   },
   history: {
     recordReadingHistory: true,
@@ -0,0 +1,49 @@
+import { act, renderHook, waitFor } from "@testing-library/react";
+import { describe, expect, it, vi } from "vitest";
+import { useAndSearch } from "./useAndSearch";
+
+describe("useAndSearch", () => {
+  const items = ["Apple", "Banana", "Apple Pie"];
+  const search = vi.fn((names: string[], query: string) =>
+    Promise.resolve(names.filter((name) => name.includes(query))),
+  );
+
+  it("returns the items as they are for a blank query", () => {
+    const { result } = renderHook(() => useAndSearch(items, "  ", search));
+    expect(result.current).toBe(items);
+    expect(search).not.toHaveBeenCalled();
+  });
+
+  it("returns the matches once the search resolves", async () => {
+    const { result } = renderHook(() => useAndSearch(items, "Apple", search));
+    await waitFor(() => {
+      expect(result.current).toEqual(["Apple", "Apple Pie"]);
+    });
+    expect(search).toHaveBeenCalledWith(items, "Apple");
+  });
+
+  it("discards the results of a superseded search", async () => {
+    let resolveFirst: (matches: string[]) => void = () => {};
+    const slowSearch = vi.fn((names: string[], query: string) =>
+      query === "Apple"
+        ? new Promise<string[]>((resolve) => {
+            resolveFirst = resolve;
+          })
+        : Promise.resolve(names.filter((name) => name.includes(query))),
+    );
+    const { result, rerender } = renderHook(
+      ({ query }) => useAndSearch(items, query, slowSearch),
+      { initialProps: { query: "Apple" } },
+    );
+
+    rerender({ query: "Banana" });
+    await waitFor(() => {
+      expect(result.current).toEqual(["Banana"]);
+    });
+    await act(async () => {
+      resolveFirst(["Apple", "Apple Pie"]);
+    });
+
+    expect(result.current).toEqual(["Banana"]);
+  });
+});
@@ -0,0 +1,49 @@
+import { error } from "@tauri-apps/plugin-log";
+import { useEffect, useState } from "react";
+
+/**
+ * A custom hook that filters items by an asynchronous AND search, such as `andSearchBy`,
+ * which matches names in the backend.
+ *
+ * A blank query returns the items as they are. While a search is in flight, the matches of
+ * the previous search are kept so the list does not flicker, and the results of a search
+ * that was superseded by newer items or a newer query are discarded.
+ *
+ * @param items - The items to filter.
+ * @param query - The user's input string containing one or more keywords.
+ * @param search - Filters the items by the query. Must be stable across renders.
+ * @returns The items matching every keyword of the query.
+ */
+export function useAndSearch<T>(
+  items: T[],
+  query: string,
+  search: (items: T[], query: string) => Promise<T[]>,
+): T[] {
+  const [matches, setMatches] = useState<T[] | null>(null);
+  const isBlank = query.trim().length === 0;
+
+  useEffect(() => {
+    if (isBlank) {
+      setMatches(null);
+      return;
+    }
+    let isCurrent = true;
+    search(items, query)
+      .then((result) => {
+        if (isCurrent) {
+          setMatches(result);
+        }
+      })
+      .catch((e) => {
+        error(`Failed to search for "${query}": ${e}`);
+      });
+    return () => {
+      isCurrent = false;
+    };
+  }, [items, query, isBlank, search]);
+
+  if (isBlank) {
+    return items;
+  }
+  return matches ?? items;
+}
@@ -6,15 +6,23 @@ import type { DirEntry } from "../types/DirEntry";
 
 /**
  * Creates a mock BookWithState object for testing.
+ * The sort key defaults to the display name, so plain names sort by their text.
  */
 export const createMockBookWithState = (overrides?: Partial<BookWithState>): BookWithState => ({
   id: 1,
   display_name: "Mock Book",
+  sort_key: overrides?.display_name ?? "Mock Book",
   file_path: "/path/to/mock-book.zip",
   item_type: "file",
   total_pages: 100,
   created_at: "2026-03-01T15:30:00",
+  damage_code: null,
+  missing_since: null,
+  read_status: "unread",
+  rating: 0,
+  is_favorite: false,
   last_read_page_index: 0,
+  last_read_cfi: null,
   last_opened_at: "2026-03-18T12:00:00Z",
   series_id: null,
   series_order: null,
@@ -48,6 +56,7 @@ export const createMockBookshelf = (overrides?: Partial<Bookshelf>): Bookshelf =
   name: "Mock Bookshelf",
   icon_id: "folder",
   created_at: "2026-03-18T12:00:00Z",
+  rule: null,
   ...overrides,
 });
 
@@ -63,19 +72,23 @@ export const createMockTag = (overrides?: Partial<Tag>): Tag => ({
 
 /**
  * Creates a mock Series object for testing.
+ * The sort key defaults to the name, so plain names sort by their text.
  */
 export const createMockSeries = (overrides?: Partial<Series>): Series => ({
   id: 1,
   name: "Mock Series",
+  sort_key: overrides?.name ?? "Mock Series",
   created_at: "2026-03-01T15:30:00",
   ...overrides,
 });
 
 /**
  * Creates a mock DirEntry object for testing.
+ * The sort key defaults to the name, so plain names sort by their text.
  */
 export const createMockDirEntry = (overrides?: Partial<DirEntry>): DirEntry => ({
   name: "mock-entry.zip",
+  sort_key: overrides?.name ?? "mock-entry.zip",
   is_directory: false,
   last_modified: Date.parse("2026-03-18T12:00:00Z"),
   ...overrides,
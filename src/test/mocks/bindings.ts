@@ -1,18 +1,34 @@
 import { vi } from "vitest";
 
+vi.mock("../../bindings/AnnotationCommands", () => ({
+  createAnnotation: vi.fn(() => Promise.resolve({})),
+  getAnnotations: vi.fn(() => Promise.resolve([])),
+  deleteAnnotation: vi.fn(() => Promise.resolve()),
+  exportAnnotations: vi.fn(() => Promise.resolve("")),
+}));
+
 vi.mock("../../bindings/BookCommands", () => ({
   getBook: vi.fn(() => Promise.resolve([])),
   deleteBook: vi.fn(() => Promise.resolve([])),
   getBookByPath: vi.fn(() => Promise.resolve([])),
   getBookWithStateById: vi.fn(() => Promise.resolve([])),
   getAllBooksWithState: vi.fn(() => Promise.resolve([])),
+  searchBooks: vi.fn(() => Promise.resolve([])),
+  queryBooks: vi.fn(() => Promise.resolve({ books: [], total_count: 0 })),
   registerBook: vi.fn(() => Promise.resolve([])),
   recordBookOpened: vi.fn(() => Promise.resolve([])),
   getBookTags: vi.fn(() => Promise.resolve([])),
   updateBookTags: vi.fn(() => Promise.resolve([])),
+  getBookExclusionRules: vi.fn(() => Promise.resolve([])),
+  setBookExclusionRules: vi.fn(() => Promise.resolve([])),
+  getBookPageOrder: vi.fn(() => Promise.resolve([])),
+  setBookPageOrder: vi.fn(() => Promise.resolve([])),
   updateBookSeries: vi.fn(() => Promise.resolve([])),
   updateSeriesOrders: vi.fn(() => Promise.resolve([])),
   updateReadingProgress: vi.fn(() => Promise.resolve([])),
+  setBooksReadStatus: vi.fn(() => Promise.resolve()),
+  setBooksRating: vi.fn(() => Promise.resolve()),
+  setBooksFavorite: vi.fn(() => Promise.resolve()),
   clearReadingHistory: vi.fn(() => Promise.resolve([])),
   clearAllReadingHistory: vi.fn(() => Promise.resolve([])),
   getRecentlyReadBooks: vi.fn(() => Promise.resolve([])),
@@ -21,9 +37,18 @@ vi.mock("../../bindings/BookCommands", () => ({
   getBooksWithStateBySeriesId: vi.fn(() => Promise.resolve([])),
 }));
 
+vi.mock("../../bindings/BookmarkCommands", () => ({
+  addBookmark: vi.fn(() => Promise.resolve({})),
+  getBookmarks: vi.fn(() => Promise.resolve([])),
+  getAllBookmarks: vi.fn(() => Promise.resolve([])),
+  updateBookmark: vi.fn(() => Promise.resolve()),
+  deleteBookmark: vi.fn(() => Promise.resolve()),
+}));
+
 vi.mock("../../bindings/BookshelfCommands", () => ({
   createBookshelf: vi.fn(() => Promise.resolve([])),
   getAllBookshelves: vi.fn(() => Promise.resolve([])),
+  setBookshelfRule: vi.fn(() => Promise.resolve()),
   addBookToBookshelf: vi.fn(() => Promise.resolve([])),
   removeBookFromBookshelf: vi.fn(() => Promise.resolve([])),
   deleteBookshelf: vi.fn(() => Promise.resolve([])),
@@ -31,11 +56,40 @@ vi.mock("../../bindings/BookshelfCommands", () => ({
 
 vi.mock("../../bindings/ContainerCommands", () => ({
   getEntriesInContainer: vi.fn(() =>
-    Promise.resolve({ entries: [], is_directory: false, is_novel: false }),
+    Promise.resolve({ entries: [], is_directory: false, is_novel: false, damage_code: null }),
   ),
   requestPreloadAround: vi.fn(() => Promise.resolve()),
   getImage: vi.fn(() => Promise.resolve(new ArrayBuffer(0))),
+  getPageUrl: vi.fn(() => "rookreader://localhost/page/book/entry"),
   getImagePreview: vi.fn(() => Promise.resolve(new ArrayBuffer(0))),
+  getImageProgressive: vi.fn(() => Promise.resolve()),
+  cancelImageRequest: vi.fn(() => Promise.resolve()),
+  getImageTile: vi.fn(() => Promise.resolve(new ArrayBuffer(0))),
+  getImageTileInfo: vi.fn(() =>
+    Promise.resolve({ width: 0, height: 0, tile_size: 512, max_level: 0 }),
+  ),
+  getStripLayout: vi.fn(() => Promise.resolve({ width: 0, height: 0, segments: [] })),
+  getCacheStats: vi.fn(() =>
+    Promise.resolve({
+      capacityBytes: 0,
+      residentBytes: 0,
+      entryCount: 0,
+      books: [],
+      openBook: null,
+      memoryPressureShrinks: 0,
+    }),
+  ),
+  getPerformanceMetrics: vi.fn(() => Promise.resolve({ samples: [], summaries: [] })),
+  purgeImageCache: vi.fn(() => Promise.resolve()),
+  ImageStage: { Preview: 0, Full: 1 },
+}));
+
+vi.mock("../../bindings/CreatorCommands", () => ({
+  getAllCreators: vi.fn(() => Promise.resolve([])),
+  getBookCreators: vi.fn(() => Promise.resolve([])),
+  getBooksWithStateByCreatorId: vi.fn(() => Promise.resolve([])),
+  renameCreator: vi.fn(() => Promise.resolve()),
+  mergeCreators: vi.fn(() => Promise.resolve()),
 }));
 
 vi.mock("../../bindings/DirectoryCommands", () => ({
@@ -46,14 +100,57 @@ vi.mock("../../bindings/FontCommands", () => ({
   getFonts: vi.fn(() => Promise.resolve(["Arial", "Times New Roman"])),
 }));
 
+vi.mock("../../bindings/LibraryCommands", () => ({
+  getLibraryRoots: vi.fn(() => Promise.resolve([])),
+  addLibraryRoot: vi.fn(() => Promise.resolve(1)),
+  removeLibraryRoot: vi.fn(() => Promise.resolve()),
+  rescanLibrary: vi.fn(() =>
+    Promise.resolve({ filesFound: 0, added: 0, updated: 0, missing: 0, failed: [] }),
+  ),
+}));
+
+// Matches case-insensitively only; the backend also folds character width and kana.
+vi.mock("../../bindings/SearchCommands", () => ({
+  filterBySearch: vi.fn((query: string, names: string[]) => {
+    const keywords = query.toLowerCase().split(/\s+/).filter(Boolean);
+    return Promise.resolve(
+      names.flatMap((name, index) =>
+        keywords.every((keyword) => name.toLowerCase().includes(keyword)) ? [index] : [],
+      ),
+    );
+  }),
+}));
+
 vi.mock("../../bindings/SeriesCommands", () => ({
   createSeries: vi.fn(() => Promise.resolve([])),
   getAllSeries: vi.fn(() => Promise.resolve([])),
   deleteSeries: vi.fn(() => Promise.resolve([])),
 }));
 
+vi.mock("../../bindings/StatisticsCommands", () => ({
+  getReadingStatistics: vi.fn(() =>
+    Promise.resolve({
+      total_seconds_read: 0,
+      total_pages_read: 0,
+      total_books_finished: 0,
+      current_streak_days: 0,
+      longest_streak_days: 0,
+      daily: [],
+      monthly_finished: [],
+      series: [],
+    }),
+  ),
+  getReadingSessions: vi.fn(() => Promise.resolve([])),
+}));
+
 vi.mock("../../bindings/TagCommands", () => ({
   createTag: vi.fn(() => Promise.resolve([])),
   getAllTags: vi.fn(() => Promise.resolve([])),
   deleteTag: vi.fn(() => Promise.resolve([])),
 }));
+
+vi.mock("../../bindings/VerificationCommands", () => ({
+  verifyBook: vi.fn(() => Promise.resolve([])),
+  verifyLibrary: vi.fn(() => Promise.resolve([])),
+  cancelVerification: vi.fn(() => Promise.resolve()),
+}));
@@ -63,6 +63,7 @@ export type {
   AppTheme,
   AutoOpenAdjacentBookMode,
   Direction,
+  EntrySortMode,
   ImageResamplingMethod_Serialize as ImageResamplingMethod,
   InitialView,
   LogLevel,
@@ -71,6 +72,7 @@ export type {
 
 import type {
   AutoOpenAdjacentBookMode,
+  EntrySortMode,
   ImageResamplingMethod_Serialize,
 } from "../bindings/bindings";
 
@@ -91,3 +93,11 @@ export const autoOpenAdjacentBookModes = [
   "ask",
   "auto",
 ] as const satisfies readonly AutoOpenAdjacentBookMode[];
+
+/** The orders the pages of a book can be sorted in (UI dropdown order). */
+export const entrySortModes = [
+  "natural",
+  "archiveOrder",
+  "modifiedTime",
+  "coverFirst",
+] as const satisfies readonly EntrySortMode[];
@@ -8,4 +8,6 @@ export type DirEntry = {
   name: string;
   /** Last modified time as a raw timestamp (ms), as sent by the backend. */
   last_modified: number;
+  /** The key the name sorts by in natural order, as sent by the backend. */
+  sort_key: string;
 };
@@ -9,14 +9,18 @@ describe("DirEntryUtils", () => {
     // nameLen: 4 bytes
     // name: nameLen bytes
     // last_modified: 8 bytes
+    // keyLen: 4 bytes
+    // sort_key: keyLen bytes
 
     const name1 = "dir1";
     const name2 = "file1.txt";
     const name1Encoded = new TextEncoder().encode(name1);
     const name2Encoded = new TextEncoder().encode(name2);
+    const key1Encoded = new TextEncoder().encode("key1");
+    const key2Encoded = new TextEncoder().encode("key2");
 
-    const size1 = 1 + 4 + name1Encoded.length + 8;
-    const size2 = 1 + 4 + name2Encoded.length + 8;
+    const size1 = 1 + 4 + name1Encoded.length + 8 + 4 + key1Encoded.length;
+    const size2 = 1 + 4 + name2Encoded.length + 8 + 4 + key2Encoded.length;
     const buffer = new Uint8Array(size1 + size2);
     const view = new DataView(buffer.buffer);
 
@@ -30,6 +34,10 @@ describe("DirEntryUtils", () => {
     offset += name1Encoded.length;
     view.setBigUint64(offset, BigInt(1000));
     offset += 8;
+    view.setUint32(offset, key1Encoded.length);
+    offset += 4;
+    buffer.set(key1Encoded, offset);
+    offset += key1Encoded.length;
 
     // Entry 2: file
     view.setUint8(offset++, 0);
@@ -39,6 +47,10 @@ describe("DirEntryUtils", () => {
     offset += name2Encoded.length;
     view.setBigUint64(offset, BigInt(2000));
     offset += 8;
+    view.setUint32(offset, key2Encoded.length);
+    offset += 4;
+    buffer.set(key2Encoded, offset);
+    offset += key2Encoded.length;
 
     const result = convertEntriesInDir(buffer.buffer);
 
@@ -47,11 +59,13 @@ describe("DirEntryUtils", () => {
       is_directory: true,
       name: "dir1",
       last_modified: 1000,
+      sort_key: "key1",
     });
     expect(result[1]).toEqual({
       is_directory: false,
       name: "file1.txt",
       last_modified: 2000,
+      sort_key: "key2",
     });
   });
 
@@ -8,6 +8,8 @@ import type { DirEntry } from "../types/DirEntry";
  * - nameLen: 4 bytes (length of the name string)
  * - name: nameLen bytes (UTF-8 encoded name string)
  * - last_modified: 8 bytes (Unix timestamp as a 64-bit integer)
+ * - keyLen: 4 bytes (length of the sort key string)
+ * - sort_key: keyLen bytes (ASCII natural sort key of the name)
  *
  * @param entriesData - The ArrayBuffer containing the serialized directory entry data.
  * @returns A promise that resolves to an array of DirEntry objects.
@@ -45,7 +47,20 @@ export const convertEntriesInDir = (entriesData: ArrayBuffer) => {
     const last_modified = Number(timestamp);
     offset += 8;
 
-    entries.push({ is_directory, name, last_modified });
+    // sort_key (keyLen: 4 bytes + key data)
+    if (offset + 4 > buffer.length) {
+      break;
+    }
+    const keyLen = view.getUint32(offset);
+    offset += 4;
+
+    if (offset + keyLen > buffer.length) {
+      break;
+    }
+    const sort_key = decoder.decode(buffer.subarray(offset, offset + keyLen));
+    offset += keyLen;
+
+    entries.push({ is_directory, name, last_modified, sort_key });
   }
   return entries;
 };
@@ -0,0 +1,23 @@
+import { describe, expect, it } from "vitest";
+import { compareSortKeys } from "./NaturalSortUtils";
+
+describe("compareSortKeys", () => {
+  // The keys the backend computes for "2.jpg" and "10.jpg" (see `natural_sort::sort_key`)
+  const key2 = "0012200002e200006a20000702000067 00003200002e00006a000070000067";
+  const key10 = "00210200002e200006a20000702000067 00003100003000002e00006a000070000067";
+
+  it("orders keys as plain strings", () => {
+    expect(compareSortKeys(key2, key10)).toBeLessThan(0);
+    expect(compareSortKeys(key10, key2)).toBeGreaterThan(0);
+    expect(compareSortKeys(key2, key2)).toBe(0);
+  });
+
+  it("sorts names by their keys instead of their text", () => {
+    const names = [
+      { name: "10.jpg", sort_key: key10 },
+      { name: "2.jpg", sort_key: key2 },
+    ];
+    names.sort((a, b) => compareSortKeys(a.sort_key, b.sort_key));
+    expect(names.map((n) => n.name)).toEqual(["2.jpg", "10.jpg"]);
+  });
+});
@@ -0,0 +1,17 @@
+/**
+ * Compares two natural sort keys computed by the backend (`sort_key` of books, series and
+ * directory entries).
+ *
+ * The backend derives the keys from its Japanese-aware natural order, which reads digit
+ * runs and kanji numerals by value and orders the volume markers `上`/`中`/`下` by meaning.
+ * The keys are ASCII, so comparing them as plain strings orders names exactly as the
+ * backend does.
+ *
+ * @param a - The sort key of the first name.
+ * @param b - The sort key of the second name.
+ * @returns A negative number if `a` comes first, positive if `b` does, 0 if equal.
+ *
+ * @example
+ * books.sort((a, b) => compareSortKeys(a.sort_key, b.sort_key));
+ */
+export const compareSortKeys = (a: string, b: string) => (a < b ? -1 : a > b ? 1 : 0);
@@ -1,4 +1,5 @@
-import { describe, expect, it } from "vitest";
+import { describe, expect, it, vi } from "vitest";
+import { filterBySearch } from "../bindings/SearchCommands";
 import { andSearchBy } from "./SearchUtils";
 
 interface Named {
@@ -14,27 +15,28 @@ describe("andSearchBy", () => {
   ];
   const getName = (item: Named) => item.name;
 
-  it("returns the original array for an empty or whitespace-only query", () => {
-    expect(andSearchBy(items, "", getName)).toBe(items);
-    expect(andSearchBy(items, "   ", getName)).toBe(items);
+  it("returns the original array for an empty or whitespace-only query", async () => {
+    expect(await andSearchBy(items, "", getName)).toBe(items);
+    expect(await andSearchBy(items, "   ", getName)).toBe(items);
+    expect(filterBySearch).not.toHaveBeenCalled();
   });
 
-  it("matches only items containing every keyword (AND search)", () => {
-    const result = andSearchBy(items, "test file", getName);
-    expect(result.map(getName)).toEqual(["test_file", "another test file"]);
-  });
+  it("passes the names to the backend and returns the items it matched", async () => {
+    vi.mocked(filterBySearch).mockResolvedValueOnce([1, 3]);
 
-  it("is case-insensitive", () => {
-    const result = andSearchBy(items, "TEST", getName);
-    expect(result.map(getName)).toEqual(["Test Dir", "test_file", "another test file"]);
-  });
+    const result = await andSearchBy(items, "test file", getName);
 
-  it("returns an empty array when no item matches", () => {
-    expect(andSearchBy(items, "missing", getName)).toEqual([]);
+    expect(filterBySearch).toHaveBeenCalledWith("test file", [
+      "Test Dir",
+      "test_file",
+      "Example",
+      "another test file",
+    ]);
+    expect(result.map(getName)).toEqual(["test_file", "another test file"]);
   });
 
-  it("splits on full-width spaces as well", () => {
-    const result = andSearchBy(items, "test　file", getName);
-    expect(result.map(getName)).toEqual(["test_file", "another test file"]);
+  it("returns an empty array when nothing matches", async () => {
+    vi.mocked(filterBySearch).mockResolvedValueOnce([]);
+    expect(await andSearchBy(items, "nothing", getName)).toEqual([]);
   });
 });
@@ -1,27 +1,31 @@
+import { filterBySearch } from "../bindings/SearchCommands";
+
 /**
- * Filters items whose name contains every whitespace-separated keyword of
- * `query` (case-insensitive AND search). Whitespace includes full-width spaces
- * (via `\s`). Returns the original array when the query is empty or blank.
+ * Filters items whose name contains every whitespace-separated keyword of `query`
+ * (AND search).
+ *
+ * The matching is done by the backend, which compares names and keywords after the same
+ * normalization as the book search, so case, character width and kana differences are
+ * ignored. Whitespace includes full-width spaces. Resolves to the original array without
+ * calling the backend when the query is empty or blank.
  *
  * @param items - The items to filter.
  * @param query - The user's input string containing one or more keywords.
  * @param getName - Extracts the searchable name from an item.
- * @returns A new array of items whose name matches every keyword.
+ * @returns A promise that resolves to the items whose name matches every keyword.
+ * @throws {CommandError} If the backend command fails.
  *
  * @example
- * andSearchBy(books, "test file", (b) => b.display_name);
+ * const matches = await andSearchBy(books, "test file", (b) => b.display_name);
  */
-export const andSearchBy = <T>(items: T[], query: string, getName: (item: T) => string): T[] => {
-  const keywords = query
-    .trim()
-    .toLowerCase()
-    .split(/\s+/)
-    .filter((keyword) => keyword.length > 0);
-  if (keywords.length === 0) {
+export const andSearchBy = async <T>(
+  items: T[],
+  query: string,
+  getName: (item: T) => string,
+): Promise<T[]> => {
+  if (query.trim().length === 0) {
     return items;
   }
-  return items.filter((item) => {
-    const name = getName(item).toLowerCase();
-    return keywords.every((keyword) => name.includes(keyword));
-  });
+  const indices = await filterBySearch(query, items.map(getName));
+  return indices.map((index) => items[index]);
 };